@@ -0,0 +1,169 @@
+use txml::{writer::Writer, FromXml, ToXml};
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+pub struct Protocol {
+    #[xml(attr = "name")]
+    pub name: String,
+    #[xml(child = "copyright")]
+    pub copyright: Option<String>,
+    #[xml(child = "interface")]
+    pub interfaces: Vec<Interface>,
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+#[xml(deny_unknown)]
+pub struct Interface {
+    #[xml(attr = "name")]
+    pub name: String,
+    #[xml(attr = "version")]
+    pub version: u32,
+    #[xml(child = "description")]
+    pub description: Description,
+    #[xml(child = "request")]
+    pub requests: Vec<Message>,
+    #[xml(child = "event")]
+    pub events: Vec<Message>,
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+pub struct Message {
+    #[xml(attr = "name")]
+    pub name: String,
+    #[xml(attr = "since", default = "one")]
+    pub since: u32,
+    #[xml(attr = "deprecated-since")]
+    pub deprecated_since: Option<u32>,
+    #[xml(child = "arg")]
+    pub args: Vec<Arg>,
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+pub struct Arg {
+    #[xml(attr = "name")]
+    pub name: String,
+    #[xml(attr = "type")]
+    pub kind: String,
+    #[xml(attr = "allow-null", default)]
+    pub allow_null: bool,
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+pub struct Description {
+    #[xml(attr = "summary")]
+    pub summary: String,
+    #[xml(text)]
+    pub body: String,
+}
+
+fn one() -> u32 {
+    1
+}
+
+fn main() {
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<protocol name="test_protocol">
+  <copyright>Test Copyright</copyright>
+  <interface name="test_interface" version="2">
+    <description summary="Test interface">Interface &amp; description.</description>
+    <request name="test_request" since="2" deprecated-since="3">
+      <arg name="id" type="new_id"/>
+      <arg name="text" type="string" allow-null="true"/>
+    </request>
+    <event name="test_event">
+      <arg name="value" type="uint"/>
+    </event>
+  </interface>
+  <x-extension/>
+</protocol>"#;
+
+    const RESULT: &str = r#"Protocol {
+    name: "test_protocol",
+    copyright: Some(
+        "Test Copyright",
+    ),
+    interfaces: [
+        Interface {
+            name: "test_interface",
+            version: 2,
+            description: Description {
+                summary: "Test interface",
+                body: "Interface & description.",
+            },
+            requests: [
+                Message {
+                    name: "test_request",
+                    since: 2,
+                    deprecated_since: Some(
+                        3,
+                    ),
+                    args: [
+                        Arg {
+                            name: "id",
+                            kind: "new_id",
+                            allow_null: false,
+                        },
+                        Arg {
+                            name: "text",
+                            kind: "string",
+                            allow_null: true,
+                        },
+                    ],
+                },
+            ],
+            events: [
+                Message {
+                    name: "test_event",
+                    since: 1,
+                    deprecated_since: None,
+                    args: [
+                        Arg {
+                            name: "value",
+                            kind: "uint",
+                            allow_null: false,
+                        },
+                    ],
+                },
+            ],
+        },
+    ],
+}"#;
+
+    let protocol: Protocol = txml::convert::from_str(XML).unwrap();
+    assert_eq!(format!("{:#?}", protocol), RESULT);
+
+    let mut protocol = protocol;
+    protocol.interfaces[0].events[0].args.push(Arg {
+        name: "a < b".to_string(),
+        kind: "int".to_string(),
+        allow_null: false,
+    });
+    let mut writer = Writer::new(String::new());
+    protocol.interfaces[0].events[0]
+        .to_xml("event", &mut writer)
+        .unwrap();
+    assert_eq!(
+        writer.into_inner(),
+        concat!(
+            r#"<event name="test_event" since="1">"#,
+            r#"<arg name="value" type="uint" allow-null="false"/>"#,
+            r#"<arg name="a &lt; b" type="int" allow-null="false"/>"#,
+            "</event>",
+        )
+    );
+
+    let mut writer = Writer::new(String::new());
+    protocol.to_xml("protocol", &mut writer).unwrap();
+    let xml = writer.into_inner();
+    assert_eq!(txml::convert::from_str::<Protocol>(&xml).unwrap(), protocol);
+
+    let err = txml::convert::from_str::<Interface>(r#"<interface name="a" version="x"/>"#);
+    assert_eq!(
+        err.unwrap_err().to_string(),
+        r#"invalid value "x" for attribute `version`: invalid digit found in string"#
+    );
+    let err = txml::convert::from_str::<Interface>(r#"<interface name="a" verison="1"/>"#);
+    assert_eq!(
+        err.unwrap_err().to_string(),
+        "unexpected attribute `verison`"
+    );
+}
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Protocol<'a> {
+    #[serde(rename = "@name")]
+    pub name: &'a str,
+    pub copyright: Option<String>,
+    #[serde(rename = "interface")]
+    pub interfaces: Vec<Interface<'a>>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Interface<'a> {
+    #[serde(rename = "@name")]
+    pub name: &'a str,
+    #[serde(rename = "@version")]
+    pub version: u32,
+    pub description: Description,
+    #[serde(rename = "$value", default)]
+    pub items: Vec<Item<'a>>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Item<'a> {
+    #[serde(borrow)]
+    Request(Message<'a>),
+    #[serde(borrow)]
+    Event(Message<'a>),
+    Enum {
+        #[serde(rename = "@name")]
+        name: String,
+        #[serde(rename = "entry")]
+        entries: Vec<Entry>,
+    },
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Message<'a> {
+    #[serde(rename = "@name")]
+    pub name: &'a str,
+    #[serde(rename = "@type")]
+    pub kind: Option<MessageKind>,
+    #[serde(rename = "@since", default = "one")]
+    pub since: u32,
+    #[serde(rename = "arg", default)]
+    pub args: Vec<Arg<'a>>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageKind {
+    Destructor,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Arg<'a> {
+    #[serde(rename = "@name")]
+    pub name: &'a str,
+    #[serde(rename = "@type")]
+    pub kind: &'a str,
+    #[serde(rename = "@allow-null", default)]
+    pub allow_null: bool,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Entry {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@value")]
+    pub value: u32,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Description {
+    #[serde(rename = "@summary")]
+    pub summary: String,
+    #[serde(rename = "$text", default)]
+    pub body: String,
+}
+
+fn one() -> u32 {
+    1
+}
+
+fn main() {
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<protocol name="test_protocol">
+  <copyright>Test &amp; Copyright</copyright>
+  <interface name="test_interface" version="2">
+    <description summary="Test interface">Interface description.</description>
+    <request name="test_request" since="2">
+      <arg name="id" type="new_id"/>
+      <arg name="text" type="string" allow-null="true"/>
+    </request>
+    <!-- comments are ignored -->
+    <event name="test_event">
+      <arg name="value" type="uint"/>
+    </event>
+    <request name="destroy" type="destructor"/>
+    <enum name="test_enum">
+      <entry name="one" value="1"/>
+    </enum>
+  </interface>
+</protocol>"#;
+
+    const RESULT: &str = r#"Protocol {
+    name: "test_protocol",
+    copyright: Some(
+        "Test & Copyright",
+    ),
+    interfaces: [
+        Interface {
+            name: "test_interface",
+            version: 2,
+            description: Description {
+                summary: "Test interface",
+                body: "Interface description.",
+            },
+            items: [
+                Request(
+                    Message {
+                        name: "test_request",
+                        kind: None,
+                        since: 2,
+                        args: [
+                            Arg {
+                                name: "id",
+                                kind: "new_id",
+                                allow_null: false,
+                            },
+                            Arg {
+                                name: "text",
+                                kind: "string",
+                                allow_null: true,
+                            },
+                        ],
+                    },
+                ),
+                Event(
+                    Message {
+                        name: "test_event",
+                        kind: None,
+                        since: 1,
+                        args: [
+                            Arg {
+                                name: "value",
+                                kind: "uint",
+                                allow_null: false,
+                            },
+                        ],
+                    },
+                ),
+                Request(
+                    Message {
+                        name: "destroy",
+                        kind: Some(
+                            Destructor,
+                        ),
+                        since: 1,
+                        args: [],
+                    },
+                ),
+                Enum {
+                    name: "test_enum",
+                    entries: [
+                        Entry {
+                            name: "one",
+                            value: 1,
+                        },
+                    ],
+                },
+            ],
+        },
+    ],
+}"#;
+
+    let protocol: Protocol = txml::de::from_str(XML).unwrap();
+    assert_eq!(format!("{:#?}", protocol), RESULT);
+
+    let xml = txml::ser::to_string(&protocol.interfaces[0].items[2], "item").unwrap();
+    assert_eq!(
+        xml,
+        r#"<request name="destroy" type="destructor" since="1"/>"#
+    );
+
+    let xml = txml::ser::to_string(&protocol, "protocol").unwrap();
+    assert_eq!(txml::de::from_str::<Protocol>(&xml).unwrap(), protocol);
+
+    let err = txml::de::from_str::<Entry>(r#"<entry name="a" value="x"/>"#);
+    assert_eq!(
+        err.unwrap_err().to_string(),
+        "invalid digit found in string"
+    );
+}
@@ -0,0 +1,27 @@
+use txml::vulkan::{self, Category};
+
+fn main() {
+    let registry = vulkan::parse(include_str!("../fixtures/vulkan/vk.xml")).unwrap();
+
+    for ty in &registry.types {
+        if ty.category == Some(Category::Struct) {
+            println!("struct {} {{", ty.name);
+            for member in &ty.members {
+                println!("    {};", member.code);
+            }
+            println!("}}");
+        }
+    }
+    for command in &registry.commands {
+        let params: Vec<_> = command.params.iter().map(|p| &p.code[..]).collect();
+        println!(
+            "{} {}({});",
+            command.return_type,
+            command.name,
+            params.join(", ")
+        );
+    }
+    for extension in &registry.extensions {
+        println!("extension {} ({})", extension.name, extension.number);
+    }
+}
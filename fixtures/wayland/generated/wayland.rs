@@ -0,0 +1,6978 @@
+// Generated by txml from the `wayland` protocol.
+//
+// Copyright © 2008-2011 Kristian Høgsberg
+// Copyright © 2010-2011 Intel Corporation
+// Copyright © 2012-2013 Collabora, Ltd.
+//
+// Permission is hereby granted, free of charge, to any person
+// obtaining a copy of this software and associated documentation files
+// (the "Software"), to deal in the Software without restriction,
+// including without limitation the rights to use, copy, modify, merge,
+// publish, distribute, sublicense, and/or sell copies of the Software,
+// and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice (including the
+// next paragraph) shall be included in all copies or substantial
+// portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS
+// BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN
+// ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// The ID of an object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectId(pub u32);
+
+/// The ID of an object created by a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NewId(pub u32);
+
+/// An object created by a message whose interface isn't known in advance.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UntypedNewId {
+    /// The name of the interface.
+    pub interface: ::std::string::String,
+    /// The version of the interface.
+    pub version: u32,
+    /// The ID of the object.
+    pub id: NewId,
+}
+
+/// A signed 24.8 fixed-point number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fixed(pub i32);
+
+impl Fixed {
+    /// Converts a number to fixed-point, rounding to the nearest 1/256.
+    pub fn from_f64(value: f64) -> Self {
+        Fixed((value * 256.0).round() as i32)
+    }
+
+    /// Converts the number to floating-point.
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.0) / 256.0
+    }
+}
+
+/// An entry of an opcode table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageDesc {
+    /// The name of the message.
+    pub name: &'static str,
+    /// The interface version that added the message.
+    pub since: u32,
+    /// The types of the arguments, in the format used by libwayland.
+    pub signature: &'static str,
+    /// The interface of each argument, for `object` and `new_id` arguments.
+    pub types: &'static [::core::option::Option<&'static str>],
+}
+
+/// core global object
+///
+/// The core global object.  This is a special singleton object.  It
+/// is used for internal Wayland protocol features.
+pub mod wl_display {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_display";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 1;
+
+    /// A `wl_display` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlDisplay(pub super::ObjectId);
+
+    impl WlDisplay {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "sync",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_callback")],
+        },
+        super::MessageDesc {
+            name: "get_registry",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_registry")],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "error",
+            since: 1,
+            signature: "ous",
+            types: &[None, None, None],
+        },
+        super::MessageDesc {
+            name: "delete_id",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// asynchronous roundtrip
+        ///
+        /// The sync request asks the server to emit the 'done' event
+        /// on the returned wl_callback object.  Since requests are
+        /// handled in-order and events are delivered in-order, this can
+        /// be used as a barrier to ensure all previous requests and the
+        /// resulting events have been handled.
+        ///
+        /// The object returned by this request will be destroyed by the
+        /// compositor after the callback is fired and as such the client must not
+        /// attempt to use it after that point.
+        ///
+        /// The callback_data passed in the callback is undefined and should be ignored.
+        Sync {
+            /// callback object for the sync request
+            callback: super::NewId,
+        },
+        /// get global registry object
+        ///
+        /// This request creates a registry object that allows the client
+        /// to list and bind the global objects available from the
+        /// compositor.
+        ///
+        /// It should be noted that the server side resources consumed in
+        /// response to a get_registry request can only be released when the
+        /// client disconnects, not when the client side proxy is destroyed.
+        /// Therefore, clients should invoke get_registry as infrequently as
+        /// possible to avoid wasting memory.
+        GetRegistry {
+            /// global registry object
+            registry: super::NewId,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Sync { .. } => 0,
+                Request::GetRegistry { .. } => 1,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Sync { .. } => 1,
+                Request::GetRegistry { .. } => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// fatal error event
+        ///
+        /// The error event is sent out when a fatal (non-recoverable)
+        /// error has occurred.  The object_id argument is the object
+        /// where the error occurred, most often in response to a request
+        /// to that object.  The code identifies the error and is defined
+        /// by the object interface.  As such, each interface defines its
+        /// own set of error codes.  The message is a brief description
+        /// of the error, for (debugging) convenience.
+        Error {
+            /// object where the error occurred
+            object_id: super::ObjectId,
+            /// error code
+            code: u32,
+            /// error description
+            message: ::std::string::String,
+        },
+        /// acknowledge object ID deletion
+        ///
+        /// This event is used internally by the object ID management logic.
+        ///
+        /// When the server stops using an object created by the client, the server
+        /// sends this event. In particular, after sending this event, the server
+        /// will no longer send any events that contain the object as the receiver
+        /// or as an argument.
+        ///
+        /// When the client receives this event, it knows that it can reuse the
+        /// object ID.
+        DeleteId {
+            /// deleted object ID
+            id: u32,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Error { .. } => 0,
+                Event::DeleteId { .. } => 1,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Error { .. } => 1,
+                Event::DeleteId { .. } => 1,
+            }
+        }
+    }
+
+    /// global error values
+    ///
+    /// These errors are global and can be emitted in response to any
+    /// server request.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// server couldn't find object
+        InvalidObject = 0,
+        /// method doesn't exist on the specified interface or malformed request
+        InvalidMethod = 1,
+        /// server is out of memory
+        NoMemory = 2,
+        /// implementation error in compositor
+        Implementation = 3,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidObject),
+                1 => Some(Error::InvalidMethod),
+                2 => Some(Error::NoMemory),
+                3 => Some(Error::Implementation),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// global registry object
+///
+/// The singleton global registry object.  The server has a number of
+/// global objects that are available to all clients.  These objects
+/// typically represent an actual object in the server (for example,
+/// an input device) or they are singleton objects that provide
+/// extension functionality.
+///
+/// When a client creates a registry object, the registry object
+/// will emit a global event for each global currently in the
+/// registry.  Globals come and go as a result of device or
+/// monitor hotplugs, reconfiguration or other events, and the
+/// registry will send out global and global_remove events to
+/// keep the client up to date with the changes.  To mark the end
+/// of the initial burst of events, the client can use the
+/// wl_display.sync request immediately after calling
+/// wl_display.get_registry.
+///
+/// A client can bind to a global object by using the bind
+/// request.  This creates a client-side handle that lets the object
+/// emit events to the client and lets the client invoke requests on
+/// the object.
+pub mod wl_registry {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_registry";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 1;
+
+    /// A `wl_registry` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlRegistry(pub super::ObjectId);
+
+    impl WlRegistry {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "bind",
+            since: 1,
+            signature: "usun",
+            types: &[None, None, None, None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "global",
+            since: 1,
+            signature: "usu",
+            types: &[None, None, None],
+        },
+        super::MessageDesc {
+            name: "global_remove",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// bind an object to the display
+        ///
+        /// Binds a new, client-created object to the server using the
+        /// specified name as the identifier.
+        Bind {
+            /// unique numeric name of the object
+            name: u32,
+            /// bound object
+            id: super::UntypedNewId,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Bind { .. } => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Bind { .. } => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// announce global object
+        ///
+        /// Notify the client of global objects.
+        ///
+        /// The event notifies the client that a global object with
+        /// the given name is now available, and it implements the
+        /// given version of the given interface.
+        Global {
+            /// numeric name of the global object
+            name: u32,
+            /// interface implemented by the object
+            interface: ::std::string::String,
+            /// interface version
+            version: u32,
+        },
+        /// announce removal of global object
+        ///
+        /// Notify the client of removed global objects.
+        ///
+        /// This event notifies the client that the global identified
+        /// by name is no longer available.  If the client bound to
+        /// the global using the bind request, the client should now
+        /// destroy that object.
+        ///
+        /// The object remains valid and requests to the object will be
+        /// ignored until the client destroys it, to avoid races between
+        /// the global going away and a client sending a request to it.
+        GlobalRemove {
+            /// numeric name of the global object
+            name: u32,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Global { .. } => 0,
+                Event::GlobalRemove { .. } => 1,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Global { .. } => 1,
+                Event::GlobalRemove { .. } => 1,
+            }
+        }
+    }
+}
+
+/// callback object
+///
+/// Clients can handle the 'done' event to get notified when
+/// the related request is done.
+///
+/// Note, because wl_callback objects are created from multiple independent
+/// factory interfaces, the wl_callback interface is frozen at version 1.
+pub mod wl_callback {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_callback";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 1;
+
+    /// A `wl_callback` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlCallback(pub super::ObjectId);
+
+    impl WlCallback {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "done",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// done event
+        ///
+        /// Notify the client when the related request is done.
+        Done {
+            /// request-specific data for the callback
+            callback_data: u32,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Done { .. } => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Done { .. } => 1,
+            }
+        }
+    }
+}
+
+/// the compositor singleton
+///
+/// A compositor.  This object is a singleton global.  The
+/// compositor is in charge of combining the contents of multiple
+/// surfaces into one displayable output.
+pub mod wl_compositor {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_compositor";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 7;
+
+    /// A `wl_compositor` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlCompositor(pub super::ObjectId);
+
+    impl WlCompositor {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "create_surface",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_surface")],
+        },
+        super::MessageDesc {
+            name: "create_region",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_region")],
+        },
+        super::MessageDesc {
+            name: "release",
+            since: 7,
+            signature: "7",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// create new surface
+        ///
+        /// Ask the compositor to create a new surface.
+        CreateSurface {
+            /// the new surface
+            id: super::NewId,
+        },
+        /// create new region
+        ///
+        /// Ask the compositor to create a new region.
+        CreateRegion {
+            /// the new region
+            id: super::NewId,
+        },
+        /// destroy wl_compositor
+        ///
+        /// This request destroys the wl_compositor. This has no effect on any other objects.
+        ///
+        /// Added in version 7.
+        Release,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::CreateSurface { .. } => 0,
+                Request::CreateRegion { .. } => 1,
+                Request::Release => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::CreateSurface { .. } => 1,
+                Request::CreateRegion { .. } => 1,
+                Request::Release => 7,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+}
+
+/// a shared memory pool
+///
+/// The wl_shm_pool object encapsulates a piece of memory shared
+/// between the compositor and client.  Through the wl_shm_pool
+/// object, the client can allocate shared memory wl_buffer objects.
+/// All objects created through the same pool share the same
+/// underlying mapped memory. Reusing the mapped memory avoids the
+/// setup/teardown overhead and is useful when interactively resizing
+/// a surface or for many small buffers.
+pub mod wl_shm_pool {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_shm_pool";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 3;
+
+    /// A `wl_shm_pool` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlShmPool(pub super::ObjectId);
+
+    impl WlShmPool {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "create_buffer",
+            since: 1,
+            signature: "niiiiu",
+            types: &[Some("wl_buffer"), None, None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "resize",
+            since: 1,
+            signature: "i",
+            types: &[None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// create a buffer from the pool
+        ///
+        /// Create a wl_buffer object from the pool.
+        ///
+        /// The buffer is created offset bytes into the pool and has
+        /// width and height as specified.  The stride argument specifies
+        /// the number of bytes from the beginning of one row to the beginning
+        /// of the next.  The format is the pixel format of the buffer and
+        /// must be one of those advertised through the wl_shm.format event.
+        ///
+        /// A buffer will keep a reference to the pool it was created from
+        /// so it is valid to destroy the pool immediately after creating
+        /// a buffer from it.
+        CreateBuffer {
+            /// buffer to create
+            id: super::NewId,
+            /// buffer byte offset within the pool
+            offset: i32,
+            /// buffer width, in pixels
+            width: i32,
+            /// buffer height, in pixels
+            height: i32,
+            /// number of bytes from the beginning of one row to the beginning of the next row
+            stride: i32,
+            /// buffer pixel format
+            format: super::wl_shm::Format,
+        },
+        /// destroy the pool
+        ///
+        /// Destroy the shared memory pool.
+        ///
+        /// The mmapped memory will be released when all
+        /// buffers that have been created from this pool
+        /// are gone.
+        Destroy,
+        /// change the size of the pool mapping
+        ///
+        /// This request will cause the server to remap the backing memory
+        /// for the pool from the file descriptor passed when the pool was
+        /// created, but using the new size.  This request can only be
+        /// used to make the pool bigger.
+        ///
+        /// This request only changes the amount of bytes that are mmapped
+        /// by the server and does not touch the file corresponding to the
+        /// file descriptor passed at creation time. It is the client's
+        /// responsibility to ensure that the file is at least as big as
+        /// the new pool size.
+        Resize {
+            /// new size of the pool, in bytes
+            size: i32,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::CreateBuffer { .. } => 0,
+                Request::Destroy => 1,
+                Request::Resize { .. } => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::CreateBuffer { .. } => 1,
+                Request::Destroy => 1,
+                Request::Resize { .. } => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+
+    /// wl_shm_pool error values
+    ///
+    /// These errors can be emitted in response to wl_shm_pool requests.
+    ///
+    /// Added in version 3.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// buffer format is not known
+        InvalidFormat = 0,
+        /// invalid size or stride during buffer creation
+        InvalidStride = 1,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidFormat),
+                1 => Some(Error::InvalidStride),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// shared memory support
+///
+/// A singleton global object that provides support for shared
+/// memory.
+///
+/// Clients can create wl_shm_pool objects using the create_pool
+/// request.
+///
+/// On binding the wl_shm object one or more format events
+/// are emitted to inform clients about the valid pixel formats
+/// that can be used for buffers.
+pub mod wl_shm {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_shm";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 3;
+
+    /// A `wl_shm` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlShm(pub super::ObjectId);
+
+    impl WlShm {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "create_pool",
+            since: 1,
+            signature: "nhi",
+            types: &[Some("wl_shm_pool"), None, None],
+        },
+        super::MessageDesc {
+            name: "release",
+            since: 2,
+            signature: "2",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "format",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// create a shm pool
+        ///
+        /// Create a new wl_shm_pool object.
+        ///
+        /// The pool can be used to create shared memory based buffer
+        /// objects.  The server will mmap size bytes of the passed file
+        /// descriptor, to use as backing memory for the pool.
+        CreatePool {
+            /// pool to create
+            id: super::NewId,
+            /// file descriptor for the pool
+            fd: ::std::os::unix::io::OwnedFd,
+            /// pool size, in bytes
+            size: i32,
+        },
+        /// release the shm object
+        ///
+        /// Using this request a client can tell the server that it is not going to
+        /// use the shm object anymore.
+        ///
+        /// Objects created via this interface remain unaffected.
+        ///
+        /// Added in version 2.
+        Release,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::CreatePool { .. } => 0,
+                Request::Release => 1,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::CreatePool { .. } => 1,
+                Request::Release => 2,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// pixel format description
+        ///
+        /// Informs the client about a valid pixel format that
+        /// can be used for buffers. Known formats include
+        /// argb8888 and xrgb8888.
+        ///
+        /// Extensions to drm_fourcc.h (or the format enum) do not require
+        /// increasing the wl_shm version; as a result, clients may receive format
+        /// codes which were not in the list at the time the client was made.
+        Format {
+            /// buffer pixel format
+            format: Format,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Format { .. } => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Format { .. } => 1,
+            }
+        }
+    }
+
+    /// wl_shm error values
+    ///
+    /// These errors can be emitted in response to wl_shm requests.
+    ///
+    /// Added in version 3.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// buffer format is not known
+        ///
+        /// Deprecated since version 3.
+        InvalidFormat = 0,
+        /// invalid size or stride during pool creation
+        InvalidStride = 1,
+        /// mmapping the file descriptor failed
+        InvalidFd = 2,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidFormat),
+                1 => Some(Error::InvalidStride),
+                2 => Some(Error::InvalidFd),
+                _ => None,
+            }
+        }
+    }
+
+    /// pixel formats
+    ///
+    /// This describes the memory layout of an individual pixel.
+    ///
+    /// All renderers should support argb8888 and xrgb8888 but any other
+    /// formats are optional and may not be supported by the particular
+    /// renderer in use.
+    ///
+    /// The drm format codes match the macros defined in drm_fourcc.h, except
+    /// argb8888 and xrgb8888. The formats actually supported by the compositor
+    /// will be reported by the format event. See drm_fourcc.h for more detailed
+    /// format descriptions.
+    ///
+    /// For all wl_shm formats and unless specified in another protocol
+    /// extension, pre-multiplied alpha is used for pixel values.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Format {
+        /// 32-bit ARGB format, \[31:0\] A:R:G:B 8:8:8:8 little endian
+        Argb8888 = 0,
+        /// 32-bit RGB format, \[31:0\] x:R:G:B 8:8:8:8 little endian
+        Xrgb8888 = 1,
+        /// 8-bit color index format, \[7:0\] C
+        C8 = 538982467,
+        /// 8-bit RGB format, \[7:0\] R:G:B 3:3:2
+        Rgb332 = 943867730,
+        /// 8-bit BGR format, \[7:0\] B:G:R 2:3:3
+        Bgr233 = 944916290,
+        /// 16-bit xRGB format, \[15:0\] x:R:G:B 4:4:4:4 little endian
+        Xrgb4444 = 842093144,
+        /// 16-bit xBGR format, \[15:0\] x:B:G:R 4:4:4:4 little endian
+        Xbgr4444 = 842089048,
+        /// 16-bit RGBx format, \[15:0\] R:G:B:x 4:4:4:4 little endian
+        Rgbx4444 = 842094674,
+        /// 16-bit BGRx format, \[15:0\] B:G:R:x 4:4:4:4 little endian
+        Bgrx4444 = 842094658,
+        /// 16-bit ARGB format, \[15:0\] A:R:G:B 4:4:4:4 little endian
+        Argb4444 = 842093121,
+        /// 16-bit ABGR format, \[15:0\] A:B:G:R 4:4:4:4 little endian
+        Abgr4444 = 842089025,
+        /// 16-bit RGBA format, \[15:0\] R:G:B:A 4:4:4:4 little endian
+        Rgba4444 = 842088786,
+        /// 16-bit BGRA format, \[15:0\] B:G:R:A 4:4:4:4 little endian
+        Bgra4444 = 842088770,
+        /// 16-bit xRGB format, \[15:0\] x:R:G:B 1:5:5:5 little endian
+        Xrgb1555 = 892424792,
+        /// 16-bit xBGR 1555 format, \[15:0\] x:B:G:R 1:5:5:5 little endian
+        Xbgr1555 = 892420696,
+        /// 16-bit RGBx 5551 format, \[15:0\] R:G:B:x 5:5:5:1 little endian
+        Rgbx5551 = 892426322,
+        /// 16-bit BGRx 5551 format, \[15:0\] B:G:R:x 5:5:5:1 little endian
+        Bgrx5551 = 892426306,
+        /// 16-bit ARGB 1555 format, \[15:0\] A:R:G:B 1:5:5:5 little endian
+        Argb1555 = 892424769,
+        /// 16-bit ABGR 1555 format, \[15:0\] A:B:G:R 1:5:5:5 little endian
+        Abgr1555 = 892420673,
+        /// 16-bit RGBA 5551 format, \[15:0\] R:G:B:A 5:5:5:1 little endian
+        Rgba5551 = 892420434,
+        /// 16-bit BGRA 5551 format, \[15:0\] B:G:R:A 5:5:5:1 little endian
+        Bgra5551 = 892420418,
+        /// 16-bit RGB 565 format, \[15:0\] R:G:B 5:6:5 little endian
+        Rgb565 = 909199186,
+        /// 16-bit BGR 565 format, \[15:0\] B:G:R 5:6:5 little endian
+        Bgr565 = 909199170,
+        /// 24-bit RGB format, \[23:0\] R:G:B little endian
+        Rgb888 = 875710290,
+        /// 24-bit BGR format, \[23:0\] B:G:R little endian
+        Bgr888 = 875710274,
+        /// 32-bit xBGR format, \[31:0\] x:B:G:R 8:8:8:8 little endian
+        Xbgr8888 = 875709016,
+        /// 32-bit RGBx format, \[31:0\] R:G:B:x 8:8:8:8 little endian
+        Rgbx8888 = 875714642,
+        /// 32-bit BGRx format, \[31:0\] B:G:R:x 8:8:8:8 little endian
+        Bgrx8888 = 875714626,
+        /// 32-bit ABGR format, \[31:0\] A:B:G:R 8:8:8:8 little endian
+        Abgr8888 = 875708993,
+        /// 32-bit RGBA format, \[31:0\] R:G:B:A 8:8:8:8 little endian
+        Rgba8888 = 875708754,
+        /// 32-bit BGRA format, \[31:0\] B:G:R:A 8:8:8:8 little endian
+        Bgra8888 = 875708738,
+        /// 32-bit xRGB format, \[31:0\] x:R:G:B 2:10:10:10 little endian
+        Xrgb2101010 = 808669784,
+        /// 32-bit xBGR format, \[31:0\] x:B:G:R 2:10:10:10 little endian
+        Xbgr2101010 = 808665688,
+        /// 32-bit RGBx format, \[31:0\] R:G:B:x 10:10:10:2 little endian
+        Rgbx1010102 = 808671314,
+        /// 32-bit BGRx format, \[31:0\] B:G:R:x 10:10:10:2 little endian
+        Bgrx1010102 = 808671298,
+        /// 32-bit ARGB format, \[31:0\] A:R:G:B 2:10:10:10 little endian
+        Argb2101010 = 808669761,
+        /// 32-bit ABGR format, \[31:0\] A:B:G:R 2:10:10:10 little endian
+        Abgr2101010 = 808665665,
+        /// 32-bit RGBA format, \[31:0\] R:G:B:A 10:10:10:2 little endian
+        Rgba1010102 = 808665426,
+        /// 32-bit BGRA format, \[31:0\] B:G:R:A 10:10:10:2 little endian
+        Bgra1010102 = 808665410,
+        /// packed YCbCr format, \[31:0\] Cr0:Y1:Cb0:Y0 8:8:8:8 little endian
+        Yuyv = 1448695129,
+        /// packed YCbCr format, \[31:0\] Cb0:Y1:Cr0:Y0 8:8:8:8 little endian
+        Yvyu = 1431918169,
+        /// packed YCbCr format, \[31:0\] Y1:Cr0:Y0:Cb0 8:8:8:8 little endian
+        Uyvy = 1498831189,
+        /// packed YCbCr format, \[31:0\] Y1:Cb0:Y0:Cr0 8:8:8:8 little endian
+        Vyuy = 1498765654,
+        /// packed AYCbCr format, \[31:0\] A:Y:Cb:Cr 8:8:8:8 little endian
+        Ayuv = 1448433985,
+        /// 2 plane YCbCr Cr:Cb format, 2x2 subsampled Cr:Cb plane
+        Nv12 = 842094158,
+        /// 2 plane YCbCr Cb:Cr format, 2x2 subsampled Cb:Cr plane
+        Nv21 = 825382478,
+        /// 2 plane YCbCr Cr:Cb format, 2x1 subsampled Cr:Cb plane
+        Nv16 = 909203022,
+        /// 2 plane YCbCr Cb:Cr format, 2x1 subsampled Cb:Cr plane
+        Nv61 = 825644622,
+        /// 3 plane YCbCr format, 4x4 subsampled Cb (1) and Cr (2) planes
+        Yuv410 = 961959257,
+        /// 3 plane YCbCr format, 4x4 subsampled Cr (1) and Cb (2) planes
+        Yvu410 = 961893977,
+        /// 3 plane YCbCr format, 4x1 subsampled Cb (1) and Cr (2) planes
+        Yuv411 = 825316697,
+        /// 3 plane YCbCr format, 4x1 subsampled Cr (1) and Cb (2) planes
+        Yvu411 = 825316953,
+        /// 3 plane YCbCr format, 2x2 subsampled Cb (1) and Cr (2) planes
+        Yuv420 = 842093913,
+        /// 3 plane YCbCr format, 2x2 subsampled Cr (1) and Cb (2) planes
+        Yvu420 = 842094169,
+        /// 3 plane YCbCr format, 2x1 subsampled Cb (1) and Cr (2) planes
+        Yuv422 = 909202777,
+        /// 3 plane YCbCr format, 2x1 subsampled Cr (1) and Cb (2) planes
+        Yvu422 = 909203033,
+        /// 3 plane YCbCr format, non-subsampled Cb (1) and Cr (2) planes
+        Yuv444 = 875713881,
+        /// 3 plane YCbCr format, non-subsampled Cr (1) and Cb (2) planes
+        Yvu444 = 875714137,
+        /// \[7:0\] R
+        R8 = 538982482,
+        /// \[15:0\] R little endian
+        R16 = 540422482,
+        /// \[15:0\] R:G 8:8 little endian
+        Rg88 = 943212370,
+        /// \[15:0\] G:R 8:8 little endian
+        Gr88 = 943215175,
+        /// \[31:0\] R:G 16:16 little endian
+        Rg1616 = 842221394,
+        /// \[31:0\] G:R 16:16 little endian
+        Gr1616 = 842224199,
+        /// \[63:0\] x:R:G:B 16:16:16:16 little endian
+        Xrgb16161616f = 1211388504,
+        /// \[63:0\] x:B:G:R 16:16:16:16 little endian
+        Xbgr16161616f = 1211384408,
+        /// \[63:0\] A:R:G:B 16:16:16:16 little endian
+        Argb16161616f = 1211388481,
+        /// \[63:0\] A:B:G:R 16:16:16:16 little endian
+        Abgr16161616f = 1211384385,
+        /// \[31:0\] X:Y:Cb:Cr 8:8:8:8 little endian
+        Xyuv8888 = 1448434008,
+        /// \[23:0\] Cr:Cb:Y 8:8:8 little endian
+        Vuy888 = 875713878,
+        /// Y followed by U then V, 10:10:10. Non-linear modifier only
+        Vuy101010 = 808670550,
+        /// \[63:0\] Cr0:0:Y1:0:Cb0:0:Y0:0 10:6:10:6:10:6:10:6 little endian per 2 Y pixels
+        Y210 = 808530521,
+        /// \[63:0\] Cr0:0:Y1:0:Cb0:0:Y0:0 12:4:12:4:12:4:12:4 little endian per 2 Y pixels
+        Y212 = 842084953,
+        /// \[63:0\] Cr0:Y1:Cb0:Y0 16:16:16:16 little endian per 2 Y pixels
+        Y216 = 909193817,
+        /// \[31:0\] A:Cr:Y:Cb 2:10:10:10 little endian
+        Y410 = 808531033,
+        /// \[63:0\] A:0:Cr:0:Y:0:Cb:0 12:4:12:4:12:4:12:4 little endian
+        Y412 = 842085465,
+        /// \[63:0\] A:Cr:Y:Cb 16:16:16:16 little endian
+        Y416 = 909194329,
+        /// \[31:0\] X:Cr:Y:Cb 2:10:10:10 little endian
+        Xvyu2101010 = 808670808,
+        /// \[63:0\] X:0:Cr:0:Y:0:Cb:0 12:4:12:4:12:4:12:4 little endian
+        Xvyu1216161616 = 909334104,
+        /// \[63:0\] X:Cr:Y:Cb 16:16:16:16 little endian
+        Xvyu16161616 = 942954072,
+        /// \[63:0\]   A3:A2:Y3:0:Cr0:0:Y2:0:A1:A0:Y1:0:Cb0:0:Y0:0  1:1:8:2:8:2:8:2:1:1:8:2:8:2:8:2 little endian
+        Y0l0 = 810299481,
+        /// \[63:0\]   X3:X2:Y3:0:Cr0:0:Y2:0:X1:X0:Y1:0:Cb0:0:Y0:0  1:1:8:2:8:2:8:2:1:1:8:2:8:2:8:2 little endian
+        X0l0 = 810299480,
+        /// \[63:0\]   A3:A2:Y3:Cr0:Y2:A1:A0:Y1:Cb0:Y0  1:1:10:10:10:1:1:10:10:10 little endian
+        Y0l2 = 843853913,
+        /// \[63:0\]   X3:X2:Y3:Cr0:Y2:X1:X0:Y1:Cb0:Y0  1:1:10:10:10:1:1:10:10:10 little endian
+        X0l2 = 843853912,
+        Yuv4208bit = 942691673,
+        Yuv42010bit = 808539481,
+        Xrgb8888A8 = 943805016,
+        Xbgr8888A8 = 943800920,
+        Rgbx8888A8 = 943806546,
+        Bgrx8888A8 = 943806530,
+        Rgb888A8 = 943798354,
+        Bgr888A8 = 943798338,
+        Rgb565A8 = 943797586,
+        Bgr565A8 = 943797570,
+        /// non-subsampled Cr:Cb plane
+        Nv24 = 875714126,
+        /// non-subsampled Cb:Cr plane
+        Nv42 = 842290766,
+        /// 2x1 subsampled Cr:Cb plane, 10 bit per channel
+        P210 = 808530512,
+        /// 2x2 subsampled Cr:Cb plane 10 bits per channel
+        P010 = 808530000,
+        /// 2x2 subsampled Cr:Cb plane 12 bits per channel
+        P012 = 842084432,
+        /// 2x2 subsampled Cr:Cb plane 16 bits per channel
+        P016 = 909193296,
+        /// \[63:0\] A:x:B:x:G:x:R:x 10:6:10:6:10:6:10:6 little endian
+        Axbxgxrx106106106106 = 808534593,
+        /// 2x2 subsampled Cr:Cb plane
+        Nv15 = 892425806,
+        Q410 = 808531025,
+        Q401 = 825242705,
+        /// \[63:0\] x:R:G:B 16:16:16:16 little endian
+        Xrgb16161616 = 942953048,
+        /// \[63:0\] x:B:G:R 16:16:16:16 little endian
+        Xbgr16161616 = 942948952,
+        /// \[63:0\] A:R:G:B 16:16:16:16 little endian
+        Argb16161616 = 942953025,
+        /// \[63:0\] A:B:G:R 16:16:16:16 little endian
+        Abgr16161616 = 942948929,
+        /// \[7:0\] C0:C1:C2:C3:C4:C5:C6:C7 1:1:1:1:1:1:1:1 eight pixels/byte
+        C1 = 538980675,
+        /// \[7:0\] C0:C1:C2:C3 2:2:2:2 four pixels/byte
+        C2 = 538980931,
+        /// \[7:0\] C0:C1 4:4 two pixels/byte
+        C4 = 538981443,
+        /// \[7:0\] D0:D1:D2:D3:D4:D5:D6:D7 1:1:1:1:1:1:1:1 eight pixels/byte
+        D1 = 538980676,
+        /// \[7:0\] D0:D1:D2:D3 2:2:2:2 four pixels/byte
+        D2 = 538980932,
+        /// \[7:0\] D0:D1 4:4 two pixels/byte
+        D4 = 538981444,
+        /// \[7:0\] D
+        D8 = 538982468,
+        /// \[7:0\] R0:R1:R2:R3:R4:R5:R6:R7 1:1:1:1:1:1:1:1 eight pixels/byte
+        R1 = 538980690,
+        /// \[7:0\] R0:R1:R2:R3 2:2:2:2 four pixels/byte
+        R2 = 538980946,
+        /// \[7:0\] R0:R1 4:4 two pixels/byte
+        R4 = 538981458,
+        /// \[15:0\] x:R 6:10 little endian
+        R10 = 540029266,
+        /// \[15:0\] x:R 4:12 little endian
+        R12 = 540160338,
+        /// \[31:0\] A:Cr:Cb:Y 8:8:8:8 little endian
+        Avuy8888 = 1498764865,
+        /// \[31:0\] X:Cr:Cb:Y 8:8:8:8 little endian
+        Xvuy8888 = 1498764888,
+        /// 2x2 subsampled Cr:Cb plane 10 bits per channel packed
+        P030 = 808661072,
+        /// \[47:0\] R:G:B 16:16:16 little endian
+        Rgb161616 = 942950226,
+        /// \[47:0\] B:G:R 16:16:16 little endian
+        Bgr161616 = 942950210,
+        /// \[15:0\] R 16 little endian
+        R16f = 1210064978,
+        /// \[31:0\] G:R 16:16 little endian
+        Gr1616f = 1210077767,
+        /// \[47:0\] B:G:R 16:16:16 little endian
+        Bgr161616f = 1213351746,
+        /// \[31:0\] R 32 little endian
+        R32f = 1176510546,
+        /// \[63:0\] G:R 32:32 little endian
+        Gr3232f = 1176523335,
+        /// \[95:0\] B:G:R 32:32:32 little endian
+        Bgr323232f = 1179797314,
+        /// \[127:0\] A:B:G:R 32:32:32:32 little endian
+        Abgr32323232f = 1178092097,
+        /// 2x1 subsampled Cr:Cb plane
+        Nv20 = 808605262,
+        /// non-subsampled Cr:Cb plane
+        Nv30 = 808670798,
+        /// 2x2 subsampled Cb (1) and Cr (2) planes 10 bits per channel
+        S010 = 808530003,
+        /// 2x1 subsampled Cb (1) and Cr (2) planes 10 bits per channel
+        S210 = 808530515,
+        /// non-subsampled Cb (1) and Cr (2) planes 10 bits per channel
+        S410 = 808531027,
+        /// 2x2 subsampled Cb (1) and Cr (2) planes 12 bits per channel
+        S012 = 842084435,
+        /// 2x1 subsampled Cb (1) and Cr (2) planes 12 bits per channel
+        S212 = 842084947,
+        /// non-subsampled Cb (1) and Cr (2) planes 12 bits per channel
+        S412 = 842085459,
+        /// 2x2 subsampled Cb (1) and Cr (2) planes 16 bits per channel
+        S016 = 909193299,
+        /// 2x1 subsampled Cb (1) and Cr (2) planes 16 bits per channel
+        S216 = 909193811,
+        /// non-subsampled Cb (1) and Cr (2) planes 16 bits per channel
+        S416 = 909194323,
+        /// \[31:0\] x:Cr:Cb:Y 2:10:10:10 little endian
+        Xvuy2101010 = 808671576,
+        /// 2x1 subsampled Cr:Cb plane 10 bits per channel packed
+        P230 = 808661584,
+        T430 = 808662100,
+        /// 8-bit Y-only
+        Y8 = 1497715271,
+        /// \[31:0\] x:Y2:Y1:Y0 2:10:10:10 little endian
+        Xyyy2101010 = 876695641,
+    }
+
+    impl Format {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Format::Argb8888),
+                1 => Some(Format::Xrgb8888),
+                538982467 => Some(Format::C8),
+                943867730 => Some(Format::Rgb332),
+                944916290 => Some(Format::Bgr233),
+                842093144 => Some(Format::Xrgb4444),
+                842089048 => Some(Format::Xbgr4444),
+                842094674 => Some(Format::Rgbx4444),
+                842094658 => Some(Format::Bgrx4444),
+                842093121 => Some(Format::Argb4444),
+                842089025 => Some(Format::Abgr4444),
+                842088786 => Some(Format::Rgba4444),
+                842088770 => Some(Format::Bgra4444),
+                892424792 => Some(Format::Xrgb1555),
+                892420696 => Some(Format::Xbgr1555),
+                892426322 => Some(Format::Rgbx5551),
+                892426306 => Some(Format::Bgrx5551),
+                892424769 => Some(Format::Argb1555),
+                892420673 => Some(Format::Abgr1555),
+                892420434 => Some(Format::Rgba5551),
+                892420418 => Some(Format::Bgra5551),
+                909199186 => Some(Format::Rgb565),
+                909199170 => Some(Format::Bgr565),
+                875710290 => Some(Format::Rgb888),
+                875710274 => Some(Format::Bgr888),
+                875709016 => Some(Format::Xbgr8888),
+                875714642 => Some(Format::Rgbx8888),
+                875714626 => Some(Format::Bgrx8888),
+                875708993 => Some(Format::Abgr8888),
+                875708754 => Some(Format::Rgba8888),
+                875708738 => Some(Format::Bgra8888),
+                808669784 => Some(Format::Xrgb2101010),
+                808665688 => Some(Format::Xbgr2101010),
+                808671314 => Some(Format::Rgbx1010102),
+                808671298 => Some(Format::Bgrx1010102),
+                808669761 => Some(Format::Argb2101010),
+                808665665 => Some(Format::Abgr2101010),
+                808665426 => Some(Format::Rgba1010102),
+                808665410 => Some(Format::Bgra1010102),
+                1448695129 => Some(Format::Yuyv),
+                1431918169 => Some(Format::Yvyu),
+                1498831189 => Some(Format::Uyvy),
+                1498765654 => Some(Format::Vyuy),
+                1448433985 => Some(Format::Ayuv),
+                842094158 => Some(Format::Nv12),
+                825382478 => Some(Format::Nv21),
+                909203022 => Some(Format::Nv16),
+                825644622 => Some(Format::Nv61),
+                961959257 => Some(Format::Yuv410),
+                961893977 => Some(Format::Yvu410),
+                825316697 => Some(Format::Yuv411),
+                825316953 => Some(Format::Yvu411),
+                842093913 => Some(Format::Yuv420),
+                842094169 => Some(Format::Yvu420),
+                909202777 => Some(Format::Yuv422),
+                909203033 => Some(Format::Yvu422),
+                875713881 => Some(Format::Yuv444),
+                875714137 => Some(Format::Yvu444),
+                538982482 => Some(Format::R8),
+                540422482 => Some(Format::R16),
+                943212370 => Some(Format::Rg88),
+                943215175 => Some(Format::Gr88),
+                842221394 => Some(Format::Rg1616),
+                842224199 => Some(Format::Gr1616),
+                1211388504 => Some(Format::Xrgb16161616f),
+                1211384408 => Some(Format::Xbgr16161616f),
+                1211388481 => Some(Format::Argb16161616f),
+                1211384385 => Some(Format::Abgr16161616f),
+                1448434008 => Some(Format::Xyuv8888),
+                875713878 => Some(Format::Vuy888),
+                808670550 => Some(Format::Vuy101010),
+                808530521 => Some(Format::Y210),
+                842084953 => Some(Format::Y212),
+                909193817 => Some(Format::Y216),
+                808531033 => Some(Format::Y410),
+                842085465 => Some(Format::Y412),
+                909194329 => Some(Format::Y416),
+                808670808 => Some(Format::Xvyu2101010),
+                909334104 => Some(Format::Xvyu1216161616),
+                942954072 => Some(Format::Xvyu16161616),
+                810299481 => Some(Format::Y0l0),
+                810299480 => Some(Format::X0l0),
+                843853913 => Some(Format::Y0l2),
+                843853912 => Some(Format::X0l2),
+                942691673 => Some(Format::Yuv4208bit),
+                808539481 => Some(Format::Yuv42010bit),
+                943805016 => Some(Format::Xrgb8888A8),
+                943800920 => Some(Format::Xbgr8888A8),
+                943806546 => Some(Format::Rgbx8888A8),
+                943806530 => Some(Format::Bgrx8888A8),
+                943798354 => Some(Format::Rgb888A8),
+                943798338 => Some(Format::Bgr888A8),
+                943797586 => Some(Format::Rgb565A8),
+                943797570 => Some(Format::Bgr565A8),
+                875714126 => Some(Format::Nv24),
+                842290766 => Some(Format::Nv42),
+                808530512 => Some(Format::P210),
+                808530000 => Some(Format::P010),
+                842084432 => Some(Format::P012),
+                909193296 => Some(Format::P016),
+                808534593 => Some(Format::Axbxgxrx106106106106),
+                892425806 => Some(Format::Nv15),
+                808531025 => Some(Format::Q410),
+                825242705 => Some(Format::Q401),
+                942953048 => Some(Format::Xrgb16161616),
+                942948952 => Some(Format::Xbgr16161616),
+                942953025 => Some(Format::Argb16161616),
+                942948929 => Some(Format::Abgr16161616),
+                538980675 => Some(Format::C1),
+                538980931 => Some(Format::C2),
+                538981443 => Some(Format::C4),
+                538980676 => Some(Format::D1),
+                538980932 => Some(Format::D2),
+                538981444 => Some(Format::D4),
+                538982468 => Some(Format::D8),
+                538980690 => Some(Format::R1),
+                538980946 => Some(Format::R2),
+                538981458 => Some(Format::R4),
+                540029266 => Some(Format::R10),
+                540160338 => Some(Format::R12),
+                1498764865 => Some(Format::Avuy8888),
+                1498764888 => Some(Format::Xvuy8888),
+                808661072 => Some(Format::P030),
+                942950226 => Some(Format::Rgb161616),
+                942950210 => Some(Format::Bgr161616),
+                1210064978 => Some(Format::R16f),
+                1210077767 => Some(Format::Gr1616f),
+                1213351746 => Some(Format::Bgr161616f),
+                1176510546 => Some(Format::R32f),
+                1176523335 => Some(Format::Gr3232f),
+                1179797314 => Some(Format::Bgr323232f),
+                1178092097 => Some(Format::Abgr32323232f),
+                808605262 => Some(Format::Nv20),
+                808670798 => Some(Format::Nv30),
+                808530003 => Some(Format::S010),
+                808530515 => Some(Format::S210),
+                808531027 => Some(Format::S410),
+                842084435 => Some(Format::S012),
+                842084947 => Some(Format::S212),
+                842085459 => Some(Format::S412),
+                909193299 => Some(Format::S016),
+                909193811 => Some(Format::S216),
+                909194323 => Some(Format::S416),
+                808671576 => Some(Format::Xvuy2101010),
+                808661584 => Some(Format::P230),
+                808662100 => Some(Format::T430),
+                1497715271 => Some(Format::Y8),
+                876695641 => Some(Format::Xyyy2101010),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// content for a wl_surface
+///
+/// A buffer provides the content for a wl_surface. Buffers are
+/// created through factory interfaces such as wl_shm, wp_linux_buffer_params
+/// (from the linux-dmabuf protocol extension) or similar. It has a width and
+/// a height and can be attached to a wl_surface, but the mechanism by which a
+/// client provides and updates the contents is defined by the buffer factory
+/// interface.
+///
+/// Color channels are assumed to be electrical rather than optical (in other
+/// words, encoded with a transfer function) unless otherwise specified. If
+/// the buffer uses a format that has an alpha channel, the alpha channel is
+/// assumed to be premultiplied into the electrical color channel values
+/// (after transfer function encoding) unless otherwise specified.
+///
+/// Note, because wl_buffer objects are created from multiple independent
+/// factory interfaces, the wl_buffer interface is frozen at version 1.
+pub mod wl_buffer {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_buffer";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 1;
+
+    /// A `wl_buffer` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlBuffer(pub super::ObjectId);
+
+    impl WlBuffer {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "release",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// destroy a buffer
+        ///
+        /// Destroy a buffer. If and how you need to release the backing
+        /// storage is defined by the buffer factory interface.
+        ///
+        /// For possible side-effects to a surface, see wl_surface.attach.
+        Destroy,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// compositor releases buffer
+        ///
+        /// Sent when this wl_buffer is no longer used by the compositor.
+        ///
+        /// For more information on when release events may or may not be sent,
+        /// and what consequences it has, please see the description of
+        /// wl_surface.attach.
+        ///
+        /// If a client receives a release event before the frame callback
+        /// requested in the same wl_surface.commit that attaches this
+        /// wl_buffer to a surface, then the client is immediately free to
+        /// reuse the buffer and its backing storage, and does not need a
+        /// second buffer for the next surface content update. Typically
+        /// this is possible, when the compositor maintains a copy of the
+        /// wl_surface contents, e.g. as a GL texture. This is an important
+        /// optimization for GL(ES) compositors with wl_shm clients.
+        Release,
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Release => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Release => 1,
+            }
+        }
+    }
+}
+
+/// offer to transfer data
+///
+/// A wl_data_offer represents a piece of data offered for transfer
+/// by another client (the source client).  It is used by the
+/// copy-and-paste and drag-and-drop mechanisms.  The offer
+/// describes the different mime types that the data can be
+/// converted to and provides the mechanism for transferring the
+/// data directly from the source client.
+pub mod wl_data_offer {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_data_offer";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 4;
+
+    /// A `wl_data_offer` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlDataOffer(pub super::ObjectId);
+
+    impl WlDataOffer {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "accept",
+            since: 1,
+            signature: "u?s",
+            types: &[None, None],
+        },
+        super::MessageDesc {
+            name: "receive",
+            since: 1,
+            signature: "sh",
+            types: &[None, None],
+        },
+        super::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "finish",
+            since: 3,
+            signature: "3",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "set_actions",
+            since: 3,
+            signature: "3uu",
+            types: &[None, None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "offer",
+            since: 1,
+            signature: "s",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "source_actions",
+            since: 3,
+            signature: "3u",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "action",
+            since: 3,
+            signature: "3u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// accept one of the offered mime types
+        ///
+        /// Indicate that the client can accept the given mime type, or
+        /// NULL for not accepted.
+        ///
+        /// For objects of version 2 or older, this request is used by the
+        /// client to give feedback whether the client can receive the given
+        /// mime type, or NULL if none is accepted; the feedback does not
+        /// determine whether the drag-and-drop operation succeeds or not.
+        ///
+        /// For objects of version 3 or newer, this request determines the
+        /// final result of the drag-and-drop operation. If the end result
+        /// is that no mime types were accepted, the drag-and-drop operation
+        /// will be cancelled and the corresponding drag source will receive
+        /// wl_data_source.cancelled. Clients may still use this event in
+        /// conjunction with wl_data_source.action for feedback.
+        Accept {
+            /// serial number of the accept request
+            serial: u32,
+            /// mime type accepted by the client
+            mime_type: ::core::option::Option<::std::string::String>,
+        },
+        /// request that the data is transferred
+        ///
+        /// To transfer the offered data, the client issues this request
+        /// and indicates the mime type it wants to receive.  The transfer
+        /// happens through the passed file descriptor (typically created
+        /// with the pipe system call).  The source client writes the data
+        /// in the mime type representation requested and then closes the
+        /// file descriptor.
+        ///
+        /// The receiving client reads from the read end of the pipe until
+        /// EOF and then closes its end, at which point the transfer is
+        /// complete.
+        ///
+        /// This request may happen multiple times for different mime types,
+        /// both before and after wl_data_device.drop. Drag-and-drop destination
+        /// clients may preemptively fetch data or examine it more closely to
+        /// determine acceptance.
+        Receive {
+            /// mime type desired by receiver
+            mime_type: ::std::string::String,
+            /// file descriptor for data transfer
+            fd: ::std::os::unix::io::OwnedFd,
+        },
+        /// destroy data offer
+        ///
+        /// Destroy the data offer.
+        Destroy,
+        /// the offer will no longer be used
+        ///
+        /// Notifies the compositor that the drag destination successfully
+        /// finished the drag-and-drop operation.
+        ///
+        /// Upon receiving this request, the compositor will emit
+        /// wl_data_source.dnd_finished on the drag source client.
+        ///
+        /// It is a client error to perform other requests than
+        /// wl_data_offer.destroy after this one. It is also an error to perform
+        /// this request after a NULL mime type has been set in
+        /// wl_data_offer.accept or no action was received through
+        /// wl_data_offer.action.
+        ///
+        /// If wl_data_offer.finish request is received for a non drag and drop
+        /// operation, the invalid_finish protocol error is raised.
+        ///
+        /// Added in version 3.
+        Finish,
+        /// set the available/preferred drag-and-drop actions
+        ///
+        /// Sets the actions that the destination side client supports for
+        /// this operation. This request may trigger the emission of
+        /// wl_data_source.action and wl_data_offer.action events if the compositor
+        /// needs to change the selected action.
+        ///
+        /// This request can be called multiple times throughout the
+        /// drag-and-drop operation, typically in response to wl_data_device.enter
+        /// or wl_data_device.motion events.
+        ///
+        /// This request determines the final result of the drag-and-drop
+        /// operation. If the end result is that no action is accepted,
+        /// the drag source will receive wl_data_source.cancelled.
+        ///
+        /// The dnd_actions argument must contain only values expressed in the
+        /// wl_data_device_manager.dnd_actions enum, and the preferred_action
+        /// argument must only contain one of those values set, otherwise it
+        /// will result in a protocol error.
+        ///
+        /// While managing an "ask" action, the destination drag-and-drop client
+        /// may perform further wl_data_offer.receive requests, and is expected
+        /// to perform one last wl_data_offer.set_actions request with a preferred
+        /// action other than "ask" (and optionally wl_data_offer.accept) before
+        /// requesting wl_data_offer.finish, in order to convey the action selected
+        /// by the user. If the preferred action is not in the
+        /// wl_data_offer.source_actions mask, an error will be raised.
+        ///
+        /// If the "ask" action is dismissed (e.g. user cancellation), the client
+        /// is expected to perform wl_data_offer.destroy right away.
+        ///
+        /// This request can only be made on drag-and-drop offers, a protocol error
+        /// will be raised otherwise.
+        ///
+        /// Added in version 3.
+        SetActions {
+            /// actions supported by the destination client
+            dnd_actions: super::wl_data_device_manager::DndAction,
+            /// action preferred by the destination client
+            preferred_action: super::wl_data_device_manager::DndAction,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Accept { .. } => 0,
+                Request::Receive { .. } => 1,
+                Request::Destroy => 2,
+                Request::Finish => 3,
+                Request::SetActions { .. } => 4,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Accept { .. } => 1,
+                Request::Receive { .. } => 1,
+                Request::Destroy => 1,
+                Request::Finish => 3,
+                Request::SetActions { .. } => 3,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// advertise offered mime type
+        ///
+        /// Sent immediately after creating the wl_data_offer object.  One
+        /// event per offered mime type.
+        Offer {
+            /// offered mime type
+            mime_type: ::std::string::String,
+        },
+        /// notify the source-side available actions
+        ///
+        /// This event indicates the actions offered by the data source. It
+        /// will be sent immediately after creating the wl_data_offer object,
+        /// or anytime the source side changes its offered actions through
+        /// wl_data_source.set_actions.
+        ///
+        /// Added in version 3.
+        SourceActions {
+            /// actions offered by the data source
+            source_actions: super::wl_data_device_manager::DndAction,
+        },
+        /// notify the selected action
+        ///
+        /// This event indicates the action selected by the compositor after
+        /// matching the source/destination side actions. Only one action (or
+        /// none) will be offered here.
+        ///
+        /// This event can be emitted multiple times during the drag-and-drop
+        /// operation in response to destination side action changes through
+        /// wl_data_offer.set_actions.
+        ///
+        /// This event will no longer be emitted after wl_data_device.drop
+        /// happened on the drag-and-drop destination, the client must
+        /// honor the last action received, or the last preferred one set
+        /// through wl_data_offer.set_actions when handling an "ask" action.
+        ///
+        /// Compositors may also change the selected action on the fly, mainly
+        /// in response to keyboard modifier changes during the drag-and-drop
+        /// operation.
+        ///
+        /// The most recent action received is always the valid one. Prior to
+        /// receiving wl_data_device.drop, the chosen action may change (e.g.
+        /// due to keyboard modifiers being pressed). At the time of receiving
+        /// wl_data_device.drop the drag-and-drop destination must honor the
+        /// last action received.
+        ///
+        /// Action changes may still happen after wl_data_device.drop,
+        /// especially on "ask" actions, where the drag-and-drop destination
+        /// may choose another action afterwards. Action changes happening
+        /// at this stage are always the result of inter-client negotiation, the
+        /// compositor shall no longer be able to induce a different action.
+        ///
+        /// Upon "ask" actions, it is expected that the drag-and-drop destination
+        /// may potentially choose a different action and/or mime type,
+        /// based on wl_data_offer.source_actions and finally chosen by the
+        /// user (e.g. popping up a menu with the available options). The
+        /// final wl_data_offer.set_actions and wl_data_offer.accept requests
+        /// must happen before the call to wl_data_offer.finish.
+        ///
+        /// Added in version 3.
+        Action {
+            /// action selected by the compositor
+            dnd_action: super::wl_data_device_manager::DndAction,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Offer { .. } => 0,
+                Event::SourceActions { .. } => 1,
+                Event::Action { .. } => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Offer { .. } => 1,
+                Event::SourceActions { .. } => 3,
+                Event::Action { .. } => 3,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// finish request was called untimely
+        InvalidFinish = 0,
+        /// action mask contains invalid values
+        InvalidActionMask = 1,
+        /// action argument has an invalid value
+        InvalidAction = 2,
+        /// offer doesn't accept this request
+        InvalidOffer = 3,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidFinish),
+                1 => Some(Error::InvalidActionMask),
+                2 => Some(Error::InvalidAction),
+                3 => Some(Error::InvalidOffer),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// offer to transfer data
+///
+/// The wl_data_source object is the source side of a wl_data_offer.
+/// It is created by the source client in a data transfer and
+/// provides a way to describe the offered data and a way to respond
+/// to requests to transfer the data.
+pub mod wl_data_source {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_data_source";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 4;
+
+    /// A `wl_data_source` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlDataSource(pub super::ObjectId);
+
+    impl WlDataSource {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "offer",
+            since: 1,
+            signature: "s",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "set_actions",
+            since: 3,
+            signature: "3u",
+            types: &[None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "target",
+            since: 1,
+            signature: "?s",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "send",
+            since: 1,
+            signature: "sh",
+            types: &[None, None],
+        },
+        super::MessageDesc {
+            name: "cancelled",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "dnd_drop_performed",
+            since: 3,
+            signature: "3",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "dnd_finished",
+            since: 3,
+            signature: "3",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "action",
+            since: 3,
+            signature: "3u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// add an offered mime type
+        ///
+        /// This request adds a mime type to the set of mime types
+        /// advertised to targets.  Can be called several times to offer
+        /// multiple types.
+        Offer {
+            /// mime type offered by the data source
+            mime_type: ::std::string::String,
+        },
+        /// destroy the data source
+        ///
+        /// Destroy the data source.
+        Destroy,
+        /// set the available drag-and-drop actions
+        ///
+        /// Sets the actions that the source side client supports for this
+        /// operation. This request may trigger wl_data_source.action and
+        /// wl_data_offer.action events if the compositor needs to change the
+        /// selected action.
+        ///
+        /// The dnd_actions argument must contain only values expressed in the
+        /// wl_data_device_manager.dnd_actions enum, otherwise it will result
+        /// in a protocol error.
+        ///
+        /// This request must be made once only, and can only be made on sources
+        /// used in drag-and-drop, so it must be performed before
+        /// wl_data_device.start_drag. Attempting to use the source other than
+        /// for drag-and-drop will raise a protocol error.
+        ///
+        /// Added in version 3.
+        SetActions {
+            /// actions supported by the data source
+            dnd_actions: super::wl_data_device_manager::DndAction,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Offer { .. } => 0,
+                Request::Destroy => 1,
+                Request::SetActions { .. } => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Offer { .. } => 1,
+                Request::Destroy => 1,
+                Request::SetActions { .. } => 3,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// a target accepts an offered mime type
+        ///
+        /// Sent when a target accepts pointer_focus or motion events.  If
+        /// a target does not accept any of the offered types, type is NULL.
+        ///
+        /// Used for feedback during drag-and-drop.
+        Target {
+            /// mime type accepted by the target
+            mime_type: ::core::option::Option<::std::string::String>,
+        },
+        /// send the data
+        ///
+        /// Request for data from the client.  Send the data as the
+        /// specified mime type over the passed file descriptor, then
+        /// close it.
+        Send {
+            /// mime type for the data
+            mime_type: ::std::string::String,
+            /// file descriptor for the data
+            fd: ::std::os::unix::io::OwnedFd,
+        },
+        /// selection was cancelled
+        ///
+        /// This data source is no longer valid. There are several reasons why
+        /// this could happen:
+        ///
+        /// - The data source has been replaced by another data source.
+        /// - The drag-and-drop operation was performed, but the drop destination
+        ///   did not accept any of the mime types offered through
+        ///   wl_data_source.target.
+        /// - The drag-and-drop operation was performed, but the drop destination
+        ///   did not select any of the actions present in the mask offered through
+        ///   wl_data_source.action.
+        /// - The drag-and-drop operation was performed but didn't happen over a
+        ///   surface.
+        /// - The compositor cancelled the drag-and-drop operation (e.g. compositor
+        ///   dependent timeouts to avoid stale drag-and-drop transfers).
+        ///
+        /// The client should clean up and destroy this data source.
+        ///
+        /// For objects of version 2 or older, wl_data_source.cancelled will
+        /// only be emitted if the data source was replaced by another data
+        /// source.
+        Cancelled,
+        /// the drag-and-drop operation physically finished
+        ///
+        /// The user performed the drop action. This event does not indicate
+        /// acceptance, wl_data_source.cancelled may still be emitted afterwards
+        /// if the drop destination does not accept any mime type.
+        ///
+        /// However, this event might not be received if the compositor cancelled
+        /// the drag-and-drop operation before this event could happen.
+        ///
+        /// Note that the data_source may still be used in the future and should
+        /// not be destroyed here.
+        ///
+        /// Added in version 3.
+        DndDropPerformed,
+        /// the drag-and-drop operation concluded
+        ///
+        /// The drop destination finished interoperating with this data
+        /// source, so the client is now free to destroy this data source and
+        /// free all associated data.
+        ///
+        /// If the action used to perform the operation was "move", the
+        /// source can now delete the transferred data.
+        ///
+        /// Added in version 3.
+        DndFinished,
+        /// notify the selected action
+        ///
+        /// This event indicates the action selected by the compositor after
+        /// matching the source/destination side actions. Only one action (or
+        /// none) will be offered here.
+        ///
+        /// This event can be emitted multiple times during the drag-and-drop
+        /// operation, mainly in response to destination side changes through
+        /// wl_data_offer.set_actions, and as the data device enters/leaves
+        /// surfaces.
+        ///
+        /// It is only possible to receive this event after
+        /// wl_data_source.dnd_drop_performed if the drag-and-drop operation
+        /// ended in an "ask" action, in which case the final wl_data_source.action
+        /// event will happen immediately before wl_data_source.dnd_finished.
+        ///
+        /// Compositors may also change the selected action on the fly, mainly
+        /// in response to keyboard modifier changes during the drag-and-drop
+        /// operation.
+        ///
+        /// The most recent action received is always the valid one. The chosen
+        /// action may change alongside negotiation (e.g. an "ask" action can turn
+        /// into a "move" operation), so the effects of the final action must
+        /// always be applied in wl_data_source.dnd_finished.
+        ///
+        /// Clients can trigger cursor surface changes from this point, so
+        /// they reflect the current action.
+        ///
+        /// Added in version 3.
+        Action {
+            /// action selected by the compositor
+            dnd_action: super::wl_data_device_manager::DndAction,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Target { .. } => 0,
+                Event::Send { .. } => 1,
+                Event::Cancelled => 2,
+                Event::DndDropPerformed => 3,
+                Event::DndFinished => 4,
+                Event::Action { .. } => 5,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Target { .. } => 1,
+                Event::Send { .. } => 1,
+                Event::Cancelled => 1,
+                Event::DndDropPerformed => 3,
+                Event::DndFinished => 3,
+                Event::Action { .. } => 3,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// action mask contains invalid values
+        InvalidActionMask = 0,
+        /// source doesn't accept this request
+        InvalidSource = 1,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidActionMask),
+                1 => Some(Error::InvalidSource),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// data transfer device
+///
+/// There is one wl_data_device per seat which can be obtained
+/// from the global wl_data_device_manager singleton.
+///
+/// A wl_data_device provides access to inter-client data transfer
+/// mechanisms such as copy-and-paste and drag-and-drop.
+pub mod wl_data_device {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_data_device";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 4;
+
+    /// A `wl_data_device` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlDataDevice(pub super::ObjectId);
+
+    impl WlDataDevice {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "start_drag",
+            since: 1,
+            signature: "?oo?ou",
+            types: &[Some("wl_data_source"), Some("wl_surface"), Some("wl_surface"), None],
+        },
+        super::MessageDesc {
+            name: "set_selection",
+            since: 1,
+            signature: "?ou",
+            types: &[Some("wl_data_source"), None],
+        },
+        super::MessageDesc {
+            name: "release",
+            since: 2,
+            signature: "2",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "data_offer",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_data_offer")],
+        },
+        super::MessageDesc {
+            name: "enter",
+            since: 1,
+            signature: "uoff?o",
+            types: &[None, Some("wl_surface"), None, None, Some("wl_data_offer")],
+        },
+        super::MessageDesc {
+            name: "leave",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "motion",
+            since: 1,
+            signature: "uff",
+            types: &[None, None, None],
+        },
+        super::MessageDesc {
+            name: "drop",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "selection",
+            since: 1,
+            signature: "?o",
+            types: &[Some("wl_data_offer")],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// start drag-and-drop operation
+        ///
+        /// This request asks the compositor to start a drag-and-drop
+        /// operation on behalf of the client.
+        ///
+        /// The source argument is the data source that provides the data
+        /// for the eventual data transfer. If source is NULL, enter, leave
+        /// and motion events are sent only to the client that initiated the
+        /// drag and the client is expected to handle the data passing
+        /// internally. If source is destroyed, the drag-and-drop session will be
+        /// cancelled.
+        ///
+        /// The origin surface is the surface where the drag originates and
+        /// the client must have an active implicit grab that matches the
+        /// serial.
+        ///
+        /// The icon surface is an optional (can be NULL) surface that
+        /// provides an icon to be moved around with the cursor.  Initially,
+        /// the top-left corner of the icon surface is placed at the cursor
+        /// hotspot, but subsequent wl_surface.offset requests can move the
+        /// relative position. Attach requests must be confirmed with
+        /// wl_surface.commit as usual. The icon surface is given the role of
+        /// a drag-and-drop icon. If the icon surface already has another role,
+        /// it raises a protocol error.
+        ///
+        /// The input region is ignored for wl_surfaces with the role of a
+        /// drag-and-drop icon.
+        ///
+        /// The given source may not be used in any further set_selection or
+        /// start_drag requests. Attempting to reuse a previously-used source
+        /// may send a used_source error.
+        StartDrag {
+            /// data source for the eventual transfer
+            source: ::core::option::Option<super::ObjectId>,
+            /// surface where the drag originates
+            origin: super::ObjectId,
+            /// drag-and-drop icon surface
+            icon: ::core::option::Option<super::ObjectId>,
+            /// serial number of the implicit grab on the origin
+            serial: u32,
+        },
+        /// copy data to the selection
+        ///
+        /// This request asks the compositor to set the selection
+        /// to the data from the source on behalf of the client.
+        ///
+        /// To unset the selection, set the source to NULL.
+        ///
+        /// The given source may not be used in any further set_selection or
+        /// start_drag requests. Attempting to reuse a previously-used source
+        /// may send a used_source error.
+        SetSelection {
+            /// data source for the selection
+            source: ::core::option::Option<super::ObjectId>,
+            /// serial number of the event that triggered this request
+            serial: u32,
+        },
+        /// destroy data device
+        ///
+        /// This request destroys the data device.
+        ///
+        /// Added in version 2.
+        Release,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::StartDrag { .. } => 0,
+                Request::SetSelection { .. } => 1,
+                Request::Release => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::StartDrag { .. } => 1,
+                Request::SetSelection { .. } => 1,
+                Request::Release => 2,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// introduce a new wl_data_offer
+        ///
+        /// The data_offer event introduces a new wl_data_offer object,
+        /// which will subsequently be used in either the
+        /// data_device.enter event (for drag-and-drop) or the
+        /// data_device.selection event (for selections).  Immediately
+        /// following the data_device.data_offer event, the new data_offer
+        /// object will send out data_offer.offer events to describe the
+        /// mime types it offers.
+        DataOffer {
+            /// the new data_offer object
+            id: super::NewId,
+        },
+        /// initiate drag-and-drop session
+        ///
+        /// This event is sent when an active drag-and-drop pointer enters
+        /// a surface owned by the client.  The position of the pointer at
+        /// enter time is provided by the x and y arguments, in surface-local
+        /// coordinates.
+        Enter {
+            /// serial number of the enter event
+            serial: u32,
+            /// client surface entered
+            surface: super::ObjectId,
+            /// surface-local x coordinate
+            x: super::Fixed,
+            /// surface-local y coordinate
+            y: super::Fixed,
+            /// source data_offer object
+            id: ::core::option::Option<super::ObjectId>,
+        },
+        /// end drag-and-drop session
+        ///
+        /// This event is sent when the drag-and-drop pointer leaves the
+        /// surface and the session ends.  The client must destroy the
+        /// wl_data_offer introduced at enter time at this point.
+        Leave,
+        /// drag-and-drop session motion
+        ///
+        /// This event is sent when the drag-and-drop pointer moves within
+        /// the currently focused surface. The new position of the pointer
+        /// is provided by the x and y arguments, in surface-local
+        /// coordinates.
+        Motion {
+            /// timestamp with millisecond granularity
+            time: u32,
+            /// surface-local x coordinate
+            x: super::Fixed,
+            /// surface-local y coordinate
+            y: super::Fixed,
+        },
+        /// end drag-and-drop session successfully
+        ///
+        /// The event is sent when a drag-and-drop operation is ended
+        /// because the implicit grab is removed.
+        ///
+        /// The drag-and-drop destination is expected to honor the last action
+        /// received through wl_data_offer.action, if the resulting action is
+        /// "copy" or "move", the destination can still perform
+        /// wl_data_offer.receive requests, and is expected to end all
+        /// transfers with a wl_data_offer.finish request.
+        ///
+        /// If the resulting action is "ask", the action will not be considered
+        /// final. The drag-and-drop destination is expected to perform one last
+        /// wl_data_offer.set_actions request, or wl_data_offer.destroy in order
+        /// to cancel the operation.
+        Drop,
+        /// advertise new selection
+        ///
+        /// The selection event is sent out to notify the client of a new
+        /// wl_data_offer for the selection for this device.  The
+        /// data_device.data_offer and the data_offer.offer events are
+        /// sent out immediately before this event to introduce the data
+        /// offer object.  The selection event is sent to a client
+        /// immediately before receiving keyboard focus and when a new
+        /// selection is set while the client has keyboard focus.  The
+        /// data_offer is valid until a new data_offer or NULL is received
+        /// or until the client loses keyboard focus.  Switching surface with
+        /// keyboard focus within the same client doesn't mean a new selection
+        /// will be sent.  The client must destroy the previous selection
+        /// data_offer, if any, upon receiving this event.
+        Selection {
+            /// selection data_offer object
+            id: ::core::option::Option<super::ObjectId>,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::DataOffer { .. } => 0,
+                Event::Enter { .. } => 1,
+                Event::Leave => 2,
+                Event::Motion { .. } => 3,
+                Event::Drop => 4,
+                Event::Selection { .. } => 5,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::DataOffer { .. } => 1,
+                Event::Enter { .. } => 1,
+                Event::Leave => 1,
+                Event::Motion { .. } => 1,
+                Event::Drop => 1,
+                Event::Selection { .. } => 1,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// given wl_surface has another role
+        Role = 0,
+        /// source has already been used
+        UsedSource = 1,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::Role),
+                1 => Some(Error::UsedSource),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// data transfer interface
+///
+/// The wl_data_device_manager is a singleton global object that
+/// provides access to inter-client data transfer mechanisms such as
+/// copy-and-paste and drag-and-drop.  These mechanisms are tied to
+/// a wl_seat and this interface lets a client get a wl_data_device
+/// corresponding to a wl_seat.
+///
+/// Depending on the version bound, the objects created from the bound
+/// wl_data_device_manager object will have different requirements for
+/// functioning properly. See wl_data_source.set_actions,
+/// wl_data_offer.accept and wl_data_offer.finish for details.
+pub mod wl_data_device_manager {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_data_device_manager";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 4;
+
+    /// A `wl_data_device_manager` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlDataDeviceManager(pub super::ObjectId);
+
+    impl WlDataDeviceManager {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "create_data_source",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_data_source")],
+        },
+        super::MessageDesc {
+            name: "get_data_device",
+            since: 1,
+            signature: "no",
+            types: &[Some("wl_data_device"), Some("wl_seat")],
+        },
+        super::MessageDesc {
+            name: "release",
+            since: 4,
+            signature: "4",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// create a new data source
+        ///
+        /// Create a new data source.
+        CreateDataSource {
+            /// data source to create
+            id: super::NewId,
+        },
+        /// create a new data device
+        ///
+        /// Create a new data device for a given seat.
+        GetDataDevice {
+            /// data device to create
+            id: super::NewId,
+            /// seat associated with the data device
+            seat: super::ObjectId,
+        },
+        /// destroy wl_data_device_manager
+        ///
+        /// This request destroys the wl_data_device_manager. This has no effect on any other
+        /// objects.
+        ///
+        /// Added in version 4.
+        Release,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::CreateDataSource { .. } => 0,
+                Request::GetDataDevice { .. } => 1,
+                Request::Release => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::CreateDataSource { .. } => 1,
+                Request::GetDataDevice { .. } => 1,
+                Request::Release => 4,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+
+    /// drag and drop actions
+    ///
+    /// This is a bitmask of the available/preferred actions in a
+    /// drag-and-drop operation.
+    ///
+    /// In the compositor, the selected action is a result of matching the
+    /// actions offered by the source and destination sides.  "action" events
+    /// with a "none" action will be sent to both source and destination if
+    /// there is no match. All further checks will effectively happen on
+    /// (source actions ∩ destination actions).
+    ///
+    /// In addition, compositors may also pick different actions in
+    /// reaction to key modifiers being pressed. One common design that
+    /// is used in major toolkits (and the behavior recommended for
+    /// compositors) is:
+    ///
+    /// - If no modifiers are pressed, the first match (in bit order)
+    ///   will be used.
+    /// - Pressing Shift selects "move", if enabled in the mask.
+    /// - Pressing Control selects "copy", if enabled in the mask.
+    ///
+    /// Behavior beyond that is considered implementation-dependent.
+    /// Compositors may for example bind other modifiers (like Alt/Meta)
+    /// or drags initiated with other buttons than BTN_LEFT to specific
+    /// actions (e.g. "ask").
+    ///
+    /// Added in version 3.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct DndAction(u32);
+
+    impl DndAction {
+        /// no action
+        pub const NONE: Self = Self(0);
+
+        /// copy action
+        pub const COPY: Self = Self(1);
+
+        /// move action
+        pub const MOVE: Self = Self(2);
+
+        /// ask action
+        pub const ASK: Self = Self(4);
+
+        /// Creates a value from its bits, keeping unknown bits.
+        pub const fn from_bits(bits: u32) -> Self {
+            Self(bits)
+        }
+
+        /// Returns the bits of the value.
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+
+        /// Returns whether all of the bits of `other` are set.
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        /// Returns whether no bits are set.
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl ::core::ops::BitOr for DndAction {
+        type Output = Self;
+
+        fn bitor(self, other: Self) -> Self {
+            Self(self.0 | other.0)
+        }
+    }
+
+    impl ::core::ops::BitAnd for DndAction {
+        type Output = Self;
+
+        fn bitand(self, other: Self) -> Self {
+            Self(self.0 & other.0)
+        }
+    }
+
+    impl ::core::ops::BitOrAssign for DndAction {
+        fn bitor_assign(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+    }
+}
+
+/// create desktop-style surfaces
+///
+/// This interface is implemented by servers that provide
+/// desktop-style user interfaces.
+///
+/// It allows clients to associate a wl_shell_surface with
+/// a basic surface.
+///
+/// Note! This protocol is deprecated and not intended for production use.
+/// For desktop-style user interfaces, use xdg_shell. Compositors and clients
+/// should not implement this interface.
+pub mod wl_shell {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_shell";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 1;
+
+    /// A `wl_shell` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlShell(pub super::ObjectId);
+
+    impl WlShell {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "get_shell_surface",
+            since: 1,
+            signature: "no",
+            types: &[Some("wl_shell_surface"), Some("wl_surface")],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// create a shell surface from a surface
+        ///
+        /// Create a shell surface for an existing surface. This gives
+        /// the wl_surface the role of a shell surface. If the wl_surface
+        /// already has another role, it raises a protocol error.
+        ///
+        /// Only one shell surface can be associated with a given surface.
+        GetShellSurface {
+            /// shell surface to create
+            id: super::NewId,
+            /// surface to be given the shell surface role
+            surface: super::ObjectId,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::GetShellSurface { .. } => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::GetShellSurface { .. } => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// given wl_surface has another role
+        Role = 0,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::Role),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// desktop-style metadata interface
+///
+/// An interface that may be implemented by a wl_surface, for
+/// implementations that provide a desktop-style user interface.
+///
+/// It provides requests to treat surfaces like toplevel, fullscreen
+/// or popup windows, move, resize or maximize them, associate
+/// metadata like title and class, etc.
+///
+/// On the server side the object is automatically destroyed when
+/// the related wl_surface is destroyed. On the client side,
+/// wl_shell_surface_destroy() must be called before destroying
+/// the wl_surface object.
+pub mod wl_shell_surface {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_shell_surface";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 1;
+
+    /// A `wl_shell_surface` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlShellSurface(pub super::ObjectId);
+
+    impl WlShellSurface {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "pong",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "move",
+            since: 1,
+            signature: "ou",
+            types: &[Some("wl_seat"), None],
+        },
+        super::MessageDesc {
+            name: "resize",
+            since: 1,
+            signature: "ouu",
+            types: &[Some("wl_seat"), None, None],
+        },
+        super::MessageDesc {
+            name: "set_toplevel",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "set_transient",
+            since: 1,
+            signature: "oiiu",
+            types: &[Some("wl_surface"), None, None, None],
+        },
+        super::MessageDesc {
+            name: "set_fullscreen",
+            since: 1,
+            signature: "uu?o",
+            types: &[None, None, Some("wl_output")],
+        },
+        super::MessageDesc {
+            name: "set_popup",
+            since: 1,
+            signature: "ouoiiu",
+            types: &[Some("wl_seat"), None, Some("wl_surface"), None, None, None],
+        },
+        super::MessageDesc {
+            name: "set_maximized",
+            since: 1,
+            signature: "?o",
+            types: &[Some("wl_output")],
+        },
+        super::MessageDesc {
+            name: "set_title",
+            since: 1,
+            signature: "s",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "set_class",
+            since: 1,
+            signature: "s",
+            types: &[None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "ping",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "configure",
+            since: 1,
+            signature: "uii",
+            types: &[None, None, None],
+        },
+        super::MessageDesc {
+            name: "popup_done",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// respond to a ping event
+        ///
+        /// A client must respond to a ping event with a pong request or
+        /// the client may be deemed unresponsive.
+        Pong {
+            /// serial number of the ping event
+            serial: u32,
+        },
+        /// start an interactive move
+        ///
+        /// Start a pointer-driven move of the surface.
+        ///
+        /// This request must be used in response to a button press event.
+        /// The server may ignore move requests depending on the state of
+        /// the surface (e.g. fullscreen or maximized).
+        Move {
+            /// seat whose pointer is used
+            seat: super::ObjectId,
+            /// serial number of the implicit grab on the pointer
+            serial: u32,
+        },
+        /// start an interactive resize
+        ///
+        /// Start a pointer-driven resizing of the surface.
+        ///
+        /// This request must be used in response to a button press event.
+        /// The server may ignore resize requests depending on the state of
+        /// the surface (e.g. fullscreen or maximized).
+        Resize {
+            /// seat whose pointer is used
+            seat: super::ObjectId,
+            /// serial number of the implicit grab on the pointer
+            serial: u32,
+            /// which edge or corner is being dragged
+            edges: Resize,
+        },
+        /// make the surface a toplevel surface
+        ///
+        /// Map the surface as a toplevel surface.
+        ///
+        /// A toplevel surface is not fullscreen, maximized or transient.
+        SetToplevel,
+        /// make the surface a transient surface
+        ///
+        /// Map the surface relative to an existing surface.
+        ///
+        /// The x and y arguments specify the location of the upper left
+        /// corner of the surface relative to the upper left corner of the
+        /// parent surface, in surface-local coordinates.
+        ///
+        /// The flags argument controls details of the transient behaviour.
+        SetTransient {
+            /// parent surface
+            parent: super::ObjectId,
+            /// surface-local x coordinate
+            x: i32,
+            /// surface-local y coordinate
+            y: i32,
+            /// transient surface behavior
+            flags: Transient,
+        },
+        /// make the surface a fullscreen surface
+        ///
+        /// Map the surface as a fullscreen surface.
+        ///
+        /// If an output parameter is given then the surface will be made
+        /// fullscreen on that output. If the client does not specify the
+        /// output then the compositor will apply its policy - usually
+        /// choosing the output on which the surface has the biggest surface
+        /// area.
+        ///
+        /// The client may specify a method to resolve a size conflict
+        /// between the output size and the surface size - this is provided
+        /// through the method parameter.
+        ///
+        /// The framerate parameter is used only when the method is set
+        /// to "driver", to indicate the preferred framerate. A value of 0
+        /// indicates that the client does not care about framerate.  The
+        /// framerate is specified in mHz, that is framerate of 60000 is 60Hz.
+        ///
+        /// A method of "scale" or "driver" implies a scaling operation of
+        /// the surface, either via a direct scaling operation or a change of
+        /// the output mode. This will override any kind of output scaling, so
+        /// that mapping a surface with a buffer size equal to the mode can
+        /// fill the screen independent of buffer_scale.
+        ///
+        /// A method of "fill" means we don't scale up the buffer, however
+        /// any output scale is applied. This means that you may run into
+        /// an edge case where the application maps a buffer with the same
+        /// size of the output mode but buffer_scale 1 (thus making a
+        /// surface larger than the output). In this case it is allowed to
+        /// downscale the results to fit the screen.
+        ///
+        /// The compositor must reply to this request with a configure event
+        /// with the dimensions for the output on which the surface will
+        /// be made fullscreen.
+        SetFullscreen {
+            /// method for resolving size conflict
+            method: FullscreenMethod,
+            /// framerate in mHz
+            framerate: u32,
+            /// output on which the surface is to be fullscreen
+            output: ::core::option::Option<super::ObjectId>,
+        },
+        /// make the surface a popup surface
+        ///
+        /// Map the surface as a popup.
+        ///
+        /// A popup surface is a transient surface with an added pointer
+        /// grab.
+        ///
+        /// An existing implicit grab will be changed to owner-events mode,
+        /// and the popup grab will continue after the implicit grab ends
+        /// (i.e. releasing the mouse button does not cause the popup to
+        /// be unmapped).
+        ///
+        /// The popup grab continues until the window is destroyed or a
+        /// mouse button is pressed in any other client's window. A click
+        /// in any of the client's surfaces is reported as normal, however,
+        /// clicks in other clients' surfaces will be discarded and trigger
+        /// the callback.
+        ///
+        /// The x and y arguments specify the location of the upper left
+        /// corner of the surface relative to the upper left corner of the
+        /// parent surface, in surface-local coordinates.
+        SetPopup {
+            /// seat whose pointer is used
+            seat: super::ObjectId,
+            /// serial number of the implicit grab on the pointer
+            serial: u32,
+            /// parent surface
+            parent: super::ObjectId,
+            /// surface-local x coordinate
+            x: i32,
+            /// surface-local y coordinate
+            y: i32,
+            /// transient surface behavior
+            flags: Transient,
+        },
+        /// make the surface a maximized surface
+        ///
+        /// Map the surface as a maximized surface.
+        ///
+        /// If an output parameter is given then the surface will be
+        /// maximized on that output. If the client does not specify the
+        /// output then the compositor will apply its policy - usually
+        /// choosing the output on which the surface has the biggest surface
+        /// area.
+        ///
+        /// The compositor will reply with a configure event telling
+        /// the expected new surface size. The operation is completed
+        /// on the next buffer attach to this surface.
+        ///
+        /// A maximized surface typically fills the entire output it is
+        /// bound to, except for desktop elements such as panels. This is
+        /// the main difference between a maximized shell surface and a
+        /// fullscreen shell surface.
+        ///
+        /// The details depend on the compositor implementation.
+        SetMaximized {
+            /// output on which the surface is to be maximized
+            output: ::core::option::Option<super::ObjectId>,
+        },
+        /// set surface title
+        ///
+        /// Set a short title for the surface.
+        ///
+        /// This string may be used to identify the surface in a task bar,
+        /// window list, or other user interface elements provided by the
+        /// compositor.
+        ///
+        /// The string must be encoded in UTF-8.
+        SetTitle {
+            /// surface title
+            title: ::std::string::String,
+        },
+        /// set surface class
+        ///
+        /// Set a class for the surface.
+        ///
+        /// The surface class identifies the general class of applications
+        /// to which the surface belongs. A common convention is to use the
+        /// file name (or the full path if it is a non-standard location) of
+        /// the application's .desktop file as the class.
+        SetClass {
+            /// surface class
+            class_: ::std::string::String,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Pong { .. } => 0,
+                Request::Move { .. } => 1,
+                Request::Resize { .. } => 2,
+                Request::SetToplevel => 3,
+                Request::SetTransient { .. } => 4,
+                Request::SetFullscreen { .. } => 5,
+                Request::SetPopup { .. } => 6,
+                Request::SetMaximized { .. } => 7,
+                Request::SetTitle { .. } => 8,
+                Request::SetClass { .. } => 9,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Pong { .. } => 1,
+                Request::Move { .. } => 1,
+                Request::Resize { .. } => 1,
+                Request::SetToplevel => 1,
+                Request::SetTransient { .. } => 1,
+                Request::SetFullscreen { .. } => 1,
+                Request::SetPopup { .. } => 1,
+                Request::SetMaximized { .. } => 1,
+                Request::SetTitle { .. } => 1,
+                Request::SetClass { .. } => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// ping client
+        ///
+        /// Ping a client to check if it is receiving events and sending
+        /// requests. A client is expected to reply with a pong request.
+        Ping {
+            /// serial number of the ping
+            serial: u32,
+        },
+        /// suggest resize
+        ///
+        /// The configure event asks the client to resize its surface.
+        ///
+        /// The size is a hint, in the sense that the client is free to
+        /// ignore it if it doesn't resize, pick a smaller size (to
+        /// satisfy aspect ratio or resize in steps of NxM pixels).
+        ///
+        /// The edges parameter provides a hint about how the surface
+        /// was resized. The client may use this information to decide
+        /// how to adjust its content to the new size (e.g. a scrolling
+        /// area might adjust its content position to leave the viewable
+        /// content unmoved).
+        ///
+        /// The client is free to dismiss all but the last configure
+        /// event it received.
+        ///
+        /// The width and height arguments specify the size of the window
+        /// in surface-local coordinates.
+        Configure {
+            /// how the surface was resized
+            edges: Resize,
+            /// new width of the surface
+            width: i32,
+            /// new height of the surface
+            height: i32,
+        },
+        /// popup interaction is done
+        ///
+        /// The popup_done event is sent out when a popup grab is broken,
+        /// that is, when the user clicks a surface that doesn't belong
+        /// to the client owning the popup surface.
+        PopupDone,
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Ping { .. } => 0,
+                Event::Configure { .. } => 1,
+                Event::PopupDone => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Ping { .. } => 1,
+                Event::Configure { .. } => 1,
+                Event::PopupDone => 1,
+            }
+        }
+    }
+
+    /// edge values for resizing
+    ///
+    /// These values are used to indicate which edge of a surface
+    /// is being dragged in a resize operation. The server may
+    /// use this information to adapt its behavior, e.g. choose
+    /// an appropriate cursor image.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct Resize(u32);
+
+    impl Resize {
+        /// no edge
+        pub const NONE: Self = Self(0);
+
+        /// top edge
+        pub const TOP: Self = Self(1);
+
+        /// bottom edge
+        pub const BOTTOM: Self = Self(2);
+
+        /// left edge
+        pub const LEFT: Self = Self(4);
+
+        /// top and left edges
+        pub const TOP_LEFT: Self = Self(5);
+
+        /// bottom and left edges
+        pub const BOTTOM_LEFT: Self = Self(6);
+
+        /// right edge
+        pub const RIGHT: Self = Self(8);
+
+        /// top and right edges
+        pub const TOP_RIGHT: Self = Self(9);
+
+        /// bottom and right edges
+        pub const BOTTOM_RIGHT: Self = Self(10);
+
+        /// Creates a value from its bits, keeping unknown bits.
+        pub const fn from_bits(bits: u32) -> Self {
+            Self(bits)
+        }
+
+        /// Returns the bits of the value.
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+
+        /// Returns whether all of the bits of `other` are set.
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        /// Returns whether no bits are set.
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl ::core::ops::BitOr for Resize {
+        type Output = Self;
+
+        fn bitor(self, other: Self) -> Self {
+            Self(self.0 | other.0)
+        }
+    }
+
+    impl ::core::ops::BitAnd for Resize {
+        type Output = Self;
+
+        fn bitand(self, other: Self) -> Self {
+            Self(self.0 & other.0)
+        }
+    }
+
+    impl ::core::ops::BitOrAssign for Resize {
+        fn bitor_assign(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+    }
+
+    /// details of transient behaviour
+    ///
+    /// These flags specify details of the expected behaviour
+    /// of transient surfaces. Used in the set_transient request.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct Transient(u32);
+
+    impl Transient {
+        /// do not set keyboard focus
+        pub const INACTIVE: Self = Self(1);
+
+        /// Creates a value from its bits, keeping unknown bits.
+        pub const fn from_bits(bits: u32) -> Self {
+            Self(bits)
+        }
+
+        /// Returns the bits of the value.
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+
+        /// Returns whether all of the bits of `other` are set.
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        /// Returns whether no bits are set.
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl ::core::ops::BitOr for Transient {
+        type Output = Self;
+
+        fn bitor(self, other: Self) -> Self {
+            Self(self.0 | other.0)
+        }
+    }
+
+    impl ::core::ops::BitAnd for Transient {
+        type Output = Self;
+
+        fn bitand(self, other: Self) -> Self {
+            Self(self.0 & other.0)
+        }
+    }
+
+    impl ::core::ops::BitOrAssign for Transient {
+        fn bitor_assign(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+    }
+
+    /// different method to set the surface fullscreen
+    ///
+    /// Hints to indicate to the compositor how to deal with a conflict
+    /// between the dimensions of the surface and the dimensions of the
+    /// output. The compositor is free to ignore this parameter.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum FullscreenMethod {
+        /// no preference, apply default policy
+        Default = 0,
+        /// scale, preserve the surface's aspect ratio and center on output
+        Scale = 1,
+        /// switch output mode to the smallest mode that can fit the surface, add black borders to compensate size mismatch
+        Driver = 2,
+        /// no upscaling, center on output and add black borders to compensate size mismatch
+        Fill = 3,
+    }
+
+    impl FullscreenMethod {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(FullscreenMethod::Default),
+                1 => Some(FullscreenMethod::Scale),
+                2 => Some(FullscreenMethod::Driver),
+                3 => Some(FullscreenMethod::Fill),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// an onscreen surface
+///
+/// A surface is a rectangular area that may be displayed on zero
+/// or more outputs, and shown any number of times at the compositor's
+/// discretion. They can present wl_buffers, receive user input, and
+/// define a local coordinate system.
+///
+/// The size of a surface (and relative positions on it) is described
+/// in surface-local coordinates, which may differ from the buffer
+/// coordinates of the pixel content, in case a buffer_transform
+/// or a buffer_scale is used.
+///
+/// A surface without a "role" is fairly useless: a compositor does
+/// not know where, when or how to present it. The role is the
+/// purpose of a wl_surface. Examples of roles are a cursor for a
+/// pointer (as set by wl_pointer.set_cursor), a drag icon
+/// (wl_data_device.start_drag), a sub-surface
+/// (wl_subcompositor.get_subsurface), and a window as defined by a
+/// shell protocol (e.g. wl_shell.get_shell_surface).
+///
+/// A surface can have only one role at a time. Initially a
+/// wl_surface does not have a role. Once a wl_surface is given a
+/// role, it is set permanently for the whole lifetime of the
+/// wl_surface object. Giving the current role again is allowed,
+/// unless explicitly forbidden by the relevant interface
+/// specification.
+///
+/// Surface roles are given by requests in other interfaces such as
+/// wl_pointer.set_cursor. The request should explicitly mention
+/// that this request gives a role to a wl_surface. Often, this
+/// request also creates a new protocol object that represents the
+/// role and adds additional functionality to wl_surface. When a
+/// client wants to destroy a wl_surface, they must destroy this role
+/// object before the wl_surface, otherwise a defunct_role_object error is
+/// sent.
+///
+/// Destroying the role object does not remove the role from the
+/// wl_surface, but it may stop the wl_surface from "playing the role".
+/// For instance, if a wl_subsurface object is destroyed, the wl_surface
+/// it was created for will be unmapped and forget its position and
+/// z-order. It is allowed to create a wl_subsurface for the same
+/// wl_surface again, but it is not allowed to use the wl_surface as
+/// a cursor (cursor is a different role than sub-surface, and role
+/// switching is not allowed).
+pub mod wl_surface {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_surface";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 7;
+
+    /// A `wl_surface` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlSurface(pub super::ObjectId);
+
+    impl WlSurface {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "attach",
+            since: 1,
+            signature: "?oii",
+            types: &[Some("wl_buffer"), None, None],
+        },
+        super::MessageDesc {
+            name: "damage",
+            since: 1,
+            signature: "iiii",
+            types: &[None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "frame",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_callback")],
+        },
+        super::MessageDesc {
+            name: "set_opaque_region",
+            since: 1,
+            signature: "?o",
+            types: &[Some("wl_region")],
+        },
+        super::MessageDesc {
+            name: "set_input_region",
+            since: 1,
+            signature: "?o",
+            types: &[Some("wl_region")],
+        },
+        super::MessageDesc {
+            name: "commit",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "set_buffer_transform",
+            since: 2,
+            signature: "2i",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "set_buffer_scale",
+            since: 3,
+            signature: "3i",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "damage_buffer",
+            since: 4,
+            signature: "4iiii",
+            types: &[None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "offset",
+            since: 5,
+            signature: "5ii",
+            types: &[None, None],
+        },
+        super::MessageDesc {
+            name: "get_release",
+            since: 7,
+            signature: "7n",
+            types: &[Some("wl_callback")],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "enter",
+            since: 1,
+            signature: "o",
+            types: &[Some("wl_output")],
+        },
+        super::MessageDesc {
+            name: "leave",
+            since: 1,
+            signature: "o",
+            types: &[Some("wl_output")],
+        },
+        super::MessageDesc {
+            name: "preferred_buffer_scale",
+            since: 6,
+            signature: "6i",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "preferred_buffer_transform",
+            since: 6,
+            signature: "6u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// delete surface
+        ///
+        /// Deletes the surface and invalidates its object ID.
+        Destroy,
+        /// set the surface contents
+        ///
+        /// Set a buffer as the content of this surface.
+        ///
+        /// The new size of the surface is calculated based on the buffer
+        /// size transformed by the inverse buffer_transform and the
+        /// inverse buffer_scale. This means that at commit time the supplied
+        /// buffer size must be an integer multiple of the buffer_scale. If
+        /// that's not the case, an invalid_size error is sent.
+        ///
+        /// The x and y arguments specify the location of the new pending
+        /// buffer's upper left corner, relative to the current buffer's upper
+        /// left corner, in surface-local coordinates. In other words, the
+        /// x and y, combined with the new surface size define in which
+        /// directions the surface's size changes. Setting anything other than 0
+        /// as x and y arguments is discouraged, and should instead be replaced
+        /// with using the separate wl_surface.offset request.
+        ///
+        /// When the bound wl_surface version is 5 or higher, passing any
+        /// non-zero x or y is a protocol violation, and will result in an
+        /// 'invalid_offset' error being raised. The x and y arguments are ignored
+        /// and do not change the pending state. To achieve equivalent semantics,
+        /// use wl_surface.offset.
+        ///
+        /// Surface contents are double-buffered state, see wl_surface.commit.
+        ///
+        /// The initial surface contents are void; there is no content.
+        /// wl_surface.attach assigns the given wl_buffer as the pending
+        /// wl_buffer. wl_surface.commit makes the pending wl_buffer the new
+        /// surface contents, and the size of the surface becomes the size
+        /// calculated from the wl_buffer, as described above. After commit,
+        /// there is no pending buffer until the next attach.
+        ///
+        /// Committing a pending wl_buffer allows the compositor to read the
+        /// pixels in the wl_buffer. The compositor may access the pixels at
+        /// any time after the wl_surface.commit request. When the compositor
+        /// will not access the pixels anymore, it will send the
+        /// wl_buffer.release event. Only after receiving wl_buffer.release,
+        /// the client may reuse the wl_buffer. A wl_buffer that has been
+        /// attached and then replaced by another attach instead of committed
+        /// will not receive a release event, and is not used by the
+        /// compositor.
+        ///
+        /// If a pending wl_buffer has been committed to more than one wl_surface,
+        /// the delivery of wl_buffer.release events becomes undefined. A well
+        /// behaved client should not rely on wl_buffer.release events in this
+        /// case. Instead, clients hitting this case should use
+        /// wl_surface.get_release or use a protocol extension providing per-commit
+        /// release notifications (if none of these options are available, a
+        /// fallback can be implemented by creating multiple wl_buffer objects from
+        /// the same backing storage).
+        ///
+        /// Destroying the wl_buffer after wl_buffer.release does not change
+        /// the surface contents. Destroying the wl_buffer before wl_buffer.release
+        /// is allowed as long as the underlying buffer storage isn't re-used (this
+        /// can happen e.g. on client process termination). However, if the client
+        /// destroys the wl_buffer before receiving the wl_buffer.release event and
+        /// mutates the underlying buffer storage, the surface contents become
+        /// undefined immediately.
+        ///
+        /// If wl_surface.attach is sent with a NULL wl_buffer, the
+        /// following wl_surface.commit will remove the surface content.
+        ///
+        /// If a pending wl_buffer has been destroyed, the result is not specified.
+        /// Many compositors are known to remove the surface content on the following
+        /// wl_surface.commit, but this behaviour is not universal. Clients seeking to
+        /// maximise compatibility should not destroy pending buffers and should
+        /// ensure that they explicitly remove content from surfaces, even after
+        /// destroying buffers.
+        Attach {
+            /// buffer of surface contents
+            buffer: ::core::option::Option<super::ObjectId>,
+            /// surface-local x coordinate
+            x: i32,
+            /// surface-local y coordinate
+            y: i32,
+        },
+        /// mark part of the surface damaged
+        ///
+        /// This request is used to describe the regions where the pending
+        /// buffer is different from the current surface contents, and where
+        /// the surface therefore needs to be repainted. The compositor
+        /// ignores the parts of the damage that fall outside of the surface.
+        ///
+        /// Damage is double-buffered state, see wl_surface.commit.
+        ///
+        /// The damage rectangle is specified in surface-local coordinates,
+        /// where x and y specify the upper left corner of the damage rectangle.
+        ///
+        /// The initial value for pending damage is empty: no damage.
+        /// wl_surface.damage adds pending damage: the new pending damage
+        /// is the union of old pending damage and the given rectangle.
+        ///
+        /// wl_surface.commit assigns pending damage as the current damage,
+        /// and clears pending damage. The server will clear the current
+        /// damage as it repaints the surface.
+        ///
+        /// Note! New clients should not use this request. Instead damage can be
+        /// posted with wl_surface.damage_buffer which uses buffer coordinates
+        /// instead of surface coordinates.
+        Damage {
+            /// surface-local x coordinate
+            x: i32,
+            /// surface-local y coordinate
+            y: i32,
+            /// width of damage rectangle
+            width: i32,
+            /// height of damage rectangle
+            height: i32,
+        },
+        /// request a frame throttling hint
+        ///
+        /// Request a notification when it is a good time to start drawing a new
+        /// frame, by creating a frame callback. This is useful for throttling
+        /// redrawing operations, and driving animations.
+        ///
+        /// When a client is animating on a wl_surface, it can use the 'frame'
+        /// request to get notified when it is a good time to draw and commit the
+        /// next frame of animation. If the client commits an update earlier than
+        /// that, it is likely that some updates will not make it to the display,
+        /// and the client is wasting resources by drawing too often.
+        ///
+        /// The frame request will take effect on the next wl_surface.commit.
+        /// The notification will only be posted for one frame unless
+        /// requested again. For a wl_surface, the notifications are posted in
+        /// the order the frame requests were committed.
+        ///
+        /// The server must send the notifications so that a client
+        /// will not send excessive updates, while still allowing
+        /// the highest possible update rate for clients that wait for the reply
+        /// before drawing again. The server should give some time for the client
+        /// to draw and commit after sending the frame callback events to let it
+        /// hit the next output refresh.
+        ///
+        /// A server should avoid signaling the frame callbacks if the
+        /// surface is not visible in any way, e.g. the surface is off-screen,
+        /// or completely obscured by other opaque surfaces.
+        ///
+        /// The object returned by this request will be destroyed by the
+        /// compositor after the callback is fired and as such the client must not
+        /// attempt to use it after that point.
+        ///
+        /// The callback_data passed in the callback is the current time, in
+        /// milliseconds, with an undefined base.
+        Frame {
+            /// callback object for the frame request
+            callback: super::NewId,
+        },
+        /// set opaque region
+        ///
+        /// This request sets the region of the surface that contains
+        /// opaque content.
+        ///
+        /// The opaque region is an optimization hint for the compositor
+        /// that lets it optimize the redrawing of content behind opaque
+        /// regions.  Setting an opaque region is not required for correct
+        /// behaviour, but marking transparent content as opaque will result
+        /// in repaint artifacts.
+        ///
+        /// The opaque region is specified in surface-local coordinates.
+        ///
+        /// The compositor ignores the parts of the opaque region that fall
+        /// outside of the surface.
+        ///
+        /// Opaque region is double-buffered state, see wl_surface.commit.
+        ///
+        /// wl_surface.set_opaque_region changes the pending opaque region.
+        /// wl_surface.commit copies the pending region to the current region.
+        /// Otherwise, the pending and current regions are never changed.
+        ///
+        /// The initial value for an opaque region is empty. Setting the pending
+        /// opaque region has copy semantics, and the wl_region object can be
+        /// destroyed immediately. A NULL wl_region causes the pending opaque
+        /// region to be set to empty.
+        SetOpaqueRegion {
+            /// opaque region of the surface
+            region: ::core::option::Option<super::ObjectId>,
+        },
+        /// set input region
+        ///
+        /// This request sets the region of the surface that can receive
+        /// pointer and touch events.
+        ///
+        /// Input events happening outside of this region will try the next
+        /// surface in the server surface stack. The compositor ignores the
+        /// parts of the input region that fall outside of the surface.
+        ///
+        /// The input region is specified in surface-local coordinates.
+        ///
+        /// Input region is double-buffered state, see wl_surface.commit.
+        ///
+        /// wl_surface.set_input_region changes the pending input region.
+        /// wl_surface.commit copies the pending region to the current region.
+        /// Otherwise the pending and current regions are never changed,
+        /// except cursor and icon surfaces are special cases, see
+        /// wl_pointer.set_cursor and wl_data_device.start_drag.
+        ///
+        /// The initial value for an input region is infinite. That means the
+        /// whole surface will accept input. Setting the pending input region
+        /// has copy semantics, and the wl_region object can be destroyed
+        /// immediately. A NULL wl_region causes the input region to be set
+        /// to infinite.
+        SetInputRegion {
+            /// input region of the surface
+            region: ::core::option::Option<super::ObjectId>,
+        },
+        /// commit pending surface state
+        ///
+        /// Surface state (input, opaque, and damage regions, attached buffers,
+        /// etc.) is double-buffered. Protocol requests modify the pending state,
+        /// as opposed to the active state in use by the compositor.
+        ///
+        /// All requests that need a commit to become effective are documented
+        /// to affect double-buffered state.
+        ///
+        /// Other interfaces may add further double-buffered surface state.
+        ///
+        /// A commit request atomically creates a Content Update (CU) from the
+        /// pending state, even if the pending state has not been touched. The
+        /// content update is placed at the end of a per-surface queue until it
+        /// becomes active. After commit, the new pending state is as documented for
+        /// each related request.
+        ///
+        /// A CU is either a Desync Content Update (DCU) or a Sync Content Update
+        /// (SCU). If the surface is effectively synchronized at the commit request,
+        /// it is a SCU, otherwise a DCU.
+        ///
+        /// When a surface transitions from effectively synchronized to effectively
+        /// desynchronized, all SCUs in its queue which are not reachable by any
+        /// DCU become DCUs and dependency edges from outside the queue to these CUs
+        /// are removed.
+        ///
+        /// See wl_subsurface for the definition of 'effectively synchronized' and
+        /// 'effectively desynchronized'.
+        ///
+        /// When a CU is placed in the queue, the CU has a dependency on the CU in
+        /// front of it and to the SCU at end of the queue of every direct child
+        /// surface if that SCU exists and does not have another dependent. This can
+        /// form a directed acyclic graph of CUs with dependencies as edges.
+        ///
+        /// In addition to surface state, the CU can have constraints that must be
+        /// satisfied before it can be applied. Other interfaces may add CU
+        /// constraints.
+        ///
+        /// All DCUs which do not have a SCU in front of themselves in their queue,
+        /// are candidates. If the graph that's reachable by a candidate does not
+        /// have any unsatisfied constraints, the entire graph must be applied
+        /// atomically.
+        ///
+        /// When a CU is applied, the wl_buffer is applied before all other state.
+        /// This means that all coordinates in double-buffered state are relative to
+        /// the newly attached wl_buffers, except for wl_surface.attach itself. If
+        /// there is no newly attached wl_buffer, the coordinates are relative to
+        /// the previous content update.
+        Commit,
+        /// sets the buffer transformation
+        ///
+        /// This request sets the transformation that the client has already applied
+        /// to the content of the buffer. The accepted values for the transform
+        /// parameter are the values for wl_output.transform.
+        ///
+        /// The compositor applies the inverse of this transformation whenever it
+        /// uses the buffer contents.
+        ///
+        /// Buffer transform is double-buffered state, see wl_surface.commit.
+        ///
+        /// A newly created surface has its buffer transformation set to normal.
+        ///
+        /// wl_surface.set_buffer_transform changes the pending buffer
+        /// transformation. wl_surface.commit copies the pending buffer
+        /// transformation to the current one. Otherwise, the pending and current
+        /// values are never changed.
+        ///
+        /// The purpose of this request is to allow clients to render content
+        /// according to the output transform, thus permitting the compositor to
+        /// use certain optimizations even if the display is rotated. Using
+        /// hardware overlays and scanning out a client buffer for fullscreen
+        /// surfaces are examples of such optimizations. Those optimizations are
+        /// highly dependent on the compositor implementation, so the use of this
+        /// request should be considered on a case-by-case basis.
+        ///
+        /// Note that if the transform value includes 90 or 270 degree rotation,
+        /// the width of the buffer will become the surface height and the height
+        /// of the buffer will become the surface width.
+        ///
+        /// If transform is not one of the values from the
+        /// wl_output.transform enum the invalid_transform protocol error
+        /// is raised.
+        ///
+        /// Added in version 2.
+        SetBufferTransform {
+            /// transform for interpreting buffer contents
+            transform: super::wl_output::Transform,
+        },
+        /// sets the buffer scaling factor
+        ///
+        /// This request sets an optional scaling factor on how the compositor
+        /// interprets the contents of the buffer attached to the window.
+        ///
+        /// Buffer scale is double-buffered state, see wl_surface.commit.
+        ///
+        /// A newly created surface has its buffer scale set to 1.
+        ///
+        /// wl_surface.set_buffer_scale changes the pending buffer scale.
+        /// wl_surface.commit copies the pending buffer scale to the current one.
+        /// Otherwise, the pending and current values are never changed.
+        ///
+        /// The purpose of this request is to allow clients to supply higher
+        /// resolution buffer data for use on high resolution outputs. It is
+        /// intended that you pick the same buffer scale as the scale of the
+        /// output that the surface is displayed on. This means the compositor
+        /// can avoid scaling when rendering the surface on that output.
+        ///
+        /// Note that if the scale is larger than 1, then you have to attach
+        /// a buffer that is larger (by a factor of scale in each dimension)
+        /// than the desired surface size.
+        ///
+        /// If scale is not greater than 0 the invalid_scale protocol error is
+        /// raised.
+        ///
+        /// Added in version 3.
+        SetBufferScale {
+            /// scale for interpreting buffer contents
+            scale: i32,
+        },
+        /// mark part of the surface damaged using buffer coordinates
+        ///
+        /// This request is used to describe the regions where the pending
+        /// buffer is different from the current surface contents, and where
+        /// the surface therefore needs to be repainted. The compositor
+        /// ignores the parts of the damage that fall outside of the surface.
+        ///
+        /// Damage is double-buffered state, see wl_surface.commit.
+        ///
+        /// The damage rectangle is specified in buffer coordinates,
+        /// where x and y specify the upper left corner of the damage rectangle.
+        ///
+        /// The initial value for pending damage is empty: no damage.
+        /// wl_surface.damage_buffer adds pending damage: the new pending
+        /// damage is the union of old pending damage and the given rectangle.
+        ///
+        /// wl_surface.commit assigns pending damage as the current damage,
+        /// and clears pending damage. The server will clear the current
+        /// damage as it repaints the surface.
+        ///
+        /// This request differs from wl_surface.damage in only one way - it
+        /// takes damage in buffer coordinates instead of surface-local
+        /// coordinates. While this generally is more intuitive than surface
+        /// coordinates, it is especially desirable when using wp_viewport
+        /// or when a drawing library (like EGL) is unaware of buffer scale
+        /// and buffer transform.
+        ///
+        /// Note: Because buffer transformation changes and damage requests may
+        /// be interleaved in the protocol stream, it is impossible to determine
+        /// the actual mapping between surface and buffer damage until
+        /// wl_surface.commit time. Therefore, compositors wishing to take both
+        /// kinds of damage into account will have to accumulate damage from the
+        /// two requests separately and only transform from one to the other
+        /// after receiving the wl_surface.commit.
+        ///
+        /// Added in version 4.
+        DamageBuffer {
+            /// buffer-local x coordinate
+            x: i32,
+            /// buffer-local y coordinate
+            y: i32,
+            /// width of damage rectangle
+            width: i32,
+            /// height of damage rectangle
+            height: i32,
+        },
+        /// set the surface contents offset
+        ///
+        /// The x and y arguments specify the location of the new pending
+        /// buffer's upper left corner, relative to the current buffer's upper
+        /// left corner, in surface-local coordinates. In other words, the
+        /// x and y, combined with the new surface size define in which
+        /// directions the surface's size changes.
+        ///
+        /// The exact semantics of wl_surface.offset are role-specific. Refer to
+        /// the documentation of specific roles for more information.
+        ///
+        /// Surface location offset is double-buffered state, see
+        /// wl_surface.commit.
+        ///
+        /// This request is semantically equivalent to and the replaces the x and y
+        /// arguments in the wl_surface.attach request in wl_surface versions prior
+        /// to 5. See wl_surface.attach for details.
+        ///
+        /// Added in version 5.
+        Offset {
+            /// surface-local x coordinate
+            x: i32,
+            /// surface-local y coordinate
+            y: i32,
+        },
+        /// get a release callback
+        ///
+        /// Create a callback for the release of the buffer attached by the client
+        /// with wl_surface.attach.
+        ///
+        /// The compositor will release the buffer when it has finished its usage of
+        /// the underlying storage for the relevant commit. Once the client receives
+        /// this event, and assuming the associated buffer is not pending release
+        /// from other wl_surface.commit requests, the client can safely re-use the
+        /// buffer.
+        ///
+        /// Release callbacks are double-buffered state, and will be associated
+        /// with the pending buffer at wl_surface.commit time.
+        ///
+        /// The callback_data passed in the wl_callback.done event is unused and
+        /// is always zero.
+        ///
+        /// Sending this request without attaching a non-null buffer in the same
+        /// content update is a protocol error. The compositor will send the
+        /// no_buffer error in this case.
+        ///
+        /// Added in version 7.
+        GetRelease {
+            /// callback object for the release
+            callback: super::NewId,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::Attach { .. } => 1,
+                Request::Damage { .. } => 2,
+                Request::Frame { .. } => 3,
+                Request::SetOpaqueRegion { .. } => 4,
+                Request::SetInputRegion { .. } => 5,
+                Request::Commit => 6,
+                Request::SetBufferTransform { .. } => 7,
+                Request::SetBufferScale { .. } => 8,
+                Request::DamageBuffer { .. } => 9,
+                Request::Offset { .. } => 10,
+                Request::GetRelease { .. } => 11,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::Attach { .. } => 1,
+                Request::Damage { .. } => 1,
+                Request::Frame { .. } => 1,
+                Request::SetOpaqueRegion { .. } => 1,
+                Request::SetInputRegion { .. } => 1,
+                Request::Commit => 1,
+                Request::SetBufferTransform { .. } => 2,
+                Request::SetBufferScale { .. } => 3,
+                Request::DamageBuffer { .. } => 4,
+                Request::Offset { .. } => 5,
+                Request::GetRelease { .. } => 7,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// surface enters an output
+        ///
+        /// This is emitted whenever a surface's creation, movement, or resizing
+        /// results in some part of it being within the scanout region of an
+        /// output.
+        ///
+        /// Note that a surface may be overlapping with zero or more outputs.
+        Enter {
+            /// output entered by the surface
+            output: super::ObjectId,
+        },
+        /// surface leaves an output
+        ///
+        /// This is emitted whenever a surface's creation, movement, or resizing
+        /// results in it no longer having any part of it within the scanout region
+        /// of an output.
+        ///
+        /// Clients should not use the number of outputs the surface is on for frame
+        /// throttling purposes. The surface might be hidden even if no leave event
+        /// has been sent, and the compositor might expect new surface content
+        /// updates even if no enter event has been sent. The frame event should be
+        /// used instead.
+        Leave {
+            /// output left by the surface
+            output: super::ObjectId,
+        },
+        /// preferred buffer scale for the surface
+        ///
+        /// This event indicates the preferred buffer scale for this surface. It is
+        /// sent whenever the compositor's preference changes.
+        ///
+        /// Before receiving this event the preferred buffer scale for this surface
+        /// is 1.
+        ///
+        /// It is intended that scaling aware clients use this event to scale their
+        /// content and use wl_surface.set_buffer_scale to indicate the scale they
+        /// have rendered with. This allows clients to supply a higher detail
+        /// buffer.
+        ///
+        /// The compositor shall emit a scale value greater than 0.
+        ///
+        /// Added in version 6.
+        PreferredBufferScale {
+            /// preferred scaling factor
+            factor: i32,
+        },
+        /// preferred buffer transform for the surface
+        ///
+        /// This event indicates the preferred buffer transform for this surface.
+        /// It is sent whenever the compositor's preference changes.
+        ///
+        /// Before receiving this event the preferred buffer transform for this
+        /// surface is normal.
+        ///
+        /// Applying this transformation to the surface buffer contents and using
+        /// wl_surface.set_buffer_transform might allow the compositor to use the
+        /// surface buffer more efficiently.
+        ///
+        /// Added in version 6.
+        PreferredBufferTransform {
+            /// preferred transform
+            transform: super::wl_output::Transform,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Enter { .. } => 0,
+                Event::Leave { .. } => 1,
+                Event::PreferredBufferScale { .. } => 2,
+                Event::PreferredBufferTransform { .. } => 3,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Enter { .. } => 1,
+                Event::Leave { .. } => 1,
+                Event::PreferredBufferScale { .. } => 6,
+                Event::PreferredBufferTransform { .. } => 6,
+            }
+        }
+    }
+
+    /// wl_surface error values
+    ///
+    /// These errors can be emitted in response to wl_surface requests.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// buffer scale value is invalid
+        InvalidScale = 0,
+        /// buffer transform value is invalid
+        InvalidTransform = 1,
+        /// buffer size is invalid
+        InvalidSize = 2,
+        /// buffer offset is invalid
+        InvalidOffset = 3,
+        /// surface was destroyed before its role object
+        DefunctRoleObject = 4,
+        /// no buffer was attached
+        NoBuffer = 5,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidScale),
+                1 => Some(Error::InvalidTransform),
+                2 => Some(Error::InvalidSize),
+                3 => Some(Error::InvalidOffset),
+                4 => Some(Error::DefunctRoleObject),
+                5 => Some(Error::NoBuffer),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// group of input devices
+///
+/// A seat is a group of keyboards, pointer and touch devices. This
+/// object is published as a global during start up, or when such a
+/// device is hot plugged.  A seat typically has a pointer and
+/// maintains a keyboard focus and a pointer focus.
+pub mod wl_seat {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_seat";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 11;
+
+    /// A `wl_seat` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlSeat(pub super::ObjectId);
+
+    impl WlSeat {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "get_pointer",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_pointer")],
+        },
+        super::MessageDesc {
+            name: "get_keyboard",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_keyboard")],
+        },
+        super::MessageDesc {
+            name: "get_touch",
+            since: 1,
+            signature: "n",
+            types: &[Some("wl_touch")],
+        },
+        super::MessageDesc {
+            name: "release",
+            since: 5,
+            signature: "5",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "capabilities",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "name",
+            since: 2,
+            signature: "2s",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// return pointer object
+        ///
+        /// The ID provided will be initialized to the wl_pointer interface
+        /// for this seat.
+        ///
+        /// This request only takes effect if the seat has the pointer
+        /// capability, or has had the pointer capability in the past.
+        /// It is a protocol violation to issue this request on a seat that has
+        /// never had the pointer capability. The missing_capability error will
+        /// be sent in this case.
+        GetPointer {
+            /// seat pointer
+            id: super::NewId,
+        },
+        /// return keyboard object
+        ///
+        /// The ID provided will be initialized to the wl_keyboard interface
+        /// for this seat.
+        ///
+        /// This request only takes effect if the seat has the keyboard
+        /// capability, or has had the keyboard capability in the past.
+        /// It is a protocol violation to issue this request on a seat that has
+        /// never had the keyboard capability. The missing_capability error will
+        /// be sent in this case.
+        GetKeyboard {
+            /// seat keyboard
+            id: super::NewId,
+        },
+        /// return touch object
+        ///
+        /// The ID provided will be initialized to the wl_touch interface
+        /// for this seat.
+        ///
+        /// This request only takes effect if the seat has the touch
+        /// capability, or has had the touch capability in the past.
+        /// It is a protocol violation to issue this request on a seat that has
+        /// never had the touch capability. The missing_capability error will
+        /// be sent in this case.
+        GetTouch {
+            /// seat touch interface
+            id: super::NewId,
+        },
+        /// release the seat object
+        ///
+        /// Using this request a client can tell the server that it is not going to
+        /// use the seat object anymore.
+        ///
+        /// Added in version 5.
+        Release,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::GetPointer { .. } => 0,
+                Request::GetKeyboard { .. } => 1,
+                Request::GetTouch { .. } => 2,
+                Request::Release => 3,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::GetPointer { .. } => 1,
+                Request::GetKeyboard { .. } => 1,
+                Request::GetTouch { .. } => 1,
+                Request::Release => 5,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// seat capabilities changed
+        ///
+        /// This is sent on binding to the seat global or whenever a seat gains
+        /// or loses the pointer, keyboard or touch capabilities.
+        /// The argument is a capability enum containing the complete set of
+        /// capabilities this seat has.
+        ///
+        /// When the pointer capability is added, a client may create a
+        /// wl_pointer object using the wl_seat.get_pointer request. This object
+        /// will receive pointer events until the capability is removed in the
+        /// future.
+        ///
+        /// When the pointer capability is removed, a client should destroy the
+        /// wl_pointer objects associated with the seat where the capability was
+        /// removed, using the wl_pointer.release request. No further pointer
+        /// events will be received on these objects.
+        ///
+        /// In some compositors, if a seat regains the pointer capability and a
+        /// client has a previously obtained wl_pointer object of version 4 or
+        /// less, that object may start sending pointer events again. This
+        /// behavior is considered a misinterpretation of the intended behavior
+        /// and must not be relied upon by the client. wl_pointer objects of
+        /// version 5 or later must not send events if created before the most
+        /// recent event notifying the client of an added pointer capability.
+        ///
+        /// The above behavior also applies to wl_keyboard and wl_touch with the
+        /// keyboard and touch capabilities, respectively.
+        Capabilities {
+            /// capabilities of the seat
+            capabilities: Capability,
+        },
+        /// unique identifier for this seat
+        ///
+        /// In a multi-seat configuration the seat name can be used by clients to
+        /// help identify which physical devices the seat represents.
+        ///
+        /// The seat name is a UTF-8 string with no convention defined for its
+        /// contents. Each name is unique among all wl_seat globals. The name is
+        /// only guaranteed to be unique for the current compositor instance.
+        ///
+        /// The same seat names are used for all clients. Thus, the name can be
+        /// shared across processes to refer to a specific wl_seat global.
+        ///
+        /// The name event is sent after binding to the seat global, and should be sent
+        /// before announcing capabilities. This event is only sent once per seat object,
+        /// and the name does not change over the lifetime of the wl_seat global.
+        ///
+        /// Compositors may re-use the same seat name if the wl_seat global is
+        /// destroyed and re-created later.
+        ///
+        /// Added in version 2.
+        Name {
+            /// seat identifier
+            name: ::std::string::String,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Capabilities { .. } => 0,
+                Event::Name { .. } => 1,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Capabilities { .. } => 1,
+                Event::Name { .. } => 2,
+            }
+        }
+    }
+
+    /// seat capability bitmask
+    ///
+    /// This is a bitmask of capabilities this seat has; if a member is
+    /// set, then it is present on the seat.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct Capability(u32);
+
+    impl Capability {
+        /// the seat has pointer devices
+        pub const POINTER: Self = Self(1);
+
+        /// the seat has one or more keyboards
+        pub const KEYBOARD: Self = Self(2);
+
+        /// the seat has touch devices
+        pub const TOUCH: Self = Self(4);
+
+        /// Creates a value from its bits, keeping unknown bits.
+        pub const fn from_bits(bits: u32) -> Self {
+            Self(bits)
+        }
+
+        /// Returns the bits of the value.
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+
+        /// Returns whether all of the bits of `other` are set.
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        /// Returns whether no bits are set.
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl ::core::ops::BitOr for Capability {
+        type Output = Self;
+
+        fn bitor(self, other: Self) -> Self {
+            Self(self.0 | other.0)
+        }
+    }
+
+    impl ::core::ops::BitAnd for Capability {
+        type Output = Self;
+
+        fn bitand(self, other: Self) -> Self {
+            Self(self.0 & other.0)
+        }
+    }
+
+    impl ::core::ops::BitOrAssign for Capability {
+        fn bitor_assign(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+    }
+
+    /// wl_seat error values
+    ///
+    /// These errors can be emitted in response to wl_seat requests.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// get_pointer, get_keyboard or get_touch called on seat without the matching capability
+        MissingCapability = 0,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::MissingCapability),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// pointer input device
+///
+/// The wl_pointer interface represents one or more input devices,
+/// such as mice, which control the pointer location and pointer_focus
+/// of a seat.
+///
+/// The wl_pointer interface generates motion, enter and leave
+/// events for the surfaces that the pointer is located over,
+/// and button and axis events for button presses, button releases
+/// and scrolling.
+pub mod wl_pointer {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_pointer";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 11;
+
+    /// A `wl_pointer` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlPointer(pub super::ObjectId);
+
+    impl WlPointer {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "set_cursor",
+            since: 1,
+            signature: "u?oii",
+            types: &[None, Some("wl_surface"), None, None],
+        },
+        super::MessageDesc {
+            name: "release",
+            since: 3,
+            signature: "3",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "enter",
+            since: 1,
+            signature: "uoff",
+            types: &[None, Some("wl_surface"), None, None],
+        },
+        super::MessageDesc {
+            name: "leave",
+            since: 1,
+            signature: "uo",
+            types: &[None, Some("wl_surface")],
+        },
+        super::MessageDesc {
+            name: "motion",
+            since: 1,
+            signature: "uff",
+            types: &[None, None, None],
+        },
+        super::MessageDesc {
+            name: "button",
+            since: 1,
+            signature: "uuuu",
+            types: &[None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "axis",
+            since: 1,
+            signature: "uuf",
+            types: &[None, None, None],
+        },
+        super::MessageDesc {
+            name: "frame",
+            since: 5,
+            signature: "5",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "axis_source",
+            since: 5,
+            signature: "5u",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "axis_stop",
+            since: 5,
+            signature: "5uu",
+            types: &[None, None],
+        },
+        super::MessageDesc {
+            name: "axis_discrete",
+            since: 5,
+            signature: "5ui",
+            types: &[None, None],
+        },
+        super::MessageDesc {
+            name: "axis_value120",
+            since: 8,
+            signature: "8ui",
+            types: &[None, None],
+        },
+        super::MessageDesc {
+            name: "axis_relative_direction",
+            since: 9,
+            signature: "9uu",
+            types: &[None, None],
+        },
+        super::MessageDesc {
+            name: "warp",
+            since: 11,
+            signature: "11ff",
+            types: &[None, None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// set the pointer surface
+        ///
+        /// Set the pointer surface, i.e., the surface that contains the
+        /// pointer image (cursor). This request gives the surface the role
+        /// of a cursor. If the surface already has another role, it raises
+        /// a protocol error.
+        ///
+        /// The cursor actually changes only if the pointer
+        /// focus for this device is one of the requesting client's surfaces
+        /// or the surface parameter is the current pointer surface. If
+        /// there was a previous surface set with this request it is
+        /// replaced. If surface is NULL, the pointer image is hidden.
+        ///
+        /// The parameters hotspot_x and hotspot_y define the position of
+        /// the pointer surface relative to the pointer location. Its
+        /// top-left corner is always at (x, y) - (hotspot_x, hotspot_y),
+        /// where (x, y) are the coordinates of the pointer location, in
+        /// surface-local coordinates.
+        ///
+        /// On wl_surface.offset requests to the pointer surface, hotspot_x
+        /// and hotspot_y are decremented by the x and y parameters
+        /// passed to the request. The offset must be applied by
+        /// wl_surface.commit as usual.
+        ///
+        /// The hotspot can also be updated by passing the currently set
+        /// pointer surface to this request with new values for hotspot_x
+        /// and hotspot_y.
+        ///
+        /// The input region is ignored for wl_surfaces with the role of
+        /// a cursor. When the use as a cursor ends, the wl_surface is
+        /// unmapped.
+        ///
+        /// The serial parameter must match the latest wl_pointer.enter
+        /// serial number sent to the client. Otherwise the request will be
+        /// ignored.
+        SetCursor {
+            /// serial number of the enter event
+            serial: u32,
+            /// pointer surface
+            surface: ::core::option::Option<super::ObjectId>,
+            /// surface-local x coordinate
+            hotspot_x: i32,
+            /// surface-local y coordinate
+            hotspot_y: i32,
+        },
+        /// release the pointer object
+        ///
+        /// Using this request a client can tell the server that it is not going to
+        /// use the pointer object anymore.
+        ///
+        /// This request destroys the pointer proxy object, so clients must not call
+        /// wl_pointer_destroy() after using this request.
+        ///
+        /// Added in version 3.
+        Release,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::SetCursor { .. } => 0,
+                Request::Release => 1,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::SetCursor { .. } => 1,
+                Request::Release => 3,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// enter event
+        ///
+        /// Notification that this seat's pointer is focused on a certain
+        /// surface.
+        ///
+        /// When a seat's focus enters a surface, the pointer image
+        /// is undefined and a client should respond to this event by setting
+        /// an appropriate pointer image with the set_cursor request.
+        Enter {
+            /// serial number of the enter event
+            serial: u32,
+            /// surface entered by the pointer
+            surface: super::ObjectId,
+            /// surface-local x coordinate
+            surface_x: super::Fixed,
+            /// surface-local y coordinate
+            surface_y: super::Fixed,
+        },
+        /// leave event
+        ///
+        /// Notification that this seat's pointer is no longer focused on
+        /// a certain surface.
+        ///
+        /// The leave notification is sent before the enter notification
+        /// for the new focus.
+        Leave {
+            /// serial number of the leave event
+            serial: u32,
+            /// surface left by the pointer
+            surface: super::ObjectId,
+        },
+        /// pointer motion event
+        ///
+        /// Notification of pointer location change. The arguments
+        /// surface_x and surface_y are the location relative to the
+        /// focused surface.
+        Motion {
+            /// timestamp with millisecond granularity
+            time: u32,
+            /// surface-local x coordinate
+            surface_x: super::Fixed,
+            /// surface-local y coordinate
+            surface_y: super::Fixed,
+        },
+        /// pointer button event
+        ///
+        /// Mouse button click and release notifications.
+        ///
+        /// The location of the click is given by the last motion, warp or
+        /// enter event.
+        /// The time argument is a timestamp with millisecond
+        /// granularity, with an undefined base.
+        ///
+        /// The button is a button code as defined in the Linux kernel's
+        /// linux/input-event-codes.h header file, e.g. BTN_LEFT.
+        ///
+        /// Any 16-bit button code value is reserved for future additions to the
+        /// kernel's event code list. All other button codes above 0xFFFF are
+        /// currently undefined but may be used in future versions of this
+        /// protocol.
+        Button {
+            /// serial number of the button event
+            serial: u32,
+            /// timestamp with millisecond granularity
+            time: u32,
+            /// button that produced the event
+            button: u32,
+            /// physical state of the button
+            state: ButtonState,
+        },
+        /// axis event
+        ///
+        /// Scroll and other axis notifications.
+        ///
+        /// For scroll events (vertical and horizontal scroll axes), the
+        /// value parameter is the length of a vector along the specified
+        /// axis in a coordinate space identical to those of motion events,
+        /// representing a relative movement along the specified axis.
+        ///
+        /// For devices that support movements non-parallel to axes multiple
+        /// axis events will be emitted.
+        ///
+        /// When applicable, for example for touch pads, the server can
+        /// choose to emit scroll events where the motion vector is
+        /// equivalent to a motion event vector.
+        ///
+        /// When applicable, a client can transform its content relative to the
+        /// scroll distance.
+        Axis {
+            /// timestamp with millisecond granularity
+            time: u32,
+            /// axis type
+            axis: Axis,
+            /// length of vector in surface-local coordinate space
+            value: super::Fixed,
+        },
+        /// end of a pointer event sequence
+        ///
+        /// Indicates the end of a set of events that logically belong together.
+        /// A client is expected to accumulate the data in all events within the
+        /// frame before proceeding.
+        ///
+        /// All wl_pointer events before a wl_pointer.frame event belong
+        /// logically together. For example, in a diagonal scroll motion the
+        /// compositor will send an optional wl_pointer.axis_source event, two
+        /// wl_pointer.axis events (horizontal and vertical) and finally a
+        /// wl_pointer.frame event. The client may use this information to
+        /// calculate a diagonal vector for scrolling.
+        ///
+        /// When multiple wl_pointer.axis events occur within the same frame,
+        /// the motion vector is the combined motion of all events.
+        /// When a wl_pointer.axis and a wl_pointer.axis_stop event occur within
+        /// the same frame, this indicates that axis movement in one axis has
+        /// stopped but continues in the other axis.
+        /// When multiple wl_pointer.axis_stop events occur within the same
+        /// frame, this indicates that these axes stopped in the same instance.
+        ///
+        /// A wl_pointer.frame event is sent for every logical event group,
+        /// even if the group only contains a single wl_pointer event.
+        /// Specifically, a client may get a sequence: motion, frame, button,
+        /// frame, axis, frame, axis_stop, frame.
+        ///
+        /// The wl_pointer.enter and wl_pointer.leave events are logical events
+        /// generated by the compositor and not the hardware. These events are
+        /// also grouped by a wl_pointer.frame. When a pointer moves from one
+        /// surface to another, a compositor should group the
+        /// wl_pointer.leave event within the same wl_pointer.frame.
+        /// However, a client must not rely on wl_pointer.leave and
+        /// wl_pointer.enter being in the same wl_pointer.frame.
+        /// Compositor-specific policies may require the wl_pointer.leave and
+        /// wl_pointer.enter event being split across multiple wl_pointer.frame
+        /// groups.
+        ///
+        /// Added in version 5.
+        Frame,
+        /// axis source event
+        ///
+        /// Source information for scroll and other axes.
+        ///
+        /// This event does not occur on its own. It is sent before a
+        /// wl_pointer.frame event and carries the source information for
+        /// all events within that frame.
+        ///
+        /// The source specifies how this event was generated. If the source is
+        /// wl_pointer.axis_source.finger, a wl_pointer.axis_stop event will be
+        /// sent when the user lifts the finger off the device.
+        ///
+        /// If the source is wl_pointer.axis_source.wheel,
+        /// wl_pointer.axis_source.wheel_tilt or
+        /// wl_pointer.axis_source.continuous, a wl_pointer.axis_stop event may
+        /// or may not be sent. Whether a compositor sends an axis_stop event
+        /// for these sources is hardware-specific and implementation-dependent;
+        /// clients must not rely on receiving an axis_stop event for these
+        /// scroll sources and should treat scroll sequences from these scroll
+        /// sources as unterminated by default.
+        ///
+        /// This event is optional. If the source is unknown for a particular
+        /// axis event sequence, no event is sent.
+        /// Only one wl_pointer.axis_source event is permitted per frame.
+        ///
+        /// The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+        /// not guaranteed.
+        ///
+        /// Added in version 5.
+        AxisSource {
+            /// source of the axis event
+            axis_source: AxisSource,
+        },
+        /// axis stop event
+        ///
+        /// Stop notification for scroll and other axes.
+        ///
+        /// For some wl_pointer.axis_source types, a wl_pointer.axis_stop event
+        /// is sent to notify a client that the axis sequence has terminated.
+        /// This enables the client to implement kinetic scrolling.
+        /// See the wl_pointer.axis_source documentation for information on when
+        /// this event may be generated.
+        ///
+        /// Any wl_pointer.axis events with the same axis_source after this
+        /// event should be considered as the start of a new axis motion.
+        ///
+        /// The timestamp is to be interpreted identical to the timestamp in the
+        /// wl_pointer.axis event. The timestamp value may be the same as a
+        /// preceding wl_pointer.axis event.
+        ///
+        /// Added in version 5.
+        AxisStop {
+            /// timestamp with millisecond granularity
+            time: u32,
+            /// the axis stopped with this event
+            axis: Axis,
+        },
+        /// axis click event
+        ///
+        /// Discrete step information for scroll and other axes.
+        ///
+        /// This event carries the axis value of the wl_pointer.axis event in
+        /// discrete steps (e.g. mouse wheel clicks).
+        ///
+        /// This event is deprecated with wl_pointer version 8 - this event is not
+        /// sent to clients supporting version 8 or later.
+        ///
+        /// This event does not occur on its own, it is coupled with a
+        /// wl_pointer.axis event that represents this axis value on a
+        /// continuous scale. The protocol guarantees that each axis_discrete
+        /// event is always followed by exactly one axis event with the same
+        /// axis number within the same wl_pointer.frame. Note that the protocol
+        /// allows for other events to occur between the axis_discrete and
+        /// its coupled axis event, including other axis_discrete or axis
+        /// events. A wl_pointer.frame must not contain more than one axis_discrete
+        /// event per axis type.
+        ///
+        /// This event is optional; continuous scrolling devices
+        /// like two-finger scrolling on touchpads do not have discrete
+        /// steps and do not generate this event.
+        ///
+        /// The discrete value carries the directional information. e.g. a value
+        /// of -2 is two steps towards the negative direction of this axis.
+        ///
+        /// The axis number is identical to the axis number in the associated
+        /// axis event.
+        ///
+        /// The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+        /// not guaranteed.
+        ///
+        /// Added in version 5.
+        /// Deprecated since version 8.
+        AxisDiscrete {
+            /// axis type
+            axis: Axis,
+            /// number of steps
+            discrete: i32,
+        },
+        /// axis high-resolution scroll event
+        ///
+        /// Discrete high-resolution scroll information.
+        ///
+        /// This event carries high-resolution wheel scroll information,
+        /// with each multiple of 120 representing one logical scroll step
+        /// (a wheel detent). For example, an axis_value120 of 30 is one quarter of
+        /// a logical scroll step in the positive direction, a value120 of
+        /// -240 are two logical scroll steps in the negative direction within the
+        /// same hardware event.
+        /// Clients that rely on discrete scrolling should accumulate the
+        /// value120 to multiples of 120 before processing the event.
+        ///
+        /// The value120 must not be zero.
+        ///
+        /// This event replaces the wl_pointer.axis_discrete event in clients
+        /// supporting wl_pointer version 8 or later.
+        ///
+        /// Where a wl_pointer.axis_source event occurs in the same
+        /// wl_pointer.frame, the axis source applies to this event.
+        ///
+        /// The order of wl_pointer.axis_value120 and wl_pointer.axis_source is
+        /// not guaranteed.
+        ///
+        /// Added in version 8.
+        AxisValue120 {
+            /// axis type
+            axis: Axis,
+            /// scroll distance as fraction of 120
+            value120: i32,
+        },
+        /// axis relative physical direction event
+        ///
+        /// Relative directional information of the entity causing the axis
+        /// motion.
+        ///
+        /// For a wl_pointer.axis event, the wl_pointer.axis_relative_direction
+        /// event specifies the movement direction of the entity causing the
+        /// wl_pointer.axis event. For example:
+        /// - if a user's fingers on a touchpad move down and this
+        ///   causes a wl_pointer.axis vertical_scroll down event, the physical
+        ///   direction is 'identical'
+        /// - if a user's fingers on a touchpad move down and this causes a
+        ///   wl_pointer.axis vertical_scroll up scroll up event ('natural
+        ///   scrolling'), the physical direction is 'inverted'.
+        ///
+        /// A client may use this information to adjust scroll motion of
+        /// components. Specifically, enabling natural scrolling causes the
+        /// content to change direction compared to traditional scrolling.
+        /// Some widgets like volume control sliders should usually match the
+        /// physical direction regardless of whether natural scrolling is
+        /// active. This event enables clients to match the scroll direction of
+        /// a widget to the physical direction.
+        ///
+        /// This event does not occur on its own, it is coupled with a
+        /// wl_pointer.axis event that represents this axis value.
+        /// The protocol guarantees that each axis_relative_direction event is
+        /// always followed by exactly one axis event with the same
+        /// axis number within the same wl_pointer.frame. Note that the protocol
+        /// allows for other events to occur between the axis_relative_direction
+        /// and its coupled axis event.
+        ///
+        /// The axis number is identical to the axis number in the associated
+        /// axis event.
+        ///
+        /// The order of wl_pointer.axis_relative_direction,
+        /// wl_pointer.axis_discrete and wl_pointer.axis_source is not
+        /// guaranteed.
+        ///
+        /// Added in version 9.
+        AxisRelativeDirection {
+            /// axis type
+            axis: Axis,
+            /// physical direction relative to axis motion
+            direction: AxisRelativeDirection,
+        },
+        /// pointer warp event
+        ///
+        /// Notification of pointer location change within a surface.
+        ///
+        /// This location change is not due to events on the input device,
+        /// but because either the surface under the pointer was moved and
+        /// thus the relative position of the pointer changed, or because
+        /// the compositor changed the pointer position in response to an
+        /// event like pointer confinement being exited.
+        ///
+        /// The arguments surface_x and surface_y are the location relative to
+        /// the focused surface.
+        ///
+        /// This event must not occur in the same wl_pointer.frame as a
+        /// wl_pointer.enter or wl_pointer.motion event.
+        ///
+        /// Added in version 11.
+        Warp {
+            /// surface-local x coordinate
+            surface_x: super::Fixed,
+            /// surface-local y coordinate
+            surface_y: super::Fixed,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Enter { .. } => 0,
+                Event::Leave { .. } => 1,
+                Event::Motion { .. } => 2,
+                Event::Button { .. } => 3,
+                Event::Axis { .. } => 4,
+                Event::Frame => 5,
+                Event::AxisSource { .. } => 6,
+                Event::AxisStop { .. } => 7,
+                Event::AxisDiscrete { .. } => 8,
+                Event::AxisValue120 { .. } => 9,
+                Event::AxisRelativeDirection { .. } => 10,
+                Event::Warp { .. } => 11,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Enter { .. } => 1,
+                Event::Leave { .. } => 1,
+                Event::Motion { .. } => 1,
+                Event::Button { .. } => 1,
+                Event::Axis { .. } => 1,
+                Event::Frame => 5,
+                Event::AxisSource { .. } => 5,
+                Event::AxisStop { .. } => 5,
+                Event::AxisDiscrete { .. } => 5,
+                Event::AxisValue120 { .. } => 8,
+                Event::AxisRelativeDirection { .. } => 9,
+                Event::Warp { .. } => 11,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// given wl_surface has another role
+        Role = 0,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::Role),
+                _ => None,
+            }
+        }
+    }
+
+    /// physical button state
+    ///
+    /// Describes the physical state of a button that produced the button
+    /// event.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum ButtonState {
+        /// the button is not pressed
+        Released = 0,
+        /// the button is pressed
+        Pressed = 1,
+    }
+
+    impl ButtonState {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(ButtonState::Released),
+                1 => Some(ButtonState::Pressed),
+                _ => None,
+            }
+        }
+    }
+
+    /// axis types
+    ///
+    /// Describes the axis types of scroll events.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Axis {
+        /// vertical axis
+        VerticalScroll = 0,
+        /// horizontal axis
+        HorizontalScroll = 1,
+    }
+
+    impl Axis {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Axis::VerticalScroll),
+                1 => Some(Axis::HorizontalScroll),
+                _ => None,
+            }
+        }
+    }
+
+    /// axis source types
+    ///
+    /// Describes the source types for axis events. This indicates to the
+    /// client how an axis event was physically generated; a client may
+    /// adjust the user interface accordingly. For example, scroll events
+    /// from a "finger" source may be in a smooth coordinate space with
+    /// kinetic scrolling whereas a "wheel" source may be in discrete steps
+    /// of a number of lines.
+    ///
+    /// The "continuous" axis source is a device generating events in a
+    /// continuous coordinate space, but using something other than a
+    /// finger. One example for this source is button-based scrolling where
+    /// the vertical motion of a device is converted to scroll events while
+    /// a button is held down.
+    ///
+    /// The "wheel tilt" axis source indicates that the actual device is a
+    /// wheel but the scroll event is not caused by a rotation but a
+    /// (usually sideways) tilt of the wheel.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum AxisSource {
+        /// a physical wheel rotation
+        Wheel = 0,
+        /// finger on a touch surface
+        Finger = 1,
+        /// continuous coordinate space
+        Continuous = 2,
+        /// a physical wheel tilt
+        ///
+        /// Added in version 6.
+        WheelTilt = 3,
+    }
+
+    impl AxisSource {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(AxisSource::Wheel),
+                1 => Some(AxisSource::Finger),
+                2 => Some(AxisSource::Continuous),
+                3 => Some(AxisSource::WheelTilt),
+                _ => None,
+            }
+        }
+    }
+
+    /// axis relative direction
+    ///
+    /// This specifies the direction of the physical motion that caused a
+    /// wl_pointer.axis event, relative to the wl_pointer.axis direction.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum AxisRelativeDirection {
+        /// physical motion matches axis direction
+        Identical = 0,
+        /// physical motion is the inverse of the axis direction
+        Inverted = 1,
+    }
+
+    impl AxisRelativeDirection {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(AxisRelativeDirection::Identical),
+                1 => Some(AxisRelativeDirection::Inverted),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// keyboard input device
+///
+/// The wl_keyboard interface represents one or more keyboards
+/// associated with a seat.
+///
+/// Each wl_keyboard has the following logical state:
+///
+/// - an active surface (possibly null),
+/// - the keys currently logically down,
+/// - the active modifiers,
+/// - the active group.
+///
+/// By default, the active surface is null, the keys currently logically down
+/// are empty, the active modifiers and the active group are 0.
+pub mod wl_keyboard {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_keyboard";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 11;
+
+    /// A `wl_keyboard` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlKeyboard(pub super::ObjectId);
+
+    impl WlKeyboard {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "release",
+            since: 3,
+            signature: "3",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "keymap",
+            since: 1,
+            signature: "uhu",
+            types: &[None, None, None],
+        },
+        super::MessageDesc {
+            name: "enter",
+            since: 1,
+            signature: "uoa",
+            types: &[None, Some("wl_surface"), None],
+        },
+        super::MessageDesc {
+            name: "leave",
+            since: 1,
+            signature: "uo",
+            types: &[None, Some("wl_surface")],
+        },
+        super::MessageDesc {
+            name: "key",
+            since: 1,
+            signature: "uuuu",
+            types: &[None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "modifiers",
+            since: 1,
+            signature: "uuuuu",
+            types: &[None, None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "repeat_info",
+            since: 4,
+            signature: "4ii",
+            types: &[None, None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// release the keyboard object
+        ///
+        /// Added in version 3.
+        Release,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Release => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Release => 3,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// keyboard mapping
+        ///
+        /// This event provides a file descriptor to the client which can be
+        /// memory-mapped in read-only mode to provide a keyboard mapping
+        /// description.
+        ///
+        /// From version 7 onwards, the fd must be mapped with MAP_PRIVATE by
+        /// the recipient, as MAP_SHARED may fail.
+        Keymap {
+            /// keymap format
+            format: KeymapFormat,
+            /// keymap file descriptor
+            fd: ::std::os::unix::io::OwnedFd,
+            /// keymap size, in bytes
+            size: u32,
+        },
+        /// enter event
+        ///
+        /// Notification that this seat's keyboard focus is on a certain
+        /// surface.
+        ///
+        /// The compositor must send the wl_keyboard.modifiers event after this
+        /// event.
+        ///
+        /// In the wl_keyboard logical state, this event sets the active surface to
+        /// the surface argument and the keys currently logically down to the keys
+        /// in the keys argument. The compositor must not send this event if the
+        /// wl_keyboard already had an active surface immediately before this event.
+        ///
+        /// Clients should not use the list of pressed keys to emulate key-press
+        /// events. The order of keys in the list is unspecified.
+        Enter {
+            /// serial number of the enter event
+            serial: u32,
+            /// surface gaining keyboard focus
+            surface: super::ObjectId,
+            /// the keys currently logically down
+            keys: ::std::vec::Vec<u8>,
+        },
+        /// leave event
+        ///
+        /// Notification that this seat's keyboard focus is no longer on
+        /// a certain surface.
+        ///
+        /// The leave notification is sent before the enter notification
+        /// for the new focus.
+        ///
+        /// In the wl_keyboard logical state, this event resets all values to their
+        /// defaults. The compositor must not send this event if the active surface
+        /// of the wl_keyboard was not equal to the surface argument immediately
+        /// before this event.
+        Leave {
+            /// serial number of the leave event
+            serial: u32,
+            /// surface that lost keyboard focus
+            surface: super::ObjectId,
+        },
+        /// key event
+        ///
+        /// A key was pressed or released.
+        /// The time argument is a timestamp with millisecond
+        /// granularity, with an undefined base.
+        ///
+        /// The key is a platform-specific key code that can be interpreted
+        /// by feeding it to the keyboard mapping (see the keymap event).
+        ///
+        /// If this event produces a change in modifiers, then the resulting
+        /// wl_keyboard.modifiers event must be sent after this event.
+        ///
+        /// In the wl_keyboard logical state, this event adds the key to the keys
+        /// currently logically down (if the state argument is pressed) or removes
+        /// the key from the keys currently logically down (if the state argument is
+        /// released). The compositor must not send this event if the wl_keyboard
+        /// did not have an active surface immediately before this event. The
+        /// compositor must not send this event if state is pressed (resp. released)
+        /// and the key was already logically down (resp. was not logically down)
+        /// immediately before this event.
+        ///
+        /// Since version 10, compositors may send key events with the "repeated"
+        /// key state when a wl_keyboard.repeat_info event with a rate argument of
+        /// 0 has been received. This allows the compositor to take over the
+        /// responsibility of key repetition.
+        Key {
+            /// serial number of the key event
+            serial: u32,
+            /// timestamp with millisecond granularity
+            time: u32,
+            /// key that produced the event
+            key: u32,
+            /// physical state of the key
+            state: KeyState,
+        },
+        /// modifier and group state
+        ///
+        /// Notifies clients that the modifier and/or group state has
+        /// changed, and it should update its local state.
+        ///
+        /// The compositor may send this event without a surface of the client
+        /// having keyboard focus, for example to tie modifier information to
+        /// pointer focus instead. If a modifier event with pressed modifiers is sent
+        /// without a prior enter event, the client can assume the modifier state is
+        /// valid until it receives the next wl_keyboard.modifiers event. In order to
+        /// reset the modifier state again, the compositor can send a
+        /// wl_keyboard.modifiers event with no pressed modifiers.
+        ///
+        /// In the wl_keyboard logical state, this event updates the modifiers and
+        /// group.
+        Modifiers {
+            /// serial number of the modifiers event
+            serial: u32,
+            /// depressed modifiers
+            mods_depressed: u32,
+            /// latched modifiers
+            mods_latched: u32,
+            /// locked modifiers
+            mods_locked: u32,
+            /// keyboard layout
+            group: u32,
+        },
+        /// repeat rate and delay
+        ///
+        /// Informs the client about the keyboard's repeat rate and delay.
+        ///
+        /// This event is sent as soon as the wl_keyboard object has been created,
+        /// and is guaranteed to be received by the client before any key press
+        /// event.
+        ///
+        /// Negative values for either rate or delay are illegal. A rate of zero
+        /// will disable any repeating (regardless of the value of delay).
+        ///
+        /// This event can be sent later on as well with a new value if necessary,
+        /// so clients should continue listening for the event past the creation
+        /// of wl_keyboard.
+        ///
+        /// Added in version 4.
+        RepeatInfo {
+            /// the rate of repeating keys in characters per second
+            rate: i32,
+            /// delay in milliseconds since key down until repeating starts
+            delay: i32,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Keymap { .. } => 0,
+                Event::Enter { .. } => 1,
+                Event::Leave { .. } => 2,
+                Event::Key { .. } => 3,
+                Event::Modifiers { .. } => 4,
+                Event::RepeatInfo { .. } => 5,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Keymap { .. } => 1,
+                Event::Enter { .. } => 1,
+                Event::Leave { .. } => 1,
+                Event::Key { .. } => 1,
+                Event::Modifiers { .. } => 1,
+                Event::RepeatInfo { .. } => 4,
+            }
+        }
+    }
+
+    /// keyboard mapping format
+    ///
+    /// This specifies the format of the keymap provided to the
+    /// client with the wl_keyboard.keymap event.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum KeymapFormat {
+        /// no keymap; client must understand how to interpret the raw keycode
+        NoKeymap = 0,
+        /// libxkbcommon compatible, null-terminated string; to determine the xkb keycode, clients must add 8 to the key event keycode
+        XkbV1 = 1,
+    }
+
+    impl KeymapFormat {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(KeymapFormat::NoKeymap),
+                1 => Some(KeymapFormat::XkbV1),
+                _ => None,
+            }
+        }
+    }
+
+    /// physical key state
+    ///
+    /// Describes the physical state of a key that produced the key event.
+    ///
+    /// Since version 10, the key can be in a "repeated" pseudo-state which
+    /// means the same as "pressed", but is used to signal repetition in the
+    /// key event.
+    ///
+    /// The key may only enter the repeated state after entering the pressed
+    /// state and before entering the released state. This event may be
+    /// generated multiple times while the key is down.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum KeyState {
+        /// key is not pressed
+        Released = 0,
+        /// key is pressed
+        Pressed = 1,
+        /// key was repeated
+        ///
+        /// Added in version 10.
+        Repeated = 2,
+    }
+
+    impl KeyState {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(KeyState::Released),
+                1 => Some(KeyState::Pressed),
+                2 => Some(KeyState::Repeated),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// touchscreen input device
+///
+/// The wl_touch interface represents a touchscreen
+/// associated with a seat.
+///
+/// Touch interactions can consist of one or more contacts.
+/// For each contact, a series of events is generated, starting
+/// with a down event, followed by zero or more motion events,
+/// and ending with an up event. Events relating to the same
+/// contact point can be identified by the ID of the sequence.
+pub mod wl_touch {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_touch";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 11;
+
+    /// A `wl_touch` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlTouch(pub super::ObjectId);
+
+    impl WlTouch {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "release",
+            since: 3,
+            signature: "3",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "down",
+            since: 1,
+            signature: "uuoiff",
+            types: &[None, None, Some("wl_surface"), None, None, None],
+        },
+        super::MessageDesc {
+            name: "up",
+            since: 1,
+            signature: "uui",
+            types: &[None, None, None],
+        },
+        super::MessageDesc {
+            name: "motion",
+            since: 1,
+            signature: "uiff",
+            types: &[None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "frame",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "cancel",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "shape",
+            since: 6,
+            signature: "6iff",
+            types: &[None, None, None],
+        },
+        super::MessageDesc {
+            name: "orientation",
+            since: 6,
+            signature: "6if",
+            types: &[None, None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// release the touch object
+        ///
+        /// Added in version 3.
+        Release,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Release => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Release => 3,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// touch down event and beginning of a touch sequence
+        ///
+        /// A new touch point has appeared on the surface. This touch point is
+        /// assigned a unique ID. Future events from this touch point reference
+        /// this ID. The ID ceases to be valid after a touch up event and may be
+        /// reused in the future.
+        Down {
+            /// serial number of the touch down event
+            serial: u32,
+            /// timestamp with millisecond granularity
+            time: u32,
+            /// surface touched
+            surface: super::ObjectId,
+            /// the unique ID of this touch point
+            id: i32,
+            /// surface-local x coordinate
+            x: super::Fixed,
+            /// surface-local y coordinate
+            y: super::Fixed,
+        },
+        /// end of a touch event sequence
+        ///
+        /// The touch point has disappeared. No further events will be sent for
+        /// this touch point and the touch point's ID is released and may be
+        /// reused in a future touch down event.
+        Up {
+            /// serial number of the touch up event
+            serial: u32,
+            /// timestamp with millisecond granularity
+            time: u32,
+            /// the unique ID of this touch point
+            id: i32,
+        },
+        /// update of touch point coordinates
+        ///
+        /// A touch point has changed coordinates.
+        Motion {
+            /// timestamp with millisecond granularity
+            time: u32,
+            /// the unique ID of this touch point
+            id: i32,
+            /// surface-local x coordinate
+            x: super::Fixed,
+            /// surface-local y coordinate
+            y: super::Fixed,
+        },
+        /// end of touch frame event
+        ///
+        /// Indicates the end of a set of events that logically belong together.
+        /// A client is expected to accumulate the data in all events within the
+        /// frame before proceeding.
+        ///
+        /// A wl_touch.frame terminates at least one event but otherwise no
+        /// guarantee is provided about the set of events within a frame. A client
+        /// must assume that any state not updated in a frame is unchanged from the
+        /// previously known state.
+        Frame,
+        /// touch session cancelled
+        ///
+        /// Sent if the compositor decides the touch stream is a global
+        /// gesture. No further events are sent to the clients from that
+        /// particular gesture. Touch cancellation applies to all touch points
+        /// currently active on this client's surface. The client is
+        /// responsible for finalizing the touch points, future touch points on
+        /// this surface may reuse the touch point ID.
+        ///
+        /// No frame event is required after the cancel event.
+        Cancel,
+        /// update shape of touch point
+        ///
+        /// Sent when a touchpoint has changed its shape.
+        ///
+        /// This event does not occur on its own. It is sent before a
+        /// wl_touch.frame event and carries the new shape information for
+        /// any previously reported, or new touch points of that frame.
+        ///
+        /// Other events describing the touch point such as wl_touch.down,
+        /// wl_touch.motion or wl_touch.orientation may be sent within the
+        /// same wl_touch.frame. A client should treat these events as a single
+        /// logical touch point update. The order of wl_touch.shape,
+        /// wl_touch.orientation and wl_touch.motion is not guaranteed.
+        /// A wl_touch.down event is guaranteed to occur before the first
+        /// wl_touch.shape event for this touch ID but both events may occur within
+        /// the same wl_touch.frame.
+        ///
+        /// A touchpoint shape is approximated by an ellipse through the major and
+        /// minor axis length. The major axis length describes the longer diameter
+        /// of the ellipse, while the minor axis length describes the shorter
+        /// diameter. Major and minor are orthogonal and both are specified in
+        /// surface-local coordinates. The center of the ellipse is always at the
+        /// touchpoint location as reported by wl_touch.down or wl_touch.motion.
+        ///
+        /// This event is only sent by the compositor if the touch device supports
+        /// shape reports. The client has to make reasonable assumptions about the
+        /// shape if it did not receive this event.
+        ///
+        /// Added in version 6.
+        Shape {
+            /// the unique ID of this touch point
+            id: i32,
+            /// length of the major axis in surface-local coordinates
+            major: super::Fixed,
+            /// length of the minor axis in surface-local coordinates
+            minor: super::Fixed,
+        },
+        /// update orientation of touch point
+        ///
+        /// Sent when a touchpoint has changed its orientation.
+        ///
+        /// This event does not occur on its own. It is sent before a
+        /// wl_touch.frame event and carries the new shape information for
+        /// any previously reported, or new touch points of that frame.
+        ///
+        /// Other events describing the touch point such as wl_touch.down,
+        /// wl_touch.motion or wl_touch.shape may be sent within the
+        /// same wl_touch.frame. A client should treat these events as a single
+        /// logical touch point update. The order of wl_touch.shape,
+        /// wl_touch.orientation and wl_touch.motion is not guaranteed.
+        /// A wl_touch.down event is guaranteed to occur before the first
+        /// wl_touch.orientation event for this touch ID but both events may occur
+        /// within the same wl_touch.frame.
+        ///
+        /// The orientation describes the clockwise angle of a touchpoint's major
+        /// axis to the positive surface y-axis and is normalized to the -180 to
+        /// +180 degree range. The granularity of orientation depends on the touch
+        /// device, some devices only support binary rotation values between 0 and
+        /// 90 degrees.
+        ///
+        /// This event is only sent by the compositor if the touch device supports
+        /// orientation reports.
+        ///
+        /// Added in version 6.
+        Orientation {
+            /// the unique ID of this touch point
+            id: i32,
+            /// angle between major axis and positive surface y-axis in degrees
+            orientation: super::Fixed,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Down { .. } => 0,
+                Event::Up { .. } => 1,
+                Event::Motion { .. } => 2,
+                Event::Frame => 3,
+                Event::Cancel => 4,
+                Event::Shape { .. } => 5,
+                Event::Orientation { .. } => 6,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Down { .. } => 1,
+                Event::Up { .. } => 1,
+                Event::Motion { .. } => 1,
+                Event::Frame => 1,
+                Event::Cancel => 1,
+                Event::Shape { .. } => 6,
+                Event::Orientation { .. } => 6,
+            }
+        }
+    }
+}
+
+/// compositor output region
+///
+/// An output describes part of the compositor geometry.  The
+/// compositor works in the 'compositor coordinate system' and an
+/// output corresponds to a rectangular area in that space that is
+/// actually visible.  This typically corresponds to a monitor that
+/// displays part of the compositor space.  This object is published
+/// as global during start up, or when a monitor is hotplugged.
+pub mod wl_output {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_output";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 4;
+
+    /// A `wl_output` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlOutput(pub super::ObjectId);
+
+    impl WlOutput {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "release",
+            since: 3,
+            signature: "3",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "geometry",
+            since: 1,
+            signature: "iiiiissi",
+            types: &[None, None, None, None, None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "mode",
+            since: 1,
+            signature: "uiii",
+            types: &[None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "done",
+            since: 2,
+            signature: "2",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "scale",
+            since: 2,
+            signature: "2i",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "name",
+            since: 4,
+            signature: "4s",
+            types: &[None],
+        },
+        super::MessageDesc {
+            name: "description",
+            since: 4,
+            signature: "4s",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// release the output object
+        ///
+        /// Using this request a client can tell the server that it is not going to
+        /// use the output object anymore.
+        ///
+        /// Added in version 3.
+        Release,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Release => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Release => 3,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// properties of the output
+        ///
+        /// The geometry event describes geometric properties of the output.
+        /// The event is sent when binding to the output object and whenever
+        /// any of the properties change.
+        ///
+        /// The physical size can be set to zero if it doesn't make sense for this
+        /// output (e.g. for projectors or virtual outputs).
+        ///
+        /// The geometry event will be followed by a done event (starting from
+        /// version 2).
+        ///
+        /// Clients should use wl_surface.preferred_buffer_transform instead of the
+        /// transform advertised by this event to find the preferred buffer
+        /// transform to use for a surface.
+        ///
+        /// Note: wl_output only advertises partial information about the output
+        /// position and identification. Some compositors, for instance those not
+        /// implementing a desktop-style output layout or those exposing virtual
+        /// outputs, might fake this information. Instead of using x and y, clients
+        /// should use xdg_output.logical_position. Instead of using make and model,
+        /// clients should use name and description.
+        Geometry {
+            /// x position within the global compositor space
+            x: i32,
+            /// y position within the global compositor space
+            y: i32,
+            /// width in millimeters of the output
+            physical_width: i32,
+            /// height in millimeters of the output
+            physical_height: i32,
+            /// subpixel orientation of the output
+            subpixel: Subpixel,
+            /// textual description of the manufacturer
+            make: ::std::string::String,
+            /// textual description of the model
+            model: ::std::string::String,
+            /// additional transformation applied to buffer contents during presentation
+            transform: Transform,
+        },
+        /// advertise available modes for the output
+        ///
+        /// The mode event describes an available mode for the output.
+        ///
+        /// The event is sent when binding to the output object and there
+        /// will always be one mode, the current mode.  The event is sent
+        /// again if an output changes mode, for the mode that is now
+        /// current.  In other words, the current mode is always the last
+        /// mode that was received with the current flag set.
+        ///
+        /// Non-current modes are deprecated. A compositor can decide to only
+        /// advertise the current mode and never send other modes. Clients
+        /// should not rely on non-current modes.
+        ///
+        /// The size of a mode is given in physical hardware units of
+        /// the output device. This is not necessarily the same as
+        /// the output size in the global compositor space. For instance,
+        /// the output may be scaled, as described in wl_output.scale,
+        /// or transformed, as described in wl_output.transform. Clients
+        /// willing to retrieve the output size in the global compositor
+        /// space should use xdg_output.logical_size instead.
+        ///
+        /// The vertical refresh rate can be set to zero if it doesn't make
+        /// sense for this output (e.g. for virtual outputs).
+        ///
+        /// The mode event will be followed by a done event (starting from
+        /// version 2).
+        ///
+        /// Clients should not use the refresh rate to schedule frames. Instead,
+        /// they should use the wl_surface.frame event or the presentation-time
+        /// protocol.
+        ///
+        /// Note: this information is not always meaningful for all outputs. Some
+        /// compositors, such as those exposing virtual outputs, might fake the
+        /// refresh rate or the size.
+        Mode {
+            /// bitfield of mode flags
+            flags: Mode,
+            /// width of the mode in hardware units
+            width: i32,
+            /// height of the mode in hardware units
+            height: i32,
+            /// vertical refresh rate in mHz
+            refresh: i32,
+        },
+        /// sent all information about output
+        ///
+        /// This event is sent after all other properties have been
+        /// sent after binding to the output object and after any
+        /// other property changes done after that. This allows
+        /// changes to the output properties to be seen as
+        /// atomic, even if they happen via multiple events.
+        ///
+        /// Added in version 2.
+        Done,
+        /// output scaling properties
+        ///
+        /// This event contains scaling geometry information
+        /// that is not in the geometry event. It may be sent after
+        /// binding the output object or if the output scale changes
+        /// later. The compositor will emit a non-zero, positive
+        /// value for scale. If it is not sent, the client should
+        /// assume a scale of 1.
+        ///
+        /// A scale larger than 1 means that the compositor will
+        /// automatically scale surface buffers by this amount
+        /// when rendering. This is used for very high resolution
+        /// displays where applications rendering at the native
+        /// resolution would be too small to be legible.
+        ///
+        /// Clients should use wl_surface.preferred_buffer_scale
+        /// instead of this event to find the preferred buffer
+        /// scale to use for a surface.
+        ///
+        /// The scale event will be followed by a done event.
+        ///
+        /// Added in version 2.
+        Scale {
+            /// scaling factor of output
+            factor: i32,
+        },
+        /// name of this output
+        ///
+        /// Many compositors will assign user-friendly names to their outputs, show
+        /// them to the user, allow the user to refer to an output, etc. The client
+        /// may wish to know this name as well to offer the user similar behaviors.
+        ///
+        /// The name is a UTF-8 string with no convention defined for its contents.
+        /// Each name is unique among all wl_output globals. The name is only
+        /// guaranteed to be unique for the compositor instance.
+        ///
+        /// The same output name is used for all clients for a given wl_output
+        /// global. Thus, the name can be shared across processes to refer to a
+        /// specific wl_output global.
+        ///
+        /// The name is not guaranteed to be persistent across sessions, thus cannot
+        /// be used to reliably identify an output in e.g. configuration files.
+        ///
+        /// Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do
+        /// not assume that the name is a reflection of an underlying DRM connector,
+        /// X11 connection, etc.
+        ///
+        /// The name event is sent after binding the output object. This event is
+        /// only sent once per output object, and the name does not change over the
+        /// lifetime of the wl_output global.
+        ///
+        /// Compositors may re-use the same output name if the wl_output global is
+        /// destroyed and re-created later. Compositors should avoid re-using the
+        /// same name if possible.
+        ///
+        /// The name event will be followed by a done event.
+        ///
+        /// Added in version 4.
+        Name {
+            /// output name
+            name: ::std::string::String,
+        },
+        /// human-readable description of this output
+        ///
+        /// Many compositors can produce human-readable descriptions of their
+        /// outputs. The client may wish to know this description as well, e.g. for
+        /// output selection purposes.
+        ///
+        /// The description is a UTF-8 string with no convention defined for its
+        /// contents. The description is not guaranteed to be unique among all
+        /// wl_output globals. Examples might include 'Foocorp 11" Display' or
+        /// 'Virtual X11 output via :1'.
+        ///
+        /// The description event is sent after binding the output object and
+        /// whenever the description changes. The description is optional, and may
+        /// not be sent at all.
+        ///
+        /// The description event will be followed by a done event.
+        ///
+        /// Added in version 4.
+        Description {
+            /// output description
+            description: ::std::string::String,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Geometry { .. } => 0,
+                Event::Mode { .. } => 1,
+                Event::Done => 2,
+                Event::Scale { .. } => 3,
+                Event::Name { .. } => 4,
+                Event::Description { .. } => 5,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Geometry { .. } => 1,
+                Event::Mode { .. } => 1,
+                Event::Done => 2,
+                Event::Scale { .. } => 2,
+                Event::Name { .. } => 4,
+                Event::Description { .. } => 4,
+            }
+        }
+    }
+
+    /// subpixel geometry information
+    ///
+    /// This enumeration describes how the physical
+    /// pixels on an output are laid out.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Subpixel {
+        /// unknown geometry
+        Unknown = 0,
+        /// no geometry
+        None = 1,
+        /// horizontal RGB
+        HorizontalRgb = 2,
+        /// horizontal BGR
+        HorizontalBgr = 3,
+        /// vertical RGB
+        VerticalRgb = 4,
+        /// vertical BGR
+        VerticalBgr = 5,
+    }
+
+    impl Subpixel {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Subpixel::Unknown),
+                1 => Some(Subpixel::None),
+                2 => Some(Subpixel::HorizontalRgb),
+                3 => Some(Subpixel::HorizontalBgr),
+                4 => Some(Subpixel::VerticalRgb),
+                5 => Some(Subpixel::VerticalBgr),
+                _ => None,
+            }
+        }
+    }
+
+    /// transformation applied to buffer contents
+    ///
+    /// This describes transformations that clients and compositors apply to
+    /// buffer contents.
+    ///
+    /// The flipped values correspond to an initial flip around a
+    /// vertical axis followed by rotation.
+    ///
+    /// The purpose is mainly to allow clients to render accordingly and
+    /// tell the compositor, so that for fullscreen surfaces, the
+    /// compositor will still be able to scan out directly from client
+    /// surfaces.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Transform {
+        /// no transform
+        Normal = 0,
+        /// 90 degrees counter-clockwise
+        _90 = 1,
+        /// 180 degrees counter-clockwise
+        _180 = 2,
+        /// 270 degrees counter-clockwise
+        _270 = 3,
+        /// 180 degree flip around a vertical axis
+        Flipped = 4,
+        /// flip and rotate 90 degrees counter-clockwise
+        Flipped90 = 5,
+        /// flip and rotate 180 degrees counter-clockwise
+        Flipped180 = 6,
+        /// flip and rotate 270 degrees counter-clockwise
+        Flipped270 = 7,
+    }
+
+    impl Transform {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Transform::Normal),
+                1 => Some(Transform::_90),
+                2 => Some(Transform::_180),
+                3 => Some(Transform::_270),
+                4 => Some(Transform::Flipped),
+                5 => Some(Transform::Flipped90),
+                6 => Some(Transform::Flipped180),
+                7 => Some(Transform::Flipped270),
+                _ => None,
+            }
+        }
+    }
+
+    /// mode information
+    ///
+    /// These flags describe properties of an output mode.
+    /// They are used in the flags bitfield of the mode event.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct Mode(u32);
+
+    impl Mode {
+        /// indicates this is the current mode
+        pub const CURRENT: Self = Self(1);
+
+        /// indicates this is the preferred mode
+        pub const PREFERRED: Self = Self(2);
+
+        /// Creates a value from its bits, keeping unknown bits.
+        pub const fn from_bits(bits: u32) -> Self {
+            Self(bits)
+        }
+
+        /// Returns the bits of the value.
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+
+        /// Returns whether all of the bits of `other` are set.
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        /// Returns whether no bits are set.
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl ::core::ops::BitOr for Mode {
+        type Output = Self;
+
+        fn bitor(self, other: Self) -> Self {
+            Self(self.0 | other.0)
+        }
+    }
+
+    impl ::core::ops::BitAnd for Mode {
+        type Output = Self;
+
+        fn bitand(self, other: Self) -> Self {
+            Self(self.0 & other.0)
+        }
+    }
+
+    impl ::core::ops::BitOrAssign for Mode {
+        fn bitor_assign(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+    }
+}
+
+/// region interface
+///
+/// A region object describes an area.
+///
+/// Region objects are used to describe the opaque and input
+/// regions of a surface.
+pub mod wl_region {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_region";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 7;
+
+    /// A `wl_region` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlRegion(pub super::ObjectId);
+
+    impl WlRegion {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "add",
+            since: 1,
+            signature: "iiii",
+            types: &[None, None, None, None],
+        },
+        super::MessageDesc {
+            name: "subtract",
+            since: 1,
+            signature: "iiii",
+            types: &[None, None, None, None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// destroy region
+        ///
+        /// Destroy the region.  This will invalidate the object ID.
+        Destroy,
+        /// add rectangle to region
+        ///
+        /// Add the specified rectangle to the region.
+        Add {
+            /// region-local x coordinate
+            x: i32,
+            /// region-local y coordinate
+            y: i32,
+            /// rectangle width
+            width: i32,
+            /// rectangle height
+            height: i32,
+        },
+        /// subtract rectangle from region
+        ///
+        /// Subtract the specified rectangle from the region.
+        Subtract {
+            /// region-local x coordinate
+            x: i32,
+            /// region-local y coordinate
+            y: i32,
+            /// rectangle width
+            width: i32,
+            /// rectangle height
+            height: i32,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::Add { .. } => 1,
+                Request::Subtract { .. } => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::Add { .. } => 1,
+                Request::Subtract { .. } => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+}
+
+/// sub-surface compositing
+///
+/// The global interface exposing sub-surface compositing capabilities.
+/// A wl_surface, that has sub-surfaces associated, is called the
+/// parent surface. Sub-surfaces can be arbitrarily nested and create
+/// a tree of sub-surfaces.
+///
+/// The root surface in a tree of sub-surfaces is the main
+/// surface. The main surface cannot be a sub-surface, because
+/// sub-surfaces must always have a parent.
+///
+/// A main surface with its sub-surfaces forms a (compound) window.
+/// For window management purposes, this set of wl_surface objects is
+/// to be considered as a single window, and it should also behave as
+/// such.
+///
+/// The aim of sub-surfaces is to offload some of the compositing work
+/// within a window from clients to the compositor. A prime example is
+/// a video player with decorations and video in separate wl_surface
+/// objects. This should allow the compositor to pass YUV video buffer
+/// processing to dedicated overlay hardware when possible.
+pub mod wl_subcompositor {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_subcompositor";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 1;
+
+    /// A `wl_subcompositor` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlSubcompositor(pub super::ObjectId);
+
+    impl WlSubcompositor {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "get_subsurface",
+            since: 1,
+            signature: "noo",
+            types: &[Some("wl_subsurface"), Some("wl_surface"), Some("wl_surface")],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// unbind from the subcompositor interface
+        ///
+        /// Informs the server that the client will not be using this
+        /// protocol object anymore. This does not affect any other
+        /// objects, wl_subsurface objects included.
+        Destroy,
+        /// give a surface the role sub-surface
+        ///
+        /// Create a sub-surface interface for the given surface, and
+        /// associate it with the given parent surface. This turns a
+        /// plain wl_surface into a sub-surface.
+        ///
+        /// The to-be sub-surface must not already have another role, and it
+        /// must not have an existing wl_subsurface object. Otherwise the
+        /// bad_surface protocol error is raised.
+        ///
+        /// Adding sub-surfaces to a parent is a double-buffered operation on the
+        /// parent (see wl_surface.commit). The effect of adding a sub-surface
+        /// becomes visible on the next time the state of the parent surface is
+        /// applied.
+        ///
+        /// The parent surface must not be one of the child surface's descendants,
+        /// and the parent must be different from the child surface, otherwise the
+        /// bad_parent protocol error is raised.
+        ///
+        /// This request modifies the behaviour of wl_surface.commit request on
+        /// the sub-surface, see the documentation on wl_subsurface interface.
+        GetSubsurface {
+            /// the new sub-surface object ID
+            id: super::NewId,
+            /// the surface to be turned into a sub-surface
+            surface: super::ObjectId,
+            /// the parent surface
+            parent: super::ObjectId,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::GetSubsurface { .. } => 1,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::GetSubsurface { .. } => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// the to-be sub-surface is invalid
+        BadSurface = 0,
+        /// the to-be sub-surface parent is invalid
+        BadParent = 1,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::BadSurface),
+                1 => Some(Error::BadParent),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// sub-surface interface to a wl_surface
+///
+/// An additional interface to a wl_surface object, which has been
+/// made a sub-surface. A sub-surface has one parent surface. A
+/// sub-surface's size and position are not limited to that of the parent.
+/// Particularly, a sub-surface is not automatically clipped to its
+/// parent's area.
+///
+/// A sub-surface becomes mapped, when a non-NULL wl_buffer is applied
+/// and the parent surface is mapped. The order of which one happens
+/// first is irrelevant. A sub-surface is hidden if the parent becomes
+/// hidden, or if a NULL wl_buffer is applied. These rules apply
+/// recursively through the tree of surfaces.
+///
+/// A sub-surface can be in one of two modes. The possible modes are
+/// synchronized and desynchronized, see methods wl_subsurface.set_sync and
+/// wl_subsurface.set_desync.
+///
+/// The main surface can be thought to be always in desynchronized mode,
+/// since it does not have a parent in the sub-surfaces sense.
+///
+/// Even if a sub-surface is in desynchronized mode, it will behave as
+/// in synchronized mode, if its parent surface behaves as in
+/// synchronized mode. This rule is applied recursively throughout the
+/// tree of surfaces. This means, that one can set a sub-surface into
+/// synchronized mode, and then assume that all its child and grand-child
+/// sub-surfaces are synchronized, too, without explicitly setting them.
+///
+/// If a surface behaves as in synchronized mode, it is effectively
+/// synchronized, otherwise it is effectively desynchronized.
+///
+/// A sub-surface is initially in the synchronized mode.
+///
+/// The wl_subsurface interface has requests which modify double-buffered
+/// state of the parent surface (wl_subsurface.set_position, .place_above and
+/// .place_below).
+///
+/// Destroying a sub-surface takes effect immediately. If you need to
+/// synchronize the removal of a sub-surface to the parent surface update,
+/// unmap the sub-surface first by attaching a NULL wl_buffer, update parent,
+/// and then destroy the sub-surface.
+///
+/// If the parent wl_surface object is destroyed, the sub-surface is
+/// unmapped.
+///
+/// A sub-surface never has the keyboard focus of any seat.
+///
+/// The wl_surface.offset request is ignored: clients must use set_position
+/// instead to move the sub-surface.
+pub mod wl_subsurface {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_subsurface";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 1;
+
+    /// A `wl_subsurface` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlSubsurface(pub super::ObjectId);
+
+    impl WlSubsurface {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "set_position",
+            since: 1,
+            signature: "ii",
+            types: &[None, None],
+        },
+        super::MessageDesc {
+            name: "place_above",
+            since: 1,
+            signature: "o",
+            types: &[Some("wl_surface")],
+        },
+        super::MessageDesc {
+            name: "place_below",
+            since: 1,
+            signature: "o",
+            types: &[Some("wl_surface")],
+        },
+        super::MessageDesc {
+            name: "set_sync",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "set_desync",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// remove sub-surface interface
+        ///
+        /// The sub-surface interface is removed from the wl_surface object
+        /// that was turned into a sub-surface with a
+        /// wl_subcompositor.get_subsurface request. The wl_surface's association
+        /// to the parent is deleted. The wl_surface is unmapped immediately.
+        Destroy,
+        /// reposition the sub-surface
+        ///
+        /// This sets the position of the sub-surface, relative to the parent
+        /// surface.
+        ///
+        /// The sub-surface will be moved so that its origin (top left
+        /// corner pixel) will be at the location x, y of the parent surface
+        /// coordinate system. The coordinates are not restricted to the parent
+        /// surface area. Negative values are allowed.
+        ///
+        /// The initial position is 0, 0.
+        ///
+        /// Position is double-buffered state on the parent surface, see
+        /// wl_subsurface and wl_surface.commit for more information.
+        SetPosition {
+            /// x coordinate in the parent surface
+            x: i32,
+            /// y coordinate in the parent surface
+            y: i32,
+        },
+        /// restack the sub-surface
+        ///
+        /// This sub-surface is taken from the stack, and put back just
+        /// above the reference surface, changing the z-order of the sub-surfaces.
+        /// The reference surface must be one of the sibling surfaces, or the
+        /// parent surface. Using any other surface, including this sub-surface,
+        /// will cause a protocol error.
+        ///
+        /// A new sub-surface is initially added as the top-most in the stack
+        /// of its siblings and parent.
+        ///
+        /// Z-order is double-buffered state on the parent surface, see
+        /// wl_subsurface and wl_surface.commit for more information.
+        PlaceAbove {
+            /// the reference surface
+            sibling: super::ObjectId,
+        },
+        /// restack the sub-surface
+        ///
+        /// The sub-surface is placed just below the reference surface.
+        ///
+        /// See wl_subsurface.place_above.
+        PlaceBelow {
+            /// the reference surface
+            sibling: super::ObjectId,
+        },
+        /// set sub-surface to synchronized mode
+        ///
+        /// Change the commit behaviour of the sub-surface to synchronized
+        /// mode.
+        ///
+        /// See wl_subsurface and wl_surface.commit for more information.
+        SetSync,
+        /// set sub-surface to desynchronized mode
+        ///
+        /// Change the commit behaviour of the sub-surface to desynchronized
+        /// mode.
+        ///
+        /// See wl_subsurface and wl_surface.commit for more information.
+        SetDesync,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::SetPosition { .. } => 1,
+                Request::PlaceAbove { .. } => 2,
+                Request::PlaceBelow { .. } => 3,
+                Request::SetSync => 4,
+                Request::SetDesync => 5,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::SetPosition { .. } => 1,
+                Request::PlaceAbove { .. } => 1,
+                Request::PlaceBelow { .. } => 1,
+                Request::SetSync => 1,
+                Request::SetDesync => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// wl_surface is not a sibling or the parent
+        BadSurface = 0,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::BadSurface),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// wayland protocol fixes
+///
+/// This global fixes problems with other core-protocol interfaces that
+/// cannot be fixed in these interfaces themselves.
+pub mod wl_fixes {
+    /// The name of the interface.
+    pub const NAME: &str = "wl_fixes";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 2;
+
+    /// A `wl_fixes` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct WlFixes(pub super::ObjectId);
+
+    impl WlFixes {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[super::MessageDesc] = &[
+        super::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        super::MessageDesc {
+            name: "destroy_registry",
+            since: 1,
+            signature: "o",
+            types: &[Some("wl_registry")],
+        },
+        super::MessageDesc {
+            name: "ack_global_remove",
+            since: 2,
+            signature: "2ou",
+            types: &[Some("wl_registry"), None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[super::MessageDesc] = &[
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// destroys this object
+        Destroy,
+        /// destroy a wl_registry
+        ///
+        /// This request destroys a wl_registry object.
+        ///
+        /// The client should no longer use the wl_registry after making this
+        /// request.
+        ///
+        /// The compositor will emit a wl_display.delete_id event with the object ID
+        /// of the registry and will no longer emit any events on the registry. The
+        /// client should re-use the object ID once it receives the
+        /// wl_display.delete_id event.
+        DestroyRegistry {
+            /// the registry to destroy
+            registry: super::ObjectId,
+        },
+        /// acknowledge global removal
+        ///
+        /// Acknowledge the removal of the specified global.
+        ///
+        /// If no global with the specified name exists or the global is not removed,
+        /// the wl_fixes.invalid_ack_remove protocol error will be posted.
+        ///
+        /// Due to the Wayland protocol being asynchronous, the wl_global objects
+        /// cannot be destroyed immediately. For example, if a wl_global is removed
+        /// and a client attempts to bind that global around same time, it can
+        /// result in a protocol error due to an unknown global name in the bind
+        /// request.
+        ///
+        /// In order to avoid crashing clients, the compositor should remove the
+        /// wl_global once it is guaranteed that no more bind requests will come.
+        ///
+        /// The wl_fixes.ack_global_remove() request is used to signal to the
+        /// compositor that the client will not bind the given global anymore. After
+        /// all clients acknowledge the removal of the global, the compositor can
+        /// safely destroy it.
+        ///
+        /// The client must call the wl_fixes.ack_global_remove() request in
+        /// response to a wl_registry.global_remove() event even if it did not bind
+        /// the corresponding global.
+        ///
+        /// Added in version 2.
+        AckGlobalRemove {
+            /// the registry object
+            registry: super::ObjectId,
+            /// unique name of the global
+            name: u32,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::DestroyRegistry { .. } => 1,
+                Request::AckGlobalRemove { .. } => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::DestroyRegistry { .. } => 1,
+                Request::AckGlobalRemove { .. } => 2,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+
+    /// wl_fixes error values
+    ///
+    /// These errors can be emitted in response to wl_fixes requests.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// unknown global or the global is not removed
+        InvalidAckRemove = 0,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidAckRemove),
+                _ => None,
+            }
+        }
+    }
+}
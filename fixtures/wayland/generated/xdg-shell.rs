@@ -0,0 +1,2322 @@
+// Generated by txml from the `xdg_shell` protocol.
+//
+// Copyright © 2008-2013 Kristian Høgsberg
+// Copyright © 2013      Rafael Antognolli
+// Copyright © 2013      Jasper St. Pierre
+// Copyright © 2010-2013 Intel Corporation
+// Copyright © 2015-2017 Samsung Electronics Co., Ltd
+// Copyright © 2015-2017 Red Hat Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice (including the next
+// paragraph) shall be included in all copies or substantial portions of the
+// Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+// THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/// create desktop-style surfaces
+///
+/// The xdg_wm_base interface is exposed as a global object enabling clients
+/// to turn their wl_surfaces into windows in a desktop environment. It
+/// defines the basic functionality needed for clients and the compositor to
+/// create windows that can be dragged, resized, maximized, etc, as well as
+/// creating transient windows such as popup menus.
+pub mod xdg_wm_base {
+    /// The name of the interface.
+    pub const NAME: &str = "xdg_wm_base";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 7;
+
+    /// A `xdg_wm_base` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct XdgWmBase(pub crate::wayland_protocol::ObjectId);
+
+    impl XdgWmBase {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[crate::wayland_protocol::MessageDesc] = &[
+        crate::wayland_protocol::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "create_positioner",
+            since: 1,
+            signature: "n",
+            types: &[Some("xdg_positioner")],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "get_xdg_surface",
+            since: 1,
+            signature: "no",
+            types: &[Some("xdg_surface"), Some("wl_surface")],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "pong",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[crate::wayland_protocol::MessageDesc] = &[
+        crate::wayland_protocol::MessageDesc {
+            name: "ping",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// destroy xdg_wm_base
+        ///
+        /// Destroy this xdg_wm_base object.
+        ///
+        /// Destroying a bound xdg_wm_base object while there are surfaces
+        /// still alive created by this xdg_wm_base object instance is illegal
+        /// and will result in a defunct_surfaces error.
+        Destroy,
+        /// create a positioner object
+        ///
+        /// Create a positioner object. A positioner object is used to position
+        /// surfaces relative to some parent surface. See the interface description
+        /// and xdg_surface.get_popup for details.
+        CreatePositioner {
+            id: crate::wayland_protocol::NewId,
+        },
+        /// create a shell surface from a surface
+        ///
+        /// This creates an xdg_surface for the given surface. An xdg_surface is
+        /// used as basis to define a role to a given surface, such as xdg_toplevel
+        /// or xdg_popup. It also manages functionality shared between xdg_surface
+        /// based surface roles.
+        ///
+        /// While xdg_surface itself is not a role, the corresponding surface may
+        /// only be assigned a role extending xdg_surface, such as xdg_toplevel or
+        /// xdg_popup. It is illegal to create an xdg_surface for a wl_surface which
+        /// already has anassigned role and this will result in a role error.
+        ///
+        /// See the documentation of xdg_surface for more details about what an
+        /// xdg_surface is and how it is used.
+        GetXdgSurface {
+            id: crate::wayland_protocol::NewId,
+            surface: crate::wayland_protocol::ObjectId,
+        },
+        /// respond to a ping event
+        ///
+        /// A client must respond to a ping event with a pong request or
+        /// the client may be deemed unresponsive. See xdg_wm_base.ping
+        /// and xdg_wm_base.error.unresponsive.
+        Pong {
+            /// serial of the ping event
+            serial: u32,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::CreatePositioner { .. } => 1,
+                Request::GetXdgSurface { .. } => 2,
+                Request::Pong { .. } => 3,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::CreatePositioner { .. } => 1,
+                Request::GetXdgSurface { .. } => 1,
+                Request::Pong { .. } => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// check if the client is alive
+        ///
+        /// The ping event asks the client if it's still alive. Pass the
+        /// serial specified in the event back to the compositor by sending
+        /// a "pong" request back with the specified serial. See xdg_wm_base.pong.
+        ///
+        /// Compositors can use this to determine if the client is still
+        /// alive. It's unspecified what will happen if the client doesn't
+        /// respond to the ping request, or in what timeframe. Clients should
+        /// try to respond in a reasonable amount of time. The “unresponsive”
+        /// error is provided for compositors that wish to disconnect unresponsive
+        /// clients.
+        ///
+        /// A compositor is free to ping in any way it wants, but a client must
+        /// always respond to any xdg_wm_base object it created.
+        Ping {
+            /// pass this to the pong request
+            serial: u32,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Ping { .. } => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Ping { .. } => 1,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// given wl_surface has another role
+        Role = 0,
+        /// xdg_wm_base was destroyed before children
+        DefunctSurfaces = 1,
+        /// the client tried to map or destroy a non-topmost popup
+        NotTheTopmostPopup = 2,
+        /// the client specified an invalid popup parent surface
+        InvalidPopupParent = 3,
+        /// the client provided an invalid surface state
+        InvalidSurfaceState = 4,
+        /// the client provided an invalid positioner
+        InvalidPositioner = 5,
+        /// the client didn’t respond to a ping event in time
+        Unresponsive = 6,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::Role),
+                1 => Some(Error::DefunctSurfaces),
+                2 => Some(Error::NotTheTopmostPopup),
+                3 => Some(Error::InvalidPopupParent),
+                4 => Some(Error::InvalidSurfaceState),
+                5 => Some(Error::InvalidPositioner),
+                6 => Some(Error::Unresponsive),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// child surface positioner
+///
+/// The xdg_positioner provides a collection of rules for the placement of a
+/// child surface relative to a parent surface. Rules can be defined to ensure
+/// the child surface remains within the visible area's borders, and to
+/// specify how the child surface changes its position, such as sliding along
+/// an axis, or flipping around a rectangle. These positioner-created rules are
+/// constrained by the requirement that a child surface must intersect with or
+/// be at least partially adjacent to its parent surface.
+///
+/// See the various requests for details about possible rules.
+///
+/// At the time of the request, the compositor makes a copy of the rules
+/// specified by the xdg_positioner. Thus, after the request is complete the
+/// xdg_positioner object can be destroyed or reused; further changes to the
+/// object will have no effect on previous usages.
+///
+/// For an xdg_positioner object to be considered complete, it must have a
+/// non-zero size set by set_size, and a non-zero anchor rectangle set by
+/// set_anchor_rect. Passing an incomplete xdg_positioner object when
+/// positioning a surface raises an invalid_positioner error.
+pub mod xdg_positioner {
+    /// The name of the interface.
+    pub const NAME: &str = "xdg_positioner";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 7;
+
+    /// A `xdg_positioner` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct XdgPositioner(pub crate::wayland_protocol::ObjectId);
+
+    impl XdgPositioner {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[crate::wayland_protocol::MessageDesc] = &[
+        crate::wayland_protocol::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_size",
+            since: 1,
+            signature: "ii",
+            types: &[None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_anchor_rect",
+            since: 1,
+            signature: "iiii",
+            types: &[None, None, None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_anchor",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_gravity",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_constraint_adjustment",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_offset",
+            since: 1,
+            signature: "ii",
+            types: &[None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_reactive",
+            since: 3,
+            signature: "3",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_parent_size",
+            since: 3,
+            signature: "3ii",
+            types: &[None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_parent_configure",
+            since: 3,
+            signature: "3u",
+            types: &[None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[crate::wayland_protocol::MessageDesc] = &[
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// destroy the xdg_positioner object
+        ///
+        /// Notify the compositor that the xdg_positioner will no longer be used.
+        Destroy,
+        /// set the size of the to-be positioned rectangle
+        ///
+        /// Set the size of the surface that is to be positioned with the positioner
+        /// object. The size is in surface-local coordinates and corresponds to the
+        /// window geometry. See xdg_surface.set_window_geometry.
+        ///
+        /// If a zero or negative size is set the invalid_input error is raised.
+        SetSize {
+            /// width of positioned rectangle
+            width: i32,
+            /// height of positioned rectangle
+            height: i32,
+        },
+        /// set the anchor rectangle within the parent surface
+        ///
+        /// Specify the anchor rectangle within the parent surface that the child
+        /// surface will be placed relative to. The rectangle is relative to the
+        /// window geometry as defined by xdg_surface.set_window_geometry of the
+        /// parent surface.
+        ///
+        /// When the xdg_positioner object is used to position a child surface, the
+        /// anchor rectangle may not extend outside the window geometry of the
+        /// positioned child's parent surface.
+        ///
+        /// If a negative size is set the invalid_input error is raised.
+        SetAnchorRect {
+            /// x position of anchor rectangle
+            x: i32,
+            /// y position of anchor rectangle
+            y: i32,
+            /// width of anchor rectangle
+            width: i32,
+            /// height of anchor rectangle
+            height: i32,
+        },
+        /// set anchor rectangle anchor
+        ///
+        /// Defines the anchor point for the anchor rectangle. The specified anchor
+        /// is used to derive an anchor point that the child surface will be
+        /// positioned relative to. If a corner anchor is set (e.g. 'top_left' or
+        /// 'bottom_right'), the anchor point will be at the specified corner;
+        /// otherwise, the derived anchor point will be centered on the specified
+        /// edge, or in the center of the anchor rectangle if no edge is specified.
+        SetAnchor {
+            /// anchor point
+            anchor: Anchor,
+        },
+        /// set child surface gravity
+        ///
+        /// Defines in what direction a surface should be positioned, relative to
+        /// the anchor point of the parent surface. If a corner gravity is
+        /// specified (e.g. 'bottom_right' or 'top_left'), then the child surface
+        /// will be placed towards the specified gravity; otherwise, the child
+        /// surface will be centered over the anchor point on any axis that had no
+        /// gravity specified. If the gravity is not in the ‘gravity’ enum, an
+        /// invalid_input error is raised.
+        SetGravity {
+            /// gravity direction
+            gravity: Gravity,
+        },
+        /// set the adjustment to be done when constrained
+        ///
+        /// Specify how the window should be positioned if the originally intended
+        /// position caused the surface to be constrained, meaning at least
+        /// partially outside positioning boundaries set by the compositor. The
+        /// adjustment is set by constructing a bitmask describing the adjustment to
+        /// be made when the surface is constrained on that axis.
+        ///
+        /// If no bit for one axis is set, the compositor will assume that the child
+        /// surface should not change its position on that axis when constrained.
+        ///
+        /// If more than one bit for one axis is set, the order of how adjustments
+        /// are applied is specified in the corresponding adjustment descriptions.
+        ///
+        /// The default adjustment is none.
+        SetConstraintAdjustment {
+            /// bit mask of constraint adjustments
+            constraint_adjustment: ConstraintAdjustment,
+        },
+        /// set surface position offset
+        ///
+        /// Specify the surface position offset relative to the position of the
+        /// anchor on the anchor rectangle and the anchor on the surface. For
+        /// example if the anchor of the anchor rectangle is at (x, y), the surface
+        /// has the gravity bottom|right, and the offset is (ox, oy), the calculated
+        /// surface position will be (x + ox, y + oy). The offset position of the
+        /// surface is the one used for constraint testing. See
+        /// set_constraint_adjustment.
+        ///
+        /// An example use case is placing a popup menu on top of a user interface
+        /// element, while aligning the user interface element of the parent surface
+        /// with some user interface element placed somewhere in the popup surface.
+        SetOffset {
+            /// surface position x offset
+            x: i32,
+            /// surface position y offset
+            y: i32,
+        },
+        /// continuously reconstrain the surface
+        ///
+        /// When set reactive, the surface is reconstrained if the conditions used
+        /// for constraining changed, e.g. the parent window moved.
+        ///
+        /// If the conditions changed and the popup was reconstrained, an
+        /// xdg_popup.configure event is sent with updated geometry, followed by an
+        /// xdg_surface.configure event.
+        ///
+        /// Added in version 3.
+        SetReactive,
+        /// set parent size
+        ///
+        /// Set the parent window geometry the compositor should use when
+        /// positioning the popup. The compositor may use this information to
+        /// determine the future state the popup should be constrained using. If
+        /// this doesn't match the dimension of the parent the popup is eventually
+        /// positioned against, the behavior is undefined.
+        ///
+        /// The arguments are given in the surface-local coordinate space.
+        ///
+        /// Added in version 3.
+        SetParentSize {
+            /// future window geometry width of parent
+            parent_width: i32,
+            /// future window geometry height of parent
+            parent_height: i32,
+        },
+        /// set parent configure this is a response to
+        ///
+        /// Set the serial of an xdg_surface.configure event this positioner will be
+        /// used in response to. The compositor may use this information together
+        /// with set_parent_size to determine what future state the popup should be
+        /// constrained using.
+        ///
+        /// Added in version 3.
+        SetParentConfigure {
+            /// serial of parent configure event
+            serial: u32,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::SetSize { .. } => 1,
+                Request::SetAnchorRect { .. } => 2,
+                Request::SetAnchor { .. } => 3,
+                Request::SetGravity { .. } => 4,
+                Request::SetConstraintAdjustment { .. } => 5,
+                Request::SetOffset { .. } => 6,
+                Request::SetReactive => 7,
+                Request::SetParentSize { .. } => 8,
+                Request::SetParentConfigure { .. } => 9,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::SetSize { .. } => 1,
+                Request::SetAnchorRect { .. } => 1,
+                Request::SetAnchor { .. } => 1,
+                Request::SetGravity { .. } => 1,
+                Request::SetConstraintAdjustment { .. } => 1,
+                Request::SetOffset { .. } => 1,
+                Request::SetReactive => 3,
+                Request::SetParentSize { .. } => 3,
+                Request::SetParentConfigure { .. } => 3,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// invalid input provided
+        InvalidInput = 0,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidInput),
+                _ => None,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Anchor {
+        None = 0,
+        Top = 1,
+        Bottom = 2,
+        Left = 3,
+        Right = 4,
+        TopLeft = 5,
+        BottomLeft = 6,
+        TopRight = 7,
+        BottomRight = 8,
+    }
+
+    impl Anchor {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Anchor::None),
+                1 => Some(Anchor::Top),
+                2 => Some(Anchor::Bottom),
+                3 => Some(Anchor::Left),
+                4 => Some(Anchor::Right),
+                5 => Some(Anchor::TopLeft),
+                6 => Some(Anchor::BottomLeft),
+                7 => Some(Anchor::TopRight),
+                8 => Some(Anchor::BottomRight),
+                _ => None,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Gravity {
+        None = 0,
+        Top = 1,
+        Bottom = 2,
+        Left = 3,
+        Right = 4,
+        TopLeft = 5,
+        BottomLeft = 6,
+        TopRight = 7,
+        BottomRight = 8,
+    }
+
+    impl Gravity {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Gravity::None),
+                1 => Some(Gravity::Top),
+                2 => Some(Gravity::Bottom),
+                3 => Some(Gravity::Left),
+                4 => Some(Gravity::Right),
+                5 => Some(Gravity::TopLeft),
+                6 => Some(Gravity::BottomLeft),
+                7 => Some(Gravity::TopRight),
+                8 => Some(Gravity::BottomRight),
+                _ => None,
+            }
+        }
+    }
+
+    /// constraint adjustments
+    ///
+    /// The constraint adjustment value define ways the compositor will adjust
+    /// the position of the surface, if the unadjusted position would result
+    /// in the surface being partly constrained.
+    ///
+    /// Whether a surface is considered 'constrained' is left to the compositor
+    /// to determine. For example, the surface may be partly outside the
+    /// compositor's defined 'work area', thus necessitating the child surface's
+    /// position be adjusted until it is entirely inside the work area.
+    ///
+    /// The adjustments can be combined, according to a defined precedence: 1)
+    /// Flip, 2) Slide, 3) Resize.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct ConstraintAdjustment(u32);
+
+    impl ConstraintAdjustment {
+        /// don't move the child surface when constrained
+        ///
+        /// Don't alter the surface position even if it is constrained on some
+        /// axis, for example partially outside the edge of an output.
+        pub const NONE: Self = Self(0);
+
+        /// move along the x axis until unconstrained
+        ///
+        /// Slide the surface along the x axis until it is no longer constrained.
+        ///
+        /// First try to slide towards the direction of the gravity on the x axis
+        /// until either the edge in the opposite direction of the gravity is
+        /// unconstrained or the edge in the direction of the gravity is
+        /// constrained.
+        ///
+        /// Then try to slide towards the opposite direction of the gravity on the
+        /// x axis until either the edge in the direction of the gravity is
+        /// unconstrained or the edge in the opposite direction of the gravity is
+        /// constrained.
+        pub const SLIDE_X: Self = Self(1);
+
+        /// move along the y axis until unconstrained
+        ///
+        /// Slide the surface along the y axis until it is no longer constrained.
+        ///
+        /// First try to slide towards the direction of the gravity on the y axis
+        /// until either the edge in the opposite direction of the gravity is
+        /// unconstrained or the edge in the direction of the gravity is
+        /// constrained.
+        ///
+        /// Then try to slide towards the opposite direction of the gravity on the
+        /// y axis until either the edge in the direction of the gravity is
+        /// unconstrained or the edge in the opposite direction of the gravity is
+        /// constrained.
+        pub const SLIDE_Y: Self = Self(2);
+
+        /// invert the anchor and gravity on the x axis
+        ///
+        /// Invert the anchor and gravity on the x axis if the surface is
+        /// constrained on the x axis. For example, if the left edge of the
+        /// surface is constrained, the gravity is 'left' and the anchor is
+        /// 'left', change the gravity to 'right' and the anchor to 'right'.
+        ///
+        /// If the adjusted position also ends up being constrained, the resulting
+        /// position of the flip_x adjustment will be the one before the
+        /// adjustment.
+        pub const FLIP_X: Self = Self(4);
+
+        /// invert the anchor and gravity on the y axis
+        ///
+        /// Invert the anchor and gravity on the y axis if the surface is
+        /// constrained on the y axis. For example, if the bottom edge of the
+        /// surface is constrained, the gravity is 'bottom' and the anchor is
+        /// 'bottom', change the gravity to 'top' and the anchor to 'top'.
+        ///
+        /// The adjusted position is calculated given the original anchor
+        /// rectangle and offset, but with the new flipped anchor and gravity
+        /// values.
+        ///
+        /// If the adjusted position also ends up being constrained, the resulting
+        /// position of the flip_y adjustment will be the one before the
+        /// adjustment.
+        pub const FLIP_Y: Self = Self(8);
+
+        /// horizontally resize the surface
+        ///
+        /// Resize the surface horizontally so that it is completely
+        /// unconstrained.
+        pub const RESIZE_X: Self = Self(16);
+
+        /// vertically resize the surface
+        ///
+        /// Resize the surface vertically so that it is completely unconstrained.
+        pub const RESIZE_Y: Self = Self(32);
+
+        /// Creates a value from its bits, keeping unknown bits.
+        pub const fn from_bits(bits: u32) -> Self {
+            Self(bits)
+        }
+
+        /// Returns the bits of the value.
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+
+        /// Returns whether all of the bits of `other` are set.
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        /// Returns whether no bits are set.
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl ::core::ops::BitOr for ConstraintAdjustment {
+        type Output = Self;
+
+        fn bitor(self, other: Self) -> Self {
+            Self(self.0 | other.0)
+        }
+    }
+
+    impl ::core::ops::BitAnd for ConstraintAdjustment {
+        type Output = Self;
+
+        fn bitand(self, other: Self) -> Self {
+            Self(self.0 & other.0)
+        }
+    }
+
+    impl ::core::ops::BitOrAssign for ConstraintAdjustment {
+        fn bitor_assign(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+    }
+}
+
+/// desktop user interface surface base interface
+///
+///    An interface that may be implemented by a wl_surface, for
+///    implementations that provide a desktop-style user interface.
+///
+///    It provides a base set of functionality required to construct user
+///    interface elements requiring management by the compositor, such as
+///    toplevel windows, menus, etc. The types of functionality are split into
+///    xdg_surface roles.
+///
+///    Creating an xdg_surface does not set the role for a wl_surface. In order
+///    to map an xdg_surface, the client must create a role-specific object
+///    using, e.g., get_toplevel, get_popup. The wl_surface for any given
+///    xdg_surface can have at most one role, and may not be assigned any role
+///    not based on xdg_surface.
+///
+///    A role must be assigned before any other requests are made to the
+///    xdg_surface object.
+///
+///    The client must call wl_surface.commit on the corresponding wl_surface
+///    for the xdg_surface state to take effect.
+///
+///    Creating an xdg_surface from a wl_surface which has a buffer attached or
+///    committed is a client error, and any attempts by a client to attach or
+///    manipulate a buffer prior to the first xdg_surface.configure call must
+///    also be treated as errors.
+///
+///    After creating a role-specific object and setting it up (e.g. by sending
+///    the title, app ID, size constraints, parent, etc), the client must
+///    perform an initial commit without any buffer attached. The compositor
+///    will reply with initial wl_surface state such as
+///    wl_surface.preferred_buffer_scale followed by an xdg_surface.configure
+///    event. The client must acknowledge it and is then allowed to attach a
+///    buffer to map the surface.
+///
+///    Mapping an xdg_surface-based role surface is defined as making it
+///    possible for the surface to be shown by the compositor. Note that
+///    a mapped surface is not guaranteed to be visible once it is mapped.
+///
+///    For an xdg_surface to be mapped by the compositor, the following
+///    conditions must be met:
+///    (1) the client has assigned an xdg_surface-based role to the surface
+///    (2) the client has set and committed the xdg_surface state and the
+/// role-dependent state to the surface
+///    (3) the client has committed a buffer to the surface
+///
+///    A newly-unmapped surface is considered to have met condition (1) out
+///    of the 3 required conditions for mapping a surface if its role surface
+///    has not been destroyed, i.e. the client must perform the initial commit
+///    again before attaching a buffer.
+pub mod xdg_surface {
+    /// The name of the interface.
+    pub const NAME: &str = "xdg_surface";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 7;
+
+    /// A `xdg_surface` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct XdgSurface(pub crate::wayland_protocol::ObjectId);
+
+    impl XdgSurface {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[crate::wayland_protocol::MessageDesc] = &[
+        crate::wayland_protocol::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "get_toplevel",
+            since: 1,
+            signature: "n",
+            types: &[Some("xdg_toplevel")],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "get_popup",
+            since: 1,
+            signature: "n?oo",
+            types: &[Some("xdg_popup"), Some("xdg_surface"), Some("xdg_positioner")],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_window_geometry",
+            since: 1,
+            signature: "iiii",
+            types: &[None, None, None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "ack_configure",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[crate::wayland_protocol::MessageDesc] = &[
+        crate::wayland_protocol::MessageDesc {
+            name: "configure",
+            since: 1,
+            signature: "u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// destroy the xdg_surface
+        ///
+        /// Destroy the xdg_surface object. An xdg_surface must only be destroyed
+        /// after its role object has been destroyed, otherwise
+        /// a defunct_role_object error is raised.
+        Destroy,
+        /// assign the xdg_toplevel surface role
+        ///
+        /// This creates an xdg_toplevel object for the given xdg_surface and gives
+        /// the associated wl_surface the xdg_toplevel role.
+        ///
+        /// See the documentation of xdg_toplevel for more details about what an
+        /// xdg_toplevel is and how it is used.
+        GetToplevel {
+            id: crate::wayland_protocol::NewId,
+        },
+        /// assign the xdg_popup surface role
+        ///
+        /// This creates an xdg_popup object for the given xdg_surface and gives
+        /// the associated wl_surface the xdg_popup role.
+        ///
+        /// If null is passed as a parent, a parent surface must be specified using
+        /// some other protocol, before committing the initial state.
+        ///
+        /// See the documentation of xdg_popup for more details about what an
+        /// xdg_popup is and how it is used.
+        GetPopup {
+            id: crate::wayland_protocol::NewId,
+            /// parent surface for this popup
+            parent: ::core::option::Option<crate::wayland_protocol::ObjectId>,
+            /// positioner for this popup
+            positioner: crate::wayland_protocol::ObjectId,
+        },
+        /// set the new window geometry
+        ///
+        /// The window geometry of a surface is its "visible bounds" from the
+        /// user's perspective. Client-side decorations often have invisible
+        /// portions like drop-shadows which should be ignored for the
+        /// purposes of aligning, placing and constraining windows. Note that
+        /// in some situations, compositors may clip rendering to the window
+        /// geometry, so the client should avoid putting functional elements
+        /// outside of it.
+        ///
+        /// The window geometry is double-buffered state, see wl_surface.commit.
+        ///
+        /// When maintaining a position, the compositor should treat the (x, y)
+        /// coordinate of the window geometry as the top left corner of the window.
+        /// A client changing the (x, y) window geometry coordinate should in
+        /// general not alter the position of the window.
+        ///
+        /// Once the window geometry of the surface is set, it is not possible to
+        /// unset it, and it will remain the same until set_window_geometry is
+        /// called again, even if a new subsurface or buffer is attached.
+        ///
+        /// If never set, the value is the full bounds of the surface,
+        /// including any subsurfaces. This updates dynamically on every
+        /// commit. This unset is meant for extremely simple clients.
+        ///
+        /// The arguments are given in the surface-local coordinate space of
+        /// the wl_surface associated with this xdg_surface, and may extend outside
+        /// of the wl_surface itself to mark parts of the subsurface tree as part of
+        /// the window geometry.
+        ///
+        /// When applied, the effective window geometry will be the set window
+        /// geometry clamped to the bounding rectangle of the combined
+        /// geometry of the surface of the xdg_surface and the associated
+        /// subsurfaces.
+        ///
+        /// The effective geometry will not be recalculated unless a new call to
+        /// set_window_geometry is done and the new pending surface state is
+        /// subsequently applied.
+        ///
+        /// The width and height of the effective window geometry must be
+        /// greater than zero. Setting an invalid size will raise an
+        /// invalid_size error.
+        SetWindowGeometry {
+            /// x coordinate of the top-left corner of the window inside this surface
+            x: i32,
+            /// y coordinate of the top-left corner of the window inside this surface
+            y: i32,
+            /// width of the window
+            width: i32,
+            /// height of the window
+            height: i32,
+        },
+        /// ack a configure event
+        ///
+        /// When a configure event is received, if a client commits the
+        /// surface in response to the configure event, then the client
+        /// must make an ack_configure request sometime before the commit
+        /// request, passing along the serial of the configure event.
+        ///
+        /// For instance, for toplevel surfaces the compositor might use this
+        /// information to move a surface to the top left only when the client has
+        /// drawn itself for the maximized or fullscreen state.
+        ///
+        /// If the client receives multiple configure events before it
+        /// can respond to one, it only has to ack the last configure event.
+        /// Acking a configure event that was never sent raises an invalid_serial
+        /// error.
+        ///
+        /// A client is not required to commit immediately after sending
+        /// an ack_configure request - it may even ack_configure several times
+        /// before its next surface commit.
+        ///
+        /// A client may send multiple ack_configure requests before committing, but
+        /// only the last request sent before a commit indicates which configure
+        /// event the client really is responding to.
+        ///
+        /// Sending an ack_configure request consumes the serial number sent with
+        /// the request, as well as serial numbers sent by all configure events
+        /// sent on this xdg_surface prior to the configure event referenced by
+        /// the committed serial.
+        ///
+        /// It is an error to issue multiple ack_configure requests referencing a
+        /// serial from the same configure event, or to issue an ack_configure
+        /// request referencing a serial from a configure event issued before the
+        /// event identified by the last ack_configure request for the same
+        /// xdg_surface. Doing so will raise an invalid_serial error.
+        AckConfigure {
+            /// the serial from the configure event
+            serial: u32,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::GetToplevel { .. } => 1,
+                Request::GetPopup { .. } => 2,
+                Request::SetWindowGeometry { .. } => 3,
+                Request::AckConfigure { .. } => 4,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::GetToplevel { .. } => 1,
+                Request::GetPopup { .. } => 1,
+                Request::SetWindowGeometry { .. } => 1,
+                Request::AckConfigure { .. } => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// suggest a surface change
+        ///
+        /// The configure event marks the end of a configure sequence. A configure
+        /// sequence is a set of one or more events configuring the state of the
+        /// xdg_surface, including the final xdg_surface.configure event.
+        ///
+        /// Where applicable, xdg_surface surface roles will during a configure
+        /// sequence extend this event as a latched state sent as events before the
+        /// xdg_surface.configure event. Such events should be considered to make up
+        /// a set of atomically applied configuration states, where the
+        /// xdg_surface.configure commits the accumulated state.
+        ///
+        /// Clients should arrange their surface for the new states, and then send
+        /// an ack_configure request with the serial sent in this configure event at
+        /// some point before committing the new surface.
+        ///
+        /// If the client receives multiple configure events before it can respond
+        /// to one, it is free to discard all but the last event it received.
+        Configure {
+            /// serial of the configure event
+            serial: u32,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Configure { .. } => 0,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Configure { .. } => 1,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// Surface was not fully constructed
+        NotConstructed = 1,
+        /// Surface was already constructed
+        AlreadyConstructed = 2,
+        /// Attaching a buffer to an unconfigured surface
+        UnconfiguredBuffer = 3,
+        /// Invalid serial number when acking a configure event
+        InvalidSerial = 4,
+        /// Width or height was zero or negative
+        InvalidSize = 5,
+        /// Surface was destroyed before its role object
+        DefunctRoleObject = 6,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                1 => Some(Error::NotConstructed),
+                2 => Some(Error::AlreadyConstructed),
+                3 => Some(Error::UnconfiguredBuffer),
+                4 => Some(Error::InvalidSerial),
+                5 => Some(Error::InvalidSize),
+                6 => Some(Error::DefunctRoleObject),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// toplevel surface
+///
+/// This interface defines an xdg_surface role which allows a surface to,
+/// among other things, set window-like properties such as maximize,
+/// fullscreen, and minimize, set application-specific metadata like title and
+/// id, and well as trigger user interactive operations such as interactive
+/// resize and move.
+///
+/// An xdg_toplevel by default is responsible for providing the full intended
+/// visual representation of the toplevel, which depending on the window
+/// state, may mean things like a title bar, window controls and drop shadow.
+///
+/// Unmapping an xdg_toplevel means that the surface cannot be shown
+/// by the compositor until it is explicitly mapped again.
+/// All active operations (e.g., move, resize) are canceled and all
+/// attributes (e.g. title, state, stacking, ...) are discarded for
+/// an xdg_toplevel surface when it is unmapped. The xdg_toplevel returns to
+/// the state it had right after xdg_surface.get_toplevel. The client
+/// can re-map the toplevel by performing a commit without any buffer
+/// attached, waiting for a configure event and handling it as usual (see
+/// xdg_surface description).
+///
+/// Attaching a null buffer to a toplevel unmaps the surface.
+pub mod xdg_toplevel {
+    /// The name of the interface.
+    pub const NAME: &str = "xdg_toplevel";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 7;
+
+    /// A `xdg_toplevel` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct XdgToplevel(pub crate::wayland_protocol::ObjectId);
+
+    impl XdgToplevel {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[crate::wayland_protocol::MessageDesc] = &[
+        crate::wayland_protocol::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_parent",
+            since: 1,
+            signature: "?o",
+            types: &[Some("xdg_toplevel")],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_title",
+            since: 1,
+            signature: "s",
+            types: &[None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_app_id",
+            since: 1,
+            signature: "s",
+            types: &[None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "show_window_menu",
+            since: 1,
+            signature: "ouii",
+            types: &[Some("wl_seat"), None, None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "move",
+            since: 1,
+            signature: "ou",
+            types: &[Some("wl_seat"), None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "resize",
+            since: 1,
+            signature: "ouu",
+            types: &[Some("wl_seat"), None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_max_size",
+            since: 1,
+            signature: "ii",
+            types: &[None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_min_size",
+            since: 1,
+            signature: "ii",
+            types: &[None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_maximized",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "unset_maximized",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_fullscreen",
+            since: 1,
+            signature: "?o",
+            types: &[Some("wl_output")],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "unset_fullscreen",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "set_minimized",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[crate::wayland_protocol::MessageDesc] = &[
+        crate::wayland_protocol::MessageDesc {
+            name: "configure",
+            since: 1,
+            signature: "iia",
+            types: &[None, None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "close",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "configure_bounds",
+            since: 4,
+            signature: "4ii",
+            types: &[None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "wm_capabilities",
+            since: 5,
+            signature: "5a",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// destroy the xdg_toplevel
+        ///
+        /// This request destroys the role surface and unmaps the surface;
+        /// see "Unmapping" behavior in interface section for details.
+        Destroy,
+        /// set the parent of this surface
+        ///
+        /// Set the "parent" of this surface. This surface should be stacked
+        /// above the parent surface and all other ancestor surfaces.
+        ///
+        /// Parent surfaces should be set on dialogs, toolboxes, or other
+        /// "auxiliary" surfaces, so that the parent is raised when the dialog
+        /// is raised.
+        ///
+        /// Setting a null parent for a child surface unsets its parent. Setting
+        /// a null parent for a surface which currently has no parent is a no-op.
+        ///
+        /// Only mapped surfaces can have child surfaces. Setting a parent which
+        /// is not mapped is equivalent to setting a null parent. If a surface
+        /// becomes unmapped, its children's parent is set to the parent of
+        /// the now-unmapped surface. If the now-unmapped surface has no parent,
+        /// its children's parent is unset. If the now-unmapped surface becomes
+        /// mapped again, its parent-child relationship is not restored.
+        ///
+        /// The parent toplevel must not be one of the child toplevel's
+        /// descendants, and the parent must be different from the child toplevel,
+        /// otherwise the invalid_parent protocol error is raised.
+        SetParent {
+            /// parent surface for this surface
+            parent: ::core::option::Option<crate::wayland_protocol::ObjectId>,
+        },
+        /// set surface title
+        ///
+        /// Set a short title for the surface.
+        ///
+        /// This string may be used to identify the surface in a task bar,
+        /// window list, or other user interface elements provided by the
+        /// compositor.
+        ///
+        /// The string must be encoded in UTF-8.
+        SetTitle {
+            /// title of the surface
+            title: ::std::string::String,
+        },
+        /// set application ID
+        ///
+        /// Set an application identifier for the surface.
+        ///
+        /// The app ID identifies the general class of applications to which
+        /// the surface belongs. The compositor can use this to group multiple
+        /// surfaces together, or to determine how to launch a new application.
+        ///
+        /// For D-Bus activatable applications, the app ID is used as the D-Bus
+        /// service name.
+        ///
+        /// The compositor shell will try to group application surfaces together
+        /// by their app ID. As a best practice, it is suggested to select app
+        /// ID's that match the basename of the application's .desktop file.
+        /// For example, "org.freedesktop.FooViewer" where the .desktop file is
+        /// "org.freedesktop.FooViewer.desktop".
+        ///
+        /// Like other properties, a set_app_id request can be sent after the
+        /// xdg_toplevel has been mapped to update the property.
+        ///
+        /// See the desktop-entry specification \[0\] for more details on
+        /// application identifiers and how they relate to well-known D-Bus
+        /// names and .desktop files.
+        ///
+        /// \[0\] https://standards.freedesktop.org/desktop-entry-spec/
+        SetAppId {
+            /// application identifier surface belongs to
+            app_id: ::std::string::String,
+        },
+        /// show the window menu
+        ///
+        /// Clients implementing client-side decorations might want to show
+        /// a context menu when right-clicking on the decorations, giving the
+        /// user a menu that they can use to maximize or minimize the window.
+        ///
+        /// This request asks the compositor to pop up such a window menu at
+        /// the given position, relative to the local surface coordinates of
+        /// the parent surface. There are no guarantees as to what menu items
+        /// the window menu contains, or even if a window menu will be drawn
+        /// at all.
+        ///
+        /// This request must be used in response to some sort of user action
+        /// like a button press, key press, or touch down event.
+        ShowWindowMenu {
+            /// the wl_seat of the user event
+            seat: crate::wayland_protocol::ObjectId,
+            /// the serial of the user event
+            serial: u32,
+            /// the x position to pop up the window menu at
+            x: i32,
+            /// the y position to pop up the window menu at
+            y: i32,
+        },
+        /// start an interactive move
+        ///
+        /// Start an interactive, user-driven move of the surface.
+        ///
+        /// This request must be used in response to some sort of user action
+        /// like a button press, key press, or touch down event. The passed
+        /// serial is used to determine the type of interactive move (touch,
+        /// pointer, etc).
+        ///
+        /// The server may ignore move requests depending on the state of
+        /// the surface (e.g. fullscreen or maximized), or if the passed serial
+        /// is no longer valid.
+        ///
+        /// If triggered, the surface will lose the focus of the device
+        /// (wl_pointer, wl_touch, etc) used for the move. It is up to the
+        /// compositor to visually indicate that the move is taking place, such as
+        /// updating a pointer cursor, during the move. There is no guarantee
+        /// that the device focus will return when the move is completed.
+        Move {
+            /// the wl_seat of the user event
+            seat: crate::wayland_protocol::ObjectId,
+            /// the serial of the user event
+            serial: u32,
+        },
+        /// start an interactive resize
+        ///
+        /// Start a user-driven, interactive resize of the surface.
+        ///
+        /// This request must be used in response to some sort of user action
+        /// like a button press, key press, or touch down event. The passed
+        /// serial is used to determine the type of interactive resize (touch,
+        /// pointer, etc).
+        ///
+        /// The server may ignore resize requests depending on the state of
+        /// the surface (e.g. fullscreen or maximized).
+        ///
+        /// If triggered, the client will receive configure events with the
+        /// "resize" state enum value and the expected sizes. See the "resize"
+        /// enum value for more details about what is required. The client
+        /// must also acknowledge configure events using "ack_configure". After
+        /// the resize is completed, the client will receive another "configure"
+        /// event without the resize state.
+        ///
+        /// If triggered, the surface also will lose the focus of the device
+        /// (wl_pointer, wl_touch, etc) used for the resize. It is up to the
+        /// compositor to visually indicate that the resize is taking place,
+        /// such as updating a pointer cursor, during the resize. There is no
+        /// guarantee that the device focus will return when the resize is
+        /// completed.
+        ///
+        /// The edges parameter specifies how the surface should be resized, and
+        /// is one of the values of the resize_edge enum. Values not matching
+        /// a variant of the enum will cause the invalid_resize_edge protocol error.
+        /// The compositor may use this information to update the surface position
+        /// for example when dragging the top left corner. The compositor may also
+        /// use this information to adapt its behavior, e.g. choose an appropriate
+        /// cursor image.
+        Resize {
+            /// the wl_seat of the user event
+            seat: crate::wayland_protocol::ObjectId,
+            /// the serial of the user event
+            serial: u32,
+            /// which edge or corner is being dragged
+            edges: ResizeEdge,
+        },
+        /// set the maximum size
+        ///
+        /// Set a maximum size for the window.
+        ///
+        /// The client can specify a maximum size so that the compositor does
+        /// not try to configure the window beyond this size.
+        ///
+        /// The width and height arguments are in window geometry coordinates.
+        /// See xdg_surface.set_window_geometry.
+        ///
+        /// Values set in this way are double-buffered, see wl_surface.commit.
+        ///
+        /// The compositor can use this information to allow or disallow
+        /// different states like maximize or fullscreen and draw accurate
+        /// animations.
+        ///
+        /// Similarly, a tiling window manager may use this information to
+        /// place and resize client windows in a more effective way.
+        ///
+        /// The client should not rely on the compositor to obey the maximum
+        /// size. The compositor may decide to ignore the values set by the
+        /// client and request a larger size.
+        ///
+        /// If never set, or a value of zero in the request, means that the
+        /// client has no expected maximum size in the given dimension.
+        /// As a result, a client wishing to reset the maximum size
+        /// to an unspecified state can use zero for width and height in the
+        /// request.
+        ///
+        /// Requesting a maximum size to be smaller than the minimum size of
+        /// a surface is illegal and will result in an invalid_size error.
+        ///
+        /// The width and height must be greater than or equal to zero. Using
+        /// strictly negative values for width or height will result in an
+        /// invalid_size error.
+        SetMaxSize {
+            /// maximum width of the window
+            width: i32,
+            /// maximum height of the window
+            height: i32,
+        },
+        /// set the minimum size
+        ///
+        /// Set a minimum size for the window.
+        ///
+        /// The client can specify a minimum size so that the compositor does
+        /// not try to configure the window below this size.
+        ///
+        /// The width and height arguments are in window geometry coordinates.
+        /// See xdg_surface.set_window_geometry.
+        ///
+        /// Values set in this way are double-buffered, see wl_surface.commit.
+        ///
+        /// The compositor can use this information to allow or disallow
+        /// different states like maximize or fullscreen and draw accurate
+        /// animations.
+        ///
+        /// Similarly, a tiling window manager may use this information to
+        /// place and resize client windows in a more effective way.
+        ///
+        /// The client should not rely on the compositor to obey the minimum
+        /// size. The compositor may decide to ignore the values set by the
+        /// client and request a smaller size.
+        ///
+        /// If never set, or a value of zero in the request, means that the
+        /// client has no expected minimum size in the given dimension.
+        /// As a result, a client wishing to reset the minimum size
+        /// to an unspecified state can use zero for width and height in the
+        /// request.
+        ///
+        /// Requesting a minimum size to be larger than the maximum size of
+        /// a surface is illegal and will result in an invalid_size error.
+        ///
+        /// The width and height must be greater than or equal to zero. Using
+        /// strictly negative values for width and height will result in an
+        /// invalid_size error.
+        SetMinSize {
+            /// minimum width of the window
+            width: i32,
+            /// minimum height of the window
+            height: i32,
+        },
+        /// maximize the window
+        ///
+        /// Maximize the surface.
+        ///
+        /// After requesting that the surface should be maximized, the compositor
+        /// will respond by emitting a configure event. Whether this configure
+        /// actually sets the window maximized is subject to compositor policies.
+        /// The client must then update its content, drawing in the configured
+        /// state. The client must also acknowledge the configure when committing
+        /// the new content (see ack_configure).
+        ///
+        /// It is up to the compositor to decide how and where to maximize the
+        /// surface, for example which output and what region of the screen should
+        /// be used.
+        ///
+        /// If the surface was already maximized, the compositor will still emit
+        /// a configure event with the "maximized" state.
+        ///
+        /// If the surface is in a fullscreen state, this request has no direct
+        /// effect. It may alter the state the surface is returned to when
+        /// unmaximized unless overridden by the compositor.
+        SetMaximized,
+        /// unmaximize the window
+        ///
+        /// Unmaximize the surface.
+        ///
+        /// After requesting that the surface should be unmaximized, the compositor
+        /// will respond by emitting a configure event. Whether this actually
+        /// un-maximizes the window is subject to compositor policies.
+        /// If available and applicable, the compositor will include the window
+        /// geometry dimensions the window had prior to being maximized in the
+        /// configure event. The client must then update its content, drawing it in
+        /// the configured state. The client must also acknowledge the configure
+        /// when committing the new content (see ack_configure).
+        ///
+        /// It is up to the compositor to position the surface after it was
+        /// unmaximized; usually the position the surface had before maximizing, if
+        /// applicable.
+        ///
+        /// If the surface was already not maximized, the compositor will still
+        /// emit a configure event without the "maximized" state.
+        ///
+        /// If the surface is in a fullscreen state, this request has no direct
+        /// effect. It may alter the state the surface is returned to when
+        /// unmaximized unless overridden by the compositor.
+        UnsetMaximized,
+        /// set the window as fullscreen on an output
+        ///
+        /// Make the surface fullscreen.
+        ///
+        /// After requesting that the surface should be fullscreened, the
+        /// compositor will respond by emitting a configure event. Whether the
+        /// client is actually put into a fullscreen state is subject to compositor
+        /// policies. The client must also acknowledge the configure when
+        /// committing the new content (see ack_configure).
+        ///
+        /// The output passed by the request indicates the client's preference as
+        /// to which display it should be set fullscreen on. If this value is NULL,
+        /// it's up to the compositor to choose which display will be used to map
+        /// this surface.
+        ///
+        /// If the surface doesn't cover the whole output, the compositor will
+        /// position the surface in the center of the output and compensate with
+        /// border fill covering the rest of the output. The content of the
+        /// border fill is undefined, but should be assumed to be in some way that
+        /// attempts to blend into the surrounding area (e.g. solid black).
+        ///
+        /// If the fullscreened surface is not opaque, the compositor must make
+        /// sure that other screen content not part of the same surface tree (made
+        /// up of subsurfaces, popups or similarly coupled surfaces) are not
+        /// visible below the fullscreened surface.
+        SetFullscreen {
+            /// preferred output to place surface on
+            output: ::core::option::Option<crate::wayland_protocol::ObjectId>,
+        },
+        /// unset the window as fullscreen
+        ///
+        /// Make the surface no longer fullscreen.
+        ///
+        /// After requesting that the surface should be unfullscreened, the
+        /// compositor will respond by emitting a configure event.
+        /// Whether this actually removes the fullscreen state of the client is
+        /// subject to compositor policies.
+        ///
+        /// Making a surface unfullscreen sets states for the surface based on the following:
+        /// * the state(s) it may have had before becoming fullscreen
+        /// * any state(s) decided by the compositor
+        /// * any state(s) requested by the client while the surface was fullscreen
+        ///
+        /// The compositor may include the previous window geometry dimensions in
+        /// the configure event, if applicable.
+        ///
+        /// The client must also acknowledge the configure when committing the new
+        /// content (see ack_configure).
+        UnsetFullscreen,
+        /// set the window as minimized
+        ///
+        /// Request that the compositor minimize your surface. There is no
+        /// way to know if the surface is currently minimized, nor is there
+        /// any way to unset minimization on this surface.
+        ///
+        /// If you are looking to throttle redrawing when minimized, please
+        /// instead use the wl_surface.frame event for this, as this will
+        /// also work with live previews on windows in Alt-Tab, Expose or
+        /// similar compositor features.
+        SetMinimized,
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::SetParent { .. } => 1,
+                Request::SetTitle { .. } => 2,
+                Request::SetAppId { .. } => 3,
+                Request::ShowWindowMenu { .. } => 4,
+                Request::Move { .. } => 5,
+                Request::Resize { .. } => 6,
+                Request::SetMaxSize { .. } => 7,
+                Request::SetMinSize { .. } => 8,
+                Request::SetMaximized => 9,
+                Request::UnsetMaximized => 10,
+                Request::SetFullscreen { .. } => 11,
+                Request::UnsetFullscreen => 12,
+                Request::SetMinimized => 13,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::SetParent { .. } => 1,
+                Request::SetTitle { .. } => 1,
+                Request::SetAppId { .. } => 1,
+                Request::ShowWindowMenu { .. } => 1,
+                Request::Move { .. } => 1,
+                Request::Resize { .. } => 1,
+                Request::SetMaxSize { .. } => 1,
+                Request::SetMinSize { .. } => 1,
+                Request::SetMaximized => 1,
+                Request::UnsetMaximized => 1,
+                Request::SetFullscreen { .. } => 1,
+                Request::UnsetFullscreen => 1,
+                Request::SetMinimized => 1,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// suggest a surface change
+        ///
+        /// This configure event asks the client to resize its toplevel surface or
+        /// to change its state. The configured state should not be applied
+        /// immediately. See xdg_surface.configure for details.
+        ///
+        /// The width and height arguments specify a hint to the window
+        /// about how its surface should be resized in window geometry
+        /// coordinates. See set_window_geometry.
+        ///
+        /// If the width or height arguments are zero, it means the client
+        /// should decide its own window dimension. This may happen when the
+        /// compositor needs to configure the state of the surface but doesn't
+        /// have any information about any previous or expected dimension.
+        ///
+        /// The states listed in the event specify how the width/height
+        /// arguments should be interpreted, and possibly how it should be
+        /// drawn.
+        ///
+        /// The states are sent as an array of 32-bit unsigned integers in
+        /// native endianness. State values are defined in the state enum.
+        ///
+        /// Clients must send an ack_configure in response to this event. See
+        /// xdg_surface.configure and xdg_surface.ack_configure for details.
+        Configure {
+            /// suggested width of window
+            width: i32,
+            /// suggested height of window
+            height: i32,
+            /// suggested states of the window
+            states: ::std::vec::Vec<u8>,
+        },
+        /// surface wants to be closed
+        ///
+        /// The close event is sent by the compositor when the user
+        /// wants the surface to be closed. This should be equivalent to
+        /// the user clicking the close button in client-side decorations,
+        /// if your application has any.
+        ///
+        /// This is only a request that the user intends to close the
+        /// window. The client may choose to ignore this request, or show
+        /// a dialog to ask the user to save their data, etc.
+        Close,
+        /// recommended window geometry bounds
+        ///
+        /// The configure_bounds event may be sent prior to a xdg_toplevel.configure
+        /// event to communicate the bounds a window geometry size is recommended
+        /// to constrain to.
+        ///
+        /// The passed width and height are in surface coordinate space. If width
+        /// and height are 0, it means bounds is unknown and equivalent to as if no
+        /// configure_bounds event was ever sent for this surface.
+        ///
+        /// The bounds can for example correspond to the size of a monitor excluding
+        /// any panels or other shell components, so that a surface isn't created in
+        /// a way that it cannot fit.
+        ///
+        /// The bounds may change at any point, and in such a case, a new
+        /// xdg_toplevel.configure_bounds will be sent, followed by
+        /// xdg_toplevel.configure and xdg_surface.configure.
+        ///
+        /// Added in version 4.
+        ConfigureBounds {
+            /// suggested maximum width of surface
+            width: i32,
+            /// suggested maximum height of surface
+            height: i32,
+        },
+        /// compositor capabilities
+        ///
+        /// This event advertises the capabilities supported by the compositor. If
+        /// a capability isn't supported, clients should hide or disable the UI
+        /// elements that expose this functionality. For instance, if the
+        /// compositor doesn't advertise support for minimized toplevels, a button
+        /// triggering the set_minimized request should not be displayed.
+        ///
+        /// The compositor will ignore requests it doesn't support. For instance,
+        /// a compositor which doesn't advertise support for minimized will ignore
+        /// set_minimized requests.
+        ///
+        /// Compositors must send this event once before the first
+        /// xdg_surface.configure event. When the capabilities change, compositors
+        /// must send this event again and then send an xdg_surface.configure
+        /// event.
+        ///
+        /// The configured state should not be applied immediately. See
+        /// xdg_surface.configure for details.
+        ///
+        /// The capabilities are sent as an array of 32-bit unsigned integers in
+        /// native endianness. Capability values are defined in the wm_capabilities enum.
+        ///
+        /// Added in version 5.
+        WmCapabilities {
+            /// array of 32-bit capabilities
+            capabilities: ::std::vec::Vec<u8>,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Configure { .. } => 0,
+                Event::Close => 1,
+                Event::ConfigureBounds { .. } => 2,
+                Event::WmCapabilities { .. } => 3,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Configure { .. } => 1,
+                Event::Close => 1,
+                Event::ConfigureBounds { .. } => 4,
+                Event::WmCapabilities { .. } => 5,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// provided value is not a valid variant of the resize_edge enum
+        InvalidResizeEdge = 0,
+        /// invalid parent toplevel
+        InvalidParent = 1,
+        /// client provided an invalid min or max size
+        InvalidSize = 2,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidResizeEdge),
+                1 => Some(Error::InvalidParent),
+                2 => Some(Error::InvalidSize),
+                _ => None,
+            }
+        }
+    }
+
+    /// edge values for resizing
+    ///
+    /// These values are used to indicate which edge of a surface
+    /// is being dragged in a resize operation.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum ResizeEdge {
+        None = 0,
+        Top = 1,
+        Bottom = 2,
+        Left = 4,
+        TopLeft = 5,
+        BottomLeft = 6,
+        Right = 8,
+        TopRight = 9,
+        BottomRight = 10,
+    }
+
+    impl ResizeEdge {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(ResizeEdge::None),
+                1 => Some(ResizeEdge::Top),
+                2 => Some(ResizeEdge::Bottom),
+                4 => Some(ResizeEdge::Left),
+                5 => Some(ResizeEdge::TopLeft),
+                6 => Some(ResizeEdge::BottomLeft),
+                8 => Some(ResizeEdge::Right),
+                9 => Some(ResizeEdge::TopRight),
+                10 => Some(ResizeEdge::BottomRight),
+                _ => None,
+            }
+        }
+    }
+
+    /// types of state on the surface
+    ///
+    /// The different state values used on the surface. This is designed for
+    /// state values like maximized, fullscreen. It is paired with the
+    /// configure event to ensure that both the client and the compositor
+    /// setting the state can be synchronized.
+    ///
+    /// States set in this way are double-buffered, see wl_surface.commit.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum State {
+        /// the surface is maximized
+        ///
+        /// The surface is maximized. The window geometry specified in the configure
+        /// event must be obeyed by the client, or the xdg_wm_base.invalid_surface_state
+        /// error is raised.
+        ///
+        /// The client should draw without shadow or other
+        /// decoration outside of the window geometry.
+        Maximized = 1,
+        /// the surface is fullscreen
+        ///
+        /// The surface is fullscreen. The window geometry specified in the
+        /// configure event is a maximum; the client cannot resize beyond it. For
+        /// a surface to cover the whole fullscreened area, the geometry
+        /// dimensions must be obeyed by the client. For more details, see
+        /// xdg_toplevel.set_fullscreen.
+        Fullscreen = 2,
+        /// the surface is being resized
+        ///
+        /// The surface is being resized. The window geometry specified in the
+        /// configure event is a maximum; the client cannot resize beyond it.
+        /// Clients that have aspect ratio or cell sizing configuration can use
+        /// a smaller size, however.
+        Resizing = 3,
+        /// the surface is now activated
+        ///
+        /// Client window decorations should be painted as if the window is
+        /// active. Do not assume this means that the window actually has
+        /// keyboard or pointer focus.
+        Activated = 4,
+        /// the surface’s left edge is tiled
+        ///
+        /// The window is currently in a tiled layout and the left edge is
+        /// considered to be adjacent to another part of the tiling grid.
+        ///
+        /// The client should draw without shadow or other decoration outside of
+        /// the window geometry on the left edge.
+        ///
+        /// Added in version 2.
+        TiledLeft = 5,
+        /// the surface’s right edge is tiled
+        ///
+        /// The window is currently in a tiled layout and the right edge is
+        /// considered to be adjacent to another part of the tiling grid.
+        ///
+        /// The client should draw without shadow or other decoration outside of
+        /// the window geometry on the right edge.
+        ///
+        /// Added in version 2.
+        TiledRight = 6,
+        /// the surface’s top edge is tiled
+        ///
+        /// The window is currently in a tiled layout and the top edge is
+        /// considered to be adjacent to another part of the tiling grid.
+        ///
+        /// The client should draw without shadow or other decoration outside of
+        /// the window geometry on the top edge.
+        ///
+        /// Added in version 2.
+        TiledTop = 7,
+        /// the surface’s bottom edge is tiled
+        ///
+        /// The window is currently in a tiled layout and the bottom edge is
+        /// considered to be adjacent to another part of the tiling grid.
+        ///
+        /// The client should draw without shadow or other decoration outside of
+        /// the window geometry on the bottom edge.
+        ///
+        /// Added in version 2.
+        TiledBottom = 8,
+        /// surface repaint is suspended
+        ///
+        /// The surface is currently not ordinarily being repainted; for
+        /// example because its content is occluded by another window, or its
+        /// outputs are switched off due to screen locking.
+        ///
+        /// Added in version 6.
+        Suspended = 9,
+        /// the surface’s left edge is constrained
+        ///
+        /// The left edge of the window is currently constrained, meaning it
+        /// shouldn't attempt to resize from that edge. It can for example mean
+        /// it's tiled next to a monitor edge on the constrained side of the
+        /// window.
+        ///
+        /// Added in version 7.
+        ConstrainedLeft = 10,
+        /// the surface’s right edge is constrained
+        ///
+        /// The right edge of the window is currently constrained, meaning it
+        /// shouldn't attempt to resize from that edge. It can for example mean
+        /// it's tiled next to a monitor edge on the constrained side of the
+        /// window.
+        ///
+        /// Added in version 7.
+        ConstrainedRight = 11,
+        /// the surface’s top edge is constrained
+        ///
+        /// The top edge of the window is currently constrained, meaning it
+        /// shouldn't attempt to resize from that edge. It can for example mean
+        /// it's tiled next to a monitor edge on the constrained side of the
+        /// window.
+        ///
+        /// Added in version 7.
+        ConstrainedTop = 12,
+        /// the surface’s bottom edge is constrained
+        ///
+        /// The bottom edge of the window is currently constrained, meaning it
+        /// shouldn't attempt to resize from that edge. It can for example mean
+        /// it's tiled next to a monitor edge on the constrained side of the
+        /// window.
+        ///
+        /// Added in version 7.
+        ConstrainedBottom = 13,
+    }
+
+    impl State {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                1 => Some(State::Maximized),
+                2 => Some(State::Fullscreen),
+                3 => Some(State::Resizing),
+                4 => Some(State::Activated),
+                5 => Some(State::TiledLeft),
+                6 => Some(State::TiledRight),
+                7 => Some(State::TiledTop),
+                8 => Some(State::TiledBottom),
+                9 => Some(State::Suspended),
+                10 => Some(State::ConstrainedLeft),
+                11 => Some(State::ConstrainedRight),
+                12 => Some(State::ConstrainedTop),
+                13 => Some(State::ConstrainedBottom),
+                _ => None,
+            }
+        }
+    }
+
+    /// Added in version 5.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum WmCapabilities {
+        /// show_window_menu is available
+        WindowMenu = 1,
+        /// set_maximized and unset_maximized are available
+        Maximize = 2,
+        /// set_fullscreen and unset_fullscreen are available
+        Fullscreen = 3,
+        /// set_minimized is available
+        Minimize = 4,
+    }
+
+    impl WmCapabilities {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                1 => Some(WmCapabilities::WindowMenu),
+                2 => Some(WmCapabilities::Maximize),
+                3 => Some(WmCapabilities::Fullscreen),
+                4 => Some(WmCapabilities::Minimize),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// short-lived, popup surfaces for menus
+///
+/// A popup surface is a short-lived, temporary surface. It can be used to
+/// implement for example menus, popovers, tooltips and other similar user
+/// interface concepts.
+///
+/// A popup can be made to take an explicit grab. See xdg_popup.grab for
+/// details.
+///
+/// When the popup is dismissed, a popup_done event will be sent out, and at
+/// the same time the surface will be unmapped. See the xdg_popup.popup_done
+/// event for details.
+///
+/// Explicitly destroying the xdg_popup object will also dismiss the popup and
+/// unmap the surface. Clients that want to dismiss the popup when another
+/// surface of their own is clicked should dismiss the popup using the destroy
+/// request.
+///
+/// A newly created xdg_popup will be stacked on top of all previously created
+/// xdg_popup surfaces associated with the same xdg_toplevel.
+///
+/// The parent of an xdg_popup must be mapped (see the xdg_surface
+/// description) before the xdg_popup itself.
+///
+/// The client must call wl_surface.commit on the corresponding wl_surface
+/// for the xdg_popup state to take effect.
+pub mod xdg_popup {
+    /// The name of the interface.
+    pub const NAME: &str = "xdg_popup";
+    /// The latest version of the interface.
+    pub const VERSION: u32 = 7;
+
+    /// A `xdg_popup` object.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct XdgPopup(pub crate::wayland_protocol::ObjectId);
+
+    impl XdgPopup {
+        /// The name of the interface.
+        pub const NAME: &'static str = NAME;
+        /// The latest version of the interface.
+        pub const VERSION: u32 = VERSION;
+    }
+
+    /// The requests, indexed by opcode.
+    pub const REQUESTS: &[crate::wayland_protocol::MessageDesc] = &[
+        crate::wayland_protocol::MessageDesc {
+            name: "destroy",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "grab",
+            since: 1,
+            signature: "ou",
+            types: &[Some("wl_seat"), None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "reposition",
+            since: 3,
+            signature: "3ou",
+            types: &[Some("xdg_positioner"), None],
+        },
+    ];
+
+    /// The events, indexed by opcode.
+    pub const EVENTS: &[crate::wayland_protocol::MessageDesc] = &[
+        crate::wayland_protocol::MessageDesc {
+            name: "configure",
+            since: 1,
+            signature: "iiii",
+            types: &[None, None, None, None],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "popup_done",
+            since: 1,
+            signature: "",
+            types: &[],
+        },
+        crate::wayland_protocol::MessageDesc {
+            name: "repositioned",
+            since: 3,
+            signature: "3u",
+            types: &[None],
+        },
+    ];
+
+    /// The requests of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Request {
+        /// remove xdg_popup interface
+        ///
+        /// This destroys the popup. Explicitly destroying the xdg_popup
+        /// object will also dismiss the popup, and unmap the surface.
+        ///
+        /// If this xdg_popup is not the "topmost" popup, the
+        /// xdg_wm_base.not_the_topmost_popup protocol error will be sent.
+        Destroy,
+        /// make the popup take an explicit grab
+        ///
+        /// This request makes the created popup take an explicit grab. An explicit
+        /// grab will be dismissed when the user dismisses the popup, or when the
+        /// client destroys the xdg_popup. This can be done by the user clicking
+        /// outside the surface, using the keyboard, or even locking the screen
+        /// through closing the lid or a timeout.
+        ///
+        /// If the compositor denies the grab, the popup will be immediately
+        /// dismissed.
+        ///
+        /// This request must be used in response to some sort of user action like a
+        /// button press, key press, or touch down event. The serial number of the
+        /// event should be passed as 'serial'.
+        ///
+        /// The parent of a grabbing popup must either be an xdg_toplevel surface or
+        /// another xdg_popup with an explicit grab. If the parent is another
+        /// xdg_popup it means that the popups are nested, with this popup now being
+        /// the topmost popup.
+        ///
+        /// Nested popups must be destroyed in the reverse order they were created
+        /// in, e.g. the only popup you are allowed to destroy at all times is the
+        /// topmost one.
+        ///
+        /// When compositors choose to dismiss a popup, they may dismiss every
+        /// nested grabbing popup as well. When a compositor dismisses popups, it
+        /// will follow the same dismissing order as required from the client.
+        ///
+        /// If the topmost grabbing popup is destroyed, the grab will be returned to
+        /// the parent of the popup, if that parent previously had an explicit grab.
+        ///
+        /// If the parent is a grabbing popup which has already been dismissed, this
+        /// popup will be immediately dismissed. If the parent is a popup that did
+        /// not take an explicit grab, an error will be raised.
+        ///
+        /// During a popup grab, the client owning the grab will receive pointer
+        /// and touch events for all their surfaces as normal (similar to an
+        /// "owner-events" grab in X11 parlance), while the top most grabbing popup
+        /// will always have keyboard focus.
+        Grab {
+            /// the wl_seat of the user event
+            seat: crate::wayland_protocol::ObjectId,
+            /// the serial of the user event
+            serial: u32,
+        },
+        /// recalculate the popup's location
+        ///
+        /// Reposition an already-mapped popup. The popup will be placed given the
+        /// details in the passed xdg_positioner object, and a
+        /// xdg_popup.repositioned followed by xdg_popup.configure and
+        /// xdg_surface.configure will be emitted in response. Any parameters set
+        /// by the previous positioner will be discarded.
+        ///
+        /// The passed token will be sent in the corresponding
+        /// xdg_popup.repositioned event. The new popup position will not take
+        /// effect until the corresponding configure event is acknowledged by the
+        /// client. See xdg_popup.repositioned for details. The token itself is
+        /// opaque, and has no other special meaning.
+        ///
+        /// If multiple reposition requests are sent, the compositor may skip all
+        /// but the last one.
+        ///
+        /// If the popup is repositioned in response to a configure event for its
+        /// parent, the client should send an xdg_positioner.set_parent_configure
+        /// and possibly an xdg_positioner.set_parent_size request to allow the
+        /// compositor to properly constrain the popup.
+        ///
+        /// If the popup is repositioned together with a parent that is being
+        /// resized, but not in response to a configure event, the client should
+        /// send an xdg_positioner.set_parent_size request.
+        ///
+        /// Added in version 3.
+        Reposition {
+            positioner: crate::wayland_protocol::ObjectId,
+            /// reposition request token
+            token: u32,
+        },
+    }
+
+    impl Request {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Request::Destroy => 0,
+                Request::Grab { .. } => 1,
+                Request::Reposition { .. } => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Request::Destroy => 1,
+                Request::Grab { .. } => 1,
+                Request::Reposition { .. } => 3,
+            }
+        }
+    }
+
+    /// The events of the interface.
+    #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Event {
+        /// configure the popup surface
+        ///
+        /// This event asks the popup surface to configure itself given the
+        /// configuration. The configured state should not be applied immediately.
+        /// See xdg_surface.configure for details.
+        ///
+        /// The x and y arguments represent the position the popup was placed at
+        /// given the xdg_positioner rule, relative to the upper left corner of the
+        /// window geometry of the parent surface.
+        ///
+        /// For version 2 or older, the configure event for an xdg_popup is only
+        /// ever sent once for the initial configuration. Starting with version 3,
+        /// it may be sent again if the popup is setup with an xdg_positioner with
+        /// set_reactive requested, or in response to xdg_popup.reposition requests.
+        Configure {
+            /// x position relative to parent surface window geometry
+            x: i32,
+            /// y position relative to parent surface window geometry
+            y: i32,
+            /// window geometry width
+            width: i32,
+            /// window geometry height
+            height: i32,
+        },
+        /// popup interaction is done
+        ///
+        /// The popup_done event is sent out when a popup is dismissed by the
+        /// compositor. The client should destroy the xdg_popup object at this
+        /// point.
+        PopupDone,
+        /// signal the completion of a repositioned request
+        ///
+        /// The repositioned event is sent as part of a popup configuration
+        /// sequence, together with xdg_popup.configure and lastly
+        /// xdg_surface.configure to notify the completion of a reposition request.
+        ///
+        /// The repositioned event is to notify about the completion of a
+        /// xdg_popup.reposition request. The token argument is the token passed
+        /// in the xdg_popup.reposition request.
+        ///
+        /// Immediately after this event is emitted, xdg_popup.configure and
+        /// xdg_surface.configure will be sent with the updated size and position,
+        /// as well as a new configure serial.
+        ///
+        /// The client should optionally update the content of the popup, but must
+        /// acknowledge the new popup configuration for the new position to take
+        /// effect. See xdg_surface.ack_configure for details.
+        ///
+        /// Added in version 3.
+        Repositioned {
+            /// reposition request token
+            token: u32,
+        },
+    }
+
+    impl Event {
+        /// Returns the opcode of the message.
+        pub fn opcode(&self) -> u16 {
+            match *self {
+                Event::Configure { .. } => 0,
+                Event::PopupDone => 1,
+                Event::Repositioned { .. } => 2,
+            }
+        }
+
+        /// Returns the interface version that added the message.
+        pub fn since(&self) -> u32 {
+            match *self {
+                Event::Configure { .. } => 1,
+                Event::PopupDone => 1,
+                Event::Repositioned { .. } => 3,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    #[allow(clippy::enum_variant_names)]
+    pub enum Error {
+        /// tried to grab after being mapped
+        InvalidGrab = 0,
+    }
+
+    impl Error {
+        /// Returns the entry with the given value.
+        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {
+            match value {
+                0 => Some(Error::InvalidGrab),
+                _ => None,
+            }
+        }
+    }
+}
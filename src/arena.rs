@@ -0,0 +1,297 @@
+//! A document tree stored in caller-provided memory.
+//!
+//! This is a no-allocation alternative to the `dom` module: nodes are
+//! written into a slice of [`Node`]s and linked by first-child and
+//! next-sibling indices, so the tree can be navigated on targets without a
+//! heap.
+
+use crate::{Attrs, Event, Parser, Text, WHITESPACE};
+use core::fmt::{self, Display};
+
+/// An error from building a [`Tree`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The document has more nodes than the storage can hold.
+    Capacity,
+    /// The document is not well-formed.
+    Malformed,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Error::Capacity => "not enough storage for document nodes",
+            Error::Malformed => "document is not well-formed",
+        })
+    }
+}
+
+/// The contents of a node.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum NodeKind<'a> {
+    /// An element, with its name and attributes.
+    Element(&'a str, Attrs<'a>),
+    /// Character data.
+    Text(Text<'a>),
+    /// A comment.
+    Comment(&'a str),
+    /// A processing instruction.
+    Pi(&'a str),
+}
+
+/// Storage for a single node of a [`Tree`].
+#[derive(Debug, Clone)]
+pub struct Node<'a> {
+    kind: Option<NodeKind<'a>>,
+    parent: Option<usize>,
+    first_child: Option<usize>,
+    next_sibling: Option<usize>,
+}
+
+impl<'a> Node<'a> {
+    /// An unused node, for initializing storage.
+    pub const EMPTY: Node<'a> = Node {
+        kind: None,
+        parent: None,
+        first_child: None,
+        next_sibling: None,
+    };
+}
+
+impl<'a> Default for Node<'a> {
+    fn default() -> Self {
+        Node::EMPTY
+    }
+}
+
+/// A document tree backed by a slice of [`Node`]s.
+#[derive(Debug, Clone, Copy)]
+pub struct Tree<'s, 'a> {
+    nodes: &'s [Node<'a>],
+    root: usize,
+}
+
+impl<'s, 'a> Tree<'s, 'a> {
+    /// Parses a document into the given storage.
+    ///
+    /// Each element, text, comment and processing instruction takes one
+    /// node. Closing tags, the doctype and whitespace between top-level
+    /// nodes take none, and [`Error::Capacity`] is returned if the storage
+    /// runs out.
+    pub fn parse(text: &'a str, storage: &'s mut [Node<'a>]) -> Result<Self, Error> {
+        let mut len = 0;
+        let mut root = None;
+        let mut current: Option<usize> = None;
+        let mut prev: Option<usize> = None;
+        let mut parser = Parser::new(text);
+        for event in &mut parser {
+            let kind = match event {
+                Event::Open(name, attrs) => NodeKind::Element(name, attrs),
+                Event::Close(name) => {
+                    let i = current.ok_or(Error::Malformed)?;
+                    match storage[i].kind {
+                        Some(NodeKind::Element(open, _)) if open == name => {}
+                        _ => return Err(Error::Malformed),
+                    }
+                    prev = current;
+                    current = storage[i].parent;
+                    continue;
+                }
+                Event::Doctype(..) => continue,
+                Event::Pi(pi) => NodeKind::Pi(pi),
+                Event::Comment(comment) => NodeKind::Comment(comment),
+                Event::Text(text) if current.is_none() => match text {
+                    Text::Escaped(s) if s.trim_matches(WHITESPACE).is_empty() => continue,
+                    _ => return Err(Error::Malformed),
+                },
+                Event::Text(text) => NodeKind::Text(text),
+            };
+            let i = len;
+            let is_element = matches!(kind, NodeKind::Element(..));
+            *storage.get_mut(i).ok_or(Error::Capacity)? = Node {
+                kind: Some(kind),
+                parent: current,
+                first_child: None,
+                next_sibling: None,
+            };
+            len += 1;
+            match (prev, current) {
+                (Some(p), _) => storage[p].next_sibling = Some(i),
+                (None, Some(parent)) => storage[parent].first_child = Some(i),
+                (None, None) => {}
+            }
+            if current.is_none() && is_element && root.replace(i).is_some() {
+                return Err(Error::Malformed);
+            }
+            if is_element {
+                current = Some(i);
+                prev = None;
+            } else {
+                prev = Some(i);
+            }
+        }
+        if !parser.doc.is_empty() || current.is_some() {
+            return Err(Error::Malformed);
+        }
+        Ok(Tree {
+            nodes: &storage[..len],
+            root: root.ok_or(Error::Malformed)?,
+        })
+    }
+
+    /// Returns the root element.
+    pub fn root(&self) -> NodeRef<'s, 'a> {
+        self.get(self.root)
+    }
+
+    /// Iterates over the top-level nodes, which are the root element and any
+    /// comments and processing instructions around it.
+    pub fn children(&self) -> Children<'s, 'a> {
+        Children {
+            nodes: self.nodes,
+            next: Some(0),
+        }
+    }
+
+    /// Returns the node at the given index, in document order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn get(&self, index: usize) -> NodeRef<'s, 'a> {
+        assert!(index < self.nodes.len(), "node index out of bounds");
+        NodeRef {
+            nodes: self.nodes,
+            index,
+        }
+    }
+
+    /// Returns the number of nodes used.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if no nodes are used.
+    /// This is never the case for a parsed tree.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+/// A reference to a node in a [`Tree`].
+#[derive(Debug, Clone, Copy)]
+pub struct NodeRef<'s, 'a> {
+    nodes: &'s [Node<'a>],
+    index: usize,
+}
+
+impl<'s, 'a> NodeRef<'s, 'a> {
+    fn node(&self) -> &'s Node<'a> {
+        &self.nodes[self.index]
+    }
+
+    fn at(&self, index: Option<usize>) -> Option<NodeRef<'s, 'a>> {
+        Some(NodeRef {
+            nodes: self.nodes,
+            index: index?,
+        })
+    }
+
+    /// Returns the node's index, in document order.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the node's contents.
+    pub fn kind(&self) -> &'s NodeKind<'a> {
+        match self.node().kind {
+            Some(ref kind) => kind,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the element's name, or `None` if this isn't an element.
+    pub fn name(&self) -> Option<&'a str> {
+        match *self.kind() {
+            NodeKind::Element(name, _) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Returns the element's attributes, or `None` if this isn't an element.
+    pub fn attrs(&self) -> Option<Attrs<'a>> {
+        match *self.kind() {
+            NodeKind::Element(_, ref attrs) => Some(attrs.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the given attribute, if present.
+    pub fn attr(&self, name: &str) -> Option<Text<'a>> {
+        self.attrs()?.get(name)
+    }
+
+    /// Returns the parent element, or `None` for top-level nodes.
+    pub fn parent(&self) -> Option<NodeRef<'s, 'a>> {
+        self.at(self.node().parent)
+    }
+
+    /// Returns the first child node.
+    pub fn first_child(&self) -> Option<NodeRef<'s, 'a>> {
+        self.at(self.node().first_child)
+    }
+
+    /// Returns the next sibling node.
+    pub fn next_sibling(&self) -> Option<NodeRef<'s, 'a>> {
+        self.at(self.node().next_sibling)
+    }
+
+    /// Iterates over the child nodes.
+    pub fn children(&self) -> Children<'s, 'a> {
+        Children {
+            nodes: self.nodes,
+            next: self.node().first_child,
+        }
+    }
+
+    /// Iterates over the child elements with the given name.
+    pub fn children_named<'n>(&self, name: &'n str) -> impl Iterator<Item = NodeRef<'s, 'a>> + 'n
+    where
+        's: 'n,
+    {
+        self.children().filter(move |n| n.name() == Some(name))
+    }
+
+    /// Returns the first child element with the given name.
+    pub fn child(&self, name: &str) -> Option<NodeRef<'s, 'a>> {
+        self.children().find(|n| n.name() == Some(name))
+    }
+
+    /// Iterates over the character data of the child text nodes.
+    pub fn texts(&self) -> impl Iterator<Item = Text<'a>> + 's {
+        self.children().filter_map(|n| match *n.kind() {
+            NodeKind::Text(ref text) => Some(text.clone()),
+            _ => None,
+        })
+    }
+}
+
+/// An iterator over sibling nodes.
+#[derive(Debug, Clone)]
+pub struct Children<'s, 'a> {
+    nodes: &'s [Node<'a>],
+    next: Option<usize>,
+}
+
+impl<'s, 'a> Iterator for Children<'s, 'a> {
+    type Item = NodeRef<'s, 'a>;
+
+    fn next(&mut self) -> Option<NodeRef<'s, 'a>> {
+        let index = self.next?;
+        self.next = self.nodes[index].next_sibling;
+        Some(NodeRef {
+            nodes: self.nodes,
+            index,
+        })
+    }
+}
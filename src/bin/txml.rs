@@ -0,0 +1,334 @@
+//! The `txml` command-line tool.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process;
+use txml::dom::Document;
+use txml::query::{Item, Query, Value};
+use txml::wayland::{self, diff};
+use txml::writer::Writer;
+use txml::{check, json, Event, Parser, Text};
+
+const USAGE: &str = "\
+usage: txml <command> [<args>]
+
+commands:
+    check <file>...           check that documents are well-formed
+    fmt [--check] <file>...   pretty-print documents in place, or with
+                              --check, fail if they aren't formatted
+    query <query> <file>...   print the result of an XPath query
+    to-json <file>...         convert documents to JSON
+    events <file>...          print the event stream of documents
+    wayland-diff <old> <new>  print the changes between two versions of a
+                              Wayland protocol, failing if any breaks
+                              compatibility
+
+A file named `-` is read from standard input. `fmt -` writes the formatted
+document to standard output.
+";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (command, args) = match args.split_first() {
+        Some((command, args)) => (command.as_str(), args),
+        None => usage(),
+    };
+    let ok = match (command, args) {
+        ("help" | "-h" | "--help", _) => {
+            print!("{}", USAGE);
+            true
+        }
+        ("check", files) if !files.is_empty() => each(files, |_, doc| checked(doc)),
+        ("fmt", [flag, files @ ..]) if flag == "--check" && !files.is_empty() => {
+            each(files, |path, doc| format_file(path, doc, true))
+        }
+        ("fmt", files) if !files.is_empty() => {
+            each(files, |path, doc| format_file(path, doc, false))
+        }
+        ("query", [query, files @ ..]) if !files.is_empty() => {
+            let query = Query::parse(query).unwrap_or_else(|err| {
+                eprintln!("txml: {}", err);
+                process::exit(2)
+            });
+            let prefix = files.len() > 1;
+            each(files, |path, doc| run_query(&query, path, doc, prefix))
+        }
+        ("to-json", files) if !files.is_empty() => each(files, |_, doc| to_json(doc)),
+        ("events", files) if !files.is_empty() => each(files, |_, doc| events(doc)),
+        ("wayland-diff", files @ [_, _]) => wayland_diff(files),
+        _ => usage(),
+    };
+    process::exit(if ok { 0 } else { 1 });
+}
+
+fn usage() -> ! {
+    eprint!("{}", USAGE);
+    process::exit(2)
+}
+
+/// Why a command failed for one file.
+struct Failure {
+    /// The line and column in the document, if the failure has one.
+    position: Option<(usize, usize)>,
+    message: String,
+}
+
+impl From<io::Error> for Failure {
+    fn from(err: io::Error) -> Self {
+        Failure {
+            position: None,
+            message: err.to_string(),
+        }
+    }
+}
+
+/// Runs a command on each file, reporting failures on stderr. Returns
+/// whether every file succeeded.
+fn each(files: &[String], mut f: impl FnMut(&str, &str) -> Result<(), Failure>) -> bool {
+    let mut ok = true;
+    for path in files {
+        let result = read(path)
+            .map_err(Failure::from)
+            .and_then(|doc| f(path, &doc));
+        if let Err(failure) = result {
+            ok = false;
+            match failure.position {
+                Some((line, column)) => {
+                    eprintln!("{}:{}:{}: {}", path, line, column, failure.message)
+                }
+                None => eprintln!("{}: {}", path, failure.message),
+            }
+        }
+    }
+    ok
+}
+
+fn read(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut doc = String::new();
+        io::stdin().read_to_string(&mut doc)?;
+        Ok(doc)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+fn checked(doc: &str) -> Result<(), Failure> {
+    check::check(doc).map_err(|err| Failure {
+        position: Some(err.line_column(doc)),
+        message: err.kind().to_string(),
+    })
+}
+
+fn format_file(path: &str, doc: &str, check_only: bool) -> Result<(), Failure> {
+    checked(doc)?;
+    let formatted = format(doc).expect("formatting into a String failed");
+    if path == "-" && !check_only {
+        io::stdout().write_all(formatted.as_bytes())?;
+    } else if formatted != doc {
+        if check_only {
+            return Err(Failure {
+                position: None,
+                message: "not formatted".into(),
+            });
+        }
+        fs::write(path, formatted)?;
+    }
+    Ok(())
+}
+
+fn run_query(query: &Query, path: &str, doc: &str, prefix: bool) -> Result<(), Failure> {
+    checked(doc)?;
+    let doc = Document::parse(doc).ok_or_else(|| Failure {
+        position: None,
+        message: "document is not well-formed".into(),
+    })?;
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut print = |value: &dyn fmt::Display| -> io::Result<()> {
+        if prefix {
+            write!(out, "{}:", path)?;
+        }
+        writeln!(out, "{}", value)
+    };
+    match query.evaluate(&doc) {
+        Value::Nodes(items) => {
+            for item in items {
+                match item {
+                    Item::Document(doc) => print(doc)?,
+                    Item::Node(node) => print(&node)?,
+                    Item::Attr(_, _, value) => print(&value)?,
+                }
+            }
+        }
+        Value::Number(n) => print(&n)?,
+        Value::String(s) => print(&s)?,
+        Value::Boolean(b) => print(&b)?,
+    }
+    Ok(())
+}
+
+fn to_json(doc: &str) -> Result<(), Failure> {
+    checked(doc)?;
+    let value = json::from_xml(doc).map_err(|err| Failure {
+        position: None,
+        message: err.to_string(),
+    })?;
+    println!("{:#}", value);
+    Ok(())
+}
+
+fn events(doc: &str) -> Result<(), Failure> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for event in Parser::new(doc) {
+        writeln!(out, "{:?}", event)?;
+    }
+    checked(doc)
+}
+
+/// Prints the changes between the two versions of a Wayland protocol in
+/// `files`. Returns whether they're compatible.
+fn wayland_diff(files: &[String]) -> bool {
+    let mut protocols = Vec::new();
+    let parsed = each(files, |_, doc| {
+        let protocol = wayland::parse(doc).map_err(|err| Failure {
+            position: Some(err.line_column(doc)),
+            message: err.kind().to_string(),
+        })?;
+        protocols.push(protocol);
+        Ok(())
+    });
+    if !parsed {
+        return false;
+    }
+    let changes = diff::diff(&protocols[0], &protocols[1]);
+    for change in &changes {
+        println!("{}", change);
+    }
+    !changes.iter().any(diff::Change::is_breaking)
+}
+
+/// Pretty-prints a well-formed document.
+///
+/// Elements that only contain other elements, comments and processing
+/// instructions are indented by two spaces per level, and whitespace
+/// between them is replaced. Elements that contain character data, or have
+/// `xml:space="preserve"`, are written as they are.
+fn format(doc: &str) -> Result<String, fmt::Error> {
+    let mut writer = Writer::new(String::new());
+    let mut parser = Parser::new(doc);
+    let mut first = true;
+    while let Some(event) = parser.next() {
+        // Text outside of the root element is whitespace.
+        if let Event::Text(_) = event {
+            continue;
+        }
+        if !first {
+            newline(&mut writer, 0)?;
+        }
+        first = false;
+        node(&mut writer, &mut parser, event, 0)?;
+    }
+    let mut out = writer.into_inner();
+    out.push('\n');
+    Ok(out)
+}
+
+fn node<'a>(
+    writer: &mut Writer<String>,
+    parser: &mut Parser<'a>,
+    event: Event<'a>,
+    depth: usize,
+) -> fmt::Result {
+    match event {
+        Event::Open(name, attrs) => {
+            writer.open(name)?;
+            for (name, value) in attrs.clone() {
+                writer.attr(name, value)?;
+            }
+            if attrs
+                .get("xml:space")
+                .is_some_and(|space| space == "preserve")
+                || has_text(parser)
+            {
+                return copy(writer, parser);
+            }
+            let mut empty = true;
+            while let Some(event) = parser.next() {
+                match event {
+                    Event::Close(name) => {
+                        if !empty {
+                            newline(writer, depth)?;
+                        }
+                        return writer.close(name);
+                    }
+                    Event::Text(_) => {}
+                    event => {
+                        empty = false;
+                        newline(writer, depth + 1)?;
+                        node(writer, parser, event, depth + 1)?;
+                    }
+                }
+            }
+            Ok(())
+        }
+        Event::Close(name) => writer.close(name),
+        Event::Text(Text::Escaped(text)) => writer.text(Text::Escaped(text)),
+        Event::Text(Text::Verbatim(text)) => writer.cdata(text),
+        Event::Comment(text) => writer.comment(text),
+        Event::Pi(text) => writer.pi(text),
+        Event::Doctype(name, body) => writer.doctype(name, body),
+    }
+}
+
+/// Returns whether the current element directly contains character data.
+fn has_text(parser: &Parser) -> bool {
+    let mut depth = 0usize;
+    for event in parser.clone() {
+        match event {
+            Event::Open(..) => depth += 1,
+            Event::Close(..) if depth == 0 => return false,
+            Event::Close(..) => depth -= 1,
+            Event::Text(Text::Escaped(text)) if depth == 0 && !text.trim().is_empty() => {
+                return true
+            }
+            Event::Text(Text::Verbatim(_)) if depth == 0 => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Writes the rest of the current element unchanged.
+fn copy<'a>(writer: &mut Writer<String>, parser: &mut Parser<'a>) -> fmt::Result {
+    let mut depth = 0usize;
+    while let Some(event) = parser.next() {
+        match event {
+            Event::Open(..) => depth += 1,
+            Event::Close(..) if depth == 0 => return node(writer, parser, event, 0),
+            Event::Close(..) => depth -= 1,
+            _ => {}
+        }
+        match event {
+            Event::Open(name, attrs) => {
+                writer.open(name)?;
+                for (name, value) in attrs {
+                    writer.attr(name, value)?;
+                }
+            }
+            event => node(writer, parser, event, 0)?,
+        }
+    }
+    Ok(())
+}
+
+fn newline(writer: &mut Writer<String>, depth: usize) -> fmt::Result {
+    writer.text("\n")?;
+    for _ in 0..depth {
+        writer.text("  ")?;
+    }
+    Ok(())
+}
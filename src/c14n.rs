@@ -0,0 +1,314 @@
+//! Canonical XML output.
+//!
+//! Implements [Canonical XML 1.0](https://www.w3.org/TR/xml-c14n) and
+//! [Exclusive XML Canonicalization 1.0](https://www.w3.org/TR/xml-exc-c14n/)
+//! for whole documents, which gives a stable byte representation suitable
+//! for hashing and signing.
+//!
+//! The output has no XML declaration or doctype, line endings normalized to
+//! `\n`, entity and character references replaced by the characters they
+//! refer to, empty elements written as start-end tag pairs, attributes
+//! sorted, and superfluous namespace declarations removed.
+
+use crate::{Event, Parser, Text, WHITESPACE};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Canonicalization options.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Options<'a> {
+    /// Use Exclusive XML Canonicalization instead of Canonical XML.
+    pub exclusive: bool,
+    /// Keep comments in the output.
+    pub with_comments: bool,
+    /// The InclusiveNamespaces PrefixList for exclusive canonicalization.
+    /// Use `#default` for the default namespace.
+    pub inclusive_prefixes: &'a [&'a str],
+}
+
+/// A canonicalization error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The document is not well-formed.
+    Malformed,
+    /// An element or attribute name uses a prefix that isn't declared.
+    UndeclaredPrefix,
+    /// The output returned an error.
+    Write,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Error::Malformed => "document is not well-formed",
+            Error::UndeclaredPrefix => "undeclared namespace prefix",
+            Error::Write => "failed to write output",
+        })
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Self {
+        Error::Write
+    }
+}
+
+/// Namespace bindings, by prefix. The default namespace has an empty prefix.
+type Bindings<'a> = Vec<(&'a str, String)>;
+
+struct Scope<'a> {
+    name: &'a str,
+    /// The bindings in scope for this element.
+    in_scope: Bindings<'a>,
+    /// The bindings declared on this element or an output ancestor.
+    rendered: Bindings<'a>,
+}
+
+/// Writes the canonical form of a document.
+pub fn canonicalize<W: Write>(doc: &str, options: &Options, out: &mut W) -> Result<(), Error> {
+    let mut parser = Parser::new(doc);
+    let mut stack: Vec<Scope> = Vec::new();
+    let mut seen_root = false;
+    let mut buf = String::new();
+    for event in &mut parser {
+        let outside = stack.is_empty();
+        match event {
+            Event::Open(name, attrs) => {
+                if outside && seen_root {
+                    return Err(Error::Malformed);
+                }
+                seen_root = true;
+                let scope = open(name, attrs, stack.last(), options, out)?;
+                stack.push(scope);
+            }
+            Event::Close(name) => match stack.pop() {
+                Some(scope) if scope.name == name => write!(out, "</{}>", name)?,
+                _ => return Err(Error::Malformed),
+            },
+            Event::Text(text) => {
+                buf.clear();
+                decode(text, false, &mut buf)?;
+                if outside {
+                    if !buf.trim_matches(WHITESPACE).is_empty() {
+                        return Err(Error::Malformed);
+                    }
+                } else {
+                    escape(&buf, false, out)?;
+                }
+            }
+            Event::Comment(comment) if options.with_comments => {
+                buf.clear();
+                decode(Text::Verbatim(comment), false, &mut buf)?;
+                if outside && seen_root {
+                    out.write_char('\n')?;
+                }
+                write!(out, "<!--{}-->", buf)?;
+                if outside && !seen_root {
+                    out.write_char('\n')?;
+                }
+            }
+            Event::Pi(pi) => {
+                let target_end = pi.find(WHITESPACE).unwrap_or(pi.len());
+                let (target, data) = pi.split_at(target_end);
+                if target == "xml" {
+                    continue;
+                }
+                buf.clear();
+                let data = data.trim_start_matches(WHITESPACE);
+                decode(Text::Verbatim(data), false, &mut buf)?;
+                if outside && seen_root {
+                    out.write_char('\n')?;
+                }
+                if buf.is_empty() {
+                    write!(out, "<?{}?>", target)?;
+                } else {
+                    write!(out, "<?{} {}?>", target, buf)?;
+                }
+                if outside && !seen_root {
+                    out.write_char('\n')?;
+                }
+            }
+            Event::Comment(..) | Event::Doctype(..) => {}
+        }
+    }
+    if !parser.doc.is_empty() || !stack.is_empty() || !seen_root {
+        return Err(Error::Malformed);
+    }
+    Ok(())
+}
+
+fn open<'a, W: Write>(
+    name: &'a str,
+    attrs: crate::Attrs<'a>,
+    parent: Option<&Scope<'a>>,
+    options: &Options,
+    out: &mut W,
+) -> Result<Scope<'a>, Error> {
+    let mut scope = Scope {
+        name,
+        in_scope: parent.map(|p| p.in_scope.clone()).unwrap_or_default(),
+        rendered: parent.map(|p| p.rendered.clone()).unwrap_or_default(),
+    };
+
+    let mut regular = Vec::new();
+    for (key, value) in attrs {
+        let mut normalized = String::new();
+        decode(value, true, &mut normalized)?;
+        let prefix = match key.strip_prefix("xmlns") {
+            Some("") => "",
+            Some(rest) if rest.starts_with(':') => &rest[1..],
+            _ => {
+                regular.push((key, normalized));
+                continue;
+            }
+        };
+        bind(&mut scope.in_scope, prefix, normalized);
+    }
+
+    let element_prefix = prefix_of(name);
+    if !matches!(element_prefix, "" | "xml") && lookup(&scope.in_scope, element_prefix).is_none() {
+        return Err(Error::UndeclaredPrefix);
+    }
+
+    let mut candidates: Vec<&str> = Vec::new();
+    if options.exclusive {
+        candidates.push(element_prefix);
+        candidates.extend(
+            regular
+                .iter()
+                .map(|(key, _)| prefix_of(key))
+                .filter(|prefix| !prefix.is_empty()),
+        );
+        for &prefix in options.inclusive_prefixes {
+            let prefix = if prefix == "#default" { "" } else { prefix };
+            if let Some(&(prefix, _)) = scope.in_scope.iter().find(|&&(p, _)| p == prefix) {
+                candidates.push(prefix);
+            }
+        }
+    } else {
+        candidates.extend(scope.in_scope.iter().map(|&(prefix, _)| prefix));
+    }
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    write!(out, "<{}", name)?;
+    for prefix in candidates {
+        if prefix == "xml" {
+            continue;
+        }
+        let uri = match lookup(&scope.in_scope, prefix) {
+            Some(uri) => uri,
+            None if prefix.is_empty() => "",
+            None => return Err(Error::UndeclaredPrefix),
+        };
+        let rendered = match lookup(&scope.rendered, prefix) {
+            None if prefix.is_empty() => Some(""),
+            rendered => rendered,
+        };
+        if rendered == Some(uri) {
+            continue;
+        }
+        if prefix.is_empty() {
+            out.write_str(" xmlns=\"")?;
+        } else {
+            write!(out, " xmlns:{}=\"", prefix)?;
+        }
+        escape(uri, true, out)?;
+        out.write_char('"')?;
+        let uri = String::from(uri);
+        bind(&mut scope.rendered, prefix, uri);
+    }
+
+    let mut keyed = Vec::with_capacity(regular.len());
+    for (key, value) in regular {
+        let uri = match prefix_of(key) {
+            "" => "",
+            "xml" => XML_NAMESPACE,
+            prefix => lookup(&scope.in_scope, prefix).ok_or(Error::UndeclaredPrefix)?,
+        };
+        let local = key.rsplit(':').next().unwrap_or(key);
+        keyed.push(((uri, local), key, value));
+    }
+    keyed.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    for (_, key, value) in keyed {
+        write!(out, " {}=\"", key)?;
+        escape(&value, true, out)?;
+        out.write_char('"')?;
+    }
+    out.write_char('>')?;
+
+    Ok(scope)
+}
+
+fn prefix_of(name: &str) -> &str {
+    match name.find(':') {
+        Some(i) => &name[..i],
+        None => "",
+    }
+}
+
+fn lookup<'b>(bindings: &'b Bindings, prefix: &str) -> Option<&'b str> {
+    bindings
+        .iter()
+        .rev()
+        .find(|&&(p, _)| p == prefix)
+        .map(|(_, uri)| &uri[..])
+}
+
+fn bind<'a>(bindings: &mut Bindings<'a>, prefix: &'a str, uri: String) {
+    match bindings.iter_mut().find(|&&mut (p, _)| p == prefix) {
+        Some(binding) => binding.1 = uri,
+        None => bindings.push((prefix, uri)),
+    }
+}
+
+/// Replaces references and normalizes line endings.
+/// Attribute values also have whitespace characters replaced with spaces.
+fn decode(text: Text, attr: bool, out: &mut String) -> Result<(), Error> {
+    let (mut rest, escaped) = match text {
+        Text::Verbatim(s) => (s, false),
+        Text::Escaped(s) => (s, true),
+    };
+    while let Some(c) = rest.chars().next() {
+        if escaped && c == '&' {
+            let semi = rest.find(';').ok_or(Error::Malformed)?;
+            let c = Text::Escaped(&rest[..=semi]).next();
+            out.push(c.ok_or(Error::Malformed)?);
+            rest = &rest[semi + 1..];
+            continue;
+        }
+        rest = &rest[c.len_utf8()..];
+        let c = if c == '\r' {
+            rest = rest.strip_prefix('\n').unwrap_or(rest);
+            '\n'
+        } else {
+            c
+        };
+        let c = if attr && WHITESPACE.contains(&c) {
+            ' '
+        } else {
+            c
+        };
+        out.push(c);
+    }
+    Ok(())
+}
+
+fn escape<W: Write>(s: &str, attr: bool, out: &mut W) -> fmt::Result {
+    for c in s.chars() {
+        match c {
+            '&' => out.write_str("&amp;")?,
+            '<' => out.write_str("&lt;")?,
+            '>' if !attr => out.write_str("&gt;")?,
+            '"' if attr => out.write_str("&quot;")?,
+            '\t' if attr => out.write_str("&#x9;")?,
+            '\n' if attr => out.write_str("&#xA;")?,
+            '\r' => out.write_str("&#xD;")?,
+            c => out.write_char(c)?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,265 @@
+//! Well-formedness checking.
+//!
+//! The [`Parser`] reports errors by ending the event stream early, which
+//! doesn't say what went wrong or where. [`check`] walks a whole document
+//! and reports the first problem it finds with its position, for tools that
+//! need to tell the user why a document was rejected.
+//!
+//! Not every well-formedness constraint is checked: names aren't validated
+//! against the XML name production beyond their first character, and the
+//! internal subset of a doctype isn't parsed.
+
+use crate::{Event, Parser, Text, WHITESPACE};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// A well-formedness error, with the byte offset in the document where it
+/// was found.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Error<'a> {
+    offset: usize,
+    kind: ErrorKind<'a>,
+}
+
+impl<'a> Error<'a> {
+    /// Returns the byte offset of the error in the document.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns what went wrong.
+    pub fn kind(&self) -> ErrorKind<'a> {
+        self.kind
+    }
+
+    /// Returns the one-based line and column of the error in `doc`, which
+    /// must be the document that was checked. Columns count characters.
+    pub fn line_column(&self, doc: &str) -> (usize, usize) {
+        line_column(doc, self.offset)
+    }
+}
+
+impl<'a> Display for Error<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+/// The kind of a well-formedness [`Error`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorKind<'a> {
+    /// A tag, comment, CDATA section, processing instruction or doctype
+    /// isn't terminated.
+    Unterminated,
+    /// An element or attribute name is empty, doesn't start with a name
+    /// character or contains whitespace.
+    InvalidName(&'a str),
+    /// An attribute isn't of the form `name="value"`.
+    InvalidAttribute,
+    /// The named attribute appears more than once in the same tag.
+    DuplicateAttribute(&'a str),
+    /// An attribute value contains a `<`.
+    LessThanInAttribute,
+    /// An entity or character reference is unknown or invalid.
+    InvalidReference,
+    /// A closing tag doesn't match the open element.
+    MismatchedTag {
+        /// The name of the open element.
+        expected: &'a str,
+        /// The name in the closing tag.
+        found: &'a str,
+    },
+    /// A closing tag was found with no element open.
+    UnexpectedClose(&'a str),
+    /// The named element isn't closed before the end of the document.
+    Unclosed(&'a str),
+    /// Character data was found outside of the root element.
+    TextOutsideRoot,
+    /// Character data contains `]]>`, which only ends CDATA sections.
+    CdataEndInText,
+    /// A comment contains `--` or ends with `-`.
+    HyphensInComment,
+    /// A second root element was found.
+    MultipleRoots,
+    /// A doctype declaration was found after the root element started.
+    MisplacedDoctype,
+    /// The document has no root element.
+    NoRoot,
+}
+
+impl<'a> Display for ErrorKind<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ErrorKind::Unterminated => f.write_str("unterminated markup"),
+            ErrorKind::InvalidName(name) => write!(f, "invalid name `{}`", name),
+            ErrorKind::InvalidAttribute => f.write_str("invalid attribute"),
+            ErrorKind::DuplicateAttribute(name) => write!(f, "duplicate attribute `{}`", name),
+            ErrorKind::LessThanInAttribute => f.write_str("`<` in attribute value"),
+            ErrorKind::InvalidReference => f.write_str("invalid entity or character reference"),
+            ErrorKind::MismatchedTag { expected, found } => write!(
+                f,
+                "closing tag `{}` doesn't match open element `{}`",
+                found, expected
+            ),
+            ErrorKind::UnexpectedClose(name) => write!(f, "unexpected closing tag `{}`", name),
+            ErrorKind::Unclosed(name) => write!(f, "element `{}` is never closed", name),
+            ErrorKind::TextOutsideRoot => f.write_str("character data outside of the root element"),
+            ErrorKind::CdataEndInText => f.write_str("`]]>` in character data"),
+            ErrorKind::HyphensInComment => f.write_str("comment contains `--` or ends with `-`"),
+            ErrorKind::MultipleRoots => f.write_str("more than one root element"),
+            ErrorKind::MisplacedDoctype => f.write_str("doctype after the root element"),
+            ErrorKind::NoRoot => f.write_str("no root element"),
+        }
+    }
+}
+
+/// Checks that a document is well-formed.
+pub fn check(doc: &str) -> Result<(), Error<'_>> {
+    let offset = |s: &str| s.as_ptr() as usize - doc.as_ptr() as usize;
+    let error = |offset, kind| Err(Error { offset, kind });
+    let mut parser = Parser::new(doc);
+    let mut open: Vec<(usize, &str)> = Vec::new();
+    let mut seen_root = false;
+    loop {
+        let start = doc.len() - parser.doc.len();
+        let event = match parser.next() {
+            Some(event) => event,
+            None if start == doc.len() => break,
+            None => return error(start, ErrorKind::Unterminated),
+        };
+        match event {
+            Event::Open(name, attrs) => {
+                if open.is_empty() && seen_root {
+                    return error(start, ErrorKind::MultipleRoots);
+                }
+                if !is_name(name) {
+                    return error(start + 1, ErrorKind::InvalidName(name));
+                }
+                let mut rest = attrs.clone();
+                let mut names: Vec<&str> = Vec::new();
+                for (attr, value) in rest.by_ref() {
+                    if !is_name(attr) {
+                        return error(offset(attr), ErrorKind::InvalidName(attr));
+                    }
+                    if names.contains(&attr) {
+                        return error(offset(attr), ErrorKind::DuplicateAttribute(attr));
+                    }
+                    names.push(attr);
+                    let value = match value {
+                        Text::Escaped(s) | Text::Verbatim(s) => s,
+                    };
+                    if let Some(i) = value.find('<') {
+                        return error(offset(value) + i, ErrorKind::LessThanInAttribute);
+                    }
+                    check_references(value).map_err(|i| Error {
+                        offset: offset(value) + i,
+                        kind: ErrorKind::InvalidReference,
+                    })?;
+                }
+                let rest = rest.text.trim_start_matches(WHITESPACE);
+                if !rest.is_empty() {
+                    return error(offset(rest), ErrorKind::InvalidAttribute);
+                }
+                seen_root = true;
+                open.push((start, name));
+            }
+            Event::Close(name) => match open.pop() {
+                Some((_, expected)) if expected == name => {}
+                Some((_, expected)) => {
+                    return error(
+                        start,
+                        ErrorKind::MismatchedTag {
+                            expected,
+                            found: name,
+                        },
+                    )
+                }
+                None => return error(start, ErrorKind::UnexpectedClose(name)),
+            },
+            Event::Text(Text::Escaped(text)) => {
+                if open.is_empty() {
+                    if let Some(i) = text.find(|c| !WHITESPACE.contains(&c)) {
+                        return error(start + i, ErrorKind::TextOutsideRoot);
+                    }
+                }
+                if let Some(i) = text.find("]]>") {
+                    return error(start + i, ErrorKind::CdataEndInText);
+                }
+                check_references(text).map_err(|i| Error {
+                    offset: start + i,
+                    kind: ErrorKind::InvalidReference,
+                })?;
+            }
+            Event::Comment(text) => {
+                if let Some(i) = text.find("--") {
+                    return error(offset(text) + i, ErrorKind::HyphensInComment);
+                }
+                if text.ends_with('-') {
+                    return error(offset(text) + text.len() - 1, ErrorKind::HyphensInComment);
+                }
+            }
+            Event::Text(Text::Verbatim(_)) if open.is_empty() => {
+                return error(start, ErrorKind::TextOutsideRoot)
+            }
+            Event::Doctype(..) if seen_root => return error(start, ErrorKind::MisplacedDoctype),
+            Event::Text(..) | Event::Doctype(..) | Event::Pi(..) => {}
+        }
+    }
+    if let Some(&(start, name)) = open.last() {
+        return error(start, ErrorKind::Unclosed(name));
+    }
+    if !seen_root {
+        return error(doc.len(), ErrorKind::NoRoot);
+    }
+    Ok(())
+}
+
+/// Returns the one-based line and column of a byte offset in `doc`.
+/// Columns count characters.
+pub fn line_column(doc: &str, offset: usize) -> (usize, usize) {
+    let before = &doc[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+fn is_name(name: &str) -> bool {
+    match name.chars().next() {
+        Some(c) => (c.is_alphabetic() || c == '_' || c == ':') && !name.contains(WHITESPACE),
+        None => false,
+    }
+}
+
+/// Checks the entity and character references in escaped text, returning
+/// the offset of the first invalid one.
+fn check_references(text: &str) -> Result<(), usize> {
+    for (i, _) in text.match_indices('&') {
+        let rest = &text[i + 1..];
+        let end = rest.find(';').ok_or(i)?;
+        let code = match &rest[..end] {
+            "lt" | "gt" | "amp" | "apos" | "quot" => continue,
+            r if r.starts_with("#x") => r[2..]
+                .bytes()
+                .all(|b| b.is_ascii_hexdigit())
+                .then(|| u32::from_str_radix(&r[2..], 16).ok())
+                .flatten(),
+            r if r.starts_with('#') => r[1..]
+                .bytes()
+                .all(|b| b.is_ascii_digit())
+                .then(|| r[1..].parse().ok())
+                .flatten(),
+            _ => None,
+        };
+        // Character references must refer to characters allowed by the
+        // `Char` production.
+        let valid = matches!(
+            code,
+            Some(0x9 | 0xA | 0xD | 0x20..=0xD7FF | 0xE000..=0xFFFD | 0x10000..=0x10FFFF)
+        );
+        if !valid {
+            return Err(i);
+        }
+    }
+    Ok(())
+}
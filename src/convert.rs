@@ -0,0 +1,182 @@
+//! Conversion between XML elements and Rust types.
+//!
+//! [`FromXml`] reads a type from an element in the event stream, and
+//! [`ToXml`] writes it back out with a [`Writer`]. They're usually derived,
+//! with the `derive` feature:
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use txml::{FromXml, ToXml};
+//!
+//! #[derive(FromXml, ToXml)]
+//! struct Interface {
+//!     #[xml(attr = "name")]
+//!     name: String,
+//!     #[xml(attr = "version")]
+//!     version: u32,
+//!     #[xml(child = "description")]
+//!     description: Option<Description>,
+//!     #[xml(child = "request")]
+//!     requests: Vec<Message>,
+//! }
+//!
+//! #[derive(FromXml, ToXml)]
+//! struct Description {
+//!     #[xml(attr = "summary")]
+//!     summary: String,
+//!     #[xml(text)]
+//!     body: Option<String>,
+//! }
+//!
+//! #[derive(FromXml, ToXml)]
+//! struct Message {
+//!     #[xml(attr = "name")]
+//!     name: String,
+//!     #[xml(attr = "since", default)]
+//!     since: u32,
+//! }
+//!
+//! let xml = r#"<interface name="wl_shm" version="2">
+//!   <description summary="shared memory support"/>
+//!   <request name="create_pool"/>
+//!   <request name="release" since="2"/>
+//! </interface>"#;
+//! let interface: Interface = txml::convert::from_str(xml).unwrap();
+//! assert_eq!(interface.requests[1].since, 2);
+//! assert!(interface.description.unwrap().body.is_none());
+//!
+//! let mut writer = txml::writer::Writer::new(String::new());
+//! interface.requests[0].to_xml("request", &mut writer).unwrap();
+//! assert_eq!(writer.into_inner(), r#"<request name="create_pool" since="0"/>"#);
+//! # }
+//! ```
+//!
+//! Fields are marked with one of these:
+//!
+//! - `#[xml(attr = "name")]` reads an attribute with [`FromStr`], whose
+//!   error must implement [`Display`], and writes it with [`Display`]. `bool`
+//!   fields are read with [`Attrs::parse_bool`].
+//!   An `Option` field may be missing, and `default` or `default = "path"`
+//!   fills in a missing attribute with [`Default::default`] or the function
+//!   at `path`.
+//! - `#[xml(child = "name")]` reads child elements with [`FromXml`] and
+//!   writes them with [`ToXml`]. A `Vec` field holds any number of them, an
+//!   `Option` field at most one, and any other field exactly one.
+//! - `#[xml(text)]` reads the character data of the element with
+//!   [`FromStr`] and writes it with [`Display`]. An `Option` field is `None`
+//!   if the element has no character data.
+//! - `#[xml(skip)]` isn't read or written, and is read as
+//!   [`Default::default`].
+//!
+//! Attributes are written first, followed by child elements and text in the
+//! order of the fields. When reading, unknown attributes and child elements
+//! are ignored, unless the struct is marked with `#[xml(deny_unknown)]`.
+//!
+//! The type parameters of a generic struct must implement [`FromXml`] or
+//! [`ToXml`], so they can be used for child elements.
+//!
+//! [`FromStr`]: core::str::FromStr
+
+use crate::writer::Writer;
+use crate::{AttrError, Attrs, Event, Parser};
+use core::fmt::{self, Display, Write};
+
+/// A type that can be read from an XML element.
+pub trait FromXml<'a>: Sized {
+    /// Reads the element with the given name and attributes, whose
+    /// [`Event::Open`] was the last event returned by `parser`.
+    /// This consumes the rest of the element, up to and including its
+    /// [`Event::Close`].
+    fn from_xml(
+        name: &'a str,
+        attrs: Attrs<'a>,
+        parser: &mut Parser<'a>,
+    ) -> Result<Self, Error<'a>>;
+}
+
+/// A type that can be written as an XML element.
+pub trait ToXml {
+    /// Writes the value as an element with the given name.
+    fn to_xml<W: Write>(&self, name: &str, writer: &mut Writer<W>) -> fmt::Result;
+}
+
+/// Reads the root element of a document.
+pub fn from_str<'a, T: FromXml<'a>>(doc: &'a str) -> Result<T, Error<'a>> {
+    let mut parser = Parser::new(doc);
+    loop {
+        match parser.next().ok_or(Error::Malformed)? {
+            Event::Open(name, attrs) => return T::from_xml(name, attrs, &mut parser),
+            Event::Text(text) if text.clone().all(char::is_whitespace) => {}
+            Event::Close(..) | Event::Text(..) => return Err(Error::Malformed),
+            Event::Doctype(..) | Event::Pi(..) | Event::Comment(..) => {}
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> FromXml<'a> for alloc::string::String {
+    fn from_xml(_: &'a str, _: Attrs<'a>, parser: &mut Parser<'a>) -> Result<Self, Error<'a>> {
+        let mut text = alloc::string::String::new();
+        loop {
+            match parser.next().ok_or(Error::Malformed)? {
+                Event::Open(name, _) => return Err(Error::UnexpectedElement(name)),
+                Event::Close(..) => return Ok(text),
+                Event::Text(t) => text.extend(t),
+                Event::Doctype(..) | Event::Pi(..) | Event::Comment(..) => {}
+            }
+        }
+    }
+}
+
+impl ToXml for str {
+    fn to_xml<W: Write>(&self, name: &str, writer: &mut Writer<W>) -> fmt::Result {
+        writer.open(name)?;
+        if !self.is_empty() {
+            writer.text(self)?;
+        }
+        writer.close(name)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToXml for alloc::string::String {
+    fn to_xml<W: Write>(&self, name: &str, writer: &mut Writer<W>) -> fmt::Result {
+        self.as_str().to_xml(name, writer)
+    }
+}
+
+/// An error from reading a type from XML.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error<'a> {
+    /// The document is not well-formed.
+    Malformed,
+    /// An attribute is missing, invalid or unexpected.
+    Attr(AttrError<'a>),
+    /// The named child element is missing.
+    MissingElement(&'a str),
+    /// The named child element appears more than once.
+    DuplicateElement(&'a str),
+    /// The named child element isn't one of the expected elements.
+    UnexpectedElement(&'a str),
+    /// The character data of the named element couldn't be parsed.
+    InvalidText(&'a str),
+}
+
+impl<'a> Display for Error<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Malformed => f.write_str("document is not well-formed"),
+            Error::Attr(ref err) => Display::fmt(err, f),
+            Error::MissingElement(name) => write!(f, "missing element `{}`", name),
+            Error::DuplicateElement(name) => write!(f, "duplicate element `{}`", name),
+            Error::UnexpectedElement(name) => write!(f, "unexpected element `{}`", name),
+            Error::InvalidText(name) => write!(f, "invalid text in element `{}`", name),
+        }
+    }
+}
+
+impl<'a> From<AttrError<'a>> for Error<'a> {
+    fn from(err: AttrError<'a>) -> Self {
+        Error::Attr(err)
+    }
+}
@@ -0,0 +1,495 @@
+//! CSS selectors over a [`Document`].
+//!
+//! This supports the parts of [Selectors Level 3](https://www.w3.org/TR/selectors-3/)
+//! that make sense for generic XML:
+//!
+//! - Type selectors and `*`
+//! - Attribute selectors: `[attr]`, `[attr=value]`, `[attr~=value]`,
+//!   `[attr|=value]`, `[attr^=value]`, `[attr$=value]` and `[attr*=value]`
+//! - `#value` and `.value` as shorthands for `[id=value]` and `[class~=value]`
+//! - The descendant, `>`, `+` and `~` combinators, and `,` for lists
+//! - The `:not()`, `:nth-child()`, `:nth-last-child()`, `:nth-of-type()`,
+//!   `:nth-last-of-type()`, `:first-child`, `:last-child`, `:only-child`,
+//!   `:first-of-type`, `:last-of-type`, `:only-of-type`, `:empty` and
+//!   `:root` pseudo-classes
+//!
+//! A colon in an element or attribute name has to be escaped, as in
+//! `xs\:element`.
+
+use crate::dom::{Document, Element, NodeKind};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// A syntax error in a selector.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Error {
+    offset: usize,
+}
+
+impl Error {
+    /// Returns the byte offset of the error in the selector.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid selector at offset {}", self.offset)
+    }
+}
+
+/// A compiled selector list.
+#[derive(Clone, Debug)]
+pub struct Selector {
+    list: Vec<Complex>,
+}
+
+impl Selector {
+    /// Compiles a selector list.
+    pub fn parse(selector: &str) -> Result<Selector, Error> {
+        let mut parser = SelectorParser {
+            text: selector,
+            pos: 0,
+        };
+        let list = parser.list()?;
+        if parser.pos != selector.len() {
+            return Err(parser.error());
+        }
+        Ok(Selector { list })
+    }
+
+    /// Returns whether the element matches the selector.
+    pub fn matches(&self, element: Element) -> bool {
+        self.list.iter().any(|complex| complex.matches(element))
+    }
+
+    /// Iterates over the matching elements of a document, in document order.
+    pub fn select<'s, 'd, 'a>(
+        &'s self,
+        doc: &'d Document<'a>,
+    ) -> impl Iterator<Item = Element<'d, 'a>> + 's
+    where
+        'd: 's,
+    {
+        let root = doc.root();
+        core::iter::once(root)
+            .chain(root.descendants())
+            .filter(move |&element| self.matches(element))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Combinator {
+    Descendant,
+    Child,
+    Adjacent,
+    Sibling,
+}
+
+/// Compound selectors joined by combinators. The combinator with each
+/// compound is the one to its left.
+#[derive(Clone, Debug)]
+struct Complex {
+    parts: Vec<(Combinator, Vec<Simple>)>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum AttrOp {
+    Exists,
+    Equals,
+    Includes,
+    DashMatch,
+    Prefix,
+    Suffix,
+    Substring,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Nth {
+    Child,
+    LastChild,
+    OfType,
+    LastOfType,
+}
+
+#[derive(Clone, Debug)]
+enum Simple {
+    Type(String),
+    Attr(String, AttrOp, String),
+    Not(Vec<Complex>),
+    /// Matches when the element's 1-based position is `a*n + b` for some
+    /// `n >= 0`.
+    Nth(Nth, i32, i32),
+    Empty,
+    Root,
+}
+
+impl Complex {
+    fn matches(&self, element: Element) -> bool {
+        self.matches_at(self.parts.len() - 1, element)
+    }
+
+    fn matches_at(&self, i: usize, element: Element) -> bool {
+        let (combinator, ref compound) = self.parts[i];
+        if !compound.iter().all(|simple| simple.matches(element)) {
+            return false;
+        }
+        if i == 0 {
+            return true;
+        }
+        match combinator {
+            Combinator::Child => element.parent().is_some_and(|p| self.matches_at(i - 1, p)),
+            Combinator::Descendant => {
+                let mut ancestor = element.parent();
+                while let Some(a) = ancestor {
+                    if self.matches_at(i - 1, a) {
+                        return true;
+                    }
+                    ancestor = a.parent();
+                }
+                false
+            }
+            Combinator::Adjacent => siblings(element)
+                .take_while(|s| s.id() != element.id())
+                .last()
+                .is_some_and(|s| self.matches_at(i - 1, s)),
+            Combinator::Sibling => siblings(element)
+                .take_while(|s| s.id() != element.id())
+                .any(|s| self.matches_at(i - 1, s)),
+        }
+    }
+}
+
+/// Iterates over the element and its sibling elements.
+fn siblings<'d, 'a>(element: Element<'d, 'a>) -> impl Iterator<Item = Element<'d, 'a>> {
+    let (parent, single) = match element.parent() {
+        Some(parent) => (Some(parent), None),
+        None => (None, Some(element)),
+    };
+    parent
+        .into_iter()
+        .flat_map(|p| p.child_elements())
+        .chain(single)
+}
+
+impl Simple {
+    fn matches(&self, element: Element) -> bool {
+        match *self {
+            Simple::Type(ref name) => name == "*" || element.name() == name,
+            Simple::Attr(ref name, op, ref expected) => {
+                let value: String = match element.attr(name) {
+                    Some(value) => value.collect(),
+                    None => return false,
+                };
+                match op {
+                    AttrOp::Exists => true,
+                    AttrOp::Equals => value == *expected,
+                    AttrOp::Includes => value.split_ascii_whitespace().any(|word| word == expected),
+                    AttrOp::DashMatch => {
+                        value == *expected
+                            || value.starts_with(&expected[..])
+                                && value[expected.len()..].starts_with('-')
+                    }
+                    AttrOp::Prefix => !expected.is_empty() && value.starts_with(&expected[..]),
+                    AttrOp::Suffix => !expected.is_empty() && value.ends_with(&expected[..]),
+                    AttrOp::Substring => !expected.is_empty() && value.contains(&expected[..]),
+                }
+            }
+            Simple::Not(ref list) => !list.iter().any(|complex| complex.matches(element)),
+            Simple::Nth(kind, a, b) => {
+                let of_type = kind == Nth::OfType || kind == Nth::LastOfType;
+                // Positions are counted in i64 so `position - b` can't
+                // overflow for any `a` and `b` that fit in an i32.
+                let mut before = 0i64;
+                let mut after = 0i64;
+                let mut seen = false;
+                for sibling in siblings(element) {
+                    if sibling.id() == element.id() {
+                        seen = true;
+                    } else if !of_type || sibling.name() == element.name() {
+                        if seen {
+                            after += 1;
+                        } else {
+                            before += 1;
+                        }
+                    }
+                }
+                let position = match kind {
+                    Nth::Child | Nth::OfType => before + 1,
+                    Nth::LastChild | Nth::LastOfType => after + 1,
+                };
+                let (a, b) = (i64::from(a), i64::from(b));
+                match a {
+                    0 => position == b,
+                    a => (position - b) % a == 0 && (position - b) / a >= 0,
+                }
+            }
+            Simple::Empty => element.children().all(|child| match child.kind() {
+                NodeKind::Element(..) => false,
+                NodeKind::Text(text) => text.clone().next().is_none(),
+                _ => true,
+            }),
+            Simple::Root => element.parent().is_none(),
+        }
+    }
+}
+
+struct SelectorParser<'s> {
+    text: &'s str,
+    pos: usize,
+}
+
+impl<'s> SelectorParser<'s> {
+    fn error(&self) -> Error {
+        Error { offset: self.pos }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Error> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+        self.pos != start
+    }
+
+    fn list(&mut self) -> Result<Vec<Complex>, Error> {
+        let mut list = Vec::new();
+        loop {
+            self.skip_whitespace();
+            list.push(self.complex()?);
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(list);
+            }
+        }
+    }
+
+    fn complex(&mut self) -> Result<Complex, Error> {
+        let mut parts = alloc::vec![(Combinator::Descendant, self.compound()?)];
+        loop {
+            let start = self.pos;
+            let whitespace = self.skip_whitespace();
+            let combinator = match self.peek() {
+                Some('>') => Combinator::Child,
+                Some('+') => Combinator::Adjacent,
+                Some('~') => Combinator::Sibling,
+                Some(',') | Some(')') | None => {
+                    self.pos = start;
+                    return Ok(Complex { parts });
+                }
+                _ if whitespace => Combinator::Descendant,
+                _ => return Err(self.error()),
+            };
+            if combinator != Combinator::Descendant {
+                self.pos += 1;
+                self.skip_whitespace();
+            }
+            parts.push((combinator, self.compound()?));
+        }
+    }
+
+    fn compound(&mut self) -> Result<Vec<Simple>, Error> {
+        let mut compound = Vec::new();
+        if self.eat('*') {
+            compound.push(Simple::Type("*".into()));
+        } else if self.peek().is_some_and(is_ident_char) {
+            compound.push(Simple::Type(self.ident()?));
+        }
+        loop {
+            let simple = match self.peek() {
+                Some('#') => {
+                    self.pos += 1;
+                    Simple::Attr("id".into(), AttrOp::Equals, self.ident()?)
+                }
+                Some('.') => {
+                    self.pos += 1;
+                    Simple::Attr("class".into(), AttrOp::Includes, self.ident()?)
+                }
+                Some('[') => {
+                    self.pos += 1;
+                    self.attr()?
+                }
+                Some(':') => {
+                    self.pos += 1;
+                    self.pseudo(&mut compound)?;
+                    continue;
+                }
+                _ if compound.is_empty() => return Err(self.error()),
+                _ => return Ok(compound),
+            };
+            compound.push(simple);
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, Error> {
+        let mut ident = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\\' {
+                self.pos += 1;
+                let escaped = self.peek().ok_or_else(|| self.error())?;
+                self.pos += escaped.len_utf8();
+                ident.push(escaped);
+            } else if is_ident_char(c) {
+                self.pos += c.len_utf8();
+                ident.push(c);
+            } else {
+                break;
+            }
+        }
+        if ident.is_empty() {
+            return Err(self.error());
+        }
+        Ok(ident)
+    }
+
+    fn attr(&mut self) -> Result<Simple, Error> {
+        self.skip_whitespace();
+        let name = self.ident()?;
+        self.skip_whitespace();
+        let op = match self.peek() {
+            Some(']') => {
+                self.pos += 1;
+                return Ok(Simple::Attr(name, AttrOp::Exists, String::new()));
+            }
+            Some('=') => AttrOp::Equals,
+            Some('~') => AttrOp::Includes,
+            Some('|') => AttrOp::DashMatch,
+            Some('^') => AttrOp::Prefix,
+            Some('$') => AttrOp::Suffix,
+            Some('*') => AttrOp::Substring,
+            _ => return Err(self.error()),
+        };
+        if op != AttrOp::Equals {
+            self.pos += 1;
+        }
+        self.expect('=')?;
+        self.skip_whitespace();
+        let value = match self.peek() {
+            Some(quote @ '"') | Some(quote @ '\'') => {
+                let start = self.pos + 1;
+                let len = self.text[start..].find(quote).ok_or_else(|| self.error())?;
+                self.pos = start + len + 1;
+                self.text[start..start + len].into()
+            }
+            _ => self.ident()?,
+        };
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(Simple::Attr(name, op, value))
+    }
+
+    fn pseudo(&mut self, compound: &mut Vec<Simple>) -> Result<(), Error> {
+        let start = self.pos;
+        let name = self.ident()?;
+        let simple = match &name[..] {
+            "first-child" => Simple::Nth(Nth::Child, 0, 1),
+            "last-child" => Simple::Nth(Nth::LastChild, 0, 1),
+            "first-of-type" => Simple::Nth(Nth::OfType, 0, 1),
+            "last-of-type" => Simple::Nth(Nth::LastOfType, 0, 1),
+            "only-child" => {
+                compound.push(Simple::Nth(Nth::Child, 0, 1));
+                Simple::Nth(Nth::LastChild, 0, 1)
+            }
+            "only-of-type" => {
+                compound.push(Simple::Nth(Nth::OfType, 0, 1));
+                Simple::Nth(Nth::LastOfType, 0, 1)
+            }
+            "empty" => Simple::Empty,
+            "root" => Simple::Root,
+            "not" => {
+                self.expect('(')?;
+                let list = self.list()?;
+                self.expect(')')?;
+                Simple::Not(list)
+            }
+            "nth-child" | "nth-last-child" | "nth-of-type" | "nth-last-of-type" => {
+                let kind = match &name[..] {
+                    "nth-child" => Nth::Child,
+                    "nth-last-child" => Nth::LastChild,
+                    "nth-of-type" => Nth::OfType,
+                    _ => Nth::LastOfType,
+                };
+                self.expect('(')?;
+                self.skip_whitespace();
+                let (a, b) = self.nth()?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                Simple::Nth(kind, a, b)
+            }
+            _ => {
+                self.pos = start;
+                return Err(self.error());
+            }
+        };
+        compound.push(simple);
+        Ok(())
+    }
+
+    /// Parses the `an+b` syntax.
+    fn nth(&mut self) -> Result<(i32, i32), Error> {
+        let start = self.pos;
+        let end = self.text[start..]
+            .find(')')
+            .map_or(self.text.len(), |i| start + i);
+        let arg: String = self.text[start..end]
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect();
+        let error = Error { offset: start };
+        let parse = |s: &str| -> Result<i32, Error> {
+            if s.starts_with('+') && s[1..].starts_with(['+', '-']) {
+                return Err(error);
+            }
+            s.parse().map_err(|_| error)
+        };
+        let (a, b) = match &arg.to_ascii_lowercase()[..] {
+            "odd" => (2, 1),
+            "even" => (2, 0),
+            arg => match arg.find('n') {
+                Some(n) => {
+                    let a = match &arg[..n] {
+                        "" | "+" => 1,
+                        "-" => -1,
+                        a => parse(a)?,
+                    };
+                    let b = match &arg[n + 1..] {
+                        "" => 0,
+                        b if b.starts_with(['+', '-']) => parse(b)?,
+                        _ => return Err(error),
+                    };
+                    (a, b)
+                }
+                None => (0, parse(arg)?),
+            },
+        };
+        self.pos = end;
+        Ok((a, b))
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_' || c == '\\' || !c.is_ascii()
+}
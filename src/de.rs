@@ -0,0 +1,701 @@
+//! Deserializing Rust types from XML with serde.
+//!
+//! An element is deserialized as a map, where attributes are keys starting
+//! with `@`, like `@name`, child elements are keyed by their name, and the
+//! character data is keyed as `$text`. Whitespace between elements is
+//! ignored.
+//!
+//! Repeated child elements make up one sequence, even when other elements
+//! come between them: when a sequence is read, the rest of the element is
+//! scanned for more elements with the same name, and the map skips them
+//! afterwards. Only a sequence collects them, though, so a field that isn't
+//! a sequence still gets a `duplicate field` error for a repeated element.
+//! Interleaved elements can also be read in order with a `$value` field,
+//! described below.
+//!
+//! For example, this document:
+//!
+//! ```xml
+//! <interface name="wl_surface" version="6">
+//!   <description summary="an onscreen surface">...</description>
+//!   <request name="destroy" type="destructor"/>
+//!   <request name="attach">...</request>
+//! </interface>
+//! ```
+//!
+//! can be deserialized into these types:
+//!
+//! ```ignore
+//! #[derive(Deserialize)]
+//! struct Interface<'a> {
+//!     #[serde(rename = "@name")]
+//!     name: &'a str,
+//!     #[serde(rename = "@version")]
+//!     version: u32,
+//!     description: Description,
+//!     #[serde(rename = "request")]
+//!     requests: Vec<Message>,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Description {
+//!     #[serde(rename = "@summary")]
+//!     summary: String,
+//!     #[serde(rename = "$text", default)]
+//!     body: String,
+//! }
+//! ```
+//!
+//! A sequence is read from all the child elements with the same name. Other
+//! values, like strings and numbers, are read from the character data of an
+//! element, or the value of an attribute.
+//!
+//! If a struct has a field named `$value`, child elements that don't match
+//! any other field are given that key instead of their name. An enum read
+//! from such an element, or from the root element, uses the element's name
+//! as the name of its variant, so a `$value` field holding a sequence of
+//! enums can read a mix of elements in order. Elsewhere, an enum is read
+//! from character data, like a string.
+//!
+//! Strings can be borrowed from the document, as long as they don't
+//! contain entity references or span multiple text nodes.
+
+use crate::{Attrs, Event, Parser, Text};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+use serde::de::value::{BorrowedStrDeserializer, CowStrDeserializer};
+use serde::de::{self, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// An error from deserializing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The document is not well-formed.
+    Malformed,
+    /// An element was found where only character data was expected.
+    UnexpectedElement(String),
+    /// Something other than whitespace, comments or processing instructions
+    /// was found after the root element.
+    TrailingContent,
+    /// Any other error, usually reported by the type being deserialized.
+    Custom(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Malformed => f.write_str("document is not well-formed"),
+            Error::UnexpectedElement(ref name) => write!(f, "unexpected element `{}`", name),
+            Error::TrailingContent => f.write_str("unexpected content after the root element"),
+            Error::Custom(ref msg) => f.write_str(msg),
+        }
+    }
+}
+
+// This is `core::error::Error` where serde can use it, on Rust 1.81 and
+// later, and a trait of serde's own on older compilers.
+impl de::StdError for Error {}
+
+impl de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+/// Deserializes a value from the root element of a document.
+pub fn from_str<'de, T: de::Deserialize<'de>>(doc: &'de str) -> Result<T, Error> {
+    let mut de = Deserializer::from_str(doc);
+    let value = T::deserialize(&mut de)?;
+    de.end()?;
+    Ok(value)
+}
+
+/// A deserializer that reads from an XML document.
+pub struct Deserializer<'de> {
+    parser: Parser<'de>,
+}
+
+impl<'de> Deserializer<'de> {
+    /// Creates a deserializer that reads from a document.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(doc: &'de str) -> Self {
+        Deserializer {
+            parser: Parser::new(doc),
+        }
+    }
+
+    /// Checks that nothing but whitespace, comments and processing
+    /// instructions is left after the root element.
+    pub fn end(&mut self) -> Result<(), Error> {
+        match self.next() {
+            Some(_) => Err(Error::TrailingContent),
+            None if self.parser.doc.is_empty() => Ok(()),
+            None => Err(Error::Malformed),
+        }
+    }
+
+    /// Returns the next event, skipping whitespace between elements,
+    /// comments, processing instructions and doctype declarations.
+    fn next(&mut self) -> Option<Event<'de>> {
+        loop {
+            match self.parser.next()? {
+                Event::Text(text) if text.clone().all(char::is_whitespace) => {}
+                Event::Comment(..) | Event::Pi(..) | Event::Doctype(..) => {}
+                event => return Some(event),
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<Event<'de>> {
+        Deserializer {
+            parser: self.parser.clone(),
+        }
+        .next()
+    }
+
+    /// Reads character data up to the end of the current element.
+    fn read_text(&mut self) -> Result<Cow<'de, str>, Error> {
+        let mut text = Cow::Borrowed("");
+        loop {
+            match self.parser.next().ok_or(Error::Malformed)? {
+                Event::Text(t) => append(&mut text, t),
+                Event::Close(..) => return Ok(text),
+                Event::Open(name, _) => return Err(Error::UnexpectedElement(name.into())),
+                Event::Comment(..) | Event::Pi(..) | Event::Doctype(..) => {}
+            }
+        }
+    }
+
+    /// Reads character data up to the next element.
+    fn read_texts(&mut self) -> Cow<'de, str> {
+        let mut text = Cow::Borrowed("");
+        loop {
+            match self.parser.clone().next() {
+                Some(Event::Text(t)) => append(&mut text, t),
+                Some(Event::Comment(..) | Event::Pi(..) | Event::Doctype(..)) => {}
+                _ => return text,
+            }
+            self.parser.next();
+        }
+    }
+
+    /// Reads the root element's [`Event::Open`].
+    fn root(&mut self) -> Result<Element<'_, 'de>, Error> {
+        match self.next().ok_or(Error::Malformed)? {
+            Event::Open(name, attrs) => Ok(Element {
+                de: self,
+                name,
+                attrs,
+                by_name: true,
+                fields: &[],
+                collected: None,
+            }),
+            _ => Err(Error::Malformed),
+        }
+    }
+
+    fn skip_element(&mut self) -> Result<(), Error> {
+        self.parser.skip_element().ok_or(Error::Malformed)
+    }
+}
+
+fn append<'de>(text: &mut Cow<'de, str>, t: Text<'de>) {
+    match t {
+        Text::Verbatim(s) if text.is_empty() => *text = Cow::Borrowed(s),
+        Text::Escaped(s) if text.is_empty() && !s.contains('&') => *text = Cow::Borrowed(s),
+        t => text.to_mut().extend(t),
+    }
+}
+
+macro_rules! deserialize_root {
+    ($($method:ident($($arg:ident: $ty:ty),*))*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Error> {
+                self.root()?.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    deserialize_root! {
+        deserialize_any() deserialize_bool() deserialize_i8() deserialize_i16()
+        deserialize_i32() deserialize_i64() deserialize_i128() deserialize_u8()
+        deserialize_u16() deserialize_u32() deserialize_u64() deserialize_u128()
+        deserialize_f32() deserialize_f64() deserialize_char() deserialize_str()
+        deserialize_string() deserialize_bytes() deserialize_byte_buf()
+        deserialize_option() deserialize_unit()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_seq() deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_struct(name: &'static str, fields: &'static [&'static str])
+        deserialize_enum(name: &'static str, variants: &'static [&'static str])
+        deserialize_identifier() deserialize_ignored_any()
+    }
+}
+
+/// An element whose [`Event::Open`] has just been read.
+struct Element<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    name: &'de str,
+    attrs: Attrs<'de>,
+    /// Whether this element was keyed as `$value`, or is the root element,
+    /// so enums use the element's name, and sequences include any element
+    /// not matching `fields`.
+    by_name: bool,
+    /// The fields of the struct containing this element.
+    fields: &'static [&'static str],
+    /// Set if this element is read as a sequence that collects all the
+    /// remaining elements with its key, so the map containing it can skip
+    /// them.
+    collected: Option<&'a mut bool>,
+}
+
+impl<'a, 'de> Element<'a, 'de> {
+    /// Returns whether a sibling element continues a sequence of this element.
+    fn continues(&self, name: &str) -> bool {
+        if self.by_name {
+            !self.fields.contains(&name)
+        } else {
+            name == self.name
+        }
+    }
+
+    fn has_children(&self) -> bool {
+        let mut parser = self.de.parser.clone();
+        let mut depth = 0usize;
+        loop {
+            match parser.next() {
+                Some(Event::Open(..)) => return true,
+                Some(Event::Close(..)) if depth == 0 => return false,
+                Some(Event::Close(..)) => depth -= 1,
+                Some(_) => {}
+                None => return false,
+            }
+        }
+    }
+}
+
+macro_rules! deserialize_text {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                TextDeserializer(self.de.read_text()?).$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'a, 'de> de::Deserializer<'de> for Element<'a, 'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.attrs.clone().next().is_some() || self.has_children() {
+            self.deserialize_map(visitor)
+        } else {
+            self.deserialize_str(visitor)
+        }
+    }
+
+    deserialize_text! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16
+        deserialize_u32 deserialize_u64 deserialize_u128 deserialize_f32
+        deserialize_f64 deserialize_char deserialize_str deserialize_string
+        deserialize_bytes deserialize_byte_buf deserialize_identifier
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.de.skip_element()?;
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(Seq {
+            first: Some(self),
+            resume: None,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_struct("", &[], visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_map(Map {
+            de: self.de,
+            attrs: self.attrs,
+            fields,
+            value: None,
+            collected: false,
+            done: Vec::new(),
+        })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        if self.by_name {
+            visitor.visit_enum(self)
+        } else {
+            TextDeserializer(self.de.read_text()?).deserialize_enum(name, variants, visitor)
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_unit(visitor)
+    }
+}
+
+impl<'a, 'de> de::EnumAccess<'de> for Element<'a, 'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<(T::Value, Self), Error> {
+        let variant = seed.deserialize(BorrowedStrDeserializer::new(self.name))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'a, 'de> de::VariantAccess<'de> for Element<'a, 'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        self.de.skip_element()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(Element {
+            by_name: false,
+            ..self
+        })
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(
+            Element {
+                by_name: false,
+                ..self
+            },
+            visitor,
+        )
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_struct(self, "", fields, visitor)
+    }
+}
+
+/// The content of an element, as a map.
+struct Map<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    attrs: Attrs<'de>,
+    fields: &'static [&'static str],
+    value: Option<Value<'de>>,
+    /// Set when the last value was a sequence that read every remaining
+    /// element with its key.
+    collected: bool,
+    /// The keys of those sequences, whose elements are skipped.
+    done: Vec<&'de str>,
+}
+
+/// The value for the last key returned by a [`Map`].
+enum Value<'de> {
+    Attr(Text<'de>),
+    Text(Cow<'de, str>),
+    Element(&'de str, &'de str, Attrs<'de>, bool),
+}
+
+impl<'a, 'de> de::MapAccess<'de> for Map<'a, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        if let Some((name, value)) = self.attrs.next() {
+            self.value = Some(Value::Attr(value));
+            let field = self
+                .fields
+                .iter()
+                .find(|field| field.strip_prefix('@') == Some(name));
+            return match field {
+                Some(field) => seed.deserialize(BorrowedStrDeserializer::new(field)),
+                None => seed.deserialize(CowStrDeserializer::new(Cow::Owned(alloc::format!(
+                    "@{}", name
+                )))),
+            }
+            .map(Some);
+        }
+        loop {
+            match self.de.peek().ok_or(Error::Malformed)? {
+                Event::Close(..) => {
+                    self.de.next();
+                    return Ok(None);
+                }
+                Event::Text(..) => {
+                    self.value = Some(Value::Text(self.de.read_texts()));
+                    return seed
+                        .deserialize(BorrowedStrDeserializer::new("$text"))
+                        .map(Some);
+                }
+                Event::Open(name, attrs) => {
+                    self.de.next();
+                    let by_name = self.fields.contains(&"$value") && !self.fields.contains(&name);
+                    let key = if by_name { "$value" } else { name };
+                    if self.done.contains(&key) {
+                        self.de.skip_element()?;
+                        continue;
+                    }
+                    self.value = Some(Value::Element(key, name, attrs, by_name));
+                    return seed
+                        .deserialize(BorrowedStrDeserializer::new(key))
+                        .map(Some);
+                }
+                _ => return Err(Error::Malformed),
+            }
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        match self.value.take().ok_or(Error::Malformed)? {
+            Value::Attr(text) => {
+                let mut value = Cow::Borrowed("");
+                append(&mut value, text);
+                seed.deserialize(TextDeserializer(value))
+            }
+            Value::Text(text) => seed.deserialize(TextDeserializer(text)),
+            Value::Element(key, name, attrs, by_name) => {
+                let value = seed.deserialize(Element {
+                    de: self.de,
+                    name,
+                    attrs,
+                    by_name,
+                    fields: self.fields,
+                    collected: Some(&mut self.collected),
+                })?;
+                if core::mem::take(&mut self.collected) {
+                    self.done.push(key);
+                }
+                Ok(value)
+            }
+        }
+    }
+}
+
+/// Elements with the same key, as a sequence.
+struct Seq<'a, 'de> {
+    first: Option<Element<'a, 'de>>,
+    /// Where to continue reading the parent element once the sequence ends,
+    /// if other content was skipped to find its elements.
+    resume: Option<Parser<'de>>,
+}
+
+impl<'a, 'de> de::SeqAccess<'de> for Seq<'a, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        let element = match self.first.take() {
+            Some(element) => element,
+            None => return Ok(None),
+        };
+        // Deserialize the element through a reborrow, so the next one can be
+        // found afterwards.
+        let value = seed.deserialize(Element {
+            de: &mut *element.de,
+            name: element.name,
+            attrs: element.attrs.clone(),
+            by_name: element.by_name,
+            fields: element.fields,
+            collected: None,
+        })?;
+        // Look for the next element in the sequence, skipping over other
+        // content and remembering where it started.
+        let mut scan = Deserializer {
+            parser: element.de.parser.clone(),
+        };
+        loop {
+            let before = scan.parser.clone();
+            match scan.next() {
+                Some(Event::Open(name, attrs)) if element.continues(name) => {
+                    element.de.parser = scan.parser;
+                    self.first = Some(Element {
+                        name,
+                        attrs,
+                        ..element
+                    });
+                    break;
+                }
+                Some(Event::Open(..)) => {
+                    self.resume.get_or_insert(before);
+                    scan.skip_element()?;
+                }
+                Some(Event::Text(..)) => {
+                    self.resume.get_or_insert(before);
+                }
+                _ => {
+                    if let Some(resume) = self.resume.take() {
+                        element.de.parser = resume;
+                    }
+                    if let Some(collected) = element.collected {
+                        *collected = true;
+                    }
+                    break;
+                }
+            }
+        }
+        Ok(Some(value))
+    }
+}
+
+/// Character data or an attribute value.
+struct TextDeserializer<'de>(Cow<'de, str>);
+
+impl<'de> TextDeserializer<'de> {
+    fn parse<T: core::str::FromStr>(&self) -> Result<T, Error>
+    where
+        T::Err: Display,
+    {
+        self.0.trim().parse().map_err(de::Error::custom)
+    }
+}
+
+macro_rules! deserialize_parse {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.$visit(self.parse()?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for TextDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Cow::Borrowed(s) => visitor.visit_borrowed_str(s),
+            Cow::Owned(s) => visitor.visit_string(s),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0.trim() {
+            "true" | "1" => visitor.visit_bool(true),
+            "false" | "0" => visitor.visit_bool(false),
+            s => Err(de::Error::invalid_value(de::Unexpected::Str(s), &visitor)),
+        }
+    }
+
+    deserialize_parse! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        char str string bytes byte_buf seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
@@ -0,0 +1,628 @@
+//! A lightweight document tree.
+//!
+//! The tree borrows names and text from the input, so building it only
+//! allocates for the node and attribute lists. Nodes can be edited through
+//! the [`Document`], which only allocates for the new strings, and the tree
+//! is written back out through its [`Display`] impl. The whitespace between
+//! top-level nodes isn't part of the tree, but the whitespace before each
+//! top-level node is kept and written back out with it.
+
+use crate::writer::Writer;
+use crate::{Event, Parser, Text, WHITESPACE};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+use core::slice;
+
+/// A node index into a [`Document`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub(crate) fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// The contents of a node.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum NodeKind<'d> {
+    /// An element, with its name.
+    Element(&'d str),
+    /// Character data.
+    Text(Text<'d>),
+    /// A comment.
+    Comment(&'d str),
+    /// A processing instruction.
+    Pi(&'d str),
+    /// A doctype declaration.
+    Doctype(&'d str, &'d str),
+}
+
+#[derive(Debug, Clone)]
+enum Value<'a> {
+    /// Text from the input, with entity references.
+    Escaped(&'a str),
+    /// Text set by the user, or from a CDATA section.
+    Verbatim(Cow<'a, str>),
+}
+
+impl<'a> Value<'a> {
+    fn as_text(&self) -> Text<'_> {
+        match *self {
+            Value::Escaped(s) => Text::Escaped(s),
+            Value::Verbatim(ref s) => Text::Verbatim(s),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Data<'a> {
+    Element(Cow<'a, str>, Vec<(Cow<'a, str>, Value<'a>)>),
+    Text(Value<'a>),
+    Cdata(&'a str),
+    Comment(Cow<'a, str>),
+    Pi(Cow<'a, str>),
+    Doctype(&'a str, &'a str),
+}
+
+#[derive(Debug, Clone)]
+struct NodeData<'a> {
+    data: Data<'a>,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+/// A parsed XML document.
+#[derive(Debug, Clone)]
+pub struct Document<'a> {
+    nodes: Vec<NodeData<'a>>,
+    top: Vec<NodeId>,
+    space: Vec<(NodeId, &'a str)>,
+    trailing: &'a str,
+    root: NodeId,
+}
+
+impl<'a> Document<'a> {
+    /// Parses a document, returning `None` if it isn't well-formed.
+    pub fn parse(text: &'a str) -> Option<Self> {
+        let mut doc = Document {
+            nodes: Vec::new(),
+            top: Vec::new(),
+            space: Vec::new(),
+            trailing: "",
+            root: NodeId(0),
+        };
+        let mut space = "";
+        let mut root = None;
+        let mut current: Option<NodeId> = None;
+        let mut parser = Parser::new(text);
+        for event in &mut parser {
+            let data = match event {
+                Event::Open(name, attrs) => Data::Element(
+                    name.into(),
+                    attrs
+                        .map(|(key, value)| match value {
+                            Text::Escaped(s) => (key.into(), Value::Escaped(s)),
+                            Text::Verbatim(s) => (key.into(), Value::Verbatim(s.into())),
+                        })
+                        .collect(),
+                ),
+                Event::Close(name) => {
+                    let id = current?;
+                    match doc.nodes[id.0].data {
+                        Data::Element(ref open, _) if open == name => {}
+                        _ => return None,
+                    }
+                    current = doc.nodes[id.0].parent;
+                    continue;
+                }
+                Event::Doctype(name, body) => Data::Doctype(name, body),
+                Event::Pi(pi) => Data::Pi(pi.into()),
+                Event::Comment(comment) => Data::Comment(comment.into()),
+                Event::Text(text) if current.is_none() => match text {
+                    Text::Escaped(s) if s.trim_matches(WHITESPACE).is_empty() => {
+                        space = s;
+                        continue;
+                    }
+                    _ => return None,
+                },
+                Event::Text(Text::Escaped(s)) => Data::Text(Value::Escaped(s)),
+                Event::Text(Text::Verbatim(s)) => Data::Cdata(s),
+            };
+            let is_element = matches!(data, Data::Element(..));
+            let id = doc.create(data);
+            doc.nodes[id.0].parent = current;
+            match current {
+                Some(parent) => doc.nodes[parent.0].children.push(id),
+                None => {
+                    if is_element && root.replace(id).is_some() {
+                        return None;
+                    }
+                    if !space.is_empty() {
+                        doc.space.push((id, space));
+                        space = "";
+                    }
+                    doc.top.push(id);
+                }
+            }
+            if is_element {
+                current = Some(id);
+            }
+        }
+        if !parser.doc.is_empty() || current.is_some() {
+            return None;
+        }
+        doc.root = root?;
+        doc.trailing = space;
+        Some(doc)
+    }
+
+    /// Returns the root element.
+    pub fn root(&self) -> Element<'_, 'a> {
+        Element {
+            doc: self,
+            id: self.root,
+        }
+    }
+
+    /// Returns the doctype declaration's name and body, if present.
+    pub fn doctype(&self) -> Option<(&str, &str)> {
+        self.children().find_map(|node| match node.kind() {
+            NodeKind::Doctype(name, body) => Some((name, body)),
+            _ => None,
+        })
+    }
+
+    /// Iterates over the top-level nodes, which are the root element and any
+    /// comments, processing instructions and doctype declarations around it.
+    pub fn children(&self) -> Children<'_, 'a> {
+        Children {
+            doc: self,
+            ids: self.top.iter(),
+        }
+    }
+
+    /// Returns the node with the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is from a different document.
+    pub fn get(&self, id: NodeId) -> Node<'_, 'a> {
+        assert!(id.0 < self.nodes.len(), "node index out of bounds");
+        Node { doc: self, id }
+    }
+
+    /// Returns the number of nodes in the document, including removed nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the document has no nodes.
+    /// This is never the case for a parsed document.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn create(&mut self, data: Data<'a>) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(NodeData {
+            data,
+            parent: None,
+            children: Vec::new(),
+        });
+        id
+    }
+
+    /// Creates a detached element with no attributes.
+    pub fn create_element(&mut self, name: impl Into<Cow<'a, str>>) -> NodeId {
+        self.create(Data::Element(name.into(), Vec::new()))
+    }
+
+    /// Creates a detached text node.
+    pub fn create_text(&mut self, text: impl Into<Cow<'a, str>>) -> NodeId {
+        self.create(Data::Text(Value::Verbatim(text.into())))
+    }
+
+    /// Creates a detached comment.
+    pub fn create_comment(&mut self, text: impl Into<Cow<'a, str>>) -> NodeId {
+        self.create(Data::Comment(text.into()))
+    }
+
+    fn attrs_mut(&mut self, id: NodeId) -> &mut Vec<(Cow<'a, str>, Value<'a>)> {
+        match self.nodes[id.0].data {
+            Data::Element(_, ref mut attrs) => attrs,
+            _ => panic!("node is not an element"),
+        }
+    }
+
+    /// Appends a node to an element's children, removing it from its
+    /// previous position first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` isn't an element, or if `child` is the root element
+    /// or an ancestor of `parent`.
+    pub fn append_child(&mut self, parent: NodeId, child: NodeId) {
+        self.take_for_insert(parent, child);
+        self.nodes[parent.0].children.push(child);
+    }
+
+    /// Inserts a node into an element's children at the given position,
+    /// removing it from its previous position first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` isn't an element, if `child` is the root element or
+    /// an ancestor of `parent`, or if `index` is greater than the number of
+    /// children after removing `child`.
+    pub fn insert_child(&mut self, parent: NodeId, index: usize, child: NodeId) {
+        self.take_for_insert(parent, child);
+        self.nodes[parent.0].children.insert(index, child);
+    }
+
+    fn take_for_insert(&mut self, parent: NodeId, child: NodeId) {
+        assert!(
+            matches!(self.nodes[parent.0].data, Data::Element(..)),
+            "node is not an element"
+        );
+        let mut ancestor = Some(parent);
+        while let Some(id) = ancestor {
+            assert!(id != child, "cannot insert a node into itself");
+            ancestor = self.nodes[id.0].parent;
+        }
+        self.remove(child);
+        self.nodes[child.0].parent = Some(parent);
+    }
+
+    /// Detaches a node from its parent. The node can be inserted again later.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is the root element.
+    pub fn remove(&mut self, id: NodeId) {
+        assert!(id != self.root, "cannot remove the root element");
+        let siblings = match self.nodes[id.0].parent.take() {
+            Some(parent) => &mut self.nodes[parent.0].children,
+            None => &mut self.top,
+        };
+        siblings.retain(|&sibling| sibling != id);
+    }
+
+    /// Renames an element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node isn't an element.
+    pub fn set_name(&mut self, id: NodeId, name: impl Into<Cow<'a, str>>) {
+        match self.nodes[id.0].data {
+            Data::Element(ref mut old, _) => *old = name.into(),
+            _ => panic!("node is not an element"),
+        }
+    }
+
+    /// Sets the value of an attribute, adding it after the existing
+    /// attributes if it isn't present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node isn't an element.
+    pub fn set_attr(
+        &mut self,
+        id: NodeId,
+        name: impl Into<Cow<'a, str>>,
+        value: impl Into<Cow<'a, str>>,
+    ) {
+        let name = name.into();
+        let value = Value::Verbatim(value.into());
+        let attrs = self.attrs_mut(id);
+        match attrs.iter_mut().find(|(key, _)| *key == name) {
+            Some(attr) => attr.1 = value,
+            None => attrs.push((name, value)),
+        }
+    }
+
+    /// Sets the value of an attribute and moves it to the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node isn't an element, or if `index` is greater than
+    /// the number of other attributes.
+    pub fn insert_attr(
+        &mut self,
+        id: NodeId,
+        index: usize,
+        name: impl Into<Cow<'a, str>>,
+        value: impl Into<Cow<'a, str>>,
+    ) {
+        let name = name.into();
+        let attrs = self.attrs_mut(id);
+        attrs.retain(|(key, _)| *key != name);
+        attrs.insert(index, (name, Value::Verbatim(value.into())));
+    }
+
+    /// Removes an attribute, returning whether it was present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node isn't an element.
+    pub fn remove_attr(&mut self, id: NodeId, name: &str) -> bool {
+        let attrs = self.attrs_mut(id);
+        let len = attrs.len();
+        attrs.retain(|(key, _)| key != name);
+        attrs.len() != len
+    }
+
+    /// Sets the character data of a text node, or replaces the children of
+    /// an element with a single text node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is neither a text node nor an element.
+    pub fn set_text(&mut self, id: NodeId, text: impl Into<Cow<'a, str>>) {
+        let value = Value::Verbatim(text.into());
+        match self.nodes[id.0].data {
+            Data::Text(ref mut old) => *old = value,
+            Data::Cdata(..) => self.nodes[id.0].data = Data::Text(value),
+            Data::Element(..) => {
+                for child in core::mem::take(&mut self.nodes[id.0].children) {
+                    self.nodes[child.0].parent = None;
+                }
+                let text = self.create(Data::Text(value));
+                self.append_child(id, text);
+            }
+            _ => panic!("node is not a text node or element"),
+        }
+    }
+
+    fn write<W: Write>(&self, id: NodeId, out: &mut Writer<W>) -> fmt::Result {
+        let node = &self.nodes[id.0];
+        match node.data {
+            Data::Element(ref name, ref attrs) => {
+                out.open(name)?;
+                for (key, value) in attrs {
+                    out.attr(key, value.as_text())?;
+                }
+                for &child in &node.children {
+                    self.write(child, out)?;
+                }
+                out.close(name)
+            }
+            Data::Text(ref value) => out.text(value.as_text()),
+            Data::Cdata(s) => out.cdata(s),
+            Data::Comment(ref s) => out.comment(s),
+            Data::Pi(ref s) => out.pi(s),
+            Data::Doctype(name, body) => out.doctype(name, body),
+        }
+    }
+}
+
+impl<'a> Display for Document<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = Writer::new(f);
+        for &id in &self.top {
+            if let Some(&(_, space)) = self.space.iter().find(|&&(node, _)| node == id) {
+                out.text(Text::Escaped(space))?;
+            }
+            self.write(id, &mut out)?;
+        }
+        out.text(Text::Escaped(self.trailing))
+    }
+}
+
+/// A reference to a node in a [`Document`].
+#[derive(Debug, Clone, Copy)]
+pub struct Node<'d, 'a> {
+    doc: &'d Document<'a>,
+    id: NodeId,
+}
+
+impl<'d, 'a> Node<'d, 'a> {
+    /// Returns the node's index.
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Returns the node's contents.
+    pub fn kind(&self) -> NodeKind<'d> {
+        match self.doc.nodes[self.id.0].data {
+            Data::Element(ref name, _) => NodeKind::Element(name),
+            Data::Text(ref value) => NodeKind::Text(value.as_text()),
+            Data::Cdata(s) => NodeKind::Text(Text::Verbatim(s)),
+            Data::Comment(ref s) => NodeKind::Comment(s),
+            Data::Pi(ref s) => NodeKind::Pi(s),
+            Data::Doctype(name, body) => NodeKind::Doctype(name, body),
+        }
+    }
+
+    /// Returns the parent element, or `None` for top-level and removed nodes.
+    pub fn parent(&self) -> Option<Element<'d, 'a>> {
+        let id = self.doc.nodes[self.id.0].parent?;
+        Some(Element { doc: self.doc, id })
+    }
+
+    /// Returns the node as an element, if it is one.
+    pub fn as_element(&self) -> Option<Element<'d, 'a>> {
+        match self.doc.nodes[self.id.0].data {
+            Data::Element(..) => Some(Element {
+                doc: self.doc,
+                id: self.id,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns the node's character data, if it is a text node.
+    pub fn as_text(&self) -> Option<Text<'d>> {
+        match self.kind() {
+            NodeKind::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+impl<'d, 'a> Display for Node<'d, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.doc.write(self.id, &mut Writer::new(f))
+    }
+}
+
+/// A reference to an element in a [`Document`].
+#[derive(Debug, Clone, Copy)]
+pub struct Element<'d, 'a> {
+    doc: &'d Document<'a>,
+    id: NodeId,
+}
+
+impl<'d, 'a> Element<'d, 'a> {
+    fn data(&self) -> (&'d str, &'d [(Cow<'a, str>, Value<'a>)]) {
+        match self.doc.nodes[self.id.0].data {
+            Data::Element(ref name, ref attrs) => (name, attrs),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the element's node index.
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Returns the element as a node.
+    pub fn node(&self) -> Node<'d, 'a> {
+        Node {
+            doc: self.doc,
+            id: self.id,
+        }
+    }
+
+    /// Returns the element's name.
+    pub fn name(&self) -> &'d str {
+        self.data().0
+    }
+
+    /// Iterates over the element's attributes.
+    pub fn attrs(&self) -> impl Iterator<Item = (&'d str, Text<'d>)> {
+        self.data()
+            .1
+            .iter()
+            .map(|(key, value)| (&key[..], value.as_text()))
+    }
+
+    /// Returns the value of the given attribute, if present.
+    pub fn attr(&self, name: &str) -> Option<Text<'d>> {
+        self.attrs().find(|&(key, _)| key == name).map(|(_, v)| v)
+    }
+
+    /// Returns the parent element, or `None` for the root element and
+    /// removed elements.
+    pub fn parent(&self) -> Option<Element<'d, 'a>> {
+        self.node().parent()
+    }
+
+    /// Iterates over the child nodes.
+    pub fn children(&self) -> Children<'d, 'a> {
+        Children {
+            doc: self.doc,
+            ids: self.doc.nodes[self.id.0].children.iter(),
+        }
+    }
+
+    /// Iterates over the child elements.
+    pub fn child_elements(&self) -> impl Iterator<Item = Element<'d, 'a>> {
+        self.children().filter_map(|node| node.as_element())
+    }
+
+    /// Iterates over the child elements with the given name.
+    pub fn children_named<'n>(&self, name: &'n str) -> impl Iterator<Item = Element<'d, 'a>> + 'n
+    where
+        'd: 'n,
+    {
+        self.child_elements().filter(move |e| e.name() == name)
+    }
+
+    /// Returns the first child element with the given name.
+    pub fn child(&self, name: &str) -> Option<Element<'d, 'a>> {
+        self.child_elements().find(|e| e.name() == name)
+    }
+
+    /// Iterates over the descendant elements in document order, not
+    /// including this element.
+    pub fn descendants(&self) -> Descendants<'d, 'a> {
+        Descendants {
+            doc: self.doc,
+            stack: alloc::vec![self.doc.nodes[self.id.0].children.iter()],
+        }
+    }
+
+    /// Returns the concatenated character data of all descendant text nodes.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        self.collect_text(&mut text);
+        text
+    }
+
+    fn collect_text(&self, out: &mut String) {
+        for child in self.children() {
+            match child.kind() {
+                NodeKind::Text(t) => out.extend(t),
+                NodeKind::Element(..) => Element {
+                    doc: self.doc,
+                    id: child.id,
+                }
+                .collect_text(out),
+                NodeKind::Comment(..) | NodeKind::Pi(..) | NodeKind::Doctype(..) => {}
+            }
+        }
+    }
+}
+
+impl<'d, 'a> Display for Element<'d, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.node(), f)
+    }
+}
+
+/// An iterator over child nodes.
+#[derive(Debug, Clone)]
+pub struct Children<'d, 'a> {
+    doc: &'d Document<'a>,
+    ids: slice::Iter<'d, NodeId>,
+}
+
+impl<'d, 'a> Iterator for Children<'d, 'a> {
+    type Item = Node<'d, 'a>;
+
+    fn next(&mut self) -> Option<Node<'d, 'a>> {
+        let id = *self.ids.next()?;
+        Some(Node { doc: self.doc, id })
+    }
+}
+
+/// An iterator over descendant elements in document order.
+#[derive(Debug, Clone)]
+pub struct Descendants<'d, 'a> {
+    doc: &'d Document<'a>,
+    stack: Vec<slice::Iter<'d, NodeId>>,
+}
+
+impl<'d, 'a> Iterator for Descendants<'d, 'a> {
+    type Item = Element<'d, 'a>;
+
+    fn next(&mut self) -> Option<Element<'d, 'a>> {
+        loop {
+            let id = match self.stack.last_mut()?.next() {
+                Some(&id) => id,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            let node = &self.doc.nodes[id.0];
+            if let Data::Element(..) = node.data {
+                self.stack.push(node.children.iter());
+                return Some(Element { doc: self.doc, id });
+            }
+        }
+    }
+}
@@ -0,0 +1,625 @@
+//! Conversion between XML and JSON.
+//!
+//! Documents are converted with the [BadgerFish] convention:
+//!
+//! - An element becomes a property of its parent object, named after the
+//!   element, whose value is an object.
+//! - Character data goes in the `$` property of the element's object.
+//!   Whitespace between elements is ignored.
+//! - Attributes go in properties named after the attribute, starting
+//!   with `@`.
+//! - Child elements with the same name become an array.
+//! - The namespaces in scope for an element go in its `@xmlns` property,
+//!   an object with the default namespace in `$` and others under their
+//!   prefix.
+//!
+//! For example, this document:
+//!
+//! ```xml
+//! <interface name="wl_callback" version="1">
+//!   <event name="done" type="destructor">
+//!     <description summary="done event">Notify the client.</description>
+//!     <arg name="callback_data" type="uint"/>
+//!   </event>
+//! </interface>
+//! ```
+//!
+//! becomes this JSON:
+//!
+//! ```json
+//! {
+//!   "interface": {
+//!     "@name": "wl_callback",
+//!     "@version": "1",
+//!     "event": {
+//!       "@name": "done",
+//!       "@type": "destructor",
+//!       "description": {
+//!         "@summary": "done event",
+//!         "$": "Notify the client."
+//!       },
+//!       "arg": {
+//!         "@name": "callback_data",
+//!         "@type": "uint"
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! The order of elements with different names, and of text between
+//! elements, isn't kept. When converting JSON to XML, strings, numbers,
+//! booleans and `null` are also accepted in place of an element's object,
+//! as the element's character data.
+//!
+//! [BadgerFish]: http://www.sklar.com/badgerfish/
+
+use crate::writer::Writer;
+use crate::{is_name, Event, Parser};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+
+/// An error from converting between XML and JSON.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The XML document is not well-formed.
+    Malformed,
+    /// The JSON text has a syntax error at the given byte offset.
+    Syntax(usize),
+    /// The JSON text nests arrays and objects more deeply than
+    /// [`Value::MAX_DEPTH`], at the given byte offset.
+    TooDeep(usize),
+    /// The JSON value doesn't follow the convention, for the given reason.
+    Invalid(&'static str),
+    /// The output returned an error.
+    Write,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Malformed => f.write_str("document is not well-formed"),
+            Error::Syntax(offset) => write!(f, "invalid JSON at offset {}", offset),
+            Error::TooDeep(offset) => write!(f, "JSON nested too deeply at offset {}", offset),
+            Error::Invalid(reason) => write!(f, "invalid value: {}", reason),
+            Error::Write => f.write_str("failed to write output"),
+        }
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Self {
+        Error::Write
+    }
+}
+
+/// A JSON value.
+///
+/// The [`Display`] impl writes JSON text, which is indented with the
+/// alternate flag (`{:#}`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// `null`.
+    Null,
+    /// `true` or `false`.
+    Bool(bool),
+    /// A number.
+    Number(f64),
+    /// A string.
+    String(String),
+    /// An array.
+    Array(Vec<Value>),
+    /// An object, with its properties in order.
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// The maximum nesting depth of arrays and objects in parsed JSON text.
+    pub const MAX_DEPTH: usize = 128;
+
+    /// Parses JSON text, with arrays and objects nested at most
+    /// [`MAX_DEPTH`](Value::MAX_DEPTH) deep.
+    pub fn parse(text: &str) -> Result<Value, Error> {
+        let mut parser = JsonParser {
+            text,
+            pos: 0,
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos != text.len() {
+            return Err(Error::Syntax(parser.pos));
+        }
+        Ok(value)
+    }
+
+    /// Returns the value of the given property, if this is an object that
+    /// has it.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match *self {
+            Value::Object(ref members) => members.iter().find(|m| m.0 == key).map(|m| &m.1),
+            _ => None,
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter, indent: usize) -> fmt::Result {
+        let pretty = f.alternate();
+        let newline = |f: &mut fmt::Formatter, indent: usize| {
+            if pretty {
+                f.write_char('\n')?;
+                for _ in 0..indent {
+                    f.write_str("  ")?;
+                }
+            }
+            Ok(())
+        };
+        match *self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Number(n) if n.is_finite() => write!(f, "{}", n),
+            Value::Number(_) => f.write_str("null"),
+            Value::String(ref s) => write_string(f, s),
+            Value::Array(ref items) if items.is_empty() => f.write_str("[]"),
+            Value::Array(ref items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i != 0 {
+                        f.write_char(',')?;
+                    }
+                    newline(f, indent + 1)?;
+                    item.write(f, indent + 1)?;
+                }
+                newline(f, indent)?;
+                f.write_char(']')
+            }
+            Value::Object(ref members) if members.is_empty() => f.write_str("{}"),
+            Value::Object(ref members) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i != 0 {
+                        f.write_char(',')?;
+                    }
+                    newline(f, indent + 1)?;
+                    write_string(f, key)?;
+                    f.write_str(if pretty { ": " } else { ":" })?;
+                    value.write(f, indent + 1)?;
+                }
+                newline(f, indent)?;
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+struct JsonParser<'a> {
+    text: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn error(&self) -> Error {
+        Error::Syntax(self.pos)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\r', '\n']).len();
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        self.skip_whitespace();
+        if self.rest().starts_with(['[', '{']) {
+            if self.depth == Value::MAX_DEPTH {
+                return Err(Error::TooDeep(self.pos));
+            }
+            self.depth += 1;
+            let value = self.container();
+            self.depth -= 1;
+            value
+        } else if self.eat("null") {
+            Ok(Value::Null)
+        } else if self.eat("true") {
+            Ok(Value::Bool(true))
+        } else if self.eat("false") {
+            Ok(Value::Bool(false))
+        } else if self.rest().starts_with('"') {
+            self.string().map(Value::String)
+        } else {
+            self.number()
+        }
+    }
+
+    fn container(&mut self) -> Result<Value, Error> {
+        if self.eat("[") {
+            let mut items = Vec::new();
+            if !self.eat("]") {
+                loop {
+                    items.push(self.value()?);
+                    if self.eat("]") {
+                        break;
+                    }
+                    if !self.eat(",") {
+                        return Err(self.error());
+                    }
+                }
+            }
+            Ok(Value::Array(items))
+        } else if self.eat("{") {
+            let mut members = Vec::new();
+            if !self.eat("}") {
+                loop {
+                    self.skip_whitespace();
+                    let key = self.string()?;
+                    if !self.eat(":") {
+                        return Err(self.error());
+                    }
+                    members.push((key, self.value()?));
+                    if self.eat("}") {
+                        break;
+                    }
+                    if !self.eat(",") {
+                        return Err(self.error());
+                    }
+                }
+            }
+            Ok(Value::Object(members))
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Parses a number, following the grammar in RFC 8259:
+    /// `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?`.
+    fn number(&mut self) -> Result<Value, Error> {
+        let bytes = self.rest().as_bytes();
+        let digits = |i: usize| bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let mut len = usize::from(bytes.first() == Some(&b'-'));
+        match bytes.get(len) {
+            Some(b'0') => len += 1,
+            Some(b'1'..=b'9') => len += digits(len),
+            _ => return Err(Error::Syntax(self.pos + len)),
+        }
+        if bytes.get(len) == Some(&b'.') {
+            len += 1;
+            match digits(len) {
+                0 => return Err(Error::Syntax(self.pos + len)),
+                n => len += n,
+            }
+        }
+        if let Some(b'e' | b'E') = bytes.get(len) {
+            len += 1;
+            if let Some(b'+' | b'-') = bytes.get(len) {
+                len += 1;
+            }
+            match digits(len) {
+                0 => return Err(Error::Syntax(self.pos + len)),
+                n => len += n,
+            }
+        }
+        let n = self.rest()[..len].parse().map_err(|_| self.error())?;
+        self.pos += len;
+        Ok(Value::Number(n))
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        if !self.rest().starts_with('"') {
+            return Err(self.error());
+        }
+        self.pos += 1;
+        let mut s = String::new();
+        loop {
+            let c = self.rest().chars().next().ok_or_else(|| self.error())?;
+            match c {
+                '"' => {
+                    self.pos += 1;
+                    return Ok(s);
+                }
+                '\\' => {
+                    self.pos += 1;
+                    let escape = self.rest().chars().next().ok_or_else(|| self.error())?;
+                    self.pos += 1;
+                    s.push(match escape {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => self.unicode_escape()?,
+                        _ => return Err(self.error()),
+                    });
+                }
+                c if (c as u32) < 0x20 => return Err(self.error()),
+                c => {
+                    self.pos += c.len_utf8();
+                    s.push(c);
+                }
+            }
+        }
+    }
+
+    /// Parses the rest of a `\u` escape, including a second escape for a
+    /// surrogate pair.
+    fn unicode_escape(&mut self) -> Result<char, Error> {
+        let high = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if !self.rest().starts_with("\\u") {
+                return Err(self.error());
+            }
+            self.pos += 2;
+            let low = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error());
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.error())
+    }
+
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let digits = self.rest().get(..4).ok_or_else(|| self.error())?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(self.error());
+        }
+        self.pos += 4;
+        Ok(u32::from_str_radix(digits, 16).unwrap())
+    }
+}
+
+/// The namespace declarations in scope, as pairs of a prefix, or `$` for
+/// the default namespace, and a URI.
+type Scope = Vec<(String, String)>;
+
+/// An element whose content is being converted.
+struct Frame {
+    name: String,
+    scope: Scope,
+    attrs: Vec<(String, Value)>,
+    text: String,
+    children: Vec<(String, Value)>,
+}
+
+impl Frame {
+    fn into_value(self) -> Value {
+        let mut members = Vec::new();
+        if !self.scope.is_empty() {
+            let namespaces = self
+                .scope
+                .into_iter()
+                .map(|(prefix, uri)| (prefix, Value::String(uri)))
+                .collect();
+            members.push(("@xmlns".into(), Value::Object(namespaces)));
+        }
+        members.extend(self.attrs);
+        if !self.text.is_empty() {
+            members.push(("$".into(), Value::String(self.text)));
+        }
+        members.extend(self.children);
+        Value::Object(members)
+    }
+}
+
+fn add_child(children: &mut Vec<(String, Value)>, name: String, value: Value) {
+    match children.iter_mut().find(|(key, _)| *key == name) {
+        Some((_, Value::Array(items))) => items.push(value),
+        Some((_, existing)) => {
+            let first = core::mem::replace(existing, Value::Null);
+            *existing = Value::Array(alloc::vec![first, value]);
+        }
+        None => children.push((name, value)),
+    }
+}
+
+/// Converts an XML document to JSON.
+pub fn from_xml(doc: &str) -> Result<Value, Error> {
+    let mut parser = Parser::new(doc);
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut root = Vec::new();
+    for event in &mut parser {
+        match event {
+            Event::Open(name, attrs) => {
+                let mut scope = stack.last().map(|f| f.scope.clone()).unwrap_or_default();
+                let mut attr_values = Vec::new();
+                for (key, value) in attrs {
+                    let value: String = value.collect();
+                    let prefix = match key.strip_prefix("xmlns") {
+                        Some("") => "$",
+                        Some(rest) if rest.starts_with(':') => &rest[1..],
+                        _ => {
+                            attr_values.push((alloc::format!("@{}", key), Value::String(value)));
+                            continue;
+                        }
+                    };
+                    match scope.iter_mut().find(|(p, _)| p == prefix) {
+                        Some(entry) => entry.1 = value,
+                        None => scope.push((prefix.into(), value)),
+                    }
+                }
+                stack.push(Frame {
+                    name: name.into(),
+                    scope,
+                    attrs: attr_values,
+                    text: String::new(),
+                    children: Vec::new(),
+                });
+            }
+            Event::Close(name) => {
+                let mut frame = match stack.pop() {
+                    Some(frame) if frame.name == name => frame,
+                    _ => return Err(Error::Malformed),
+                };
+                let name = core::mem::take(&mut frame.name);
+                let value = frame.into_value();
+                match stack.last_mut() {
+                    Some(parent) => add_child(&mut parent.children, name, value),
+                    None => root.push((name, value)),
+                }
+            }
+            Event::Text(text) => {
+                if let Some(frame) = stack.last_mut() {
+                    if !text.clone().all(char::is_whitespace) {
+                        frame.text.extend(text);
+                    }
+                }
+            }
+            Event::Doctype(..) | Event::Pi(..) | Event::Comment(..) => {}
+        }
+    }
+    if !stack.is_empty() || !parser.doc.is_empty() || root.len() != 1 {
+        return Err(Error::Malformed);
+    }
+    Ok(Value::Object(root))
+}
+
+/// Converts JSON to an XML document, writing it to `writer`.
+///
+/// The value must be an object with a single property, for the root element.
+/// Element names, attribute names and namespace prefixes must be valid XML
+/// names, or [`Error::Invalid`] is returned.
+pub fn to_xml<W: Write>(value: &Value, writer: &mut Writer<W>) -> Result<(), Error> {
+    match *value {
+        Value::Object(ref members) if members.len() == 1 => {
+            let (name, value) = &members[0];
+            write_element(writer, name, value, &Vec::new())
+        }
+        _ => Err(Error::Invalid(
+            "the root value must be an object with one property",
+        )),
+    }
+}
+
+fn scalar(value: &Value) -> Option<Scalar<'_>> {
+    match *value {
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => Some(Scalar(value)),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+/// A string, number, boolean or `null`, displayed as character data.
+struct Scalar<'v>(&'v Value);
+
+impl<'v> Display for Scalar<'v> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self.0 {
+            Value::String(ref s) => f.write_str(s),
+            Value::Null => Ok(()),
+            ref value => Display::fmt(value, f),
+        }
+    }
+}
+
+fn write_element<W: Write>(
+    writer: &mut Writer<W>,
+    name: &str,
+    value: &Value,
+    parent_scope: &Scope,
+) -> Result<(), Error> {
+    if !is_name(name) {
+        return Err(Error::Invalid("an element name isn't a valid XML name"));
+    }
+    writer.open(name)?;
+    let members = match *value {
+        Value::Object(ref members) => members,
+        ref value => {
+            let text = scalar(value).ok_or(Error::Invalid("an element can't be an array"))?;
+            writer.text_display(text)?;
+            writer.close(name)?;
+            return Ok(());
+        }
+    };
+    let mut scope = parent_scope.clone();
+    if let Some(Value::Object(ref namespaces)) = value.get("@xmlns") {
+        for (prefix, uri) in namespaces {
+            let uri = match *uri {
+                Value::String(ref uri) => uri,
+                _ => return Err(Error::Invalid("a namespace must be a string")),
+            };
+            if prefix != "$" && (!is_name(prefix) || prefix.contains(':')) {
+                return Err(Error::Invalid("a namespace prefix isn't a valid XML name"));
+            }
+            if parent_scope.iter().any(|(p, u)| p == prefix && u == uri) {
+                continue;
+            }
+            if prefix == "$" {
+                writer.attr("xmlns", uri.as_str())?;
+            } else {
+                writer.attr(&alloc::format!("xmlns:{}", prefix), uri.as_str())?;
+            }
+            match scope.iter_mut().find(|(p, _)| p == prefix) {
+                Some(entry) => entry.1 = uri.clone(),
+                None => scope.push((prefix.clone(), uri.clone())),
+            }
+        }
+    }
+    for (key, value) in members {
+        if key == "@xmlns" {
+            continue;
+        }
+        if let Some(attr) = key.strip_prefix('@') {
+            if !is_name(attr) {
+                return Err(Error::Invalid("an attribute name isn't a valid XML name"));
+            }
+            let value = scalar(value).ok_or(Error::Invalid("an attribute must be a scalar"))?;
+            writer.attr_display(attr, value)?;
+        }
+    }
+    for (key, value) in members {
+        if key.starts_with('@') {
+            continue;
+        }
+        if key == "$" {
+            let text = scalar(value).ok_or(Error::Invalid("text must be a scalar"))?;
+            writer.text_display(text)?;
+        } else if let Value::Array(ref items) = *value {
+            for item in items {
+                write_element(writer, key, item, &scope)?;
+            }
+        } else {
+            write_element(writer, key, value, &scope)?;
+        }
+    }
+    writer.close(name)?;
+    Ok(())
+}
@@ -15,18 +15,97 @@
 //!
 //! This parser may be useful for parsing machine-readable specifications that
 //! use XML such as Wayland and Vulkan.
+//!
+//! ## Features
+//!
+//! - `alloc`: enables the [`c14n`], [`check`], [`css`], [`dom`], [`json`] and
+//!   [`query`] modules, owned versions of the event types, such as
+//!   [`OwnedEvent`], and [`Parser::read_text`], which need to allocate.
+//! - `cli`: builds the `txml` command-line tool. This implies `alloc` and
+//!   `wayland`.
+//! - `derive`: enables `#[derive(FromXml, ToXml)]`, described in the
+//!   [`convert`] module. This implies `alloc`.
+//! - `serde`: enables the [`de`] and [`ser`] modules, for deserializing and
+//!   serializing types that implement serde's `Deserialize` and `Serialize`.
+//!   This implies `alloc`.
+//! - `vulkan`: enables the [`vulkan`] module, for the Vulkan API registry.
+//!   This implies `alloc`.
+//! - `wayland`: enables the [`wayland`] module, for Wayland protocol
+//!   descriptions. This implies `alloc`.
 
 #![no_std]
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
 #![doc(html_root_url = "https://docs.rs/txml/0.2.0")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod arena;
+#[cfg(feature = "alloc")]
+pub mod c14n;
+#[cfg(feature = "alloc")]
+pub mod check;
+pub mod convert;
+#[cfg(feature = "alloc")]
+pub mod css;
+#[cfg(feature = "serde")]
+pub mod de;
+#[cfg(feature = "alloc")]
+pub mod dom;
+#[cfg(feature = "alloc")]
+pub mod json;
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "alloc")]
+pub mod query;
+#[cfg(feature = "serde")]
+pub mod ser;
+pub mod stream;
+#[cfg(feature = "vulkan")]
+pub mod vulkan;
+#[cfg(feature = "wayland")]
+pub mod wayland;
+pub mod writer;
+
+pub use convert::{FromXml, ToXml};
+#[cfg(feature = "alloc")]
+pub use owned::{OwnedAttrs, OwnedEvent, OwnedText};
+#[cfg(feature = "derive")]
+pub use txml_derive::{FromXml, ToXml};
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::String;
+}
+
 use core::convert::TryInto;
 use core::fmt::{self, Debug, Display, Write};
+use core::str::FromStr;
 
 const WHITESPACE: &[char] = &[' ', '\t', '\r', '\n'];
 const WHITESPACE_AND_RANGLE_AND_SLASH: &[char] = &[' ', '\t', '\r', '\n', '>', '/'];
 
+/// Checks a string against the `Name` production of the XML spec.
+pub(crate) fn is_name(name: &str) -> bool {
+    fn is_start(c: char) -> bool {
+        matches!(c,
+            ':' | 'A'..='Z' | '_' | 'a'..='z' | '\u{C0}'..='\u{D6}' | '\u{D8}'..='\u{F6}'
+            | '\u{F8}'..='\u{2FF}' | '\u{370}'..='\u{37D}' | '\u{37F}'..='\u{1FFF}'
+            | '\u{200C}'..='\u{200D}' | '\u{2070}'..='\u{218F}' | '\u{2C00}'..='\u{2FEF}'
+            | '\u{3001}'..='\u{D7FF}' | '\u{F900}'..='\u{FDCF}' | '\u{FDF0}'..='\u{FFFD}'
+            | '\u{10000}'..='\u{EFFFF}')
+    }
+    let mut chars = name.chars();
+    chars.next().is_some_and(is_start)
+        && chars.all(|c| {
+            is_start(c)
+                || matches!(c,
+                    '-' | '.' | '0'..='9' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}')
+        })
+}
+
 /// An XML event.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Event<'a> {
@@ -62,6 +141,190 @@ impl<'a> Attrs<'a> {
         }
         None
     }
+
+    /// Parses the value of the given attribute, if present.
+    ///
+    /// Without the `alloc` feature, values longer than 256 bytes that
+    /// contain entity references can't be parsed.
+    pub fn parse<'n, T: FromStr>(&self, name: &'n str) -> Result<Option<T>, AttrError<'n>>
+    where
+        'a: 'n,
+        T::Err: Display,
+    {
+        self.parse_with(name, |s| s.parse().map_err(ParseError::new))
+    }
+
+    /// Parses the value of the given attribute, which must be present.
+    pub fn require<'n, T: FromStr>(&self, name: &'n str) -> Result<T, AttrError<'n>>
+    where
+        'a: 'n,
+        T::Err: Display,
+    {
+        self.parse(name)?.ok_or(AttrError::Missing(name))
+    }
+
+    /// Parses the value of the given boolean attribute, if present.
+    /// `true` and `1` are true, and `false` and `0` are false.
+    pub fn parse_bool<'n>(&self, name: &'n str) -> Result<Option<bool>, AttrError<'n>>
+    where
+        'a: 'n,
+    {
+        self.parse_with(name, |s| match s {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => Err(ParseError::new("expected `true`, `false`, `1` or `0`")),
+        })
+    }
+
+    /// Parses the value of the given boolean attribute, which must be
+    /// present, like [`parse_bool`](Attrs::parse_bool).
+    pub fn require_bool<'n>(&self, name: &'n str) -> Result<bool, AttrError<'n>>
+    where
+        'a: 'n,
+    {
+        self.parse_bool(name)?.ok_or(AttrError::Missing(name))
+    }
+
+    /// Returns the values of the given attributes, in the same order,
+    /// checking that no other attributes are present and that none are
+    /// repeated. Missing attributes are `None`, so required ones can be
+    /// checked with `ok_or(AttrError::Missing(name))`.
+    pub fn extract<const N: usize>(
+        &self,
+        names: [&str; N],
+    ) -> Result<[Option<Text<'a>>; N], AttrError<'a>> {
+        let mut values = core::array::from_fn(|_| None);
+        for (key, value) in self.clone() {
+            let i = names
+                .iter()
+                .position(|&name| name == key)
+                .ok_or(AttrError::Unknown(key))?;
+            if values[i].replace(value).is_some() {
+                return Err(AttrError::Duplicate(key));
+            }
+        }
+        Ok(values)
+    }
+
+    fn parse_with<'n, T>(
+        &self,
+        name: &'n str,
+        f: impl FnOnce(&str) -> Result<T, ParseError>,
+    ) -> Result<Option<T>, AttrError<'n>>
+    where
+        'a: 'n,
+    {
+        let value = match self.get(name) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        let parsed = match value {
+            Text::Verbatim(s) => f(s),
+            Text::Escaped(s) if !s.contains('&') => f(s),
+            #[cfg(feature = "alloc")]
+            Text::Escaped(_) => f(&value.clone().collect::<alloc::string::String>()),
+            #[cfg(not(feature = "alloc"))]
+            Text::Escaped(_) => {
+                let mut buf = Buf {
+                    bytes: [0; 256],
+                    len: 0,
+                };
+                match write!(buf, "{}", value) {
+                    Ok(()) => f(core::str::from_utf8(&buf.bytes[..buf.len]).unwrap()),
+                    Err(_) => Err(ParseError::new("value too long to decode")),
+                }
+            }
+        };
+        parsed
+            .map(Some)
+            .map_err(|err| AttrError::Invalid(name, value, err))
+    }
+}
+
+/// A fixed-size buffer for decoding attribute values without allocating.
+#[cfg(not(feature = "alloc"))]
+struct Buf {
+    bytes: [u8; 256],
+    len: usize,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl Write for Buf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let dest = self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?;
+        dest.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// An error from reading attributes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttrError<'a> {
+    /// The named attribute is missing.
+    Missing(&'a str),
+    /// The named attribute has a value that couldn't be parsed, with the
+    /// reason it couldn't.
+    Invalid(&'a str, Text<'a>, ParseError),
+    /// The named attribute appears more than once.
+    Duplicate(&'a str),
+    /// The named attribute isn't one of the expected attributes.
+    Unknown(&'a str),
+}
+
+impl<'a> Display for AttrError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AttrError::Missing(name) => write!(f, "missing attribute `{}`", name),
+            AttrError::Invalid(name, ref value, ref err) => {
+                write!(f, "invalid value {:?} for attribute `{}`", value, name)?;
+                if !err.message().is_empty() {
+                    write!(f, ": {}", err)?;
+                }
+                Ok(())
+            }
+            AttrError::Duplicate(name) => write!(f, "duplicate attribute `{}`", name),
+            AttrError::Unknown(name) => write!(f, "unexpected attribute `{}`", name),
+        }
+    }
+}
+
+/// Why an attribute value couldn't be parsed.
+///
+/// With the `alloc` feature, this keeps the [`Display`] text of the error
+/// returned by the parsing function, such as a [`FromStr`] impl. Without
+/// it, there's nowhere to keep the text, so the error is empty.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    #[cfg(feature = "alloc")]
+    message: alloc::string::String,
+}
+
+impl ParseError {
+    /// Creates an error from the error returned by a parsing function.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn new(err: impl Display) -> Self {
+        ParseError {
+            #[cfg(feature = "alloc")]
+            message: alloc::format!("{}", err),
+        }
+    }
+
+    /// Returns the text of the error, which is empty without the `alloc`
+    /// feature.
+    pub fn message(&self) -> &str {
+        #[cfg(feature = "alloc")]
+        return &self.message;
+        #[cfg(not(feature = "alloc"))]
+        return "";
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.message())
+    }
 }
 
 impl<'a> Debug for Attrs<'a> {
@@ -112,7 +375,7 @@ impl<'a> Display for Text<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Text::Verbatim(s) => f.write_str(s),
-            Text::Escaped(s) => Text::Escaped(s).map(|c| f.write_char(c)).collect(),
+            Text::Escaped(s) => Text::Escaped(s).try_for_each(|c| f.write_char(c)),
         }
     }
 }
@@ -156,7 +419,7 @@ impl<'a> Iterator for Text<'a> {
                             n.try_into().ok()
                         }
                         s if s.starts_with('#') => {
-                            let n = u32::from_str_radix(&esc[1..], 10).ok()?;
+                            let n = esc[1..].parse::<u32>().ok()?;
                             n.try_into().ok()
                         }
                         _ => None,
@@ -172,15 +435,21 @@ impl<'a> Iterator for Text<'a> {
     }
 }
 
+impl<'a> From<&'a str> for Text<'a> {
+    fn from(s: &'a str) -> Self {
+        Text::Verbatim(s)
+    }
+}
+
 impl<'a> PartialEq for Text<'a> {
     fn eq(&self, other: &Self) -> bool {
-        self.clone().zip(other.clone()).all(|(a, b)| a == b)
+        self.clone().eq(other.clone())
     }
 }
 
 impl<'a> PartialEq<str> for Text<'a> {
     fn eq(&self, other: &str) -> bool {
-        self.clone().zip(other.chars()).all(|(a, b)| a == b)
+        self.clone().eq(other.chars())
     }
 }
 
@@ -191,6 +460,7 @@ impl<'a, 'b> PartialEq<&'b str> for Text<'a> {
 }
 
 /// An iterator over XML events.
+#[derive(Clone)]
 pub struct Parser<'a> {
     doc: &'a str,
     self_closing: Option<&'a str>,
@@ -205,6 +475,78 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Skips the rest of the current element, up to and including its
+    /// [`Event::Close`]. Call this right after an [`Event::Open`].
+    ///
+    /// Returns `None` if the document ends before the element does.
+    pub fn skip_element(&mut self) -> Option<()> {
+        let mut depth = 0usize;
+        loop {
+            match self.next()? {
+                Event::Open(..) => depth += 1,
+                Event::Close(..) if depth == 0 => return Some(()),
+                Event::Close(..) => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns an iterator over the content of the current element.
+    /// Call this right after an [`Event::Open`].
+    ///
+    /// The iterator ends at the element's [`Event::Close`], which it consumes
+    /// but doesn't yield. If it's dropped before then, the rest of the element
+    /// is skipped, so the parser is always left after the element.
+    pub fn element_events(&mut self) -> ElementEvents<'_, 'a> {
+        ElementEvents {
+            parser: self,
+            depth: 0,
+            done: false,
+        }
+    }
+
+    /// Reads the character data of the current element, including CDATA
+    /// sections, up to and including its [`Event::Close`].
+    /// Call this right after an [`Event::Open`].
+    ///
+    /// Entity references are replaced, and comments and processing
+    /// instructions are ignored. If `nested` is [`NestedElements::Reject`]
+    /// and a child element is found, the parser is left after its
+    /// [`Event::Open`].
+    #[cfg(feature = "alloc")]
+    pub fn read_text(
+        &mut self,
+        nested: NestedElements,
+    ) -> Result<alloc::string::String, TextError> {
+        let mut text = alloc::string::String::new();
+        self.read_text_to(&mut text, nested)?;
+        Ok(text)
+    }
+
+    /// Like [`read_text`](Parser::read_text), but writes the character
+    /// data to `out` instead of allocating a string.
+    pub fn read_text_to<W: Write>(
+        &mut self,
+        out: &mut W,
+        nested: NestedElements,
+    ) -> Result<(), TextError> {
+        let mut depth = 0usize;
+        loop {
+            match self.next().ok_or(TextError::Malformed)? {
+                Event::Open(..) if nested == NestedElements::Reject => {
+                    return Err(TextError::NestedElement)
+                }
+                Event::Open(..) => depth += 1,
+                Event::Close(..) if depth == 0 => return Ok(()),
+                Event::Close(..) => depth -= 1,
+                Event::Text(text) if depth == 0 || nested == NestedElements::Include => {
+                    write!(out, "{}", text)?
+                }
+                _ => {}
+            }
+        }
+    }
+
     fn consume(&mut self, pattern: &str) -> bool {
         if self.doc.starts_with(pattern) {
             self.doc = &self.doc[pattern.len()..];
@@ -231,7 +573,7 @@ impl<'a> Iterator for Parser<'a> {
         } else if self.consume("<?") {
             Some(Event::Pi(self.consume_to("?>")?))
         } else if self.consume("<!DOCTYPE") {
-            let i = self.doc.find(&['[', '>'])?;
+            let i = self.doc.find(['[', '>'])?;
             if self.doc[i..].starts_with("[") {
                 let name = self.doc[..i].trim_matches(WHITESPACE);
                 self.doc = &self.doc[i + 1..];
@@ -261,7 +603,7 @@ impl<'a> Iterator for Parser<'a> {
             }
             Some(Event::Open(tag, Attrs { text: attrs }))
         } else if !self.doc.is_empty() {
-            let i = self.doc.find('<').unwrap_or_else(|| self.doc.len());
+            let i = self.doc.find('<').unwrap_or(self.doc.len());
             let ret = &self.doc[..i];
             self.doc = &self.doc[i..];
             Some(Event::Text(Text::Escaped(ret)))
@@ -270,3 +612,109 @@ impl<'a> Iterator for Parser<'a> {
         }
     }
 }
+
+/// An iterator over the content of an element, returned by
+/// [`Parser::element_events`].
+pub struct ElementEvents<'p, 'a> {
+    parser: &'p mut Parser<'a>,
+    depth: usize,
+    done: bool,
+}
+
+impl<'p, 'a> ElementEvents<'p, 'a> {
+    fn leave(&mut self) {
+        match self.depth {
+            0 => self.done = true,
+            _ => self.depth -= 1,
+        }
+    }
+
+    /// Skips the rest of the element opened by the last event, like
+    /// [`Parser::skip_element`].
+    pub fn skip_element(&mut self) -> Option<()> {
+        if self.done {
+            return None;
+        }
+        self.leave();
+        self.parser.skip_element()
+    }
+
+    /// Returns an iterator over the content of the element opened by the
+    /// last event, like [`Parser::element_events`].
+    pub fn element_events(&mut self) -> ElementEvents<'_, 'a> {
+        let done = self.done;
+        self.leave();
+        ElementEvents {
+            parser: self.parser,
+            depth: 0,
+            done,
+        }
+    }
+}
+
+impl<'p, 'a> Iterator for ElementEvents<'p, 'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Event<'a>> {
+        if self.done {
+            return None;
+        }
+        let event = self.parser.next();
+        match event {
+            Some(Event::Open(..)) => self.depth += 1,
+            Some(Event::Close(..)) if self.depth == 0 => {
+                self.done = true;
+                return None;
+            }
+            Some(Event::Close(..)) => self.depth -= 1,
+            Some(_) => {}
+            None => self.done = true,
+        }
+        event
+    }
+}
+
+impl<'p, 'a> Drop for ElementEvents<'p, 'a> {
+    fn drop(&mut self) {
+        while self.next().is_some() {}
+    }
+}
+
+/// What [`Parser::read_text`] does with elements nested in the element
+/// whose text is being read.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NestedElements {
+    /// Include the text of nested elements.
+    Include,
+    /// Skip nested elements and their text.
+    Skip,
+    /// Return [`TextError::NestedElement`].
+    Reject,
+}
+
+/// An error from [`Parser::read_text`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextError {
+    /// The document ended before the element did.
+    Malformed,
+    /// The element contains another element.
+    NestedElement,
+    /// The output returned an error.
+    Write,
+}
+
+impl Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            TextError::Malformed => "document is not well-formed",
+            TextError::NestedElement => "unexpected element in text content",
+            TextError::Write => "failed to write output",
+        })
+    }
+}
+
+impl From<fmt::Error> for TextError {
+    fn from(_: fmt::Error) -> Self {
+        TextError::Write
+    }
+}
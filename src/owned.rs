@@ -0,0 +1,129 @@
+use crate::{Attrs, Event, Text};
+use alloc::string::String;
+use core::fmt::{self, Debug, Display};
+
+/// An owned version of [`Event`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum OwnedEvent {
+    /// An opening tag of an XML element.
+    Open(String, OwnedAttrs),
+    /// A closing tag of an XML element.
+    Close(String),
+    /// A doctype declaration.
+    Doctype(String, String),
+    /// A processing instruction.
+    Pi(String),
+    /// A comment.
+    Comment(String),
+    /// Character data.
+    Text(OwnedText),
+}
+
+/// An owned version of [`Attrs`].
+#[derive(Clone, Eq, PartialEq)]
+pub struct OwnedAttrs {
+    text: String,
+}
+
+/// An owned version of [`Text`].
+#[derive(Clone, Eq)]
+pub enum OwnedText {
+    /// Text interpreted as-is, without any replacements.
+    Verbatim(String),
+    /// Text interpreted with XML entity references.
+    Escaped(String),
+}
+
+impl<'a> Event<'a> {
+    /// Copies the event into an [`OwnedEvent`].
+    pub fn to_owned(&self) -> OwnedEvent {
+        match *self {
+            Event::Open(name, ref attrs) => OwnedEvent::Open(name.into(), attrs.to_owned()),
+            Event::Close(name) => OwnedEvent::Close(name.into()),
+            Event::Doctype(name, body) => OwnedEvent::Doctype(name.into(), body.into()),
+            Event::Pi(pi) => OwnedEvent::Pi(pi.into()),
+            Event::Comment(comment) => OwnedEvent::Comment(comment.into()),
+            Event::Text(ref text) => OwnedEvent::Text(text.to_owned()),
+        }
+    }
+}
+
+impl<'a> Attrs<'a> {
+    /// Copies the attributes into an [`OwnedAttrs`].
+    pub fn to_owned(&self) -> OwnedAttrs {
+        OwnedAttrs {
+            text: self.text.into(),
+        }
+    }
+}
+
+impl<'a> Text<'a> {
+    /// Copies the text into an [`OwnedText`].
+    pub fn to_owned(&self) -> OwnedText {
+        match *self {
+            Text::Verbatim(s) => OwnedText::Verbatim(s.into()),
+            Text::Escaped(s) => OwnedText::Escaped(s.into()),
+        }
+    }
+}
+
+impl OwnedEvent {
+    /// Borrows the event as an [`Event`].
+    pub fn as_borrowed(&self) -> Event<'_> {
+        match *self {
+            OwnedEvent::Open(ref name, ref attrs) => Event::Open(name, attrs.as_borrowed()),
+            OwnedEvent::Close(ref name) => Event::Close(name),
+            OwnedEvent::Doctype(ref name, ref body) => Event::Doctype(name, body),
+            OwnedEvent::Pi(ref pi) => Event::Pi(pi),
+            OwnedEvent::Comment(ref comment) => Event::Comment(comment),
+            OwnedEvent::Text(ref text) => Event::Text(text.as_borrowed()),
+        }
+    }
+}
+
+impl OwnedAttrs {
+    /// Borrows the attributes as an [`Attrs`].
+    pub fn as_borrowed(&self) -> Attrs<'_> {
+        Attrs { text: &self.text }
+    }
+}
+
+impl Debug for OwnedAttrs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl OwnedText {
+    /// Borrows the text as a [`Text`].
+    pub fn as_borrowed(&self) -> Text<'_> {
+        match *self {
+            OwnedText::Verbatim(ref s) => Text::Verbatim(s),
+            OwnedText::Escaped(ref s) => Text::Escaped(s),
+        }
+    }
+}
+
+impl Display for OwnedText {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl Debug for OwnedText {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.as_borrowed(), f)
+    }
+}
+
+impl PartialEq for OwnedText {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_borrowed() == other.as_borrowed()
+    }
+}
+
+impl<'a> From<Event<'a>> for OwnedEvent {
+    fn from(event: Event<'a>) -> Self {
+        event.to_owned()
+    }
+}
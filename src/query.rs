@@ -0,0 +1,891 @@
+//! XPath queries over a [`Document`].
+//!
+//! This implements a subset of [XPath 1.0](https://www.w3.org/TR/xpath-10/):
+//!
+//! - Location paths with the `child`, `descendant`, `descendant-or-self`,
+//!   `attribute`, `parent`, `ancestor` and `self` axes, including the `//`,
+//!   `@`, `.` and `..` abbreviations
+//! - Name tests, `*`, `text()` and `node()`
+//! - Predicates, including positional predicates like `[1]` and `[last()]`
+//! - The `=`, `!=`, `<`, `<=`, `>`, `>=`, `and`, `or` and `|` operators
+//! - The `count()`, `contains()`, `starts-with()`, `not()`, `last()`,
+//!   `position()`, `name()`, `string()`, `true()` and `false()` functions
+//!
+//! Arithmetic, variables and namespaces aren't supported.
+
+use crate::dom::{Document, Element, Node, NodeId, NodeKind};
+use crate::Text;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// A syntax error in a query.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Error {
+    offset: usize,
+}
+
+impl Error {
+    /// Returns the byte offset of the error in the query.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid query at offset {}", self.offset)
+    }
+}
+
+/// A compiled query.
+#[derive(Clone, Debug)]
+pub struct Query {
+    expr: Expr,
+}
+
+/// The result of evaluating a [`Query`].
+#[derive(Clone, Debug)]
+pub enum Value<'d, 'a> {
+    /// A set of nodes, in document order.
+    Nodes(Vec<Item<'d, 'a>>),
+    /// A number.
+    Number(f64),
+    /// A string.
+    String(String),
+    /// A boolean.
+    Boolean(bool),
+}
+
+/// A node selected by a [`Query`].
+#[derive(Clone, Debug)]
+pub enum Item<'d, 'a> {
+    /// The document itself, which is the parent of the top-level nodes.
+    Document(&'d Document<'a>),
+    /// A node in the document.
+    Node(Node<'d, 'a>),
+    /// An attribute, with the element it belongs to and its name and value.
+    Attr(Element<'d, 'a>, &'d str, Text<'d>),
+}
+
+impl<'d, 'a> Item<'d, 'a> {
+    /// Returns the item's string value: the concatenated character data for
+    /// elements and documents, and the text of other nodes and attributes.
+    pub fn text(&self) -> String {
+        match *self {
+            Item::Document(doc) => doc.root().text(),
+            Item::Node(node) => match node.kind() {
+                NodeKind::Element(..) => node.as_element().unwrap().text(),
+                NodeKind::Text(text) => text.collect(),
+                NodeKind::Comment(s) | NodeKind::Pi(s) => s.into(),
+                NodeKind::Doctype(..) => String::new(),
+            },
+            Item::Attr(_, _, ref value) => value.clone().collect(),
+        }
+    }
+}
+
+impl Query {
+    /// Compiles a query.
+    pub fn parse(query: &str) -> Result<Query, Error> {
+        let tokens = tokenize(query)?;
+        let mut parser = QueryParser {
+            tokens: &tokens,
+            pos: 0,
+            end: query.len(),
+        };
+        let expr = parser.expr()?;
+        if parser.pos != tokens.len() {
+            return Err(parser.error());
+        }
+        Ok(Query { expr })
+    }
+
+    /// Evaluates the query with the document as the context node.
+    pub fn evaluate<'d, 'a>(&self, doc: &'d Document<'a>) -> Value<'d, 'a> {
+        Evaluator::new(doc).evaluate(&self.expr, Ref::Document)
+    }
+
+    /// Evaluates the query with the given element as the context node.
+    pub fn evaluate_at<'d, 'a>(&self, doc: &'d Document<'a>, element: NodeId) -> Value<'d, 'a> {
+        Evaluator::new(doc).evaluate(&self.expr, Ref::Node(element))
+    }
+
+    /// Evaluates the query with the document as the context node, and
+    /// returns the selected nodes, or nothing if the result isn't a node set.
+    pub fn select<'d, 'a>(&self, doc: &'d Document<'a>) -> Vec<Item<'d, 'a>> {
+        match self.evaluate(doc) {
+            Value::Nodes(nodes) => nodes,
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Slash,
+    DoubleSlash,
+    LBracket,
+    RBracket,
+    LParen,
+    RParen,
+    At,
+    Comma,
+    Dot,
+    DotDot,
+    Star,
+    Pipe,
+    DoubleColon,
+    Op(Op),
+    Literal(String),
+    Number(f64),
+    Name(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+fn tokenize(query: &str) -> Result<Vec<(usize, Token)>, Error> {
+    let mut tokens = Vec::new();
+    let mut rest = query;
+    loop {
+        rest = rest.trim_start();
+        let offset = query.len() - rest.len();
+        let c = match rest.chars().next() {
+            Some(c) => c,
+            None => break,
+        };
+        let (token, len) = match c {
+            '/' if rest.starts_with("//") => (Token::DoubleSlash, 2),
+            '/' => (Token::Slash, 1),
+            '[' => (Token::LBracket, 1),
+            ']' => (Token::RBracket, 1),
+            '(' => (Token::LParen, 1),
+            ')' => (Token::RParen, 1),
+            '@' => (Token::At, 1),
+            ',' => (Token::Comma, 1),
+            '*' => (Token::Star, 1),
+            '|' => (Token::Pipe, 1),
+            ':' if rest.starts_with("::") => (Token::DoubleColon, 2),
+            '=' => (Token::Op(Op::Eq), 1),
+            '!' if rest.starts_with("!=") => (Token::Op(Op::Ne), 2),
+            '<' if rest.starts_with("<=") => (Token::Op(Op::Le), 2),
+            '<' => (Token::Op(Op::Lt), 1),
+            '>' if rest.starts_with(">=") => (Token::Op(Op::Ge), 2),
+            '>' => (Token::Op(Op::Gt), 1),
+            '"' | '\'' => {
+                let end = rest[1..].find(c).ok_or(Error { offset })?;
+                (Token::Literal(rest[1..end + 1].into()), end + 2)
+            }
+            '.' if rest.starts_with("..") => (Token::DotDot, 2),
+            '.' | '0'..='9' => {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_digit() && c != '.')
+                    .unwrap_or(rest.len());
+                match &rest[..len] {
+                    "." => (Token::Dot, 1),
+                    number => {
+                        let number = number.parse().map_err(|_| Error { offset })?;
+                        (Token::Number(number), len)
+                    }
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut len = 0;
+                for (i, c) in rest.char_indices() {
+                    let colon = c == ':' && !rest[i..].starts_with("::");
+                    if !(c.is_alphanumeric() || "_-.".contains(c) || colon) {
+                        break;
+                    }
+                    len = i + c.len_utf8();
+                }
+                (Token::Name(rest[..len].into()), len)
+            }
+            _ => return Err(Error { offset }),
+        };
+        tokens.push((offset, token));
+        rest = &rest[len..];
+    }
+    Ok(tokens)
+}
+
+#[derive(Clone, Debug)]
+enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Compare(Op, Box<Expr>, Box<Expr>),
+    Union(Box<Expr>, Box<Expr>),
+    Literal(String),
+    Number(f64),
+    Call(Function, Vec<Expr>),
+    /// A path, starting from the document, the context node, or the result
+    /// of a filtered expression.
+    Path(Option<Box<Expr>>, Vec<Expr>, Vec<Step>),
+    Root,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Function {
+    Count,
+    Contains,
+    StartsWith,
+    Not,
+    Last,
+    Position,
+    Name,
+    String,
+    True,
+    False,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Axis {
+    Child,
+    Descendant,
+    DescendantOrSelf,
+    Attribute,
+    Parent,
+    Ancestor,
+    Itself,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum NodeTest {
+    Name(String),
+    Any,
+    Text,
+    Node,
+}
+
+#[derive(Clone, Debug)]
+struct Step {
+    axis: Axis,
+    test: NodeTest,
+    predicates: Vec<Expr>,
+}
+
+impl Step {
+    fn descendant_or_self() -> Self {
+        Step {
+            axis: Axis::DescendantOrSelf,
+            test: NodeTest::Node,
+            predicates: Vec::new(),
+        }
+    }
+}
+
+struct QueryParser<'t> {
+    tokens: &'t [(usize, Token)],
+    pos: usize,
+    end: usize,
+}
+
+impl<'t> QueryParser<'t> {
+    fn peek(&self) -> Option<&'t Token> {
+        self.tokens.get(self.pos).map(|(_, t)| t)
+    }
+
+    fn peek_at(&self, n: usize) -> Option<&'t Token> {
+        self.tokens.get(self.pos + n).map(|(_, t)| t)
+    }
+
+    fn error(&self) -> Error {
+        let offset = self.tokens.get(self.pos).map_or(self.end, |&(o, _)| o);
+        Error { offset }
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<(), Error> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn eat_name(&mut self, name: &str) -> bool {
+        match self.peek() {
+            Some(Token::Name(n)) if n == name => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, Error> {
+        let mut lhs = self.and_expr()?;
+        while self.eat_name("or") {
+            lhs = Expr::Or(Box::new(lhs), Box::new(self.and_expr()?));
+        }
+        Ok(lhs)
+    }
+
+    fn and_expr(&mut self) -> Result<Expr, Error> {
+        let mut lhs = self.compare_expr()?;
+        while self.eat_name("and") {
+            lhs = Expr::And(Box::new(lhs), Box::new(self.compare_expr()?));
+        }
+        Ok(lhs)
+    }
+
+    fn compare_expr(&mut self) -> Result<Expr, Error> {
+        let mut lhs = self.union_expr()?;
+        while let Some(&Token::Op(op)) = self.peek() {
+            self.pos += 1;
+            lhs = Expr::Compare(op, Box::new(lhs), Box::new(self.union_expr()?));
+        }
+        Ok(lhs)
+    }
+
+    fn union_expr(&mut self) -> Result<Expr, Error> {
+        let mut lhs = self.path_expr()?;
+        while self.eat(&Token::Pipe) {
+            lhs = Expr::Union(Box::new(lhs), Box::new(self.path_expr()?));
+        }
+        Ok(lhs)
+    }
+
+    fn path_expr(&mut self) -> Result<Expr, Error> {
+        let primary = match (self.peek(), self.peek_at(1)) {
+            (Some(Token::Literal(s)), _) => {
+                self.pos += 1;
+                Some(Expr::Literal(s.clone()))
+            }
+            (Some(&Token::Number(n)), _) => {
+                self.pos += 1;
+                Some(Expr::Number(n))
+            }
+            (Some(Token::LParen), _) => {
+                self.pos += 1;
+                let expr = self.expr()?;
+                self.expect(&Token::RParen)?;
+                Some(expr)
+            }
+            (Some(Token::Name(name)), Some(Token::LParen)) if name != "text" && name != "node" => {
+                Some(self.call(name)?)
+            }
+            _ => None,
+        };
+        let (start, predicates) = match primary {
+            Some(expr) => {
+                let predicates = self.predicates()?;
+                match self.peek() {
+                    Some(Token::Slash) | Some(Token::DoubleSlash) => {}
+                    _ if predicates.is_empty() => return Ok(expr),
+                    _ => return Ok(Expr::Path(Some(Box::new(expr)), predicates, Vec::new())),
+                }
+                (Some(Box::new(expr)), predicates)
+            }
+            None => match self.peek() {
+                Some(Token::Slash) | Some(Token::DoubleSlash) => {
+                    (Some(Box::new(Expr::Root)), Vec::new())
+                }
+                _ => (None, Vec::new()),
+            },
+        };
+
+        let mut steps = Vec::new();
+        let mut first = start.is_none();
+        loop {
+            if first {
+                first = false;
+            } else if self.eat(&Token::DoubleSlash) {
+                steps.push(Step::descendant_or_self());
+            } else if !self.eat(&Token::Slash) {
+                break;
+            }
+            match self.step()? {
+                Some(step) => steps.push(step),
+                // A lone `/` selects the document.
+                None if steps.is_empty() && start.is_some() && predicates.is_empty() => break,
+                None => return Err(self.error()),
+            }
+        }
+        Ok(Expr::Path(start, predicates, steps))
+    }
+
+    fn call(&mut self, name: &str) -> Result<Expr, Error> {
+        let function = match name {
+            "count" => Function::Count,
+            "contains" => Function::Contains,
+            "starts-with" => Function::StartsWith,
+            "not" => Function::Not,
+            "last" => Function::Last,
+            "position" => Function::Position,
+            "name" => Function::Name,
+            "string" => Function::String,
+            "true" => Function::True,
+            "false" => Function::False,
+            _ => return Err(self.error()),
+        };
+        let (min, max) = match function {
+            Function::Count | Function::Not => (1, 1),
+            Function::Contains | Function::StartsWith => (2, 2),
+            Function::Name | Function::String => (0, 1),
+            Function::Last | Function::Position | Function::True | Function::False => (0, 0),
+        };
+        let start = self.pos;
+        self.pos += 2;
+        let mut args = Vec::new();
+        if !self.eat(&Token::RParen) {
+            loop {
+                args.push(self.expr()?);
+                if self.eat(&Token::RParen) {
+                    break;
+                }
+                self.expect(&Token::Comma)?;
+            }
+        }
+        if args.len() < min || args.len() > max {
+            self.pos = start;
+            return Err(self.error());
+        }
+        Ok(Expr::Call(function, args))
+    }
+
+    fn step(&mut self) -> Result<Option<Step>, Error> {
+        let axis = match (self.peek(), self.peek_at(1)) {
+            (Some(Token::Dot), _) => {
+                self.pos += 1;
+                return Ok(Some(Step {
+                    axis: Axis::Itself,
+                    test: NodeTest::Node,
+                    predicates: Vec::new(),
+                }));
+            }
+            (Some(Token::DotDot), _) => {
+                self.pos += 1;
+                return Ok(Some(Step {
+                    axis: Axis::Parent,
+                    test: NodeTest::Node,
+                    predicates: Vec::new(),
+                }));
+            }
+            (Some(Token::At), _) => {
+                self.pos += 1;
+                Axis::Attribute
+            }
+            (Some(Token::Name(name)), Some(Token::DoubleColon)) => {
+                let axis = match &name[..] {
+                    "child" => Axis::Child,
+                    "descendant" => Axis::Descendant,
+                    "descendant-or-self" => Axis::DescendantOrSelf,
+                    "attribute" => Axis::Attribute,
+                    "parent" => Axis::Parent,
+                    "ancestor" => Axis::Ancestor,
+                    "self" => Axis::Itself,
+                    _ => return Err(self.error()),
+                };
+                self.pos += 2;
+                axis
+            }
+            _ => Axis::Child,
+        };
+        let test = match (self.peek(), self.peek_at(1)) {
+            (Some(Token::Star), _) => {
+                self.pos += 1;
+                NodeTest::Any
+            }
+            (Some(Token::Name(name)), Some(Token::LParen)) => {
+                let test = match &name[..] {
+                    "text" => NodeTest::Text,
+                    "node" => NodeTest::Node,
+                    _ => return Err(self.error()),
+                };
+                self.pos += 2;
+                self.expect(&Token::RParen)?;
+                test
+            }
+            (Some(Token::Name(name)), _) => {
+                self.pos += 1;
+                NodeTest::Name(name.clone())
+            }
+            _ if axis == Axis::Child => return Ok(None),
+            _ => return Err(self.error()),
+        };
+        let predicates = self.predicates()?;
+        Ok(Some(Step {
+            axis,
+            test,
+            predicates,
+        }))
+    }
+
+    fn predicates(&mut self) -> Result<Vec<Expr>, Error> {
+        let mut predicates = Vec::new();
+        while self.eat(&Token::LBracket) {
+            predicates.push(self.expr()?);
+            self.expect(&Token::RBracket)?;
+        }
+        Ok(predicates)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Ref {
+    Document,
+    Node(NodeId),
+    /// An attribute, by element and index.
+    Attr(NodeId, usize),
+}
+
+enum Val {
+    Nodes(Vec<Ref>),
+    Number(f64),
+    String(String),
+    Boolean(bool),
+}
+
+struct Evaluator<'d, 'a> {
+    doc: &'d Document<'a>,
+    /// The position of each node in document order.
+    order: Vec<usize>,
+}
+
+impl<'d, 'a> Evaluator<'d, 'a> {
+    fn new(doc: &'d Document<'a>) -> Self {
+        let mut evaluator = Evaluator {
+            doc,
+            order: alloc::vec![usize::MAX; doc.len()],
+        };
+        let mut next = 0;
+        let mut stack: Vec<Node> = doc.children().collect();
+        stack.reverse();
+        while let Some(node) = stack.pop() {
+            evaluator.order[node.id().index()] = next;
+            next += 1;
+            if let Some(element) = node.as_element() {
+                let len = stack.len();
+                stack.extend(element.children());
+                stack[len..].reverse();
+            }
+        }
+        evaluator
+    }
+
+    fn evaluate(&self, expr: &Expr, context: Ref) -> Value<'d, 'a> {
+        match self.eval(expr, context, 1, 1) {
+            Val::Nodes(nodes) => Value::Nodes(nodes.into_iter().map(|r| self.item(r)).collect()),
+            Val::Number(n) => Value::Number(n),
+            Val::String(s) => Value::String(s),
+            Val::Boolean(b) => Value::Boolean(b),
+        }
+    }
+
+    fn item(&self, r: Ref) -> Item<'d, 'a> {
+        match r {
+            Ref::Document => Item::Document(self.doc),
+            Ref::Node(id) => Item::Node(self.doc.get(id)),
+            Ref::Attr(id, i) => {
+                let element = self.doc.get(id).as_element().unwrap();
+                let (name, value) = element.attrs().nth(i).unwrap();
+                Item::Attr(element, name, value)
+            }
+        }
+    }
+
+    fn sort_key(&self, r: Ref) -> (usize, usize) {
+        match r {
+            Ref::Document => (0, 0),
+            Ref::Node(id) => (self.order[id.index()].wrapping_add(1), 0),
+            Ref::Attr(id, i) => (self.order[id.index()].wrapping_add(1), i + 1),
+        }
+    }
+
+    fn sort(&self, nodes: &mut Vec<Ref>) {
+        nodes.sort_by_key(|&r| self.sort_key(r));
+        nodes.dedup();
+    }
+
+    fn eval(&self, expr: &Expr, context: Ref, position: usize, size: usize) -> Val {
+        match *expr {
+            Expr::Or(ref a, ref b) => Val::Boolean(
+                self.boolean(&self.eval(a, context, position, size))
+                    || self.boolean(&self.eval(b, context, position, size)),
+            ),
+            Expr::And(ref a, ref b) => Val::Boolean(
+                self.boolean(&self.eval(a, context, position, size))
+                    && self.boolean(&self.eval(b, context, position, size)),
+            ),
+            Expr::Compare(op, ref a, ref b) => {
+                let a = self.eval(a, context, position, size);
+                let b = self.eval(b, context, position, size);
+                Val::Boolean(self.compare(op, &a, &b))
+            }
+            Expr::Union(ref a, ref b) => {
+                let mut nodes = match self.eval(a, context, position, size) {
+                    Val::Nodes(nodes) => nodes,
+                    _ => Vec::new(),
+                };
+                if let Val::Nodes(more) = self.eval(b, context, position, size) {
+                    nodes.extend(more);
+                }
+                self.sort(&mut nodes);
+                Val::Nodes(nodes)
+            }
+            Expr::Literal(ref s) => Val::String(s.clone()),
+            Expr::Number(n) => Val::Number(n),
+            Expr::Call(function, ref args) => {
+                let arg = |i: usize| self.eval(&args[i], context, position, size);
+                match function {
+                    Function::Count => Val::Number(match arg(0) {
+                        Val::Nodes(nodes) => nodes.len() as f64,
+                        _ => 0.0,
+                    }),
+                    Function::Contains => {
+                        Val::Boolean(self.string(&arg(0)).contains(&self.string(&arg(1))[..]))
+                    }
+                    Function::StartsWith => {
+                        Val::Boolean(self.string(&arg(0)).starts_with(&self.string(&arg(1))[..]))
+                    }
+                    Function::Not => Val::Boolean(!self.boolean(&arg(0))),
+                    Function::Last => Val::Number(size as f64),
+                    Function::Position => Val::Number(position as f64),
+                    Function::Name => {
+                        let target = match args.first() {
+                            Some(_) => match arg(0) {
+                                Val::Nodes(nodes) => nodes.first().copied(),
+                                _ => None,
+                            },
+                            None => Some(context),
+                        };
+                        Val::String(match target.map(|r| self.item(r)) {
+                            Some(Item::Node(node)) => match node.kind() {
+                                NodeKind::Element(name) => name.into(),
+                                _ => String::new(),
+                            },
+                            Some(Item::Attr(_, name, _)) => name.into(),
+                            _ => String::new(),
+                        })
+                    }
+                    Function::String => Val::String(match args.first() {
+                        Some(_) => self.string(&arg(0)),
+                        None => self.item(context).text(),
+                    }),
+                    Function::True => Val::Boolean(true),
+                    Function::False => Val::Boolean(false),
+                }
+            }
+            Expr::Root => Val::Nodes(alloc::vec![Ref::Document]),
+            Expr::Path(ref start, ref predicates, ref steps) => {
+                let mut nodes = match *start {
+                    Some(ref start) => match self.eval(start, context, position, size) {
+                        Val::Nodes(nodes) => nodes,
+                        _ => return Val::Nodes(Vec::new()),
+                    },
+                    None => alloc::vec![context],
+                };
+                for predicate in predicates {
+                    nodes = self.filter(nodes, predicate);
+                }
+                for step in steps {
+                    let mut next = Vec::new();
+                    for &node in &nodes {
+                        let mut candidates = Vec::new();
+                        self.axis(step.axis, node, &mut candidates);
+                        candidates.retain(|&r| self.test(step.axis, &step.test, r));
+                        for predicate in &step.predicates {
+                            candidates = self.filter(candidates, predicate);
+                        }
+                        next.extend(candidates);
+                    }
+                    self.sort(&mut next);
+                    nodes = next;
+                }
+                Val::Nodes(nodes)
+            }
+        }
+    }
+
+    fn filter(&self, nodes: Vec<Ref>, predicate: &Expr) -> Vec<Ref> {
+        let size = nodes.len();
+        let mut kept = Vec::new();
+        for (i, node) in nodes.into_iter().enumerate() {
+            let keep = match self.eval(predicate, node, i + 1, size) {
+                Val::Number(n) => n == (i + 1) as f64,
+                value => self.boolean(&value),
+            };
+            if keep {
+                kept.push(node);
+            }
+        }
+        kept
+    }
+
+    /// Collects the nodes on an axis, in axis order.
+    fn axis(&self, axis: Axis, node: Ref, out: &mut Vec<Ref>) {
+        match axis {
+            Axis::Child => match node {
+                Ref::Document => out.extend(self.doc.children().map(|n| Ref::Node(n.id()))),
+                Ref::Node(id) => {
+                    if let Some(element) = self.doc.get(id).as_element() {
+                        out.extend(element.children().map(|n| Ref::Node(n.id())));
+                    }
+                }
+                Ref::Attr(..) => {}
+            },
+            Axis::Descendant => {
+                let mut children = Vec::new();
+                self.axis(Axis::Child, node, &mut children);
+                for child in children {
+                    out.push(child);
+                    self.axis(Axis::Descendant, child, out);
+                }
+            }
+            Axis::DescendantOrSelf => {
+                out.push(node);
+                self.axis(Axis::Descendant, node, out);
+            }
+            Axis::Attribute => {
+                if let Ref::Node(id) = node {
+                    if let Some(element) = self.doc.get(id).as_element() {
+                        out.extend((0..element.attrs().count()).map(|i| Ref::Attr(id, i)));
+                    }
+                }
+            }
+            Axis::Parent => match node {
+                Ref::Document => {}
+                Ref::Node(id) => out.push(match self.doc.get(id).parent() {
+                    Some(parent) => Ref::Node(parent.id()),
+                    None => Ref::Document,
+                }),
+                Ref::Attr(id, _) => out.push(Ref::Node(id)),
+            },
+            Axis::Ancestor => {
+                let mut current = node;
+                loop {
+                    let len = out.len();
+                    self.axis(Axis::Parent, current, out);
+                    match out.get(len) {
+                        Some(&parent) => current = parent,
+                        None => break,
+                    }
+                }
+            }
+            Axis::Itself => out.push(node),
+        }
+    }
+
+    fn test(&self, axis: Axis, test: &NodeTest, r: Ref) -> bool {
+        match (test, r) {
+            (NodeTest::Node, _) => true,
+            (_, Ref::Document) => false,
+            (NodeTest::Text, Ref::Node(id)) => self.doc.get(id).as_text().is_some(),
+            (NodeTest::Text, Ref::Attr(..)) => false,
+            (_, Ref::Attr(..)) if axis != Axis::Attribute => false,
+            (NodeTest::Any, Ref::Attr(..)) => true,
+            (NodeTest::Name(name), Ref::Attr(..)) => match self.item(r) {
+                Item::Attr(_, attr, _) => attr == name,
+                _ => false,
+            },
+            (NodeTest::Any, Ref::Node(id)) => self.doc.get(id).as_element().is_some(),
+            (NodeTest::Name(name), Ref::Node(id)) => {
+                self.doc.get(id).kind() == NodeKind::Element(name)
+            }
+        }
+    }
+
+    fn string(&self, value: &Val) -> String {
+        match *value {
+            Val::Nodes(ref nodes) => nodes
+                .first()
+                .map(|&r| self.item(r).text())
+                .unwrap_or_default(),
+            Val::Number(n) => number_to_string(n),
+            Val::String(ref s) => s.clone(),
+            Val::Boolean(b) => b.to_string(),
+        }
+    }
+
+    fn number(&self, value: &Val) -> f64 {
+        match *value {
+            Val::Number(n) => n,
+            Val::Boolean(b) => b as u8 as f64,
+            ref value => string_to_number(&self.string(value)),
+        }
+    }
+
+    fn boolean(&self, value: &Val) -> bool {
+        match *value {
+            Val::Nodes(ref nodes) => !nodes.is_empty(),
+            Val::Number(n) => n != 0.0 && !n.is_nan(),
+            Val::String(ref s) => !s.is_empty(),
+            Val::Boolean(b) => b,
+        }
+    }
+
+    fn compare(&self, op: Op, a: &Val, b: &Val) -> bool {
+        match (a, b) {
+            (Val::Nodes(a), Val::Nodes(b)) => a.iter().any(|&x| {
+                let x = Val::String(self.item(x).text());
+                b.iter()
+                    .any(|&y| self.compare(op, &x, &Val::String(self.item(y).text())))
+            }),
+            (Val::Nodes(_), Val::Boolean(_)) | (Val::Boolean(_), Val::Nodes(_)) => self
+                .compare_atoms(
+                    op,
+                    &Val::Boolean(self.boolean(a)),
+                    &Val::Boolean(self.boolean(b)),
+                ),
+            (Val::Nodes(nodes), other) => nodes
+                .iter()
+                .any(|&x| self.compare_atoms(op, &Val::String(self.item(x).text()), other)),
+            (other, Val::Nodes(nodes)) => nodes
+                .iter()
+                .any(|&y| self.compare_atoms(op, other, &Val::String(self.item(y).text()))),
+            (a, b) => self.compare_atoms(op, a, b),
+        }
+    }
+
+    fn compare_atoms(&self, op: Op, a: &Val, b: &Val) -> bool {
+        match op {
+            Op::Eq | Op::Ne => {
+                let equal = match (a, b) {
+                    (Val::Boolean(_), _) | (_, Val::Boolean(_)) => {
+                        self.boolean(a) == self.boolean(b)
+                    }
+                    (Val::Number(_), _) | (_, Val::Number(_)) => self.number(a) == self.number(b),
+                    _ => self.string(a) == self.string(b),
+                };
+                equal == (op == Op::Eq)
+            }
+            Op::Lt => self.number(a) < self.number(b),
+            Op::Le => self.number(a) <= self.number(b),
+            Op::Gt => self.number(a) > self.number(b),
+            Op::Ge => self.number(a) >= self.number(b),
+        }
+    }
+}
+
+fn string_to_number(s: &str) -> f64 {
+    let s = s.trim();
+    if s.is_empty() || s.contains(|c: char| !c.is_ascii_digit() && c != '.' && c != '-') {
+        return f64::NAN;
+    }
+    s.parse().unwrap_or(f64::NAN)
+}
+
+fn number_to_string(n: f64) -> String {
+    if n.is_infinite() {
+        if n > 0.0 { "Infinity" } else { "-Infinity" }.into()
+    } else {
+        n.to_string()
+    }
+}
@@ -0,0 +1,556 @@
+//! Serializing Rust types to XML with serde.
+//!
+//! This uses the same conventions as the [`de`](crate::de) module: a struct
+//! or map is written as an element, where keys starting with `@` are
+//! attributes, `$text` is character data, and other keys are child
+//! elements. A sequence is written as repeated elements with the same name,
+//! and `None` isn't written at all.
+//!
+//! Nothing is written for an empty sequence either, so a sequence field
+//! that may be empty needs `#[serde(default)]` to be deserialized again.
+//! Otherwise, it's a `missing field` error, which can't be told apart from
+//! an element that's really missing.
+//!
+//! Output is written as it's serialized, without buffering, so attributes
+//! have to come before the character data and child elements of their
+//! element. An attribute after them is an [`Error::Unsupported`], so fields
+//! for attributes should be declared first.
+//!
+//! An enum is written with its variant as the element's name if it's the
+//! root value or in a `$value` field, and as character data otherwise, in
+//! which case only unit variants are supported.
+
+use crate::writer::Writer;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Write};
+use serde::ser::{self, Impossible, Serialize};
+
+/// An error from serializing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The output returned an error.
+    Write,
+    /// The value can't be represented in XML with these conventions.
+    Unsupported(&'static str),
+    /// Any other error, usually reported by the type being serialized.
+    Custom(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Write => f.write_str("failed to write output"),
+            Error::Unsupported(what) => write!(f, "{} can't be serialized here", what),
+            Error::Custom(ref msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl ser::StdError for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Self {
+        Error::Write
+    }
+}
+
+/// Serializes a value as the root element of a document, with the given
+/// name unless it's an enum.
+pub fn to_string<T: Serialize + ?Sized>(value: &T, name: &str) -> Result<String, Error> {
+    let mut writer = Writer::new(String::new());
+    to_writer(&mut writer, value, name)?;
+    Ok(writer.into_inner())
+}
+
+/// Serializes a value as an element with the given name unless it's an enum,
+/// writing it to `writer`.
+pub fn to_writer<W: Write, T: Serialize + ?Sized>(
+    writer: &mut Writer<W>,
+    value: &T,
+    name: &str,
+) -> Result<(), Error> {
+    value.serialize(Element {
+        out: writer,
+        name,
+        by_name: true,
+    })
+}
+
+/// A value to be written as an element.
+struct Element<'w, W> {
+    out: &'w mut Writer<W>,
+    name: &'w str,
+    /// Whether this is the root element or in a `$value` field, so enums
+    /// use the element's name.
+    by_name: bool,
+}
+
+impl<'w, W: Write> Element<'w, W> {
+    fn text(self, text: impl Display) -> Result<(), Error> {
+        if self.name == "$value" {
+            self.out.text_display(text)?;
+        } else {
+            self.out.open(self.name)?;
+            self.out.text_display(text)?;
+            self.out.close(self.name)?;
+        }
+        Ok(())
+    }
+
+    fn content(self, name: &'w str) -> Result<Content<'w, W>, Error> {
+        self.out.open(name)?;
+        Ok(Content {
+            out: self.out,
+            name,
+            key: None,
+        })
+    }
+}
+
+macro_rules! serialize_display {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Self::Ok, Error> {
+                self.text(v)
+            }
+        )*
+    };
+}
+
+impl<'w, W: Write> ser::Serializer for Element<'w, W> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Seq<'w, W>;
+    type SerializeTuple = Seq<'w, W>;
+    type SerializeTupleStruct = Seq<'w, W>;
+    type SerializeTupleVariant = Seq<'w, W>;
+    type SerializeMap = Content<'w, W>;
+    type SerializeStruct = Content<'w, W>;
+    type SerializeStructVariant = Content<'w, W>;
+
+    serialize_display! {
+        serialize_bool(bool) serialize_i8(i8) serialize_i16(i16)
+        serialize_i32(i32) serialize_i64(i64) serialize_i128(i128)
+        serialize_u8(u8) serialize_u16(u16) serialize_u32(u32)
+        serialize_u64(u64) serialize_u128(u128) serialize_f32(f32)
+        serialize_f64(f64) serialize_char(char) serialize_str(&str)
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), Error> {
+        Err(Error::Unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        if self.name != "$value" {
+            self.out.open(self.name)?;
+            self.out.close(self.name)?;
+        }
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        if self.by_name {
+            self.out.open(variant)?;
+            self.out.close(variant)?;
+            Ok(())
+        } else {
+            self.text(variant)
+        }
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        if !self.by_name {
+            return Err(Error::Unsupported("a newtype variant"));
+        }
+        value.serialize(Element {
+            out: self.out,
+            name: variant,
+            by_name: false,
+        })
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Seq<'w, W>, Error> {
+        Ok(Seq {
+            out: self.out,
+            name: self.name,
+            by_name: self.by_name,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Seq<'w, W>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Seq<'w, W>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Seq<'w, W>, Error> {
+        if !self.by_name {
+            return Err(Error::Unsupported("a tuple variant"));
+        }
+        Ok(Seq {
+            out: self.out,
+            name: variant,
+            by_name: false,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Content<'w, W>, Error> {
+        let name = self.name;
+        self.content(name)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Content<'w, W>, Error> {
+        let name = self.name;
+        self.content(name)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Content<'w, W>, Error> {
+        if !self.by_name {
+            return Err(Error::Unsupported("a struct variant"));
+        }
+        self.content(variant)
+    }
+}
+
+/// Repeated elements with the same name.
+struct Seq<'w, W> {
+    out: &'w mut Writer<W>,
+    name: &'w str,
+    by_name: bool,
+}
+
+impl<'w, W: Write> Seq<'w, W> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(Element {
+            out: self.out,
+            name: self.name,
+            by_name: self.by_name,
+        })
+    }
+}
+
+impl<'w, W: Write> ser::SerializeSeq for Seq<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'w, W: Write> ser::SerializeTuple for Seq<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'w, W: Write> ser::SerializeTupleStruct for Seq<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'w, W: Write> ser::SerializeTupleVariant for Seq<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// The attributes and content of an element, whose opening tag has been
+/// started.
+struct Content<'w, W> {
+    out: &'w mut Writer<W>,
+    name: &'w str,
+    key: Option<String>,
+}
+
+impl<'w, W: Write> Content<'w, W> {
+    fn field<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), Error> {
+        if let Some(name) = key.strip_prefix('@') {
+            if let Some(value) = value.serialize(TextSerializer)? {
+                if !self.out.in_start_tag() {
+                    return Err(Error::Unsupported("an attribute after content"));
+                }
+                self.out.attr(name, value.as_str())?;
+            }
+        } else if key == "$text" {
+            match value.serialize(TextSerializer)? {
+                Some(value) if !value.is_empty() => self.out.text(value.as_str())?,
+                _ => {}
+            }
+        } else {
+            value.serialize(Element {
+                out: &mut *self.out,
+                name: key,
+                by_name: key == "$value",
+            })?;
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), Error> {
+        self.out.close(self.name)?;
+        Ok(())
+    }
+}
+
+impl<'w, W: Write> ser::SerializeMap for Content<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        let key = key
+            .serialize(TextSerializer)?
+            .ok_or(Error::Unsupported("a key of `None`"))?;
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.field(&key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<'w, W: Write> ser::SerializeStruct for Content<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<'w, W: Write> ser::SerializeStructVariant for Content<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+/// Serializes character data, an attribute value or a key, returning
+/// `None` for `None`.
+struct TextSerializer;
+
+macro_rules! serialize_to_string {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Option<String>, Error> {
+                Ok(Some(v.to_string()))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for TextSerializer {
+    type Ok = Option<String>;
+    type Error = Error;
+    type SerializeSeq = Impossible<Option<String>, Error>;
+    type SerializeTuple = Impossible<Option<String>, Error>;
+    type SerializeTupleStruct = Impossible<Option<String>, Error>;
+    type SerializeTupleVariant = Impossible<Option<String>, Error>;
+    type SerializeMap = Impossible<Option<String>, Error>;
+    type SerializeStruct = Impossible<Option<String>, Error>;
+    type SerializeStructVariant = Impossible<Option<String>, Error>;
+
+    serialize_to_string! {
+        serialize_bool(bool) serialize_i8(i8) serialize_i16(i16)
+        serialize_i32(i32) serialize_i64(i64) serialize_i128(i128)
+        serialize_u8(u8) serialize_u16(u16) serialize_u32(u32)
+        serialize_u64(u64) serialize_u128(u128) serialize_f32(f32)
+        serialize_f64(f64) serialize_char(char) serialize_str(&str)
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Option<String>, Error> {
+        Err(Error::Unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Option<String>, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Option<String>, Error> {
+        Ok(Some(String::new()))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Option<String>, Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Option<String>, Error> {
+        Ok(Some(variant.into()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Option<String>, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Option<String>, Error> {
+        Err(Error::Unsupported("a newtype variant"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Err(Error::Unsupported("a sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        Err(Error::Unsupported("a tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(Error::Unsupported("a tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(Error::Unsupported("a tuple variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Err(Error::Unsupported("a map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Err(Error::Unsupported("a struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(Error::Unsupported("a struct variant"))
+    }
+}
@@ -0,0 +1,261 @@
+//! Path matching over the event stream.
+//!
+//! A [`Matcher`] runs a [`Pattern`] over a [`Parser`] without building a
+//! tree, yielding the events of matched elements as they pass by. It keeps
+//! one word of state per level of element nesting, in storage provided by
+//! the caller, so it doesn't allocate and works on documents of any size.
+//!
+//! Patterns are a small subset of XPath: a sequence of steps, each preceded
+//! by `/` for a child or `//` for a descendant, where a step is an element
+//! name or `*`, optionally followed by predicates like `[@name]` or
+//! `[@type='fd']`, with optional whitespace around the `=`. For example,
+//! `/protocol/interface/request` or
+//! `//interface[@name='wl_surface']//arg[@type='fd']`.
+
+use crate::{Attrs, Event, Parser, WHITESPACE};
+use core::fmt::{self, Display};
+
+/// A syntax error in a pattern.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Error {
+    offset: usize,
+}
+
+impl Error {
+    /// Returns the byte offset of the error in the pattern.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid pattern at offset {}", self.offset)
+    }
+}
+
+/// A path pattern.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pattern<'p> {
+    text: &'p str,
+    len: usize,
+}
+
+impl<'p> Pattern<'p> {
+    /// The maximum number of steps in a pattern.
+    pub const MAX_STEPS: usize = 63;
+
+    /// Checks the syntax of a pattern.
+    pub fn new(text: &'p str) -> Result<Self, Error> {
+        let mut steps = Steps { text, rest: text };
+        let mut len = 0;
+        while !steps.rest.is_empty() {
+            if len == Pattern::MAX_STEPS {
+                return Err(steps.error());
+            }
+            steps.next_step()?;
+            len += 1;
+        }
+        if len == 0 {
+            return Err(steps.error());
+        }
+        Ok(Pattern { text, len })
+    }
+
+    fn steps(&self) -> impl Iterator<Item = Step<'p>> {
+        let mut steps = Steps {
+            text: self.text,
+            rest: self.text,
+        };
+        (0..self.len).map(move |_| steps.next_step().unwrap())
+    }
+}
+
+struct Step<'p> {
+    descendant: bool,
+    name: &'p str,
+    predicates: &'p str,
+}
+
+impl<'p> Step<'p> {
+    fn matches(&self, name: &str, attrs: &Attrs) -> bool {
+        if self.name != "*" && self.name != name {
+            return false;
+        }
+        let mut rest = self.predicates;
+        while let Some(start) = rest.strip_prefix("[@") {
+            let end = start.find(']').unwrap();
+            rest = &start[end + 1..];
+            let (attr, expected) = split_predicate(&start[..end]).unwrap();
+            let matches = match expected {
+                Some(expected) => attrs
+                    .get(attr)
+                    .is_some_and(|value| value.eq(expected.chars())),
+                None => attrs.get(attr).is_some(),
+            };
+            if !matches {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+struct Steps<'p> {
+    text: &'p str,
+    rest: &'p str,
+}
+
+impl<'p> Steps<'p> {
+    fn error(&self) -> Error {
+        Error {
+            offset: self.text.len() - self.rest.len(),
+        }
+    }
+
+    fn next_step(&mut self) -> Result<Step<'p>, Error> {
+        let descendant = if let Some(rest) = self.rest.strip_prefix("//") {
+            self.rest = rest;
+            true
+        } else if let Some(rest) = self.rest.strip_prefix('/') {
+            self.rest = rest;
+            false
+        } else {
+            return Err(self.error());
+        };
+        let name_len = self.rest.find(['/', '[']).unwrap_or(self.rest.len());
+        let name = &self.rest[..name_len];
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(self.error());
+        }
+        self.rest = &self.rest[name_len..];
+        let predicates = self.rest;
+        while let Some(rest) = self.rest.strip_prefix('[') {
+            let rest = rest.strip_prefix('@').ok_or_else(|| self.error())?;
+            let end = rest.find(']').ok_or_else(|| self.error())?;
+            split_predicate(&rest[..end]).ok_or_else(|| self.error())?;
+            self.rest = &rest[end + 1..];
+        }
+        let predicates = &predicates[..predicates.len() - self.rest.len()];
+        Ok(Step {
+            descendant,
+            name,
+            predicates,
+        })
+    }
+}
+
+/// Splits a predicate into the attribute name and the unquoted value, if it
+/// has one, allowing whitespace around the `=`.
+fn split_predicate(predicate: &str) -> Option<(&str, Option<&str>)> {
+    let (attr, value) = match predicate.find('=') {
+        Some(eq) => {
+            let value = predicate[eq + 1..].trim_matches(WHITESPACE);
+            let quoted = value.len() >= 2
+                && (value.starts_with('\'') || value.starts_with('"'))
+                && value.ends_with(&value[..1]);
+            if !quoted {
+                return None;
+            }
+            (
+                predicate[..eq].trim_end_matches(WHITESPACE),
+                Some(&value[1..value.len() - 1]),
+            )
+        }
+        None => (predicate, None),
+    };
+    if attr.is_empty() || attr.contains(WHITESPACE) {
+        return None;
+    }
+    Some((attr, value))
+}
+
+/// An iterator over the events of elements matching a [`Pattern`].
+///
+/// Each event is yielded with its depth relative to the matched element, so
+/// the matched element's own [`Event::Open`] and [`Event::Close`] have a
+/// depth of zero. The events of matches nested inside another match are
+/// only yielded once, as part of the outer match.
+///
+/// The stream ends early if the document is nested more deeply than the
+/// length of the stack storage.
+pub struct Matcher<'a, 'p, 's> {
+    parser: Parser<'a>,
+    pattern: Pattern<'p>,
+    stack: &'s mut [u64],
+    depth: usize,
+    /// The depth of the current match, if inside one.
+    matched: Option<usize>,
+}
+
+impl<'a, 'p, 's> Matcher<'a, 'p, 's> {
+    /// Creates a new matcher, which uses `stack` to store one word of state
+    /// per level of element nesting.
+    pub fn new(parser: Parser<'a>, pattern: Pattern<'p>, stack: &'s mut [u64]) -> Self {
+        Matcher {
+            parser,
+            pattern,
+            stack,
+            depth: 0,
+            matched: None,
+        }
+    }
+
+    /// Returns the underlying parser.
+    pub fn into_parser(self) -> Parser<'a> {
+        self.parser
+    }
+
+    fn open(&mut self, name: &str, attrs: &Attrs) -> Option<bool> {
+        let states = match self.depth {
+            0 => 1,
+            depth => self.stack[depth - 1],
+        };
+        let mut next = 0;
+        for (i, step) in self.pattern.steps().enumerate() {
+            if states & (1 << i) == 0 {
+                continue;
+            }
+            if step.descendant {
+                next |= 1 << i;
+            }
+            if step.matches(name, attrs) {
+                next |= 1 << (i + 1);
+            }
+        }
+        *self.stack.get_mut(self.depth)? = next;
+        self.depth += 1;
+        Some(next & (1 << self.pattern.len) != 0)
+    }
+}
+
+impl<'a, 'p, 's> Iterator for Matcher<'a, 'p, 's> {
+    type Item = (usize, Event<'a>);
+
+    fn next(&mut self) -> Option<(usize, Event<'a>)> {
+        loop {
+            let event = self.parser.next()?;
+            let relative = match event {
+                Event::Open(name, ref attrs) => {
+                    let depth = self.depth;
+                    if self.open(name, attrs)? && self.matched.is_none() {
+                        self.matched = Some(depth);
+                    }
+                    self.matched.map(|m| depth - m)
+                }
+                Event::Close(_) => {
+                    self.depth = self.depth.checked_sub(1)?;
+                    let relative = self.matched.map(|m| self.depth - m);
+                    if relative == Some(0) {
+                        self.matched = None;
+                    }
+                    relative
+                }
+                _ => self.matched.map(|m| self.depth - m),
+            };
+            if let Some(relative) = relative {
+                return Some((relative, event));
+            }
+        }
+    }
+}
@@ -0,0 +1,1075 @@
+//! The [Vulkan](https://www.vulkan.org/) API registry, `vk.xml`.
+//!
+//! [`parse`] reads the registry into a [`Registry`]: the types, enums,
+//! commands, API versions, extensions and formats it defines. C
+//! declarations in `<type>`, `<member>`, `<param>` and `<proto>` elements
+//! are written as text with `<type>` and `<name>` tags around the
+//! interesting parts, such as
+//! `const <type>char</type>* const* <name>ppEnabledLayerNames</name>`, so
+//! they're split into a [`Member`] with the type name, qualifiers, array
+//! sizes and bit width, along with the declaration as written. Unknown
+//! elements are skipped, so newer registries still parse.
+//!
+//! ```ignore
+//! let registry = txml::vulkan::parse(&xml)?;
+//! for command in &registry.commands {
+//!     println!("{} {}", command.return_type, command.name);
+//! }
+//! ```
+
+use crate::check;
+use crate::{AttrError, Attrs, Event, ParseError, Parser, Text};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+/// The registry, the root `<registry>` element.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Registry {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The types, from `<types>`.
+    pub types: Vec<Type>,
+    /// The groups of enumerants and constants.
+    pub enums: Vec<Enums>,
+    /// The commands, from `<commands>`.
+    pub commands: Vec<Command>,
+    /// The API versions, such as `VK_VERSION_1_0`.
+    pub features: Vec<Feature>,
+    /// The extensions, from `<extensions>`.
+    pub extensions: Vec<Extension>,
+    /// The image formats, from `<formats>`.
+    pub formats: Vec<Format>,
+}
+
+/// A `<type>` in `<types>`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Type {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The name of the type, from the `name` attribute or the `<name>`
+    /// element.
+    pub name: String,
+    /// The kind of type. Types without one are defined in other headers,
+    /// such as `uint32_t` or `Display`.
+    pub category: Option<Category>,
+    /// The APIs the definition is for, such as `vulkan` or `vulkansc`. Empty
+    /// if it's for all of them.
+    pub api: Vec<String>,
+    /// The type this one is an alias of.
+    pub alias: Option<String>,
+    /// The type or header this one depends on, such as the `FlagBits` enum
+    /// of a bitmask.
+    pub requires: Option<String>,
+    /// The `FlagBits` enum of a 64-bit bitmask.
+    pub bit_values: Option<String>,
+    /// The parent handles of a handle.
+    pub parent: Vec<String>,
+    /// The first type in the definition, such as `VkFlags` for a bitmask or
+    /// `VK_DEFINE_HANDLE` for a handle. For a function pointer it's the
+    /// return type as written, such as `VkBool32` or `void*`.
+    pub base: Option<String>,
+    /// The C code of the definition with the tags removed, for categories
+    /// that aren't made of members.
+    pub code: String,
+    /// Whether a struct is only returned by the implementation.
+    pub returned_only: bool,
+    /// The structs whose `pNext` chain this struct can be in.
+    pub struct_extends: Vec<String>,
+    /// The members of a struct or union.
+    pub members: Vec<Member>,
+    /// The comment attribute.
+    pub comment: Option<String>,
+}
+
+/// The `category` of a [`Type`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Category {
+    /// A header to include.
+    Include,
+    /// A preprocessor definition.
+    Define,
+    /// A typedef of a platform type, such as `VkFlags`.
+    BaseType,
+    /// A typedef of `VkFlags` or `VkFlags64`.
+    Bitmask,
+    /// A dispatchable or non-dispatchable handle.
+    Handle,
+    /// An enum, whose values are in [`Registry::enums`].
+    Enum,
+    /// A function pointer typedef.
+    FuncPointer,
+    /// A struct.
+    Struct,
+    /// A union.
+    Union,
+    /// A category this module doesn't know, such as one added by a later
+    /// registry, with its name as written.
+    Other(String),
+}
+
+impl FromStr for Category {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Infallible> {
+        Ok(match s {
+            "include" => Category::Include,
+            "define" => Category::Define,
+            "basetype" => Category::BaseType,
+            "bitmask" => Category::Bitmask,
+            "handle" => Category::Handle,
+            "enum" => Category::Enum,
+            "funcpointer" => Category::FuncPointer,
+            "struct" => Category::Struct,
+            "union" => Category::Union,
+            _ => Category::Other(String::from(s)),
+        })
+    }
+}
+
+/// A C declaration: a struct `<member>`, or a command `<param>` or
+/// `<proto>`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Member {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The name of the member or parameter.
+    pub name: String,
+    /// The name of the type, without qualifiers or pointers.
+    pub type_name: String,
+    /// The declaration with the tags removed and whitespace collapsed, such
+    /// as `const char* const* ppEnabledLayerNames`.
+    pub code: String,
+    /// Whether the type is qualified with `const`.
+    pub is_const: bool,
+    /// The number of pointers, counting the `*` between the type and the
+    /// name.
+    pub pointers: usize,
+    /// The array sizes, such as `4` or `VK_UUID_SIZE`, outermost first.
+    pub array: Vec<String>,
+    /// The width of a bitfield.
+    pub bits: Option<u32>,
+    /// Whether the value, then each level of pointers, may be zero or null.
+    pub optional: Vec<bool>,
+    /// The length of each level of pointers, such as `enabledLayerCount` or
+    /// `null-terminated`.
+    pub len: Vec<String>,
+    /// The only value the member can have, such as the `sType` of a struct.
+    pub values: Option<String>,
+    /// Whether the object must be externally synchronized, or an expression
+    /// for the parts of it that must be.
+    pub extern_sync: Option<String>,
+    /// The APIs the declaration is for. Empty if it's for all of them.
+    pub api: Vec<String>,
+    /// Whether the implicit valid usage statements are written by hand.
+    pub no_auto_validity: bool,
+    /// The `<comment>` inside the declaration.
+    pub comment: Option<String>,
+}
+
+/// A group of enumerants or constants, an `<enums>` element.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Enums {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The name of the group: the enum type, or `API Constants`.
+    pub name: String,
+    /// What the values are.
+    pub kind: EnumsKind,
+    /// The width of a bitmask in bits. Defaults to 32.
+    pub bit_width: u32,
+    /// The comment attribute.
+    pub comment: Option<String>,
+    /// The values, in order.
+    pub values: Vec<Enum>,
+}
+
+/// The `type` of an [`Enums`] group.
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq)]
+pub enum EnumsKind {
+    /// Constants, such as `VK_UUID_SIZE`. The default, when there's no
+    /// `type`.
+    #[default]
+    Constants,
+    /// The values of an enum type.
+    Enum,
+    /// The bits of a `FlagBits` type.
+    Bitmask,
+}
+
+impl FromStr for EnumsKind {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Ok(match s {
+            "constants" => EnumsKind::Constants,
+            "enum" => EnumsKind::Enum,
+            "bitmask" => EnumsKind::Bitmask,
+            _ => return Err(ParseError::new("unknown enums type")),
+        })
+    }
+}
+
+/// An `<enum>`: a value in an [`Enums`] group, or one added or referenced by
+/// a [`Require`].
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Enum {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The name of the enumerant or constant.
+    pub name: String,
+    /// The value. `None` if a [`Require`] only references the name.
+    pub value: Option<Value>,
+    /// The enum type that a [`Require`] adds the value to.
+    pub extends: Option<String>,
+    /// The C type of a constant, such as `uint32_t`.
+    pub type_name: Option<String>,
+    /// The APIs the value is for. Empty if it's for all of them.
+    pub api: Vec<String>,
+    /// The comment attribute.
+    pub comment: Option<String>,
+}
+
+/// The value of an [`Enum`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    /// A C expression, from `value`, such as `256`, `(~0U)` or
+    /// `"VK_KHR_surface"`.
+    Literal(String),
+    /// A single bit, from `bitpos`.
+    BitPos(u32),
+    /// An offset into the range of values reserved for an extension, from
+    /// `offset`, `extnumber` and `dir`.
+    Offset {
+        /// The extension number, if it's not the extension the value is
+        /// defined in.
+        extension: Option<u32>,
+        /// The offset in the range.
+        offset: u32,
+        /// Whether the value is negated, from `dir="-"`.
+        negative: bool,
+    },
+    /// Another enumerant with the same value, from `alias`.
+    Alias(String),
+}
+
+impl Value {
+    /// Returns the value as an integer. `extension` is the number of the
+    /// extension the value is defined in, used for [`Value::Offset`] values
+    /// without their own extension number.
+    ///
+    /// Returns `None` for aliases, and literals that aren't decimal or
+    /// hexadecimal integers, such as `(~0U)` or `1000.0F`.
+    pub fn to_i64(&self, extension: Option<u32>) -> Option<i64> {
+        match *self {
+            Value::Literal(ref literal) => {
+                let (negative, digits) = match literal.strip_prefix('-') {
+                    Some(digits) => (true, digits),
+                    None => (false, &literal[..]),
+                };
+                let value = match digits.strip_prefix("0x") {
+                    Some(hex) => i64::from_str_radix(hex, 16).ok()?,
+                    None => digits.parse().ok()?,
+                };
+                Some(if negative { -value } else { value })
+            }
+            Value::BitPos(bit) => 1i64.checked_shl(bit),
+            Value::Offset {
+                extension: number,
+                offset,
+                negative,
+            } => {
+                let number = i64::from(number.or(extension)?);
+                let value = 1_000_000_000 + (number - 1) * 1000 + i64::from(offset);
+                Some(if negative { -value } else { value })
+            }
+            Value::Alias(_) => None,
+        }
+    }
+}
+
+/// A `<command>`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Command {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The name of the command, from the `<proto>` or the `name` attribute
+    /// of an alias.
+    pub name: String,
+    /// The command this one is an alias of. Aliases have no prototype or
+    /// parameters.
+    pub alias: Option<String>,
+    /// The APIs the definition is for. Empty if it's for all of them.
+    pub api: Vec<String>,
+    /// The return type, from the `<proto>`.
+    pub return_type: String,
+    /// The parameters.
+    pub params: Vec<Member>,
+    /// The `VkResult` values returned on success.
+    pub success_codes: Vec<String>,
+    /// The `VkResult` values returned on failure.
+    pub error_codes: Vec<String>,
+    /// The queue types a command buffer command can be recorded for.
+    pub queues: Vec<String>,
+}
+
+/// A `<feature>`: a version of an API, such as `VK_VERSION_1_0`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Feature {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The name of the version.
+    pub name: String,
+    /// The APIs it's a version of.
+    pub api: Vec<String>,
+    /// The version number, such as `1.0`.
+    pub number: String,
+    /// The versions it depends on, as an expression.
+    pub depends: Option<String>,
+    /// The comment attribute.
+    pub comment: Option<String>,
+    /// The interfaces it adds.
+    pub require: Vec<Require>,
+    /// The interfaces it removes.
+    pub remove: Vec<Require>,
+}
+
+/// An `<extension>`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Extension {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The name of the extension, such as `VK_KHR_surface`.
+    pub name: String,
+    /// The extension number, which determines the range of its enumerant
+    /// values.
+    pub number: u32,
+    /// Whether it's an instance or device extension.
+    pub kind: Option<ExtensionKind>,
+    /// The author ID, such as `KHR`.
+    pub author: Option<String>,
+    /// The APIs it's supported by, or `disabled`.
+    pub supported: Vec<String>,
+    /// The versions and extensions it depends on, as an expression.
+    pub depends: Option<String>,
+    /// The platform it's for, such as `win32`.
+    pub platform: Option<String>,
+    /// The version or extension it was promoted to.
+    pub promoted_to: Option<String>,
+    /// The extension that deprecated it.
+    pub deprecated_by: Option<String>,
+    /// The extension that made it obsolete.
+    pub obsoleted_by: Option<String>,
+    /// Whether it's provisional.
+    pub provisional: bool,
+    /// The interfaces it adds.
+    pub require: Vec<Require>,
+    /// The interfaces it removes.
+    pub remove: Vec<Require>,
+}
+
+/// The `type` of an [`Extension`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExtensionKind {
+    /// An instance extension.
+    Instance,
+    /// A device extension.
+    Device,
+}
+
+impl FromStr for ExtensionKind {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "instance" => Ok(ExtensionKind::Instance),
+            "device" => Ok(ExtensionKind::Device),
+            _ => Err(ParseError::new("unknown extension type")),
+        }
+    }
+}
+
+/// A `<require>` or `<remove>` in a [`Feature`] or [`Extension`].
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Require {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The comment attribute.
+    pub comment: Option<String>,
+    /// The versions and extensions that must also be present, as an
+    /// expression.
+    pub depends: Option<String>,
+    /// The APIs it applies to. Empty if it applies to all of them.
+    pub api: Vec<String>,
+    /// The names of the types.
+    pub types: Vec<String>,
+    /// The names of the commands.
+    pub commands: Vec<String>,
+    /// The enumerants and constants, which may be defined here.
+    pub enums: Vec<Enum>,
+}
+
+/// A `<format>`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Format {
+    /// The opening tag in the registry file.
+    pub span: Span,
+    /// The name of the format, such as `VK_FORMAT_R8G8B8A8_UNORM`.
+    pub name: String,
+    /// The compatibility class, such as `32-bit`.
+    pub class: String,
+    /// The size of a texel block in bytes.
+    pub block_size: u32,
+    /// The number of texels in a texel block.
+    pub texels_per_block: u32,
+    /// The width, height and depth of a texel block, for compressed formats.
+    pub block_extent: Vec<u32>,
+    /// The number of bits the components are packed into.
+    pub packed: Option<u32>,
+    /// The compression scheme, such as `BC` or `ASTC LDR`.
+    pub compressed: Option<String>,
+    /// The chroma subsampling, such as `420`.
+    pub chroma: Option<String>,
+    /// The components, in memory order.
+    pub components: Vec<Component>,
+    /// The planes of a multi-planar format.
+    pub planes: Vec<Plane>,
+    /// The name of the matching SPIR-V image format, such as `Rgba8`.
+    pub spirv_image_format: Option<String>,
+}
+
+/// A `<component>` of a [`Format`].
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Component {
+    /// The name: `R`, `G`, `B`, `A`, `D` or `S`.
+    pub name: String,
+    /// The number of bits. `None` for compressed formats.
+    pub bits: Option<u32>,
+    /// The numeric format, such as `UNORM`.
+    pub numeric_format: String,
+    /// The plane the component is in, for multi-planar formats.
+    pub plane_index: Option<u32>,
+}
+
+/// A `<plane>` of a multi-planar [`Format`].
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Plane {
+    /// The index of the plane.
+    pub index: u32,
+    /// The factor the width of the plane is divided by.
+    pub width_divisor: u32,
+    /// The factor the height of the plane is divided by.
+    pub height_divisor: u32,
+    /// A single-plane format compatible with the plane.
+    pub compatible: String,
+}
+
+/// A range of bytes in a registry file.
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq)]
+pub struct Span {
+    /// The offset of the first byte.
+    pub start: usize,
+    /// The offset after the last byte.
+    pub end: usize,
+}
+
+impl Span {
+    /// Returns the one-based line and column of the start of the span in
+    /// `doc`, which must be the parsed document. Columns count characters.
+    pub fn line_column(&self, doc: &str) -> (usize, usize) {
+        check::line_column(doc, self.start)
+    }
+}
+
+/// An error from parsing a registry, with the byte offset in the document
+/// where it was found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error<'a> {
+    offset: usize,
+    kind: ErrorKind<'a>,
+}
+
+impl<'a> Error<'a> {
+    /// Returns the byte offset of the error in the document.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns what went wrong.
+    pub fn kind(&self) -> &ErrorKind<'a> {
+        &self.kind
+    }
+
+    /// Returns the one-based line and column of the error in `doc`, which
+    /// must be the document that was parsed. Columns count characters.
+    pub fn line_column(&self, doc: &str) -> (usize, usize) {
+        check::line_column(doc, self.offset)
+    }
+}
+
+impl<'a> Display for Error<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+/// The kind of a registry parsing [`Error`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind<'a> {
+    /// The document is not well-formed.
+    Malformed(check::ErrorKind<'a>),
+    /// The root element isn't `<registry>`.
+    NotRegistry(&'a str),
+    /// An attribute is missing or invalid.
+    Attr(AttrError<'a>),
+    /// The named element was found inside text, such as a comment.
+    UnexpectedElement(&'a str),
+    /// The named element has neither a `name` attribute nor a `<name>`.
+    MissingName(&'a str),
+}
+
+impl<'a> Display for ErrorKind<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ErrorKind::Malformed(ref kind) => Display::fmt(kind, f),
+            ErrorKind::NotRegistry(name) => {
+                write!(f, "expected a `registry` element, found `{}`", name)
+            }
+            ErrorKind::Attr(ref err) => Display::fmt(err, f),
+            ErrorKind::UnexpectedElement(name) => write!(f, "unexpected element `{}`", name),
+            ErrorKind::MissingName(name) => write!(f, "`{}` element has no name", name),
+        }
+    }
+}
+
+/// Parses a registry file.
+pub fn parse(doc: &str) -> Result<Registry, Error<'_>> {
+    check::check(doc).map_err(|err| Error {
+        offset: err.offset(),
+        kind: ErrorKind::Malformed(err.kind()),
+    })?;
+    let mut ctx = ParseContext {
+        doc,
+        parser: Parser::new(doc),
+    };
+    loop {
+        if let Some(tag) = ctx.tag()? {
+            if tag.name != "registry" {
+                return Err(ctx.error(tag.name, ErrorKind::NotRegistry(tag.name)));
+            }
+            return ctx.registry(tag);
+        }
+    }
+}
+
+/// An opening tag.
+struct Tag<'a> {
+    name: &'a str,
+    attrs: Attrs<'a>,
+    span: Span,
+}
+
+/// The parts of a C declaration, split around its `<type>` and `<name>`.
+#[derive(Default)]
+struct Declaration {
+    type_name: String,
+    name: String,
+    /// The text before the end of the `<type>`, the text before the end of
+    /// the `<name>`, and the text after it.
+    parts: [String; 3],
+    code: String,
+    comment: Option<String>,
+}
+
+struct ParseContext<'a> {
+    doc: &'a str,
+    parser: Parser<'a>,
+}
+
+impl<'a> ParseContext<'a> {
+    /// Returns an error at the start of `s`, which must be part of the
+    /// document.
+    fn error(&self, s: &str, kind: ErrorKind<'a>) -> Error<'a> {
+        Error {
+            offset: s.as_ptr() as usize - self.doc.as_ptr() as usize,
+            kind,
+        }
+    }
+
+    fn attr_error(&self, tag: &Tag<'a>, err: AttrError<'a>) -> Error<'a> {
+        match err {
+            AttrError::Invalid(_, Text::Escaped(value) | Text::Verbatim(value), _) => {
+                self.error(value, ErrorKind::Attr(err))
+            }
+            _ => self.error(tag.name, ErrorKind::Attr(err)),
+        }
+    }
+
+    fn attr<T: FromStr>(&self, tag: &Tag<'a>, name: &'static str) -> Result<Option<T>, Error<'a>>
+    where
+        T::Err: Display,
+    {
+        tag.attrs
+            .parse(name)
+            .map_err(|err| self.attr_error(tag, err))
+    }
+
+    fn require<T: FromStr>(&self, tag: &Tag<'a>, name: &'static str) -> Result<T, Error<'a>>
+    where
+        T::Err: Display,
+    {
+        self.attr(tag, name)?
+            .ok_or_else(|| self.attr_error(tag, AttrError::Missing(name)))
+    }
+
+    fn flag(&self, tag: &Tag<'a>, name: &'static str) -> Result<bool, Error<'a>> {
+        tag.attrs
+            .parse_bool(name)
+            .map(|flag| flag.unwrap_or(false))
+            .map_err(|err| self.attr_error(tag, err))
+    }
+
+    /// Parses a comma-separated attribute. Missing attributes are empty.
+    fn list<T: FromStr>(&self, tag: &Tag<'a>, name: &'static str) -> Result<Vec<T>, Error<'a>>
+    where
+        T::Err: Display,
+    {
+        let value = match tag.attrs.get(name) {
+            Some(value) => value,
+            None => return Ok(Vec::new()),
+        };
+        let list: String = value.clone().collect();
+        list.split(',')
+            .map(|item| {
+                item.parse().map_err(|err| {
+                    let err = ParseError::new(err);
+                    self.attr_error(tag, AttrError::Invalid(name, value.clone(), err))
+                })
+            })
+            .collect()
+    }
+
+    fn next(&mut self) -> Result<Event<'a>, Error<'a>> {
+        // The document has been checked, so this only fails at the end.
+        let rest = self.parser.doc;
+        self.parser
+            .next()
+            .ok_or_else(|| self.error(rest, ErrorKind::Malformed(check::ErrorKind::Unterminated)))
+    }
+
+    /// Returns the next opening tag, skipping text, comments and processing
+    /// instructions, or `None` at a closing tag.
+    fn tag(&mut self) -> Result<Option<Tag<'a>>, Error<'a>> {
+        loop {
+            let start = self.doc.len() - self.parser.doc.len();
+            match self.next()? {
+                Event::Open(name, attrs) => {
+                    let end = self.doc.len() - self.parser.doc.len();
+                    let span = Span { start, end };
+                    return Ok(Some(Tag { name, attrs, span }));
+                }
+                Event::Close(..) => return Ok(None),
+                Event::Text(..) | Event::Comment(..) | Event::Pi(..) | Event::Doctype(..) => {}
+            }
+        }
+    }
+
+    fn skip(&mut self) -> Result<(), Error<'a>> {
+        while self.tag()?.is_some() {
+            self.skip()?;
+        }
+        Ok(())
+    }
+
+    /// Reads the character data of the current element.
+    fn text(&mut self) -> Result<String, Error<'a>> {
+        let mut text = String::new();
+        loop {
+            match self.next()? {
+                Event::Open(name, _) => {
+                    return Err(self.error(name, ErrorKind::UnexpectedElement(name)))
+                }
+                Event::Close(..) => return Ok(text),
+                Event::Text(t) => text.extend(t),
+                Event::Comment(..) | Event::Pi(..) | Event::Doctype(..) => {}
+            }
+        }
+    }
+
+    /// Reads the mixed content of a C declaration.
+    fn declaration(&mut self) -> Result<Declaration, Error<'a>> {
+        let mut decl = Declaration::default();
+        let mut part = 0;
+        loop {
+            let text = match self.next()? {
+                Event::Open("type", _) => {
+                    let text = self.text()?;
+                    if decl.type_name.is_empty() {
+                        decl.type_name = text.clone();
+                    }
+                    decl.parts[part] += &text;
+                    part = part.max(1);
+                    text
+                }
+                Event::Open("name", _) => {
+                    let text = self.text()?;
+                    decl.name = text.clone();
+                    decl.parts[part] += &text;
+                    part = 2;
+                    text
+                }
+                // Array sizes, such as `[<enum>VK_UUID_SIZE</enum>]`.
+                Event::Open("enum", _) => {
+                    let text = self.text()?;
+                    decl.parts[part] += &text;
+                    text
+                }
+                Event::Open("comment", _) => {
+                    decl.comment = Some(self.text()?);
+                    continue;
+                }
+                Event::Open(..) => {
+                    self.skip()?;
+                    continue;
+                }
+                Event::Close(..) => break,
+                Event::Text(t) => {
+                    let text: String = t.collect();
+                    decl.parts[part] += &text;
+                    text
+                }
+                Event::Comment(..) | Event::Pi(..) | Event::Doctype(..) => continue,
+            };
+            decl.code += &text;
+        }
+        Ok(decl)
+    }
+
+    fn registry(&mut self, tag: Tag<'a>) -> Result<Registry, Error<'a>> {
+        let mut registry = Registry {
+            span: tag.span,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "types" => {
+                    while let Some(tag) = self.tag()? {
+                        match tag.name {
+                            "type" => registry.types.push(self.ty(tag)?),
+                            _ => self.skip()?,
+                        }
+                    }
+                }
+                "enums" => registry.enums.push(self.enums(tag)?),
+                "commands" => {
+                    while let Some(tag) = self.tag()? {
+                        match tag.name {
+                            "command" => registry.commands.push(self.command(tag)?),
+                            _ => self.skip()?,
+                        }
+                    }
+                }
+                "feature" => registry.features.push(self.feature(tag)?),
+                "extensions" => {
+                    while let Some(tag) = self.tag()? {
+                        match tag.name {
+                            "extension" => registry.extensions.push(self.extension(tag)?),
+                            _ => self.skip()?,
+                        }
+                    }
+                }
+                "formats" => {
+                    while let Some(tag) = self.tag()? {
+                        match tag.name {
+                            "format" => registry.formats.push(self.format(tag)?),
+                            _ => self.skip()?,
+                        }
+                    }
+                }
+                _ => self.skip()?,
+            }
+        }
+        Ok(registry)
+    }
+
+    fn ty(&mut self, tag: Tag<'a>) -> Result<Type, Error<'a>> {
+        let mut ty = Type {
+            span: tag.span,
+            name: self.attr(&tag, "name")?.unwrap_or_default(),
+            category: self.attr(&tag, "category")?,
+            api: self.list(&tag, "api")?,
+            alias: self.attr(&tag, "alias")?,
+            requires: self.attr(&tag, "requires")?,
+            bit_values: self.attr(&tag, "bitvalues")?,
+            parent: self.list(&tag, "parent")?,
+            returned_only: self.flag(&tag, "returnedonly")?,
+            struct_extends: self.list(&tag, "structextends")?,
+            comment: self.attr(&tag, "comment")?,
+            ..Default::default()
+        };
+        if let Some(Category::Struct | Category::Union) = &ty.category {
+            while let Some(tag) = self.tag()? {
+                match tag.name {
+                    "member" => ty.members.push(self.member(tag)?),
+                    _ => self.skip()?,
+                }
+            }
+        } else {
+            let decl = self.declaration()?;
+            if ty.category == Some(Category::FuncPointer) {
+                // `typedef VkBool32 (VKAPI_PTR *<name>..</name>)(<type>..</type> ..);`:
+                // the `<type>`s are the parameters.
+                let ret = decl.code.split('(').next().unwrap_or("").trim();
+                let ret = ret.strip_prefix("typedef").unwrap_or(ret).trim();
+                if !ret.is_empty() {
+                    ty.base = Some(ret.split_whitespace().collect());
+                }
+            } else if !decl.type_name.is_empty() {
+                ty.base = Some(decl.type_name);
+            }
+            if ty.name.is_empty() {
+                ty.name = decl.name;
+            }
+            ty.code = String::from(decl.code.trim());
+        }
+        if ty.name.is_empty() {
+            return Err(self.error(tag.name, ErrorKind::MissingName(tag.name)));
+        }
+        Ok(ty)
+    }
+
+    fn member(&mut self, tag: Tag<'a>) -> Result<Member, Error<'a>> {
+        let mut member = Member {
+            span: tag.span,
+            optional: self.list(&tag, "optional")?,
+            len: self.list(&tag, "len")?,
+            values: self.attr(&tag, "values")?,
+            extern_sync: self.attr(&tag, "externsync")?,
+            api: self.list(&tag, "api")?,
+            no_auto_validity: self.flag(&tag, "noautovalidity")?,
+            ..Default::default()
+        };
+        let decl = self.declaration()?;
+        if decl.name.is_empty() {
+            return Err(self.error(tag.name, ErrorKind::MissingName(tag.name)));
+        }
+        let [before, between, after] = &decl.parts;
+        member.name = decl.name;
+        member.type_name = decl.type_name;
+        member.code = decl.code.split_whitespace().collect::<Vec<_>>().join(" ");
+        member.is_const = before.split_whitespace().any(|word| word == "const");
+        member.pointers = between.matches('*').count();
+        member.comment = decl.comment;
+        let mut rest = after.trim();
+        while let Some(size) = rest.strip_prefix('[') {
+            let end = size.find(']').unwrap_or(size.len());
+            member.array.push(String::from(size[..end].trim()));
+            rest = size.get(end + 1..).unwrap_or("").trim_start();
+        }
+        if let Some(bits) = rest.strip_prefix(':') {
+            member.bits = bits.trim().parse().ok();
+        }
+        Ok(member)
+    }
+
+    fn enums(&mut self, tag: Tag<'a>) -> Result<Enums, Error<'a>> {
+        let mut enums = Enums {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            kind: self.attr(&tag, "type")?.unwrap_or_default(),
+            bit_width: self.attr(&tag, "bitwidth")?.unwrap_or(32),
+            comment: self.attr(&tag, "comment")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "enum" => enums.values.push(self.enumerant(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(enums)
+    }
+
+    fn enumerant(&mut self, tag: Tag<'a>) -> Result<Enum, Error<'a>> {
+        let value = if let Some(alias) = self.attr(&tag, "alias")? {
+            Some(Value::Alias(alias))
+        } else if let Some(bit) = self.attr(&tag, "bitpos")? {
+            Some(Value::BitPos(bit))
+        } else if let Some(offset) = self.attr(&tag, "offset")? {
+            let negative = match tag.attrs.get("dir") {
+                None => false,
+                Some(dir) if dir == "-" => true,
+                Some(dir) => {
+                    let err = ParseError::new("expected `-`");
+                    return Err(self.attr_error(&tag, AttrError::Invalid("dir", dir, err)));
+                }
+            };
+            Some(Value::Offset {
+                extension: self.attr(&tag, "extnumber")?,
+                offset,
+                negative,
+            })
+        } else {
+            self.attr(&tag, "value")?.map(Value::Literal)
+        };
+        let enumerant = Enum {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            value,
+            extends: self.attr(&tag, "extends")?,
+            type_name: self.attr(&tag, "type")?,
+            api: self.list(&tag, "api")?,
+            comment: self.attr(&tag, "comment")?,
+        };
+        self.skip()?;
+        Ok(enumerant)
+    }
+
+    fn command(&mut self, tag: Tag<'a>) -> Result<Command, Error<'a>> {
+        let mut command = Command {
+            span: tag.span,
+            name: self.attr(&tag, "name")?.unwrap_or_default(),
+            alias: self.attr(&tag, "alias")?,
+            api: self.list(&tag, "api")?,
+            success_codes: self.list(&tag, "successcodes")?,
+            error_codes: self.list(&tag, "errorcodes")?,
+            queues: self.list(&tag, "queues")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "proto" => {
+                    let proto = self.member(tag)?;
+                    command.name = proto.name;
+                    command.return_type = proto.type_name;
+                }
+                "param" => command.params.push(self.member(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        if command.name.is_empty() {
+            return Err(self.error(tag.name, ErrorKind::MissingName(tag.name)));
+        }
+        Ok(command)
+    }
+
+    fn feature(&mut self, tag: Tag<'a>) -> Result<Feature, Error<'a>> {
+        let mut feature = Feature {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            api: self.list(&tag, "api")?,
+            number: self.require(&tag, "number")?,
+            depends: self.attr(&tag, "depends")?,
+            comment: self.attr(&tag, "comment")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "require" => feature.require.push(self.require_element(tag)?),
+                "remove" => feature.remove.push(self.require_element(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(feature)
+    }
+
+    fn extension(&mut self, tag: Tag<'a>) -> Result<Extension, Error<'a>> {
+        let mut extension = Extension {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            number: self.require(&tag, "number")?,
+            kind: self.attr(&tag, "type")?,
+            author: self.attr(&tag, "author")?,
+            supported: self.list(&tag, "supported")?,
+            depends: self.attr(&tag, "depends")?,
+            platform: self.attr(&tag, "platform")?,
+            promoted_to: self.attr(&tag, "promotedto")?,
+            deprecated_by: self.attr(&tag, "deprecatedby")?,
+            obsoleted_by: self.attr(&tag, "obsoletedby")?,
+            provisional: self.flag(&tag, "provisional")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "require" => extension.require.push(self.require_element(tag)?),
+                "remove" => extension.remove.push(self.require_element(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(extension)
+    }
+
+    fn require_element(&mut self, tag: Tag<'a>) -> Result<Require, Error<'a>> {
+        let mut require = Require {
+            span: tag.span,
+            comment: self.attr(&tag, "comment")?,
+            depends: self.attr(&tag, "depends")?,
+            api: self.list(&tag, "api")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "type" => {
+                    require.types.push(self.require(&tag, "name")?);
+                    self.skip()?;
+                }
+                "command" => {
+                    require.commands.push(self.require(&tag, "name")?);
+                    self.skip()?;
+                }
+                "enum" => require.enums.push(self.enumerant(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(require)
+    }
+
+    fn format(&mut self, tag: Tag<'a>) -> Result<Format, Error<'a>> {
+        let mut format = Format {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            class: self.require(&tag, "class")?,
+            block_size: self.require(&tag, "blockSize")?,
+            texels_per_block: self.require(&tag, "texelsPerBlock")?,
+            block_extent: self.list(&tag, "blockExtent")?,
+            packed: self.attr(&tag, "packed")?,
+            compressed: self.attr(&tag, "compressed")?,
+            chroma: self.attr(&tag, "chroma")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "component" => {
+                    let bits = match tag.attrs.get("bits") {
+                        Some(bits) if bits == "compressed" => None,
+                        _ => Some(self.require(&tag, "bits")?),
+                    };
+                    format.components.push(Component {
+                        name: self.require(&tag, "name")?,
+                        bits,
+                        numeric_format: self.require(&tag, "numericFormat")?,
+                        plane_index: self.attr(&tag, "planeIndex")?,
+                    });
+                    self.skip()?;
+                }
+                "plane" => {
+                    format.planes.push(Plane {
+                        index: self.require(&tag, "index")?,
+                        width_divisor: self.require(&tag, "widthDivisor")?,
+                        height_divisor: self.require(&tag, "heightDivisor")?,
+                        compatible: self.require(&tag, "compatible")?,
+                    });
+                    self.skip()?;
+                }
+                "spirvimageformat" => {
+                    format.spirv_image_format = Some(self.require(&tag, "name")?);
+                    self.skip()?;
+                }
+                _ => self.skip()?,
+            }
+        }
+        Ok(format)
+    }
+}
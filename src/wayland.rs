@@ -0,0 +1,667 @@
+//! The [Wayland](https://wayland.freedesktop.org/) protocol description
+//! format.
+//!
+//! [`parse`] reads a protocol file, such as `wayland.xml` or one of the
+//! files from wayland-protocols, into a [`Protocol`]. Every element and
+//! attribute in the upstream `wayland.dtd` is supported. Unknown elements
+//! are skipped, so extensions to the format don't cause errors.
+//! [`validate`] checks the references and version numbers in a parsed
+//! protocol, and [`diff`] compares two versions of it. [`rust`] generates
+//! Rust bindings for a protocol, [`c`] generates the C headers and code
+//! that `wayland-scanner` would, and [`doc`] generates reference
+//! documentation.
+//!
+//! ```ignore
+//! let protocol = txml::wayland::parse(&xml)?;
+//! for interface in &protocol.interfaces {
+//!     println!("{} v{}", interface.name, interface.version);
+//! }
+//! ```
+
+pub mod c;
+pub mod diff;
+pub mod doc;
+pub mod rust;
+pub mod validate;
+
+use crate::check;
+use crate::{AttrError, Attrs, Event, ParseError, Parser, Text};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+/// A protocol, the root `<protocol>` element.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Protocol {
+    /// The opening tag in the protocol file.
+    pub span: Span,
+    /// The name of the protocol.
+    pub name: String,
+    /// The copyright notice, as written.
+    pub copyright: Option<String>,
+    /// The description of the protocol.
+    pub description: Option<Description>,
+    /// The interfaces in the protocol.
+    pub interfaces: Vec<Interface>,
+}
+
+/// An `<interface>`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Interface {
+    /// The opening tag in the protocol file.
+    pub span: Span,
+    /// The name of the interface.
+    pub name: String,
+    /// The latest version of the interface.
+    pub version: u32,
+    /// Whether the interface is frozen: no new versions will be added.
+    pub frozen: bool,
+    /// The description of the interface.
+    pub description: Option<Description>,
+    /// The requests, sent by clients.
+    pub requests: Vec<Message>,
+    /// The events, sent by compositors.
+    pub events: Vec<Message>,
+    /// The enums.
+    pub enums: Vec<Enum>,
+}
+
+/// A `<request>` or `<event>`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Message {
+    /// The opening tag in the protocol file.
+    pub span: Span,
+    /// The name of the message.
+    pub name: String,
+    /// Whether the message destroys the object, from `type="destructor"`.
+    pub destructor: bool,
+    /// The interface version that added the message. Defaults to 1.
+    pub since: u32,
+    /// The interface version that deprecated the message.
+    pub deprecated_since: Option<u32>,
+    /// The description of the message.
+    pub description: Option<Description>,
+    /// The arguments.
+    pub args: Vec<Arg>,
+}
+
+impl Message {
+    /// Returns the signature of the message in the format used by
+    /// libwayland, such as `"2?os"`: the version that added the message if
+    /// it's after 1, then a letter for each argument, preceded by `?` if it
+    /// may be null. An untyped `new_id` is written `sun`, as it's sent with
+    /// the interface name and version.
+    pub fn signature(&self) -> String {
+        let mut signature = String::new();
+        if self.since > 1 {
+            signature += &self.since.to_string();
+        }
+        for arg in &self.args {
+            if arg.allow_null {
+                signature.push('?');
+            }
+            signature += match arg.kind {
+                ArgKind::NewId if arg.interface.is_none() => "sun",
+                ArgKind::NewId => "n",
+                ArgKind::Int => "i",
+                ArgKind::Uint => "u",
+                ArgKind::Fixed => "f",
+                ArgKind::String => "s",
+                ArgKind::Object => "o",
+                ArgKind::Array => "a",
+                ArgKind::Fd => "h",
+            };
+        }
+        signature
+    }
+}
+
+/// An `<arg>` of a message.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Arg {
+    /// The opening tag in the protocol file.
+    pub span: Span,
+    /// The name of the argument.
+    pub name: String,
+    /// The type of the argument.
+    pub kind: ArgKind,
+    /// A short description of the argument.
+    pub summary: Option<String>,
+    /// The interface of an `object` or `new_id` argument. A `new_id`
+    /// argument without one is untyped, and is sent with the interface name
+    /// and version.
+    pub interface: Option<String>,
+    /// Whether an `object` or `string` argument may be null.
+    pub allow_null: bool,
+    /// The enum that the values of an `int` or `uint` argument come from.
+    pub enumeration: Option<EnumRef>,
+    /// The description of the argument.
+    pub description: Option<Description>,
+}
+
+/// The type of an [`Arg`].
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq)]
+pub enum ArgKind {
+    /// A new object, `new_id`.
+    #[default]
+    NewId,
+    /// A signed 32-bit integer, `int`.
+    Int,
+    /// An unsigned 32-bit integer, `uint`.
+    Uint,
+    /// A signed 24.8 fixed-point number, `fixed`.
+    Fixed,
+    /// A string, `string`.
+    String,
+    /// An existing object, `object`.
+    Object,
+    /// A byte array, `array`.
+    Array,
+    /// A file descriptor, `fd`.
+    Fd,
+}
+
+impl ArgKind {
+    /// Returns the name of the type, as written in the `type` attribute.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ArgKind::NewId => "new_id",
+            ArgKind::Int => "int",
+            ArgKind::Uint => "uint",
+            ArgKind::Fixed => "fixed",
+            ArgKind::String => "string",
+            ArgKind::Object => "object",
+            ArgKind::Array => "array",
+            ArgKind::Fd => "fd",
+        }
+    }
+}
+
+impl FromStr for ArgKind {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "new_id" => Ok(ArgKind::NewId),
+            "int" => Ok(ArgKind::Int),
+            "uint" => Ok(ArgKind::Uint),
+            "fixed" => Ok(ArgKind::Fixed),
+            "string" => Ok(ArgKind::String),
+            "object" => Ok(ArgKind::Object),
+            "array" => Ok(ArgKind::Array),
+            "fd" => Ok(ArgKind::Fd),
+            _ => Err(ParseError::new("unknown argument type")),
+        }
+    }
+}
+
+impl Display for ArgKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A reference to an enum from the `enum` attribute of an [`Arg`], either
+/// `name` for an enum in the same interface or `interface.name`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct EnumRef {
+    /// The interface of the enum, if it's in another interface.
+    pub interface: Option<String>,
+    /// The name of the enum.
+    pub name: String,
+}
+
+impl FromStr for EnumRef {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (interface, name) = match s.split_once('.') {
+            Some((interface, name)) => (Some(interface.into()), name),
+            None => (None, s),
+        };
+        if name.is_empty() || name.contains('.') || interface.as_deref() == Some("") {
+            return Err(ParseError::new("expected `enum` or `interface.enum`"));
+        }
+        Ok(EnumRef {
+            interface,
+            name: name.into(),
+        })
+    }
+}
+
+impl Display for EnumRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref interface) = self.interface {
+            write!(f, "{}.", interface)?;
+        }
+        f.write_str(&self.name)
+    }
+}
+
+/// An `<enum>`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Enum {
+    /// The opening tag in the protocol file.
+    pub span: Span,
+    /// The name of the enum.
+    pub name: String,
+    /// The interface version that added the enum. Defaults to 1.
+    pub since: u32,
+    /// Whether the entries are flags that can be combined.
+    pub bitfield: bool,
+    /// The description of the enum.
+    pub description: Option<Description>,
+    /// The interface version that deprecated the enum.
+    pub deprecated_since: Option<u32>,
+    /// The entries.
+    pub entries: Vec<Entry>,
+}
+
+/// An `<entry>` of an enum.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Entry {
+    /// The opening tag in the protocol file.
+    pub span: Span,
+    /// The name of the entry.
+    pub name: String,
+    /// The value, written in decimal or in hexadecimal with `0x`.
+    pub value: u32,
+    /// The value as written in the protocol file.
+    pub raw_value: String,
+    /// A short description of the entry.
+    pub summary: Option<String>,
+    /// The interface version that added the entry. Defaults to 1.
+    pub since: u32,
+    /// The interface version that deprecated the entry.
+    pub deprecated_since: Option<u32>,
+    /// The description of the entry.
+    pub description: Option<Description>,
+}
+
+/// A `<description>`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct Description {
+    /// A one-line summary.
+    pub summary: String,
+    /// The body, as written, including the indentation of each line.
+    pub body: String,
+}
+
+impl Description {
+    /// Returns the lines of the body without the indentation they have in
+    /// common, trailing whitespace, or blank lines at the start and end.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        let blank = |line: &str| line.trim().is_empty();
+        let indent = self
+            .body
+            .lines()
+            .filter(|line| !blank(line))
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        let first = self.body.lines().position(|line| !blank(line));
+        let last = self
+            .body
+            .lines()
+            .enumerate()
+            .filter(|&(_, line)| !blank(line))
+            .last();
+        let (skip, take) = match (first, last) {
+            (Some(first), Some((last, _))) => (first, last + 1 - first),
+            _ => (0, 0),
+        };
+        self.body.lines().skip(skip).take(take).map(move |line| {
+            line.get(indent..)
+                .unwrap_or_else(|| line.trim_start())
+                .trim_end()
+        })
+    }
+}
+
+/// A range of bytes in a protocol file.
+#[derive(Clone, Copy, Default, Debug, Eq, PartialEq)]
+pub struct Span {
+    /// The offset of the first byte.
+    pub start: usize,
+    /// The offset after the last byte.
+    pub end: usize,
+}
+
+impl Span {
+    /// Returns the one-based line and column of the start of the span in
+    /// `doc`, which must be the parsed document. Columns count characters.
+    pub fn line_column(&self, doc: &str) -> (usize, usize) {
+        check::line_column(doc, self.start)
+    }
+}
+
+/// An error from parsing a protocol, with the byte offset in the document
+/// where it was found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error<'a> {
+    offset: usize,
+    kind: ErrorKind<'a>,
+}
+
+impl<'a> Error<'a> {
+    /// Returns the byte offset of the error in the document.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns what went wrong.
+    pub fn kind(&self) -> &ErrorKind<'a> {
+        &self.kind
+    }
+
+    /// Returns the one-based line and column of the error in `doc`, which
+    /// must be the document that was parsed. Columns count characters.
+    pub fn line_column(&self, doc: &str) -> (usize, usize) {
+        check::line_column(doc, self.offset)
+    }
+}
+
+impl<'a> Display for Error<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+/// The kind of a protocol parsing [`Error`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind<'a> {
+    /// The document is not well-formed.
+    Malformed(check::ErrorKind<'a>),
+    /// The root element isn't `<protocol>`.
+    NotProtocol(&'a str),
+    /// An attribute is missing or invalid.
+    Attr(AttrError<'a>),
+    /// The named element was found inside text, such as a description.
+    UnexpectedElement(&'a str),
+}
+
+impl<'a> Display for ErrorKind<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ErrorKind::Malformed(ref kind) => Display::fmt(kind, f),
+            ErrorKind::NotProtocol(name) => {
+                write!(f, "expected a `protocol` element, found `{}`", name)
+            }
+            ErrorKind::Attr(ref err) => Display::fmt(err, f),
+            ErrorKind::UnexpectedElement(name) => write!(f, "unexpected element `{}`", name),
+        }
+    }
+}
+
+/// Parses a protocol file.
+pub fn parse(doc: &str) -> Result<Protocol, Error<'_>> {
+    check::check(doc).map_err(|err| Error {
+        offset: err.offset(),
+        kind: ErrorKind::Malformed(err.kind()),
+    })?;
+    let mut ctx = ParseContext {
+        doc,
+        parser: Parser::new(doc),
+    };
+    loop {
+        if let Some(tag) = ctx.tag()? {
+            if tag.name != "protocol" {
+                return Err(ctx.error(tag.name, ErrorKind::NotProtocol(tag.name)));
+            }
+            return ctx.protocol(tag);
+        }
+    }
+}
+
+/// An opening tag.
+struct Tag<'a> {
+    name: &'a str,
+    attrs: Attrs<'a>,
+    span: Span,
+}
+
+struct ParseContext<'a> {
+    doc: &'a str,
+    parser: Parser<'a>,
+}
+
+impl<'a> ParseContext<'a> {
+    /// Returns an error at the start of `s`, which must be part of the
+    /// document.
+    fn error(&self, s: &str, kind: ErrorKind<'a>) -> Error<'a> {
+        Error {
+            offset: s.as_ptr() as usize - self.doc.as_ptr() as usize,
+            kind,
+        }
+    }
+
+    fn attr_error(&self, tag: &Tag<'a>, err: AttrError<'a>) -> Error<'a> {
+        match err {
+            AttrError::Invalid(_, Text::Escaped(value) | Text::Verbatim(value), _) => {
+                self.error(value, ErrorKind::Attr(err))
+            }
+            _ => self.error(tag.name, ErrorKind::Attr(err)),
+        }
+    }
+
+    fn attr<T: FromStr>(&self, tag: &Tag<'a>, name: &'static str) -> Result<Option<T>, Error<'a>>
+    where
+        T::Err: Display,
+    {
+        tag.attrs
+            .parse(name)
+            .map_err(|err| self.attr_error(tag, err))
+    }
+
+    fn require<T: FromStr>(&self, tag: &Tag<'a>, name: &'static str) -> Result<T, Error<'a>>
+    where
+        T::Err: Display,
+    {
+        self.attr(tag, name)?
+            .ok_or_else(|| self.attr_error(tag, AttrError::Missing(name)))
+    }
+
+    fn flag(&self, tag: &Tag<'a>, name: &'static str) -> Result<bool, Error<'a>> {
+        tag.attrs
+            .parse_bool(name)
+            .map(|flag| flag.unwrap_or(false))
+            .map_err(|err| self.attr_error(tag, err))
+    }
+
+    fn since(&self, tag: &Tag<'a>) -> Result<u32, Error<'a>> {
+        Ok(self.attr(tag, "since")?.unwrap_or(1))
+    }
+
+    fn next(&mut self) -> Result<Event<'a>, Error<'a>> {
+        // The document has been checked, so this only fails at the end.
+        let rest = self.parser.doc;
+        self.parser
+            .next()
+            .ok_or_else(|| self.error(rest, ErrorKind::Malformed(check::ErrorKind::Unterminated)))
+    }
+
+    /// Returns the next opening tag, skipping text, comments and processing
+    /// instructions, or `None` at a closing tag.
+    fn tag(&mut self) -> Result<Option<Tag<'a>>, Error<'a>> {
+        loop {
+            let start = self.doc.len() - self.parser.doc.len();
+            match self.next()? {
+                Event::Open(name, attrs) => {
+                    let end = self.doc.len() - self.parser.doc.len();
+                    let span = Span { start, end };
+                    return Ok(Some(Tag { name, attrs, span }));
+                }
+                Event::Close(..) => return Ok(None),
+                Event::Text(..) | Event::Comment(..) | Event::Pi(..) | Event::Doctype(..) => {}
+            }
+        }
+    }
+
+    fn skip(&mut self) -> Result<(), Error<'a>> {
+        while self.tag()?.is_some() {
+            self.skip()?;
+        }
+        Ok(())
+    }
+
+    /// Reads the character data of the current element.
+    fn text(&mut self) -> Result<String, Error<'a>> {
+        let mut text = String::new();
+        loop {
+            match self.next()? {
+                Event::Open(name, _) => {
+                    return Err(self.error(name, ErrorKind::UnexpectedElement(name)))
+                }
+                Event::Close(..) => return Ok(text),
+                Event::Text(t) => text.extend(t),
+                Event::Comment(..) | Event::Pi(..) | Event::Doctype(..) => {}
+            }
+        }
+    }
+
+    fn protocol(&mut self, tag: Tag<'a>) -> Result<Protocol, Error<'a>> {
+        let mut protocol = Protocol {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "copyright" => protocol.copyright = Some(self.text()?),
+                "description" => protocol.description = Some(self.description(tag)?),
+                "interface" => protocol.interfaces.push(self.interface(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(protocol)
+    }
+
+    fn interface(&mut self, tag: Tag<'a>) -> Result<Interface, Error<'a>> {
+        let mut interface = Interface {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            version: self.require(&tag, "version")?,
+            frozen: self.flag(&tag, "frozen")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "description" => interface.description = Some(self.description(tag)?),
+                "request" => interface.requests.push(self.message(tag)?),
+                "event" => interface.events.push(self.message(tag)?),
+                "enum" => interface.enums.push(self.enumeration(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(interface)
+    }
+
+    fn message(&mut self, tag: Tag<'a>) -> Result<Message, Error<'a>> {
+        let destructor = match tag.attrs.get("type") {
+            None => false,
+            Some(kind) if kind == "destructor" => true,
+            Some(kind) => {
+                let err = ParseError::new("expected `destructor`");
+                return Err(self.attr_error(&tag, AttrError::Invalid("type", kind, err)));
+            }
+        };
+        let mut message = Message {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            destructor,
+            since: self.since(&tag)?,
+            deprecated_since: self.attr(&tag, "deprecated-since")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "description" => message.description = Some(self.description(tag)?),
+                "arg" => message.args.push(self.arg(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(message)
+    }
+
+    fn arg(&mut self, tag: Tag<'a>) -> Result<Arg, Error<'a>> {
+        let mut arg = Arg {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            kind: self.require(&tag, "type")?,
+            summary: self.attr(&tag, "summary")?,
+            interface: self.attr(&tag, "interface")?,
+            allow_null: self.flag(&tag, "allow-null")?,
+            enumeration: self.attr(&tag, "enum")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "description" => arg.description = Some(self.description(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(arg)
+    }
+
+    fn enumeration(&mut self, tag: Tag<'a>) -> Result<Enum, Error<'a>> {
+        let mut enumeration = Enum {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            since: self.since(&tag)?,
+            bitfield: self.flag(&tag, "bitfield")?,
+            deprecated_since: self.attr(&tag, "deprecated-since")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "description" => enumeration.description = Some(self.description(tag)?),
+                "entry" => enumeration.entries.push(self.entry(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(enumeration)
+    }
+
+    fn entry(&mut self, tag: Tag<'a>) -> Result<Entry, Error<'a>> {
+        let value = tag
+            .attrs
+            .get("value")
+            .ok_or_else(|| self.attr_error(&tag, AttrError::Missing("value")))?;
+        let mut entry = Entry {
+            span: tag.span,
+            name: self.require(&tag, "name")?,
+            value: parse_value(value.clone()).map_err(|err| {
+                self.attr_error(&tag, AttrError::Invalid("value", value.clone(), err))
+            })?,
+            raw_value: value.collect(),
+            summary: self.attr(&tag, "summary")?,
+            since: self.since(&tag)?,
+            deprecated_since: self.attr(&tag, "deprecated-since")?,
+            ..Default::default()
+        };
+        while let Some(tag) = self.tag()? {
+            match tag.name {
+                "description" => entry.description = Some(self.description(tag)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(entry)
+    }
+
+    fn description(&mut self, tag: Tag<'a>) -> Result<Description, Error<'a>> {
+        Ok(Description {
+            summary: self.require(&tag, "summary")?,
+            body: self.text()?,
+        })
+    }
+}
+
+/// Parses an entry value, in decimal or in hexadecimal with `0x`.
+fn parse_value(value: Text) -> Result<u32, ParseError> {
+    let value: String = value.collect();
+    match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).map_err(ParseError::new),
+        None => value.parse().map_err(ParseError::new),
+    }
+}
@@ -0,0 +1,405 @@
+//! Compatibility of protocol versions.
+//!
+//! [`diff`] compares two versions of a protocol and classifies each change
+//! as compatible or breaking under the Wayland versioning rules, which let
+//! peers built against different versions of a protocol talk to each other
+//! using the lower interface version:
+//!
+//! - Interfaces, enums and deprecations can be added freely.
+//! - Requests, events and enum entries can only be added with a `since`
+//!   newer than the old interface version and no newer than the new one,
+//!   so the interface version has to be bumped with them, and messages only
+//!   at the end, as their opcodes are their positions.
+//! - Nothing can be removed, and the arguments of a message, the values of
+//!   entries and the versions that added items can't change. Renaming an
+//!   argument or giving it an `enum` doesn't change the wire format, so is
+//!   compatible.
+//!
+//! Items are matched by name, except arguments, which are matched by
+//! position. Descriptions and summaries are ignored.
+
+use super::{Arg, ArgKind, Entry, Enum, EnumRef, Interface, Message, Protocol};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// A difference between two versions of a protocol.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Change {
+    path: String,
+    kind: ChangeKind,
+    breaking: bool,
+}
+
+impl Change {
+    /// Returns the path of the item that changed: the interface name,
+    /// followed by `request`, `event` or `enum` and the name of a message
+    /// or enum, followed by the name of an argument or entry, separated by
+    /// dots. For example `wl_surface`, `wl_surface.request.attach`,
+    /// `wl_surface.request.attach.buffer` or
+    /// `wl_surface.enum.error.invalid_scale`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns what changed.
+    pub fn kind(&self) -> &ChangeKind {
+        &self.kind
+    }
+
+    /// Returns whether the change breaks compatibility with peers using the
+    /// old version.
+    pub fn is_breaking(&self) -> bool {
+        self.breaking
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let class = if self.breaking {
+            "breaking"
+        } else {
+            "compatible"
+        };
+        write!(f, "{}: {}: {}", class, self.path, self.kind)
+    }
+}
+
+/// The kind of a [`Change`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ChangeKind {
+    /// The item was added.
+    Added,
+    /// The item was removed.
+    Removed,
+    /// The version of an interface changed.
+    VersionChanged {
+        /// The old version.
+        old: u32,
+        /// The new version.
+        new: u32,
+    },
+    /// A message moved, changing its opcode.
+    OpcodeChanged {
+        /// The old opcode.
+        old: usize,
+        /// The new opcode.
+        new: usize,
+    },
+    /// A message became a destructor, or stopped being one.
+    DestructorChanged(bool),
+    /// The version that added an item changed.
+    SinceChanged {
+        /// The old version.
+        old: u32,
+        /// The new version.
+        new: u32,
+    },
+    /// The version that deprecated an item changed.
+    DeprecatedSinceChanged {
+        /// The old version.
+        old: Option<u32>,
+        /// The new version.
+        new: Option<u32>,
+    },
+    /// An argument was renamed. The path has the new name.
+    Renamed(String),
+    /// The type of an argument changed.
+    TypeChanged {
+        /// The old type.
+        old: ArgKind,
+        /// The new type.
+        new: ArgKind,
+    },
+    /// The interface of an argument changed.
+    InterfaceChanged {
+        /// The old interface.
+        old: Option<String>,
+        /// The new interface.
+        new: Option<String>,
+    },
+    /// An argument started or stopped allowing null.
+    AllowNullChanged(bool),
+    /// The enum of an argument changed.
+    EnumChanged {
+        /// The old enum.
+        old: Option<EnumRef>,
+        /// The new enum.
+        new: Option<EnumRef>,
+    },
+    /// An enum became a bitfield, or stopped being one.
+    BitfieldChanged(bool),
+    /// The value of an entry changed.
+    ValueChanged {
+        /// The old value.
+        old: u32,
+        /// The new value.
+        new: u32,
+    },
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let version = |version: &Option<u32>| match *version {
+            Some(version) => format!("{}", version),
+            None => "none".into(),
+        };
+        match *self {
+            ChangeKind::Added => f.write_str("added"),
+            ChangeKind::Removed => f.write_str("removed"),
+            ChangeKind::VersionChanged { old, new } => {
+                write!(f, "version changed from {} to {}", old, new)
+            }
+            ChangeKind::OpcodeChanged { old, new } => {
+                write!(f, "opcode changed from {} to {}", old, new)
+            }
+            ChangeKind::DestructorChanged(true) => f.write_str("became a destructor"),
+            ChangeKind::DestructorChanged(false) => f.write_str("is no longer a destructor"),
+            ChangeKind::SinceChanged { old, new } => {
+                write!(f, "since changed from {} to {}", old, new)
+            }
+            ChangeKind::DeprecatedSinceChanged { ref old, ref new } => write!(
+                f,
+                "deprecated-since changed from {} to {}",
+                version(old),
+                version(new)
+            ),
+            ChangeKind::Renamed(ref old) => write!(f, "renamed from `{}`", old),
+            ChangeKind::TypeChanged { old, new } => {
+                write!(f, "type changed from `{}` to `{}`", old, new)
+            }
+            ChangeKind::InterfaceChanged { ref old, ref new } => write!(
+                f,
+                "interface changed from `{}` to `{}`",
+                old.as_deref().unwrap_or("none"),
+                new.as_deref().unwrap_or("none")
+            ),
+            ChangeKind::AllowNullChanged(true) => f.write_str("now allows null"),
+            ChangeKind::AllowNullChanged(false) => f.write_str("no longer allows null"),
+            ChangeKind::EnumChanged { ref old, ref new } => {
+                let name = |e: &Option<EnumRef>| match *e {
+                    Some(ref e) => format!("{}", e),
+                    None => "none".into(),
+                };
+                write!(f, "enum changed from `{}` to `{}`", name(old), name(new))
+            }
+            ChangeKind::BitfieldChanged(true) => f.write_str("became a bitfield"),
+            ChangeKind::BitfieldChanged(false) => f.write_str("is no longer a bitfield"),
+            ChangeKind::ValueChanged { old, new } => {
+                write!(f, "value changed from {} to {}", old, new)
+            }
+        }
+    }
+}
+
+/// Compares two versions of a protocol, returning the changes from `old` to
+/// `new`.
+///
+/// The changes are in the order of the items in `old`, with the changes to
+/// an item before those to the items it contains. Added items are listed
+/// after the existing items of the same kind.
+pub fn diff(old: &Protocol, new: &Protocol) -> Vec<Change> {
+    let mut diff = Diff::default();
+    for old_interface in &old.interfaces {
+        match new.interfaces.iter().find(|i| i.name == old_interface.name) {
+            Some(new_interface) => diff.interface(old_interface, new_interface),
+            None => diff.push(&old_interface.name, ChangeKind::Removed, true),
+        }
+    }
+    for interface in &new.interfaces {
+        if !old.interfaces.iter().any(|i| i.name == interface.name) {
+            diff.push(&interface.name, ChangeKind::Added, false);
+        }
+    }
+    diff.changes
+}
+
+#[derive(Default)]
+struct Diff {
+    changes: Vec<Change>,
+}
+
+impl Diff {
+    fn push(&mut self, path: &str, kind: ChangeKind, breaking: bool) {
+        self.changes.push(Change {
+            path: path.into(),
+            kind,
+            breaking,
+        });
+    }
+
+    fn interface(&mut self, old: &Interface, new: &Interface) {
+        let path = &old.name;
+        if old.version != new.version {
+            let kind = ChangeKind::VersionChanged {
+                old: old.version,
+                new: new.version,
+            };
+            self.push(path, kind, new.version < old.version);
+        }
+        let versions = (old.version, new.version);
+        self.messages(path, "request", versions, &old.requests, &new.requests);
+        self.messages(path, "event", versions, &old.events, &new.events);
+
+        for old_enum in &old.enums {
+            let path = format!("{}.enum.{}", path, old_enum.name);
+            match new.enums.iter().find(|e| e.name == old_enum.name) {
+                Some(new_enum) => self.enumeration(&path, versions, old_enum, new_enum),
+                None => self.push(&path, ChangeKind::Removed, true),
+            }
+        }
+        for enumeration in &new.enums {
+            if !old.enums.iter().any(|e| e.name == enumeration.name) {
+                let path = format!("{}.enum.{}", path, enumeration.name);
+                self.push(&path, ChangeKind::Added, false);
+            }
+        }
+    }
+
+    fn messages(
+        &mut self,
+        interface: &str,
+        kind: &str,
+        versions: (u32, u32),
+        old: &[Message],
+        new: &[Message],
+    ) {
+        for (old_opcode, old_message) in old.iter().enumerate() {
+            let path = format!("{}.{}.{}", interface, kind, old_message.name);
+            match new.iter().position(|m| m.name == old_message.name) {
+                Some(new_opcode) => {
+                    if old_opcode != new_opcode {
+                        let kind = ChangeKind::OpcodeChanged {
+                            old: old_opcode,
+                            new: new_opcode,
+                        };
+                        self.push(&path, kind, true);
+                    }
+                    self.message(&path, old_message, &new[new_opcode]);
+                }
+                None => self.push(&path, ChangeKind::Removed, true),
+            }
+        }
+        for message in new {
+            if !old.iter().any(|m| m.name == message.name) {
+                let path = format!("{}.{}.{}", interface, kind, message.name);
+                self.push(&path, ChangeKind::Added, !added_in(message.since, versions));
+            }
+        }
+    }
+
+    fn message(&mut self, path: &str, old: &Message, new: &Message) {
+        if old.destructor != new.destructor {
+            self.push(path, ChangeKind::DestructorChanged(new.destructor), true);
+        }
+        self.versions(
+            path,
+            (old.since, old.deprecated_since),
+            (new.since, new.deprecated_since),
+        );
+        for (old_arg, new_arg) in old.args.iter().zip(&new.args) {
+            self.arg(&format!("{}.{}", path, new_arg.name), old_arg, new_arg);
+        }
+        for arg in &old.args[old.args.len().min(new.args.len())..] {
+            self.push(&format!("{}.{}", path, arg.name), ChangeKind::Removed, true);
+        }
+        for arg in &new.args[old.args.len().min(new.args.len())..] {
+            self.push(&format!("{}.{}", path, arg.name), ChangeKind::Added, true);
+        }
+    }
+
+    fn arg(&mut self, path: &str, old: &Arg, new: &Arg) {
+        if old.name != new.name {
+            self.push(path, ChangeKind::Renamed(old.name.clone()), false);
+        }
+        if old.kind != new.kind {
+            let kind = ChangeKind::TypeChanged {
+                old: old.kind,
+                new: new.kind,
+            };
+            self.push(path, kind, true);
+        }
+        if old.interface != new.interface {
+            let kind = ChangeKind::InterfaceChanged {
+                old: old.interface.clone(),
+                new: new.interface.clone(),
+            };
+            self.push(path, kind, true);
+        }
+        if old.allow_null != new.allow_null {
+            self.push(path, ChangeKind::AllowNullChanged(new.allow_null), true);
+        }
+        if old.enumeration != new.enumeration {
+            let kind = ChangeKind::EnumChanged {
+                old: old.enumeration.clone(),
+                new: new.enumeration.clone(),
+            };
+            // Giving an argument an enum only documents its values.
+            self.push(path, kind, old.enumeration.is_some());
+        }
+    }
+
+    fn enumeration(&mut self, path: &str, versions: (u32, u32), old: &Enum, new: &Enum) {
+        if old.bitfield != new.bitfield {
+            self.push(path, ChangeKind::BitfieldChanged(new.bitfield), true);
+        }
+        self.versions(
+            path,
+            (old.since, old.deprecated_since),
+            (new.since, new.deprecated_since),
+        );
+        for old_entry in &old.entries {
+            let path = format!("{}.{}", path, old_entry.name);
+            match new.entries.iter().find(|e| e.name == old_entry.name) {
+                Some(new_entry) => self.entry(&path, old_entry, new_entry),
+                None => self.push(&path, ChangeKind::Removed, true),
+            }
+        }
+        for entry in &new.entries {
+            if !old.entries.iter().any(|e| e.name == entry.name) {
+                let path = format!("{}.{}", path, entry.name);
+                self.push(&path, ChangeKind::Added, !added_in(entry.since, versions));
+            }
+        }
+    }
+
+    fn entry(&mut self, path: &str, old: &Entry, new: &Entry) {
+        if old.value != new.value {
+            let kind = ChangeKind::ValueChanged {
+                old: old.value,
+                new: new.value,
+            };
+            self.push(path, kind, true);
+        }
+        self.versions(
+            path,
+            (old.since, old.deprecated_since),
+            (new.since, new.deprecated_since),
+        );
+    }
+
+    /// Compares the `since` and `deprecated-since` of an item.
+    fn versions(&mut self, path: &str, old: (u32, Option<u32>), new: (u32, Option<u32>)) {
+        if old.0 != new.0 {
+            let kind = ChangeKind::SinceChanged {
+                old: old.0,
+                new: new.0,
+            };
+            self.push(path, kind, true);
+        }
+        if old.1 != new.1 {
+            let kind = ChangeKind::DeprecatedSinceChanged {
+                old: old.1,
+                new: new.1,
+            };
+            self.push(path, kind, false);
+        }
+    }
+}
+
+/// Returns whether an item added with `since` belongs to the versions from
+/// `old`, exclusive, to `new`, inclusive, of its interface.
+fn added_in(since: u32, (old, new): (u32, u32)) -> bool {
+    old < since && since <= new
+}
@@ -0,0 +1,678 @@
+//! Reference documentation for protocols.
+//!
+//! [`protocol`] writes a page for a protocol, listing its interfaces, and
+//! [`interface`] writes a page for each interface, with a section for each
+//! request, event and enum. Pages are Markdown or HTML, chosen by
+//! [`Options::format`].
+//!
+//! Pages link to each other by file name, so they're meant to be written to
+//! the same directory: the page of a protocol or an interface is its name
+//! followed by [`Format::extension`], such as `wayland.md` or
+//! `wl_surface.md`. Sections have anchors of the form `request-name`,
+//! `event-name` and `enum-name`, which the `interface` and `enum`
+//! attributes of arguments link to.
+//!
+//! Descriptions are dedented with [`Description::lines`] and reflowed:
+//! the lines of each paragraph are joined, and lines starting with `-` or
+//! `*` become list items. `since` and `deprecated-since` are shown as
+//! badges, which are `<span class="badge">` elements in HTML.
+
+use super::{Arg, Description, Entry, Enum, EnumRef, Interface, Message, Protocol};
+use crate::writer::Writer;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+/// The format of the documentation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// CommonMark, with tables and HTML anchors as on GitHub.
+    #[default]
+    Markdown,
+    /// HTML pages.
+    Html,
+}
+
+impl Format {
+    /// Returns the extension of the files of this format, without a dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Markdown => "md",
+            Format::Html => "html",
+        }
+    }
+}
+
+/// Documentation options.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Options<'a> {
+    /// The format of the pages.
+    pub format: Format,
+    /// Protocols whose interfaces the documented protocol refers to, such
+    /// as `wayland` for `wl_surface`. References to their interfaces are
+    /// linked like those to the documented protocol's own.
+    pub imports: &'a [Protocol],
+}
+
+/// Writes the page of a protocol.
+pub fn protocol<W: Write>(protocol: &Protocol, options: &Options, out: &mut W) -> fmt::Result {
+    let page = Page { protocol, options };
+    match options.format {
+        Format::Markdown => page.protocol(&mut Markdown::new(out)),
+        Format::Html => page.protocol(&mut Html(Writer::new(out))),
+    }
+}
+
+/// Writes the page of an interface of a protocol.
+pub fn interface<W: Write>(
+    protocol: &Protocol,
+    interface: &Interface,
+    options: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    let page = Page { protocol, options };
+    match options.format {
+        Format::Markdown => page.interface(&mut Markdown::new(out), interface),
+        Format::Html => page.interface(&mut Html(Writer::new(out)), interface),
+    }
+}
+
+struct Page<'p> {
+    protocol: &'p Protocol,
+    options: &'p Options<'p>,
+}
+
+impl<'p> Page<'p> {
+    fn protocol<R: Render>(&self, r: &mut R) -> fmt::Result {
+        let protocol = self.protocol;
+        r.begin(&protocol.name)?;
+        r.heading(1, None, &[Inline::Code(protocol.name.clone())])?;
+        if let Some(ref description) = protocol.description {
+            self.description(r, description)?;
+        }
+        r.heading(2, None, &[Inline::Text("Interfaces".into())])?;
+        let items: Vec<_> = protocol
+            .interfaces
+            .iter()
+            .map(|interface| {
+                let mut item = vec![
+                    self.page_link(&interface.name),
+                    Inline::Text(" ".into()),
+                    version(interface.version),
+                ];
+                if let Some(ref description) = interface.description {
+                    item.push(Inline::Text(format!(": {}", description.summary)));
+                }
+                item
+            })
+            .collect();
+        r.list(&items)?;
+        if let Some(ref copyright) = protocol.copyright {
+            let copyright = Description {
+                body: copyright.clone(),
+                ..Default::default()
+            };
+            r.heading(2, None, &[Inline::Text("Copyright".into())])?;
+            r.preformatted(copyright.lines())?;
+        }
+        r.end()
+    }
+
+    fn interface<R: Render>(&self, r: &mut R, interface: &Interface) -> fmt::Result {
+        r.begin(&interface.name)?;
+        let mut title = vec![
+            Inline::Code(interface.name.clone()),
+            Inline::Text(" ".into()),
+            version(interface.version),
+        ];
+        if interface.frozen {
+            title.extend([Inline::Text(" ".into()), Inline::Badge("frozen".into())]);
+        }
+        r.heading(1, None, &title)?;
+        r.paragraph(&[
+            Inline::Text("Part of the ".into()),
+            self.page_link(&self.protocol.name),
+            Inline::Text(" protocol.".into()),
+        ])?;
+        if let Some(ref description) = interface.description {
+            self.description(r, description)?;
+        }
+        for (messages, title, kind) in [
+            (&interface.requests, "Requests", "request"),
+            (&interface.events, "Events", "event"),
+        ] {
+            if messages.is_empty() {
+                continue;
+            }
+            r.heading(2, None, &[Inline::Text(title.into())])?;
+            for message in messages {
+                self.message(r, interface, message, kind)?;
+            }
+        }
+        if !interface.enums.is_empty() {
+            r.heading(2, None, &[Inline::Text("Enums".into())])?;
+            for enumeration in &interface.enums {
+                self.enumeration(r, enumeration)?;
+            }
+        }
+        r.end()
+    }
+
+    fn message<R: Render>(
+        &self,
+        r: &mut R,
+        interface: &Interface,
+        message: &Message,
+        kind: &str,
+    ) -> fmt::Result {
+        let mut title = vec![Inline::Code(message.name.clone())];
+        if message.destructor {
+            title.extend([Inline::Text(" ".into()), Inline::Badge("destructor".into())]);
+        }
+        badges(&mut title, message.since, message.deprecated_since);
+        let anchor = format!("{}-{}", kind, message.name);
+        r.heading(3, Some(&anchor), &title)?;
+        if let Some(ref description) = message.description {
+            self.description(r, description)?;
+        }
+        if message.args.is_empty() {
+            return Ok(());
+        }
+        let rows: Vec<_> = message
+            .args
+            .iter()
+            .map(|arg| {
+                let summary = arg.summary.clone().unwrap_or_default();
+                vec![
+                    vec![Inline::Code(arg.name.clone())],
+                    self.arg_type(interface, arg),
+                    vec![Inline::Text(summary)],
+                ]
+            })
+            .collect();
+        r.table(&["Argument", "Type", "Description"], &rows)
+    }
+
+    fn arg_type(&self, interface: &Interface, arg: &Arg) -> Vec<Inline> {
+        let mut content = vec![Inline::Code(arg.kind.as_str().into())];
+        if let Some(ref name) = arg.interface {
+            content.push(Inline::Text(" ".into()));
+            content.push(self.page_link(name));
+        }
+        if let Some(ref enum_ref) = arg.enumeration {
+            content.push(Inline::Text(" ".into()));
+            content.push(self.enum_link(interface, enum_ref));
+        }
+        if arg.allow_null {
+            content.push(Inline::Text(" (nullable)".into()));
+        }
+        content
+    }
+
+    fn enumeration<R: Render>(&self, r: &mut R, enumeration: &Enum) -> fmt::Result {
+        let mut title = vec![Inline::Code(enumeration.name.clone())];
+        if enumeration.bitfield {
+            title.extend([Inline::Text(" ".into()), Inline::Badge("bitfield".into())]);
+        }
+        badges(&mut title, enumeration.since, enumeration.deprecated_since);
+        let anchor = format!("enum-{}", enumeration.name);
+        r.heading(3, Some(&anchor), &title)?;
+        if let Some(ref description) = enumeration.description {
+            self.description(r, description)?;
+        }
+        let rows: Vec<_> = enumeration
+            .entries
+            .iter()
+            .map(|entry| {
+                let value = if enumeration.bitfield {
+                    format!("{:#x}", entry.value)
+                } else {
+                    format!("{}", entry.value)
+                };
+                vec![
+                    vec![Inline::Code(entry.name.clone())],
+                    vec![Inline::Code(value)],
+                    entry_description(entry),
+                ]
+            })
+            .collect();
+        r.table(&["Entry", "Value", "Description"], &rows)
+    }
+
+    fn description<R: Render>(&self, r: &mut R, description: &Description) -> fmt::Result {
+        if !description.summary.is_empty() {
+            r.paragraph(&[Inline::Emphasis(description.summary.clone())])?;
+        }
+        for block in blocks(description) {
+            match block {
+                Block::Paragraph(text) => r.paragraph(&[Inline::Text(text)])?,
+                Block::List(items) => {
+                    let items: Vec<_> = items
+                        .into_iter()
+                        .map(|item| vec![Inline::Text(item)])
+                        .collect();
+                    r.list(&items)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a link to the page of a protocol or interface, or just its
+    /// name if it isn't documented.
+    fn page_link(&self, name: &str) -> Inline {
+        let known = name == self.protocol.name
+            || core::iter::once(self.protocol)
+                .chain(self.options.imports)
+                .flat_map(|protocol| &protocol.interfaces)
+                .any(|interface| interface.name == name);
+        if known {
+            Inline::Link(name.into(), self.href(name))
+        } else {
+            Inline::Code(name.into())
+        }
+    }
+
+    fn enum_link(&self, interface: &Interface, enum_ref: &EnumRef) -> Inline {
+        match enum_ref.interface {
+            Some(ref name) => match self.page_link(name) {
+                Inline::Link(_, href) => Inline::Link(
+                    format!("{}", enum_ref),
+                    format!("{}#enum-{}", href, enum_ref.name),
+                ),
+                _ => Inline::Code(format!("{}", enum_ref)),
+            },
+            None if interface.enums.iter().any(|e| e.name == enum_ref.name) => {
+                Inline::Link(enum_ref.name.clone(), format!("#enum-{}", enum_ref.name))
+            }
+            None => Inline::Code(enum_ref.name.clone()),
+        }
+    }
+
+    fn href(&self, name: &str) -> String {
+        format!("{}.{}", name, self.options.format.extension())
+    }
+}
+
+fn version(version: u32) -> Inline {
+    Inline::Badge(format!("version {}", version))
+}
+
+fn badges(content: &mut Vec<Inline>, since: u32, deprecated_since: Option<u32>) {
+    if since > 1 {
+        content.push(Inline::Text(" ".into()));
+        content.push(Inline::Badge(format!("since {}", since)));
+    }
+    if let Some(version) = deprecated_since {
+        content.push(Inline::Text(" ".into()));
+        content.push(Inline::Badge(format!("deprecated since {}", version)));
+    }
+}
+
+/// Returns the description of an entry for a table cell, with its
+/// paragraphs joined.
+fn entry_description(entry: &Entry) -> Vec<Inline> {
+    let mut text = entry.summary.clone().unwrap_or_default();
+    if let Some(ref description) = entry.description {
+        let blocks = blocks(description)
+            .into_iter()
+            .flat_map(|block| match block {
+                Block::Paragraph(text) => vec![text],
+                Block::List(items) => items,
+            });
+        for part in core::iter::once(description.summary.clone()).chain(blocks) {
+            if !part.is_empty() {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(&part);
+            }
+        }
+    }
+    let mut content = vec![Inline::Text(text)];
+    badges(&mut content, entry.since, entry.deprecated_since);
+    content
+}
+
+/// A paragraph or list of a description.
+enum Block {
+    Paragraph(String),
+    List(Vec<String>),
+}
+
+/// Splits the body of a description into paragraphs and lists, joining
+/// the lines of each paragraph and list item.
+fn blocks(description: &Description) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    // Whether the next line continues the last paragraph or list item.
+    let mut open = false;
+    for line in description.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            open = false;
+            continue;
+        }
+        let item = line
+            .strip_prefix("- ")
+            .or_else(|| line.strip_prefix("* "))
+            .map(str::trim_start);
+        let text = match (item, blocks.last_mut()) {
+            (Some(item), Some(Block::List(items))) => {
+                items.push(String::from(item));
+                open = true;
+                continue;
+            }
+            (Some(item), _) => {
+                blocks.push(Block::List(vec![String::from(item)]));
+                open = true;
+                continue;
+            }
+            (None, Some(Block::Paragraph(text))) if open => text,
+            (None, Some(Block::List(items))) if open => items.last_mut().unwrap(),
+            (None, _) => {
+                blocks.push(Block::Paragraph(String::from(line)));
+                open = true;
+                continue;
+            }
+        };
+        text.push(' ');
+        text.push_str(line);
+    }
+    blocks
+}
+
+/// Inline content.
+enum Inline {
+    Text(String),
+    Emphasis(String),
+    Code(String),
+    /// A link with its text in code, and its target.
+    Link(String, String),
+    Badge(String),
+}
+
+/// The output of a format.
+trait Render {
+    fn begin(&mut self, title: &str) -> fmt::Result;
+    fn end(&mut self) -> fmt::Result;
+    fn heading(&mut self, level: u8, anchor: Option<&str>, content: &[Inline]) -> fmt::Result;
+    fn paragraph(&mut self, content: &[Inline]) -> fmt::Result;
+    fn list(&mut self, items: &[Vec<Inline>]) -> fmt::Result;
+    fn table(&mut self, header: &[&str], rows: &[Vec<Vec<Inline>>]) -> fmt::Result;
+    fn preformatted<'a>(&mut self, lines: impl Iterator<Item = &'a str>) -> fmt::Result;
+}
+
+struct Markdown<'w, W> {
+    out: &'w mut W,
+    started: bool,
+}
+
+impl<'w, W: Write> Markdown<'w, W> {
+    fn new(out: &'w mut W) -> Self {
+        Markdown {
+            out,
+            started: false,
+        }
+    }
+
+    /// Separates blocks with blank lines.
+    fn block(&mut self) -> fmt::Result {
+        if self.started {
+            self.out.write_char('\n')?;
+        }
+        self.started = true;
+        Ok(())
+    }
+
+    fn inline(&mut self, content: &[Inline]) -> fmt::Result {
+        for inline in content {
+            match *inline {
+                Inline::Text(ref text) => self.text(text)?,
+                Inline::Emphasis(ref text) => {
+                    self.out.write_char('*')?;
+                    self.text(text)?;
+                    self.out.write_char('*')?;
+                }
+                Inline::Code(ref text) | Inline::Badge(ref text) => write!(self.out, "`{}`", text)?,
+                Inline::Link(ref text, ref href) => write!(self.out, "[`{}`]({})", text, href)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes text on one line, escaping the characters that are markup.
+    /// Underscores inside words, as in most Wayland names, can't start
+    /// emphasis and aren't escaped.
+    fn text(&mut self, text: &str) -> fmt::Result {
+        let mut prev = ' ';
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\n' {
+                // Summaries can span lines, which would end a table row.
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                self.out.write_char(' ')?;
+                prev = ' ';
+                continue;
+            }
+            let next = chars.peek().copied().unwrap_or(' ');
+            let escape = match c {
+                '\\' | '`' | '*' | '[' | ']' | '<' | '>' | '|' | '&' => true,
+                '_' => !(prev.is_alphanumeric() && next.is_alphanumeric()),
+                _ => false,
+            };
+            if escape {
+                self.out.write_char('\\')?;
+            }
+            self.out.write_char(c)?;
+            prev = c;
+        }
+        Ok(())
+    }
+}
+
+impl<'w, W: Write> Render for Markdown<'w, W> {
+    fn begin(&mut self, _title: &str) -> fmt::Result {
+        Ok(())
+    }
+
+    fn end(&mut self) -> fmt::Result {
+        Ok(())
+    }
+
+    fn heading(&mut self, level: u8, anchor: Option<&str>, content: &[Inline]) -> fmt::Result {
+        self.block()?;
+        if let Some(anchor) = anchor {
+            writeln!(self.out, "<a id=\"{}\"></a>", anchor)?;
+        }
+        for _ in 0..level {
+            self.out.write_char('#')?;
+        }
+        self.out.write_char(' ')?;
+        self.inline(content)?;
+        self.out.write_char('\n')
+    }
+
+    fn paragraph(&mut self, content: &[Inline]) -> fmt::Result {
+        self.block()?;
+        self.inline(content)?;
+        self.out.write_char('\n')
+    }
+
+    fn list(&mut self, items: &[Vec<Inline>]) -> fmt::Result {
+        self.block()?;
+        for item in items {
+            self.out.write_str("- ")?;
+            self.inline(item)?;
+            self.out.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    fn table(&mut self, header: &[&str], rows: &[Vec<Vec<Inline>>]) -> fmt::Result {
+        self.block()?;
+        for title in header {
+            write!(self.out, "| {} ", title)?;
+        }
+        self.out.write_str("|\n")?;
+        for _ in header {
+            self.out.write_str("| --- ")?;
+        }
+        self.out.write_str("|\n")?;
+        for row in rows {
+            for cell in row {
+                self.out.write_str("| ")?;
+                self.inline(cell)?;
+                self.out.write_char(' ')?;
+            }
+            self.out.write_str("|\n")?;
+        }
+        Ok(())
+    }
+
+    fn preformatted<'a>(&mut self, lines: impl Iterator<Item = &'a str>) -> fmt::Result {
+        self.block()?;
+        self.out.write_str("```text\n")?;
+        for line in lines {
+            writeln!(self.out, "{}", line)?;
+        }
+        self.out.write_str("```\n")
+    }
+}
+
+struct Html<W>(Writer<W>);
+
+impl<W: Write> Html<W> {
+    fn inline(&mut self, content: &[Inline]) -> fmt::Result {
+        let w = &mut self.0;
+        for inline in content {
+            match *inline {
+                Inline::Text(ref text) => w.text(text.as_str())?,
+                Inline::Emphasis(ref text) => {
+                    w.open("em")?;
+                    w.text(text.as_str())?;
+                    w.close("em")?;
+                }
+                Inline::Code(ref text) => {
+                    w.open("code")?;
+                    w.text(text.as_str())?;
+                    w.close("code")?;
+                }
+                Inline::Link(ref text, ref href) => {
+                    w.open("a")?;
+                    w.attr("href", href.as_str())?;
+                    w.open("code")?;
+                    w.text(text.as_str())?;
+                    w.close("code")?;
+                    w.close("a")?;
+                }
+                Inline::Badge(ref text) => {
+                    w.open("span")?;
+                    w.attr("class", "badge")?;
+                    w.text(text.as_str())?;
+                    w.close("span")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Render for Html<W> {
+    fn begin(&mut self, title: &str) -> fmt::Result {
+        let w = &mut self.0;
+        w.doctype("html", "")?;
+        w.text("\n")?;
+        w.open("html")?;
+        w.text("\n")?;
+        w.open("head")?;
+        w.open("meta")?;
+        w.attr("charset", "utf-8")?;
+        w.close("meta")?;
+        w.open("title")?;
+        w.text(title)?;
+        w.close("title")?;
+        w.close("head")?;
+        w.text("\n")?;
+        w.open("body")?;
+        w.text("\n")
+    }
+
+    fn end(&mut self) -> fmt::Result {
+        self.0.close("body")?;
+        self.0.text("\n")?;
+        self.0.close("html")?;
+        self.0.text("\n")
+    }
+
+    fn heading(&mut self, level: u8, anchor: Option<&str>, content: &[Inline]) -> fmt::Result {
+        let tag = ["h1", "h2", "h3", "h4", "h5", "h6"][usize::from(level) - 1];
+        self.0.open(tag)?;
+        if let Some(anchor) = anchor {
+            self.0.attr("id", anchor)?;
+        }
+        self.inline(content)?;
+        self.0.close(tag)?;
+        self.0.text("\n")
+    }
+
+    fn paragraph(&mut self, content: &[Inline]) -> fmt::Result {
+        self.0.open("p")?;
+        self.inline(content)?;
+        self.0.close("p")?;
+        self.0.text("\n")
+    }
+
+    fn list(&mut self, items: &[Vec<Inline>]) -> fmt::Result {
+        self.0.open("ul")?;
+        self.0.text("\n")?;
+        for item in items {
+            self.0.open("li")?;
+            self.0.text("")?;
+            self.inline(item)?;
+            self.0.close("li")?;
+            self.0.text("\n")?;
+        }
+        self.0.close("ul")?;
+        self.0.text("\n")
+    }
+
+    fn table(&mut self, header: &[&str], rows: &[Vec<Vec<Inline>>]) -> fmt::Result {
+        let w = &mut self.0;
+        w.open("table")?;
+        w.text("\n")?;
+        w.open("tr")?;
+        for title in header {
+            w.open("th")?;
+            w.text(*title)?;
+            w.close("th")?;
+        }
+        w.close("tr")?;
+        w.text("\n")?;
+        for row in rows {
+            self.0.open("tr")?;
+            for cell in row {
+                self.0.open("td")?;
+                self.0.text("")?;
+                self.inline(cell)?;
+                self.0.close("td")?;
+            }
+            self.0.close("tr")?;
+            self.0.text("\n")?;
+        }
+        self.0.close("table")?;
+        self.0.text("\n")
+    }
+
+    fn preformatted<'a>(&mut self, lines: impl Iterator<Item = &'a str>) -> fmt::Result {
+        self.0.open("pre")?;
+        self.0.text("")?;
+        for line in lines {
+            self.0.text(line)?;
+            self.0.text("\n")?;
+        }
+        self.0.close("pre")?;
+        self.0.text("\n")
+    }
+}
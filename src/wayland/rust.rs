@@ -0,0 +1,659 @@
+//! Rust bindings for protocols.
+//!
+//! [`generate`] writes a Rust module for each interface of a protocol,
+//! containing:
+//!
+//! - `NAME` and `VERSION` constants
+//! - a struct named after the interface in camel case, such as `WlSurface`,
+//!   wrapping the `ObjectId` of an object with the interface, with the same
+//!   `NAME` and `VERSION` as associated constants
+//! - `REQUESTS` and `EVENTS` opcode tables, giving the name, version and
+//!   wire signature of each message, in the format used by libwayland
+//! - `Request` and `Event` enums with a variant for each message and a
+//!   typed field for each argument, with `opcode` and `since` methods
+//! - an enum for each `<enum>`, or a flags type for each bitfield
+//!
+//! The bindings use a few shared types, such as `ObjectId` and `Fixed`,
+//! which [`support`] writes. By default they're written at the start of the
+//! output, but [`Options::support`] can point at a module with them instead,
+//! so several protocols can share them.
+//!
+//! This is meant to be used from a build script:
+//!
+//! ```ignore
+//! let xml = std::fs::read_to_string("protocols/wayland.xml")?;
+//! let protocol = txml::wayland::parse(&xml).map_err(|err| err.to_string())?;
+//! let mut out = String::new();
+//! txml::wayland::rust::generate(&protocol, &Default::default(), &mut out)?;
+//! let dir = std::env::var("OUT_DIR")?;
+//! std::fs::write(format!("{}/wayland.rs", dir), out)?;
+//! ```
+//!
+//! and then included with
+//! `include!(concat!(env!("OUT_DIR"), "/wayland.rs"));`.
+//!
+//! Enum arguments that refer to an enum in another protocol are typed as
+//! plain integers.
+
+use super::{Arg, ArgKind, Description, Entry, Enum, Interface, Message, Protocol};
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+/// Code generation options.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Options<'a> {
+    /// The path of the module containing the types written by [`support`],
+    /// such as `crate::wayland`. If `None`, they're written at the start of
+    /// the output.
+    pub support: Option<&'a str>,
+}
+
+/// Writes Rust bindings for a protocol.
+pub fn generate<W: Write>(protocol: &Protocol, options: &Options, out: &mut W) -> fmt::Result {
+    writeln!(
+        out,
+        "// Generated by txml from the `{}` protocol.",
+        protocol.name
+    )?;
+    if let Some(ref copyright) = protocol.copyright {
+        let copyright = Description {
+            body: copyright.clone(),
+            ..Default::default()
+        };
+        writeln!(out, "//")?;
+        for line in copyright.lines() {
+            if line.is_empty() {
+                writeln!(out, "//")?;
+            } else {
+                writeln!(out, "// {}", line)?;
+            }
+        }
+    }
+    writeln!(out)?;
+    let support = match options.support {
+        Some(path) => path,
+        None => {
+            support(out)?;
+            writeln!(out)?;
+            "super"
+        }
+    };
+    let ctx = Context { protocol, support };
+    for (i, interface) in protocol.interfaces.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        ctx.interface(out, interface)?;
+    }
+    Ok(())
+}
+
+/// Writes the types shared by all generated bindings.
+pub fn support<W: Write>(out: &mut W) -> fmt::Result {
+    out.write_str(SUPPORT)
+}
+
+const SUPPORT: &str = "\
+/// The ID of an object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectId(pub u32);
+
+/// The ID of an object created by a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NewId(pub u32);
+
+/// An object created by a message whose interface isn't known in advance.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UntypedNewId {
+    /// The name of the interface.
+    pub interface: ::std::string::String,
+    /// The version of the interface.
+    pub version: u32,
+    /// The ID of the object.
+    pub id: NewId,
+}
+
+/// A signed 24.8 fixed-point number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fixed(pub i32);
+
+impl Fixed {
+    /// Converts a number to fixed-point, rounding to the nearest 1/256.
+    pub fn from_f64(value: f64) -> Self {
+        Fixed((value * 256.0).round() as i32)
+    }
+
+    /// Converts the number to floating-point.
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.0) / 256.0
+    }
+}
+
+/// An entry of an opcode table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageDesc {
+    /// The name of the message.
+    pub name: &'static str,
+    /// The interface version that added the message.
+    pub since: u32,
+    /// The types of the arguments, in the format used by libwayland.
+    pub signature: &'static str,
+    /// The interface of each argument, for `object` and `new_id` arguments.
+    pub types: &'static [::core::option::Option<&'static str>],
+}
+";
+
+struct Context<'p> {
+    protocol: &'p Protocol,
+    support: &'p str,
+}
+
+impl<'p> Context<'p> {
+    fn interface<W: Write>(&self, out: &mut W, interface: &Interface) -> fmt::Result {
+        doc(out, "", interface.description.as_ref(), None)?;
+        writeln!(out, "pub mod {} {{", Ident(&interface.name))?;
+        writeln!(out, "    /// The name of the interface.")?;
+        writeln!(out, "    pub const NAME: &str = \"{}\";", interface.name)?;
+        writeln!(out, "    /// The latest version of the interface.")?;
+        writeln!(out, "    pub const VERSION: u32 = {};", interface.version)?;
+        self.object(out, interface)?;
+        self.table(out, "REQUESTS", "requests", &interface.requests)?;
+        self.table(out, "EVENTS", "events", &interface.events)?;
+        self.messages(out, "Request", &interface.requests)?;
+        self.messages(out, "Event", &interface.events)?;
+        for enumeration in &interface.enums {
+            writeln!(out)?;
+            if enumeration.bitfield {
+                self.bitfield(out, enumeration)?;
+            } else {
+                self.enumeration(out, enumeration)?;
+            }
+        }
+        writeln!(out, "}}")
+    }
+
+    fn object<W: Write>(&self, out: &mut W, interface: &Interface) -> fmt::Result {
+        let name = Camel(&interface.name);
+        writeln!(out)?;
+        writeln!(out, "    /// A `{}` object.", interface.name)?;
+        writeln!(
+            out,
+            "    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]"
+        )?;
+        writeln!(
+            out,
+            "    pub struct {}(pub {}::ObjectId);",
+            name, self.support
+        )?;
+        writeln!(out)?;
+        writeln!(out, "    impl {} {{", name)?;
+        writeln!(out, "        /// The name of the interface.")?;
+        writeln!(out, "        pub const NAME: &'static str = NAME;")?;
+        writeln!(out, "        /// The latest version of the interface.")?;
+        writeln!(out, "        pub const VERSION: u32 = VERSION;")?;
+        writeln!(out, "    }}")
+    }
+
+    fn table<W: Write>(
+        &self,
+        out: &mut W,
+        name: &str,
+        what: &str,
+        messages: &[Message],
+    ) -> fmt::Result {
+        writeln!(out)?;
+        writeln!(out, "    /// The {}, indexed by opcode.", what)?;
+        writeln!(
+            out,
+            "    pub const {}: &[{}::MessageDesc] = &[",
+            name, self.support
+        )?;
+        for message in messages {
+            writeln!(out, "        {}::MessageDesc {{", self.support)?;
+            writeln!(out, "            name: \"{}\",", message.name)?;
+            writeln!(out, "            since: {},", message.since)?;
+            writeln!(out, "            signature: \"{}\",", message.signature())?;
+            write!(out, "            types: &[")?;
+            for (i, arg) in message.args.iter().enumerate() {
+                if i > 0 {
+                    write!(out, ", ")?;
+                }
+                match (arg.kind, &arg.interface) {
+                    (ArgKind::NewId, None) => write!(out, "None, None, None")?,
+                    (ArgKind::NewId | ArgKind::Object, Some(interface)) => {
+                        write!(out, "Some(\"{}\")", interface)?
+                    }
+                    _ => write!(out, "None")?,
+                }
+            }
+            writeln!(out, "],")?;
+            writeln!(out, "        }},")?;
+        }
+        writeln!(out, "    ];")
+    }
+
+    fn messages<W: Write>(&self, out: &mut W, name: &str, messages: &[Message]) -> fmt::Result {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    /// The {}s of the interface.",
+            name.to_lowercase()
+        )?;
+        writeln!(out, "    #[derive(Debug)]")?;
+        writeln!(out, "    #[allow(clippy::enum_variant_names)]")?;
+        writeln!(out, "    pub enum {} {{", name)?;
+        for message in messages {
+            let versions = Versions(message.since, message.deprecated_since);
+            doc(
+                out,
+                "        ",
+                message.description.as_ref(),
+                Some(versions),
+            )?;
+            if message.args.is_empty() {
+                writeln!(out, "        {},", Camel(&message.name))?;
+                continue;
+            }
+            writeln!(out, "        {} {{", Camel(&message.name))?;
+            for arg in &message.args {
+                if let Some(ref summary) = arg.summary {
+                    writeln!(out, "            /// {}", Escape(summary))?;
+                }
+                write!(out, "            {}: ", Ident(&arg.name))?;
+                self.arg_type(out, arg)?;
+                writeln!(out, ",")?;
+            }
+            writeln!(out, "        }},")?;
+        }
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    impl {} {{", name)?;
+        writeln!(out, "        /// Returns the opcode of the message.")?;
+        writeln!(out, "        pub fn opcode(&self) -> u16 {{")?;
+        self.match_messages(out, name, messages, |out, i, _| write!(out, "{}", i))?;
+        writeln!(out, "        }}")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "        /// Returns the interface version that added the message."
+        )?;
+        writeln!(out, "        pub fn since(&self) -> u32 {{")?;
+        self.match_messages(out, name, messages, |out, _, message| {
+            write!(out, "{}", message.since)
+        })?;
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")
+    }
+
+    fn match_messages<W: Write>(
+        &self,
+        out: &mut W,
+        name: &str,
+        messages: &[Message],
+        value: impl Fn(&mut W, usize, &Message) -> fmt::Result,
+    ) -> fmt::Result {
+        writeln!(out, "            match *self {{")?;
+        for (i, message) in messages.iter().enumerate() {
+            write!(out, "                {}::{}", name, Camel(&message.name))?;
+            if !message.args.is_empty() {
+                write!(out, " {{ .. }}")?;
+            }
+            write!(out, " => ")?;
+            value(out, i, message)?;
+            writeln!(out, ",")?;
+        }
+        writeln!(out, "            }}")
+    }
+
+    fn arg_type<W: Write>(&self, out: &mut W, arg: &Arg) -> fmt::Result {
+        if arg.allow_null {
+            write!(out, "::core::option::Option<")?;
+        }
+        let support = self.support;
+        match arg.kind {
+            ArgKind::Int | ArgKind::Uint => match arg.enumeration {
+                Some(ref enum_ref) => match enum_ref.interface {
+                    None => write!(out, "{}", TypeName(&enum_ref.name))?,
+                    Some(ref owner) if self.has_enum(owner, &enum_ref.name) => {
+                        write!(out, "super::{}::{}", Ident(owner), TypeName(&enum_ref.name))?
+                    }
+                    Some(_) if arg.kind == ArgKind::Int => write!(out, "i32")?,
+                    Some(_) => write!(out, "u32")?,
+                },
+                None if arg.kind == ArgKind::Int => write!(out, "i32")?,
+                None => write!(out, "u32")?,
+            },
+            ArgKind::NewId if arg.interface.is_none() => write!(out, "{}::UntypedNewId", support)?,
+            ArgKind::NewId => write!(out, "{}::NewId", support)?,
+            ArgKind::Fixed => write!(out, "{}::Fixed", support)?,
+            ArgKind::String => write!(out, "::std::string::String")?,
+            ArgKind::Object => write!(out, "{}::ObjectId", support)?,
+            ArgKind::Array => write!(out, "::std::vec::Vec<u8>")?,
+            ArgKind::Fd => write!(out, "::std::os::unix::io::OwnedFd")?,
+        }
+        if arg.allow_null {
+            write!(out, ">")?;
+        }
+        Ok(())
+    }
+
+    fn has_enum(&self, interface: &str, name: &str) -> bool {
+        self.protocol
+            .interfaces
+            .iter()
+            .filter(|i| i.name == interface)
+            .flat_map(|i| &i.enums)
+            .any(|e| e.name == name)
+    }
+
+    fn enumeration<W: Write>(&self, out: &mut W, enumeration: &Enum) -> fmt::Result {
+        let name = TypeName(&enumeration.name);
+        let versions = Versions(enumeration.since, enumeration.deprecated_since);
+        doc(
+            out,
+            "    ",
+            enumeration.description.as_ref(),
+            Some(versions),
+        )?;
+        writeln!(
+            out,
+            "    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]"
+        )?;
+        writeln!(out, "    #[repr(u32)]")?;
+        writeln!(out, "    #[allow(clippy::enum_variant_names)]")?;
+        writeln!(out, "    pub enum {} {{", name)?;
+        let is_alias = |i: usize| {
+            enumeration.entries[..i]
+                .iter()
+                .any(|e| e.value == enumeration.entries[i].value)
+        };
+        for (i, entry) in enumeration.entries.iter().enumerate() {
+            if is_alias(i) {
+                continue;
+            }
+            entry_doc(out, entry)?;
+            writeln!(out, "        {} = {},", Camel(&entry.name), entry.value)?;
+        }
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    impl {} {{", name)?;
+        for (i, entry) in enumeration.entries.iter().enumerate() {
+            if !is_alias(i) {
+                continue;
+            }
+            let original = enumeration.entries[..i]
+                .iter()
+                .find(|e| e.value == entry.value)
+                .map_or("", |e| &e.name);
+            entry_doc(out, entry)?;
+            writeln!(out, "        #[allow(non_upper_case_globals)]")?;
+            writeln!(
+                out,
+                "        pub const {}: Self = {}::{};",
+                Camel(&entry.name),
+                name,
+                Camel(original)
+            )?;
+            writeln!(out)?;
+        }
+        writeln!(out, "        /// Returns the entry with the given value.")?;
+        writeln!(
+            out,
+            "        pub fn from_raw(value: u32) -> ::core::option::Option<Self> {{"
+        )?;
+        writeln!(out, "            match value {{")?;
+        for (i, entry) in enumeration.entries.iter().enumerate() {
+            if !is_alias(i) {
+                writeln!(
+                    out,
+                    "                {} => Some({}::{}),",
+                    entry.value,
+                    name,
+                    Camel(&entry.name)
+                )?;
+            }
+        }
+        writeln!(out, "                _ => None,")?;
+        writeln!(out, "            }}")?;
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")
+    }
+
+    fn bitfield<W: Write>(&self, out: &mut W, enumeration: &Enum) -> fmt::Result {
+        let name = TypeName(&enumeration.name);
+        let versions = Versions(enumeration.since, enumeration.deprecated_since);
+        doc(
+            out,
+            "    ",
+            enumeration.description.as_ref(),
+            Some(versions),
+        )?;
+        writeln!(
+            out,
+            "    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]"
+        )?;
+        writeln!(out, "    pub struct {}(u32);", name)?;
+        writeln!(out)?;
+        writeln!(out, "    impl {} {{", name)?;
+        for entry in &enumeration.entries {
+            entry_doc(out, entry)?;
+            writeln!(
+                out,
+                "        pub const {}: Self = Self({});",
+                Upper(&entry.name),
+                entry.value
+            )?;
+            writeln!(out)?;
+        }
+        out.write_str(BITFIELD)?;
+        writeln!(out, "    }}")?;
+        for (op, method, operator) in [("BitOr", "bitor", "|"), ("BitAnd", "bitand", "&")] {
+            writeln!(out)?;
+            writeln!(out, "    impl ::core::ops::{} for {} {{", op, name)?;
+            writeln!(out, "        type Output = Self;")?;
+            writeln!(out)?;
+            writeln!(out, "        fn {}(self, other: Self) -> Self {{", method)?;
+            writeln!(out, "            Self(self.0 {} other.0)", operator)?;
+            writeln!(out, "        }}")?;
+            writeln!(out, "    }}")?;
+        }
+        writeln!(out)?;
+        writeln!(out, "    impl ::core::ops::BitOrAssign for {} {{", name)?;
+        writeln!(out, "        fn bitor_assign(&mut self, other: Self) {{")?;
+        writeln!(out, "            self.0 |= other.0;")?;
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")
+    }
+}
+
+const BITFIELD: &str = "        /// Creates a value from its bits, keeping unknown bits.
+        pub const fn from_bits(bits: u32) -> Self {
+            Self(bits)
+        }
+
+        /// Returns the bits of the value.
+        pub const fn bits(self) -> u32 {
+            self.0
+        }
+
+        /// Returns whether all of the bits of `other` are set.
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        /// Returns whether no bits are set.
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+";
+
+/// Writes a doc comment for an item with a description, and the versions
+/// that added and deprecated it.
+///
+/// Lines keep at most three spaces of their indentation: enough for the
+/// continuation lines of a list item, but not enough for rustdoc to read
+/// indented text as a code block, which it would try to compile.
+fn doc<W: Write>(
+    out: &mut W,
+    indent: &str,
+    description: Option<&Description>,
+    versions: Option<Versions>,
+) -> fmt::Result {
+    let mut empty = true;
+    if let Some(description) = description {
+        if !description.summary.is_empty() {
+            writeln!(out, "{}/// {}", indent, Escape(&description.summary))?;
+            empty = false;
+        }
+        let mut lines = description.lines().peekable();
+        if lines.peek().is_some() && !empty {
+            writeln!(out, "{}///", indent)?;
+        }
+        for line in lines {
+            empty = false;
+            if line.is_empty() {
+                writeln!(out, "{}///", indent)?;
+            } else {
+                let text = line.trim_start();
+                let spaces = (line.len() - text.len()).min(3);
+                writeln!(out, "{0}/// {1:2$}{3}", indent, "", spaces, Escape(text))?;
+            }
+        }
+    }
+    if let Some(Versions(since, deprecated_since)) = versions {
+        if since > 1 || deprecated_since.is_some() {
+            if !empty {
+                writeln!(out, "{}///", indent)?;
+            }
+            if since > 1 {
+                writeln!(out, "{}/// Added in version {}.", indent, since)?;
+            }
+            if let Some(version) = deprecated_since {
+                writeln!(out, "{}/// Deprecated since version {}.", indent, version)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn entry_doc<W: Write>(out: &mut W, entry: &Entry) -> fmt::Result {
+    let versions = Some(Versions(entry.since, entry.deprecated_since));
+    match (&entry.description, &entry.summary) {
+        (Some(description), _) => doc(out, "        ", Some(description), versions),
+        (None, Some(summary)) => {
+            let description = Description {
+                summary: summary.clone(),
+                body: String::new(),
+            };
+            doc(out, "        ", Some(&description), versions)
+        }
+        (None, None) => doc(out, "        ", None, versions),
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Versions(u32, Option<u32>);
+
+/// Escapes text for a doc comment, so it isn't read as links or HTML.
+///
+/// Summaries are attributes, which can span lines, so line breaks and the
+/// indentation after them are written as a single space.
+struct Escape<'a>(&'a str);
+
+impl<'a> fmt::Display for Escape<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut lines = self.0.lines();
+        if let Some(line) = lines.next() {
+            escape(f, line.trim_end())?;
+        }
+        for line in lines {
+            f.write_char(' ')?;
+            escape(f, line.trim())?;
+        }
+        Ok(())
+    }
+}
+
+fn escape(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    for c in s.chars() {
+        if matches!(c, '\\' | '[' | ']' | '<' | '>') {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// A snake-case identifier, written as a raw identifier if it's a keyword.
+struct Ident<'a>(&'a str);
+
+impl<'a> fmt::Display for Ident<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            "self" | "super" | "crate" | "Self" => write!(f, "{}_", self.0),
+            name if KEYWORDS.contains(&name) => write!(f, "r#{}", name),
+            name if name.starts_with(|c: char| c.is_ascii_digit()) => write!(f, "_{}", name),
+            name => f.write_str(name),
+        }
+    }
+}
+
+/// A name converted to camel case, for types and variants, with a `_`
+/// after `Self`, which can't be used as a name.
+struct Camel<'a>(&'a str);
+
+impl<'a> fmt::Display for Camel<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0 == "self" {
+            return f.write_str("Self_");
+        }
+        if self.0.starts_with(|c: char| c.is_ascii_digit()) {
+            f.write_char('_')?;
+        }
+        for part in self.0.split('_') {
+            let mut chars = part.chars();
+            if let Some(c) = chars.next() {
+                f.write_char(c.to_ascii_uppercase())?;
+                f.write_str(chars.as_str())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A name converted to camel case for a type, avoiding the names of the
+/// `Request` and `Event` enums.
+struct TypeName<'a>(&'a str);
+
+impl<'a> fmt::Display for TypeName<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Camel(self.0).fmt(f)?;
+        if self.0 == "request" || self.0 == "event" {
+            f.write_str("Enum")?;
+        }
+        Ok(())
+    }
+}
+
+/// A name converted to upper case, for constants.
+struct Upper<'a>(&'a str);
+
+impl<'a> fmt::Display for Upper<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.starts_with(|c: char| c.is_ascii_digit()) {
+            f.write_char('_')?;
+        }
+        for c in self.0.chars() {
+            f.write_char(c.to_ascii_uppercase())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,262 @@
+//! Semantic checks for protocols.
+//!
+//! [`parse`](super::parse) only checks that a protocol file has the right
+//! shape. [`validate`] checks the rules that `wayland-scanner` and the
+//! Wayland versioning conventions rely on:
+//!
+//! - The `interface` of an `object` or `new_id` argument is defined, either
+//!   in the protocol or in one of the protocols it uses, such as `wayland`
+//!   for `wl_surface`.
+//! - The `enum` of an `int` or `uint` argument resolves, in the same
+//!   interface or with the `interface.enum` form.
+//! - `since` and `deprecated-since` are at most the interface version, and
+//!   `deprecated-since` is greater than `since`.
+//! - The entries of a bitfield enum are zero, distinct powers of two, or
+//!   combinations of other entries, such as `top_left` in
+//!   `wl_shell_surface.resize`.
+//! - Destructors don't create objects.
+
+use super::{ArgKind, EnumRef, Interface, Protocol, Span};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// A problem found by [`validate`], with the span of the item it's about.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    span: Span,
+    kind: ErrorKind,
+}
+
+impl Error {
+    /// Returns the span of the opening tag of the item with the problem.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns what went wrong.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.span.start)
+    }
+}
+
+/// The kind of a validation [`Error`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// An argument refers to an interface that isn't defined.
+    UnknownInterface(String),
+    /// An argument that isn't an `object` or `new_id` has an `interface`.
+    UnexpectedInterface(ArgKind),
+    /// An argument refers to an enum that isn't defined.
+    UnknownEnum(EnumRef),
+    /// An argument that isn't an `int` or `uint` has an `enum`.
+    UnexpectedEnum(ArgKind),
+    /// An item is added in a later version than the interface's.
+    SinceAfterVersion {
+        /// The version that added the item.
+        since: u32,
+        /// The version of the interface.
+        version: u32,
+    },
+    /// An item is deprecated in a later version than the interface's.
+    DeprecatedAfterVersion {
+        /// The version that deprecated the item.
+        deprecated_since: u32,
+        /// The version of the interface.
+        version: u32,
+    },
+    /// An item is deprecated in the version that added it, or earlier.
+    DeprecatedBeforeSince {
+        /// The version that added the item.
+        since: u32,
+        /// The version that deprecated the item.
+        deprecated_since: u32,
+    },
+    /// An entry of a bitfield enum isn't zero, a power of two or a
+    /// combination of other entries.
+    NotPowerOfTwo(u32),
+    /// An entry of a bitfield enum shares bits with the named entry.
+    OverlappingEntry(String),
+    /// A destructor has a `new_id` argument.
+    DestructorWithNewId,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ErrorKind::UnknownInterface(ref name) => write!(f, "unknown interface `{}`", name),
+            ErrorKind::UnexpectedInterface(kind) => {
+                write!(f, "`{}` argument has an interface", kind)
+            }
+            ErrorKind::UnknownEnum(ref name) => write!(f, "unknown enum `{}`", name),
+            ErrorKind::UnexpectedEnum(kind) => write!(f, "`{}` argument has an enum", kind),
+            ErrorKind::SinceAfterVersion { since, version } => write!(
+                f,
+                "since {} is after the interface version {}",
+                since, version
+            ),
+            ErrorKind::DeprecatedAfterVersion {
+                deprecated_since,
+                version,
+            } => write!(
+                f,
+                "deprecated-since {} is after the interface version {}",
+                deprecated_since, version
+            ),
+            ErrorKind::DeprecatedBeforeSince {
+                since,
+                deprecated_since,
+            } => write!(
+                f,
+                "deprecated-since {} isn't after since {}",
+                deprecated_since, since
+            ),
+            ErrorKind::NotPowerOfTwo(value) => {
+                write!(
+                    f,
+                    "bitfield entry value {:#x} isn't a power of two or a combination of other entries",
+                    value
+                )
+            }
+            ErrorKind::OverlappingEntry(ref name) => {
+                write!(f, "bitfield entry overlaps with `{}`", name)
+            }
+            ErrorKind::DestructorWithNewId => f.write_str("destructor has a `new_id` argument"),
+        }
+    }
+}
+
+/// Checks a protocol, returning every problem found, in the order they
+/// appear in the protocol file.
+///
+/// `imports` are the protocols whose interfaces and enums `protocol` may
+/// refer to. They aren't checked themselves.
+pub fn validate(protocol: &Protocol, imports: &[Protocol]) -> Vec<Error> {
+    let find_interface = |name: &str| {
+        core::iter::once(protocol)
+            .chain(imports)
+            .flat_map(|protocol| &protocol.interfaces)
+            .find(|interface| interface.name == name)
+    };
+    let mut errors = Vec::new();
+    for interface in &protocol.interfaces {
+        let mut versions = |span, since, deprecated_since| {
+            check_versions(&mut errors, interface, span, since, deprecated_since)
+        };
+        for message in interface.requests.iter().chain(&interface.events) {
+            versions(message.span, message.since, message.deprecated_since);
+        }
+        for enumeration in &interface.enums {
+            versions(
+                enumeration.span,
+                enumeration.since,
+                enumeration.deprecated_since,
+            );
+            for entry in &enumeration.entries {
+                versions(entry.span, entry.since, entry.deprecated_since);
+            }
+        }
+
+        for message in interface.requests.iter().chain(&interface.events) {
+            for arg in &message.args {
+                let mut error = |kind| {
+                    errors.push(Error {
+                        span: arg.span,
+                        kind,
+                    })
+                };
+                match (arg.kind, &arg.interface) {
+                    (ArgKind::Object | ArgKind::NewId, Some(name)) => {
+                        if find_interface(name).is_none() {
+                            error(ErrorKind::UnknownInterface(name.clone()));
+                        }
+                    }
+                    (kind, Some(_)) => error(ErrorKind::UnexpectedInterface(kind)),
+                    (_, None) => {}
+                }
+                if let Some(ref enum_ref) = arg.enumeration {
+                    if !matches!(arg.kind, ArgKind::Int | ArgKind::Uint) {
+                        error(ErrorKind::UnexpectedEnum(arg.kind));
+                    }
+                    let owner = match enum_ref.interface {
+                        Some(ref name) => find_interface(name),
+                        None => Some(interface),
+                    };
+                    let found = owner
+                        .is_some_and(|owner| owner.enums.iter().any(|e| e.name == enum_ref.name));
+                    if !found {
+                        error(ErrorKind::UnknownEnum(enum_ref.clone()));
+                    }
+                }
+                if message.destructor && arg.kind == ArgKind::NewId {
+                    error(ErrorKind::DestructorWithNewId);
+                }
+            }
+        }
+
+        for enumeration in interface.enums.iter().filter(|e| e.bitfield) {
+            let bits = enumeration
+                .entries
+                .iter()
+                .filter(|entry| entry.value.count_ones() == 1)
+                .fold(0, |bits, entry| bits | entry.value);
+            for (i, entry) in enumeration.entries.iter().enumerate() {
+                let value = entry.value;
+                let kind = if value.count_ones() > 1 {
+                    if value & !bits == 0 {
+                        continue;
+                    }
+                    ErrorKind::NotPowerOfTwo(value)
+                } else {
+                    match enumeration.entries[..i]
+                        .iter()
+                        .find(|other| other.value.count_ones() <= 1 && other.value & value != 0)
+                    {
+                        Some(other) => ErrorKind::OverlappingEntry(other.name.clone()),
+                        None => continue,
+                    }
+                };
+                errors.push(Error {
+                    span: entry.span,
+                    kind,
+                });
+            }
+        }
+    }
+    errors.sort_by_key(|error| error.span.start);
+    errors
+}
+
+fn check_versions(
+    errors: &mut Vec<Error>,
+    interface: &Interface,
+    span: Span,
+    since: u32,
+    deprecated_since: Option<u32>,
+) {
+    let version = interface.version;
+    let mut error = |kind| errors.push(Error { span, kind });
+    if since > version {
+        error(ErrorKind::SinceAfterVersion { since, version });
+    }
+    if let Some(deprecated_since) = deprecated_since {
+        if deprecated_since > version {
+            error(ErrorKind::DeprecatedAfterVersion {
+                deprecated_since,
+                version,
+            });
+        }
+        if deprecated_since <= since {
+            error(ErrorKind::DeprecatedBeforeSince {
+                since,
+                deprecated_since,
+            });
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! XML output.
+
+use crate::{is_name, Text};
+use core::fmt::{self, Display, Write};
+
+/// Writes XML to a [`fmt::Write`], escaping text and attribute values.
+///
+/// Attribute values and character data are passed as [`Text`]:
+/// [`Text::Verbatim`] is escaped, and [`Text::Escaped`] is assumed to be
+/// escaped already and is written as-is, so text from a [`Parser`](crate::Parser)
+/// round-trips unchanged.
+#[derive(Debug)]
+pub struct Writer<W> {
+    out: W,
+    in_start_tag: bool,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a new writer.
+    pub fn new(out: W) -> Self {
+        Writer {
+            out,
+            in_start_tag: false,
+        }
+    }
+
+    /// Returns the underlying output.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn finish_start_tag(&mut self) -> fmt::Result {
+        if self.in_start_tag {
+            self.in_start_tag = false;
+            self.out.write_char('>')?;
+        }
+        Ok(())
+    }
+
+    /// Writes the start of an opening tag.
+    /// Attributes can be written until any other method is called.
+    ///
+    /// Names aren't escaped, so in debug builds this panics if `name` isn't
+    /// a valid XML name.
+    pub fn open(&mut self, name: &str) -> fmt::Result {
+        debug_assert!(is_name(name), "invalid element name {:?}", name);
+        self.finish_start_tag()?;
+        self.in_start_tag = true;
+        write!(self.out, "<{}", name)
+    }
+
+    /// Writes an attribute of the current opening tag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not called directly after [`open`](Writer::open) or
+    /// another call to `attr`, and in debug builds if `name` isn't a valid
+    /// XML name.
+    pub fn attr<'t>(&mut self, name: &str, value: impl Into<Text<'t>>) -> fmt::Result {
+        assert!(
+            self.in_start_tag,
+            "attribute written outside of a start tag"
+        );
+        debug_assert!(is_name(name), "invalid attribute name {:?}", name);
+        match value.into() {
+            Text::Escaped(s) if s.contains('"') => write!(self.out, " {}='{}'", name, s),
+            Text::Escaped(s) => write!(self.out, " {}=\"{}\"", name, s),
+            Text::Verbatim(s) => {
+                write!(self.out, " {}=\"", name)?;
+                escape(&mut self.out, s, true)?;
+                self.out.write_char('"')
+            }
+        }
+    }
+
+    /// Writes an attribute of the current opening tag, formatting its value
+    /// with [`Display`] and escaping it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not called directly after [`open`](Writer::open) or
+    /// another call to `attr`, and in debug builds if `name` isn't a valid
+    /// XML name.
+    pub fn attr_display(&mut self, name: &str, value: impl Display) -> fmt::Result {
+        assert!(
+            self.in_start_tag,
+            "attribute written outside of a start tag"
+        );
+        debug_assert!(is_name(name), "invalid attribute name {:?}", name);
+        write!(self.out, " {}=\"", name)?;
+        write!(
+            Escape {
+                out: &mut self.out,
+                attr: true,
+            },
+            "{}",
+            value
+        )?;
+        self.out.write_char('"')
+    }
+
+    /// Writes a closing tag, or ends the opening tag with `/>` if the
+    /// element is empty.
+    pub fn close(&mut self, name: &str) -> fmt::Result {
+        if self.in_start_tag {
+            self.in_start_tag = false;
+            self.out.write_str("/>")
+        } else {
+            debug_assert!(is_name(name), "invalid element name {:?}", name);
+            write!(self.out, "</{}>", name)
+        }
+    }
+
+    /// Writes character data.
+    pub fn text<'t>(&mut self, text: impl Into<Text<'t>>) -> fmt::Result {
+        self.finish_start_tag()?;
+        match text.into() {
+            Text::Escaped(s) => self.out.write_str(s),
+            Text::Verbatim(s) => escape(&mut self.out, s, false),
+        }
+    }
+
+    /// Writes character data, formatting it with [`Display`] and escaping it.
+    pub fn text_display(&mut self, text: impl Display) -> fmt::Result {
+        self.finish_start_tag()?;
+        write!(
+            Escape {
+                out: &mut self.out,
+                attr: false,
+            },
+            "{}",
+            text
+        )
+    }
+
+    /// Returns whether attributes can be written, because an opening tag
+    /// was just started.
+    #[cfg(feature = "serde")]
+    pub(crate) fn in_start_tag(&self) -> bool {
+        self.in_start_tag
+    }
+
+    /// Writes a CDATA section.
+    ///
+    /// A `]]>` in the text ends the section and starts another one between
+    /// its `]]` and `>`, so that the text reads back unchanged.
+    pub fn cdata(&mut self, text: &str) -> fmt::Result {
+        self.finish_start_tag()?;
+        self.out.write_str("<![CDATA[")?;
+        for (i, part) in text.split("]]>").enumerate() {
+            if i > 0 {
+                self.out.write_str("]]]]><![CDATA[>")?;
+            }
+            self.out.write_str(part)?;
+        }
+        self.out.write_str("]]>")
+    }
+
+    /// Writes a comment.
+    ///
+    /// Comments can't contain `--` or end with `-`, so a space is written
+    /// between two hyphens and after a final one.
+    pub fn comment(&mut self, text: &str) -> fmt::Result {
+        self.finish_start_tag()?;
+        self.out.write_str("<!--")?;
+        separate(&mut self.out, text, '-', '-')?;
+        if text.ends_with('-') {
+            self.out.write_char(' ')?;
+        }
+        self.out.write_str("-->")
+    }
+
+    /// Writes a processing instruction.
+    ///
+    /// Processing instructions can't contain `?>`, so a space is written
+    /// between the two characters.
+    pub fn pi(&mut self, text: &str) -> fmt::Result {
+        self.finish_start_tag()?;
+        self.out.write_str("<?")?;
+        separate(&mut self.out, text, '?', '>')?;
+        self.out.write_str("?>")
+    }
+
+    /// Writes a doctype declaration.
+    pub fn doctype(&mut self, name: &str, body: &str) -> fmt::Result {
+        self.finish_start_tag()?;
+        if body.is_empty() {
+            write!(self.out, "<!DOCTYPE {}>", name)
+        } else {
+            write!(self.out, "<!DOCTYPE {} [{}]>", name, body)
+        }
+    }
+}
+
+struct Escape<'w, W> {
+    out: &'w mut W,
+    attr: bool,
+}
+
+impl<'w, W: Write> Write for Escape<'w, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        escape(self.out, s, self.attr)
+    }
+}
+
+fn escape<W: Write>(out: &mut W, s: &str, attr: bool) -> fmt::Result {
+    for c in s.chars() {
+        match c {
+            '&' => out.write_str("&amp;")?,
+            '<' => out.write_str("&lt;")?,
+            '>' => out.write_str("&gt;")?,
+            '"' if attr => out.write_str("&quot;")?,
+            '\t' if attr => out.write_str("&#x9;")?,
+            '\n' if attr => out.write_str("&#xA;")?,
+            '\r' => out.write_str("&#xD;")?,
+            c => out.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes `s`, with a space between each `first` and a `second` right
+/// after it.
+fn separate<W: Write>(out: &mut W, s: &str, first: char, second: char) -> fmt::Result {
+    let mut prev = None;
+    for c in s.chars() {
+        if c == second && prev == Some(first) {
+            out.write_char(' ')?;
+        }
+        out.write_char(c)?;
+        prev = Some(c);
+    }
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use txml::arena::{Error, Node, NodeKind, Tree};
+
+#[test]
+fn node_count() {
+    let text = "<!DOCTYPE a>\n<!--c-->\n<a x='1'>t<b/><![CDATA[u]]><?pi?></a>\n";
+    let mut storage = [Node::EMPTY; 8];
+    let tree = Tree::parse(text, &mut storage).unwrap();
+    assert_eq!(tree.len(), 6);
+    assert_eq!(tree.children().count(), 2);
+    let root = tree.root();
+    assert_eq!(root.index(), 1);
+    assert_eq!(root.attr("x").unwrap(), "1");
+    assert_eq!(root.children().count(), 4);
+    assert_eq!(root.child("b").unwrap().parent().unwrap().index(), 1);
+    assert!(matches!(*tree.get(5).kind(), NodeKind::Pi("pi")));
+    assert_eq!(root.texts().count(), 2);
+}
+
+#[test]
+fn capacity() {
+    let text = "<!--c--><a>t<b/></a>";
+    let mut storage = [Node::EMPTY; 4];
+    assert_eq!(Tree::parse(text, &mut storage).unwrap().len(), 4);
+    let mut storage = [Node::EMPTY; 3];
+    assert_eq!(
+        Tree::parse(text, &mut storage).unwrap_err(),
+        Error::Capacity
+    );
+    assert_eq!(Tree::parse("<a/>", &mut []).unwrap_err(), Error::Capacity);
+}
+
+#[test]
+fn malformed() {
+    let mut storage = [Node::EMPTY; 4];
+    for text in ["<a>", "<a></b>", "<a/><b/>", "x<a/>", "<!--c-->"] {
+        assert_eq!(
+            Tree::parse(text, &mut storage).unwrap_err(),
+            Error::Malformed
+        );
+    }
+}
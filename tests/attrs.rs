@@ -0,0 +1,113 @@
+use txml::{AttrError, Event, ParseError, Parser};
+
+#[test]
+fn invalid() {
+    let attrs = match Parser::new(r#"<a n="300" b="yes"/>"#).next() {
+        Some(Event::Open(_, attrs)) => attrs,
+        event => panic!("unexpected {:?}", event),
+    };
+    let err = attrs.parse::<u8>("n").unwrap_err();
+    assert!(matches!(err, AttrError::Invalid("n", _, _)));
+    let err = attrs.parse_bool("b").unwrap_err();
+    assert!(matches!(err, AttrError::Invalid("b", _, _)));
+
+    #[cfg(feature = "alloc")]
+    {
+        let err = attrs.parse::<u8>("n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid value "300" for attribute `n`: number too large to fit in target type"#
+        );
+        let err = attrs.parse_bool("b").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid value "yes" for attribute `b`: expected `true`, `false`, `1` or `0`"#
+        );
+    }
+}
+
+#[test]
+fn parse_error() {
+    let attrs = match Parser::new(r#"<a n="-1"/>"#).next() {
+        Some(Event::Open(_, attrs)) => attrs,
+        event => panic!("unexpected {:?}", event),
+    };
+    match attrs.parse::<u8>("n").unwrap_err() {
+        AttrError::Invalid(name, value, err) => {
+            assert_eq!(name, "n");
+            assert_eq!(value, "-1");
+            let expected = "-1".parse::<u8>().unwrap_err();
+            assert_eq!(err, ParseError::new(&expected));
+            if cfg!(feature = "alloc") {
+                assert_eq!(err.message(), expected.to_string());
+            } else {
+                assert_eq!(err.message(), "");
+            }
+        }
+        err => panic!("unexpected {:?}", err),
+    }
+}
+
+#[test]
+fn long_escaped_value() {
+    // Without `alloc`, values with references are decoded into a 256-byte
+    // buffer, so a longer decoded value can't be parsed.
+    let short = format!("<a v='&amp;{}'/>", "x".repeat(255));
+    let long = format!("<a v='&amp;{}'/>", "x".repeat(256));
+    fn parse(doc: &str) -> Result<usize, AttrError<'_>> {
+        match Parser::new(doc).next() {
+            Some(Event::Open(_, attrs)) => attrs.parse::<String>("v").map(|v| v.unwrap().len()),
+            event => panic!("unexpected {:?}", event),
+        }
+    }
+    assert_eq!(parse(&short), Ok(256));
+    if cfg!(feature = "alloc") {
+        assert_eq!(parse(&long), Ok(257));
+    } else {
+        match parse(&long).unwrap_err() {
+            AttrError::Invalid("v", _, err) => {
+                assert_eq!(err, ParseError::new("value too long to decode"))
+            }
+            err => panic!("unexpected {:?}", err),
+        }
+    }
+    // Values without references are passed through without decoding.
+    let plain = format!("<a v='{}'/>", "x".repeat(1000));
+    assert_eq!(parse(&plain), Ok(1000));
+}
+
+#[test]
+fn extract() {
+    fn attrs(doc: &str) -> txml::Attrs<'_> {
+        match Parser::new(doc).next() {
+            Some(Event::Open(_, attrs)) => attrs,
+            event => panic!("unexpected {:?}", event),
+        }
+    }
+
+    let [name, version, since] = attrs(r#"<a version="2" name="x"/>"#)
+        .extract(["name", "version", "since"])
+        .unwrap();
+    assert_eq!(name.unwrap(), "x");
+    assert_eq!(version.unwrap(), "2");
+    assert!(since.is_none());
+    // A missing attribute is only an error once it's required.
+    let err = since.ok_or(AttrError::Missing("since")).unwrap_err();
+    assert_eq!(err, AttrError::Missing("since"));
+    assert_eq!(err.to_string(), "missing attribute `since`");
+
+    let err = attrs(r#"<a name="x" version="2" name="y"/>"#)
+        .extract(["name", "version"])
+        .unwrap_err();
+    assert_eq!(err, AttrError::Duplicate("name"));
+    assert_eq!(err.to_string(), "duplicate attribute `name`");
+
+    let err = attrs(r#"<a name="x" extra="1" version="2"/>"#)
+        .extract(["name", "version"])
+        .unwrap_err();
+    assert_eq!(err, AttrError::Unknown("extra"));
+    assert_eq!(err.to_string(), "unexpected attribute `extra`");
+
+    let [] = attrs("<a/>").extract([]).unwrap();
+    assert!(attrs("<a b=''/>").extract([]).is_err());
+}
@@ -0,0 +1,211 @@
+//! Test vectors from the examples in the Canonical XML and Exclusive XML
+//! Canonicalization specifications. Parts of the examples that need a DTD,
+//! such as default attributes, are left out, as txml doesn't read DTDs.
+
+use txml::c14n::{canonicalize, Error, Options};
+
+fn c14n(doc: &str, options: &Options) -> Result<String, Error> {
+    let mut out = String::new();
+    canonicalize(doc, options, &mut out)?;
+    Ok(out)
+}
+
+const INCLUSIVE: Options = Options {
+    exclusive: false,
+    with_comments: false,
+    inclusive_prefixes: &[],
+};
+
+const EXCLUSIVE: Options = Options {
+    exclusive: true,
+    ..INCLUSIVE
+};
+
+// Canonical XML 1.0, 3.1: PIs, Comments, and Outside of Document Element.
+const PIS_AND_COMMENTS: &str = r#"<?xml version="1.0"?>
+
+<?xml-stylesheet   href="doc.xsl"
+   type="text/xsl"   ?>
+
+<!DOCTYPE doc SYSTEM "doc.dtd">
+
+<doc>Hello, world!<!-- Comment 1 --></doc>
+
+<?pi-without-data     ?>
+
+<!-- Comment 2 -->
+
+<!-- Comment 3 -->
+"#;
+
+#[test]
+fn pis_and_comments() {
+    assert_eq!(
+        c14n(PIS_AND_COMMENTS, &INCLUSIVE).unwrap(),
+        r#"<?xml-stylesheet href="doc.xsl"
+   type="text/xsl"   ?>
+<doc>Hello, world!</doc>
+<?pi-without-data?>"#
+    );
+    let with_comments = Options {
+        with_comments: true,
+        ..INCLUSIVE
+    };
+    assert_eq!(
+        c14n(PIS_AND_COMMENTS, &with_comments).unwrap(),
+        r#"<?xml-stylesheet href="doc.xsl"
+   type="text/xsl"   ?>
+<doc>Hello, world!<!-- Comment 1 --></doc>
+<?pi-without-data?>
+<!-- Comment 2 -->
+<!-- Comment 3 -->"#
+    );
+}
+
+// Canonical XML 1.0, 3.3: Start and End Tags.
+const TAGS: &str = r#"<doc>
+   <e1   />
+   <e2   ></e2>
+   <e3   name = "elem3"   id="elem3"   />
+   <e4   name="elem4"   id="elem4"   ></e4>
+   <e5 a:attr="out" b:attr="sorted" attr2="all" attr="I'm"
+      xmlns:b="http://www.ietf.org"
+      xmlns:a="http://www.w3.org"
+      xmlns="http://example.org"/>
+   <e6 xmlns="" xmlns:a="http://www.w3.org">
+      <e7 xmlns="http://www.ietf.org">
+         <e8 xmlns="" xmlns:a="http://www.w3.org">
+            <e9 xmlns="" xmlns:a="http://www.ietf.org"/>
+         </e8>
+      </e7>
+   </e6>
+</doc>"#;
+
+#[test]
+fn tags_inclusive() {
+    assert_eq!(
+        c14n(TAGS, &INCLUSIVE).unwrap(),
+        r#"<doc>
+   <e1></e1>
+   <e2></e2>
+   <e3 id="elem3" name="elem3"></e3>
+   <e4 id="elem4" name="elem4"></e4>
+   <e5 xmlns="http://example.org" xmlns:a="http://www.w3.org" xmlns:b="http://www.ietf.org" attr="I'm" attr2="all" b:attr="sorted" a:attr="out"></e5>
+   <e6 xmlns:a="http://www.w3.org">
+      <e7 xmlns="http://www.ietf.org">
+         <e8 xmlns="">
+            <e9 xmlns:a="http://www.ietf.org"></e9>
+         </e8>
+      </e7>
+   </e6>
+</doc>"#
+    );
+}
+
+#[test]
+fn tags_exclusive() {
+    // Only the namespaces that an element or its attributes use are
+    // rendered, so the unused `a` prefix disappears.
+    assert_eq!(
+        c14n(TAGS, &EXCLUSIVE).unwrap(),
+        r#"<doc>
+   <e1></e1>
+   <e2></e2>
+   <e3 id="elem3" name="elem3"></e3>
+   <e4 id="elem4" name="elem4"></e4>
+   <e5 xmlns="http://example.org" xmlns:a="http://www.w3.org" xmlns:b="http://www.ietf.org" attr="I'm" attr2="all" b:attr="sorted" a:attr="out"></e5>
+   <e6>
+      <e7 xmlns="http://www.ietf.org">
+         <e8 xmlns="">
+            <e9></e9>
+         </e8>
+      </e7>
+   </e6>
+</doc>"#
+    );
+}
+
+// Canonical XML 1.0, 3.4: Character Modifications and Character References.
+const CHARACTERS: &str = r#"<doc>
+   <text>First line&#x0d;&#10;Second line</text>
+   <value>&#x32;</value>
+   <compute><![CDATA[value>"0" && value<"10" ?"valid":"error"]]></compute>
+   <norm attr=' &apos;   &#x20;&#13;&#xa;&#9;   &apos; '/>
+</doc>"#;
+
+#[test]
+fn characters() {
+    assert_eq!(
+        c14n(CHARACTERS, &INCLUSIVE).unwrap(),
+        r#"<doc>
+   <text>First line&#xD;
+Second line</text>
+   <value>2</value>
+   <compute>value&gt;"0" &amp;&amp; value&lt;"10" ?"valid":"error"</compute>
+   <norm attr=" '    &#xD;&#xA;&#x9;   ' "></norm>
+</doc>"#
+    );
+}
+
+// Exclusive XML Canonicalization 1.0, 2.2: the difference between the two
+// algorithms, applied to a whole document.
+const EXCLUSIVE_EXAMPLE: &str = r#"<n0:local xmlns:n0="foo:bar" xmlns:n3="ftp://example.org">
+  <n1:elem2 xmlns:n1="http://example.net" xml:lang="en">
+    <n3:stuff xmlns:n3="ftp://example.org"/>
+  </n1:elem2>
+</n0:local>"#;
+
+#[test]
+fn inclusive_and_exclusive() {
+    assert_eq!(
+        c14n(EXCLUSIVE_EXAMPLE, &INCLUSIVE).unwrap(),
+        r#"<n0:local xmlns:n0="foo:bar" xmlns:n3="ftp://example.org">
+  <n1:elem2 xmlns:n1="http://example.net" xml:lang="en">
+    <n3:stuff></n3:stuff>
+  </n1:elem2>
+</n0:local>"#
+    );
+    assert_eq!(
+        c14n(EXCLUSIVE_EXAMPLE, &EXCLUSIVE).unwrap(),
+        r#"<n0:local xmlns:n0="foo:bar">
+  <n1:elem2 xmlns:n1="http://example.net" xml:lang="en">
+    <n3:stuff xmlns:n3="ftp://example.org"></n3:stuff>
+  </n1:elem2>
+</n0:local>"#
+    );
+    // The InclusiveNamespaces PrefixList renders a prefix where it's in
+    // scope, whether or not it's used.
+    let prefixes = Options {
+        inclusive_prefixes: &["n3"],
+        ..EXCLUSIVE
+    };
+    assert_eq!(
+        c14n(EXCLUSIVE_EXAMPLE, &prefixes).unwrap(),
+        r#"<n0:local xmlns:n0="foo:bar" xmlns:n3="ftp://example.org">
+  <n1:elem2 xmlns:n1="http://example.net" xml:lang="en">
+    <n3:stuff></n3:stuff>
+  </n1:elem2>
+</n0:local>"#
+    );
+}
+
+#[test]
+fn attribute_escaping() {
+    // Literal whitespace in attributes is normalized to spaces, with `\r\n`
+    // read as one line break, while references to it are kept.
+    assert_eq!(
+        c14n("<a b='&lt;&amp;&gt;&quot;\t\r\n&#9;&#xD;'/>", &INCLUSIVE).unwrap(),
+        r#"<a b="&lt;&amp;>&quot;  &#x9;&#xD;"></a>"#
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(c14n("<a><b></a>", &INCLUSIVE), Err(Error::Malformed));
+    assert_eq!(c14n("<a/><b/>", &INCLUSIVE), Err(Error::Malformed));
+    assert_eq!(c14n("<p:a/>", &INCLUSIVE), Err(Error::UndeclaredPrefix));
+    assert_eq!(
+        c14n("<a p:b=''/>", &EXCLUSIVE),
+        Err(Error::UndeclaredPrefix)
+    );
+}
@@ -0,0 +1,75 @@
+use txml::check::{check, ErrorKind};
+
+fn error(doc: &str) -> (usize, ErrorKind<'_>) {
+    let error = check(doc).unwrap_err();
+    (error.offset(), error.kind())
+}
+
+#[test]
+fn well_formed() {
+    assert_eq!(
+        check("<a>x]]y<!-- a - b --><![CDATA[]]]]><![CDATA[>]]></a>"),
+        Ok(())
+    );
+}
+
+#[test]
+fn cdata_end_in_text() {
+    assert_eq!(error("<a>x]]>y</a>"), (4, ErrorKind::CdataEndInText));
+    assert_eq!(
+        error("<a>x]]>y<!--a--b--></a>").1,
+        ErrorKind::CdataEndInText
+    );
+}
+
+#[test]
+fn hyphens_in_comment() {
+    assert_eq!(
+        error("<a><!--a--b--></a>"),
+        (8, ErrorKind::HyphensInComment)
+    );
+    assert_eq!(error("<a><!--a---></a>"), (8, ErrorKind::HyphensInComment));
+    assert_eq!(error("<!-----><a/>"), (4, ErrorKind::HyphensInComment));
+}
+
+#[test]
+fn references() {
+    assert_eq!(
+        check("<a b='&#9;&#xA;&#13;&#x20;&#xD7FF;&#xE000;&#xFFFD;&#x10000;&#1114111;'>&lt;&#x1F600;</a>"),
+        Ok(())
+    );
+    for reference in [
+        "&#0;",
+        "&#x0;",
+        "&#x8;",
+        "&#31;",
+        "&#xD800;",
+        "&#xDFFF;",
+        "&#xFFFE;",
+        "&#xFFFF;",
+        "&#x110000;",
+        "&#4294967296;",
+        "&#x+41;",
+        "&#+65;",
+        "&#;",
+        "&#x;",
+        "&#X41;",
+        "&nbsp;",
+        "&amp",
+    ] {
+        let doc = format!("<a>x{}</a>", reference);
+        assert_eq!(
+            error(&doc),
+            (4, ErrorKind::InvalidReference),
+            "{}",
+            reference
+        );
+        let doc = format!("<a b='{}'/>", reference);
+        assert_eq!(
+            error(&doc),
+            (6, ErrorKind::InvalidReference),
+            "{}",
+            reference
+        );
+    }
+}
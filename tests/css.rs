@@ -0,0 +1,44 @@
+use txml::css::Selector;
+use txml::dom::Document;
+
+const XML: &str = r#"<root><a class="x y"><b id="one"/></a><b id="two"/></root>"#;
+
+fn select(selector: &str) -> Vec<String> {
+    let doc = Document::parse(XML).unwrap();
+    Selector::parse(selector)
+        .unwrap()
+        .select(&doc)
+        .map(|e| match e.attr("id") {
+            Some(id) => format!("{}#{}", e.name(), id.collect::<String>()),
+            None => e.name().into(),
+        })
+        .collect()
+}
+
+#[test]
+fn whitespace() {
+    assert_eq!(select(" a\t>\nb "), ["b#one"]);
+    assert_eq!(select("a,\r\x0cb"), ["a", "b#one", "b#two"]);
+    assert_eq!(select("[class~=y]"), ["a"]);
+    assert_eq!(select("b:nth-child( 2n + 1 )"), ["b#one"]);
+}
+
+#[test]
+fn non_ascii_whitespace() {
+    // Only ASCII whitespace separates the parts of a selector: other
+    // characters outside ASCII are part of names.
+    assert!(select("\u{3000}a").is_empty());
+    assert!(select("a >\u{a0}b").is_empty());
+    assert_eq!(select("a, \u{2003}b"), ["a"]);
+}
+
+#[test]
+fn nth_overflow() {
+    assert_eq!(select("b:nth-child(n-2147483648)"), ["b#one", "b#two"]);
+    assert_eq!(select(":nth-child(-n+2147483647)").len(), 4);
+    assert!(select("b:nth-child(-2147483648n+2147483647)").is_empty());
+    assert_eq!(
+        select("b:nth-last-child(2147483647n+1)"),
+        ["b#one", "b#two"]
+    );
+}
@@ -0,0 +1,181 @@
+use txml::convert::{from_str, Error};
+use txml::writer::Writer;
+use txml::{AttrError, FromXml, ToXml};
+
+fn to_string<T: ToXml>(value: &T, name: &str) -> String {
+    let mut writer = Writer::new(String::new());
+    value.to_xml(name, &mut writer).unwrap();
+    writer.into_inner()
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+struct Attrs {
+    #[xml(attr = "name")]
+    name: String,
+    #[xml(attr = "size")]
+    size: Option<u32>,
+    #[xml(attr = "enabled", default)]
+    enabled: bool,
+    #[xml(attr = "count", default = "ten")]
+    count: u8,
+    #[xml(skip)]
+    cache: Vec<u8>,
+}
+
+fn ten() -> u8 {
+    10
+}
+
+#[test]
+fn attrs() {
+    let value: Attrs = from_str(r#"<a name="x &amp; y" enabled="1" other=""/>"#).unwrap();
+    assert_eq!(
+        value,
+        Attrs {
+            name: "x & y".into(),
+            size: None,
+            enabled: true,
+            count: 10,
+            cache: Vec::new(),
+        }
+    );
+    assert_eq!(
+        to_string(&value, "a"),
+        r#"<a name="x &amp; y" enabled="true" count="10"/>"#
+    );
+
+    let value: Attrs = from_str(r#"<a count="3" size="4" name=""/>"#).unwrap();
+    assert_eq!(
+        (value.size, value.enabled, value.count),
+        (Some(4), false, 3)
+    );
+
+    let err = from_str::<Attrs>("<a/>").unwrap_err();
+    assert_eq!(err, Error::Attr(AttrError::Missing("name")));
+    let err = from_str::<Attrs>(r#"<a name="" count="300"/>"#).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Attr(AttrError::Invalid("count", _, _))
+    ));
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+struct Children {
+    #[xml(child = "one")]
+    one: Text,
+    #[xml(child = "maybe")]
+    maybe: Option<Text>,
+    #[xml(child = "many")]
+    many: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+struct Text {
+    #[xml(text)]
+    text: Option<String>,
+}
+
+#[test]
+fn children() {
+    let xml = "<c><many>1</many><one/><unknown><one/></unknown><many>2</many></c>";
+    let value: Children = from_str(xml).unwrap();
+    assert_eq!(
+        value,
+        Children {
+            one: Text { text: None },
+            maybe: None,
+            many: vec!["1".into(), "2".into()],
+        }
+    );
+    assert_eq!(
+        to_string(&value, "c"),
+        "<c><one/><many>1</many><many>2</many></c>"
+    );
+
+    let value: Children = from_str("<c><maybe>x</maybe><one>y</one></c>").unwrap();
+    assert_eq!(value.one.text.as_deref(), Some("y"));
+    assert_eq!(value.maybe.unwrap().text.as_deref(), Some("x"));
+
+    let err = from_str::<Children>("<c><maybe/></c>").unwrap_err();
+    assert_eq!(err, Error::MissingElement("one"));
+    let err = from_str::<Children>("<c><one/><one/></c>").unwrap_err();
+    assert_eq!(err, Error::DuplicateElement("one"));
+    let err = from_str::<Children>("<c><one/><many><x/></many></c>").unwrap_err();
+    assert_eq!(err, Error::UnexpectedElement("x"));
+    let err = from_str::<Children>("<c><one/>").unwrap_err();
+    assert_eq!(err, Error::Malformed);
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+struct Number {
+    #[xml(attr = "unit")]
+    unit: Option<String>,
+    #[xml(text)]
+    value: u32,
+}
+
+#[test]
+fn text() {
+    let value: Number = from_str("<n>4<!-- c -->2<![CDATA[0]]></n>").unwrap();
+    assert_eq!(
+        value,
+        Number {
+            unit: None,
+            value: 420
+        }
+    );
+    assert_eq!(to_string(&value, "n"), "<n>420</n>");
+    let err = from_str::<Number>("<n unit='s'>x</n>").unwrap_err();
+    assert_eq!(err, Error::InvalidText("n"));
+
+    let value = Text {
+        text: Some("a < b".into()),
+    };
+    assert_eq!(to_string(&value, "t"), "<t>a &lt; b</t>");
+    assert_eq!(from_str::<Text>("<t>a &lt; b</t>").unwrap(), value);
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+#[xml(deny_unknown)]
+struct Strict {
+    #[xml(attr = "name")]
+    name: String,
+    #[xml(child = "item")]
+    items: Vec<String>,
+}
+
+#[test]
+fn deny_unknown() {
+    let value: Strict = from_str("<s name='x'><item/><!-- c --></s>").unwrap();
+    assert_eq!(value.items, [""]);
+    let err = from_str::<Strict>("<s name='x' other='y'/>").unwrap_err();
+    assert_eq!(err, Error::Attr(AttrError::Unknown("other")));
+    let err = from_str::<Strict>("<s name='x' name='y'/>").unwrap_err();
+    assert_eq!(err, Error::Attr(AttrError::Duplicate("name")));
+    let err = from_str::<Strict>("<s name='x'><other/></s>").unwrap_err();
+    assert_eq!(err, Error::UnexpectedElement("other"));
+}
+
+#[derive(Debug, PartialEq, FromXml, ToXml)]
+struct List<T> {
+    #[xml(attr = "name")]
+    name: String,
+    #[xml(child = "item")]
+    items: Vec<T>,
+    #[xml(child = "first")]
+    first: Option<T>,
+}
+
+#[test]
+fn generics() {
+    let xml = "<l name='x'><item name='a'/><first name='b'/><item name='c'/></l>";
+    let value: List<Strict> = from_str(xml).unwrap();
+    assert_eq!(value.items.len(), 2);
+    assert_eq!(value.first.as_ref().unwrap().name, "b");
+    assert_eq!(
+        to_string(&value, "l"),
+        r#"<l name="x"><item name="a"/><item name="c"/><first name="b"/></l>"#
+    );
+    let value: List<String> = from_str("<l name='y'><item>1</item></l>").unwrap();
+    assert_eq!(value.items, ["1"]);
+}
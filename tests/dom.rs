@@ -0,0 +1,94 @@
+use txml::dom::{Document, NodeKind};
+
+#[test]
+fn round_trip() {
+    let text = "<?xml version=\"1.0\"?>\n<!DOCTYPE a>\n<!--c-->\n\
+        <a x=\"1 &amp; 2\">t<b/><![CDATA[<c>]]><?pi?></a>\n<!--d-->\n";
+    let doc = Document::parse(text).unwrap();
+    assert_eq!(doc.to_string(), text);
+    assert_eq!(doc.children().count(), 5);
+    let doc = Document::parse("<!--c--><a/>").unwrap();
+    assert_eq!(doc.to_string(), "<!--c--><a/>");
+    let doc = Document::parse("<a></a>").unwrap();
+    assert_eq!(doc.to_string(), "<a/>");
+}
+
+#[test]
+fn append_and_insert() {
+    let mut doc = Document::parse("<a><b/><c/></a>").unwrap();
+    let a = doc.root().id();
+    let b = doc.root().child("b").unwrap().id();
+    let d = doc.create_element("d");
+    doc.append_child(a, d);
+    assert_eq!(doc.to_string(), "<a><b/><c/><d/></a>");
+    doc.insert_child(a, 0, d);
+    assert_eq!(doc.to_string(), "<a><d/><b/><c/></a>");
+    doc.append_child(b, d);
+    assert_eq!(doc.to_string(), "<a><b><d/></b><c/></a>");
+    assert_eq!(doc.get(d).parent().unwrap().name(), "b");
+    let text = doc.create_text("1 < 2");
+    doc.insert_child(b, 0, text);
+    assert_eq!(doc.to_string(), "<a><b>1 &lt; 2<d/></b><c/></a>");
+}
+
+#[test]
+#[should_panic(expected = "cannot insert a node into itself")]
+fn append_to_descendant() {
+    let mut doc = Document::parse("<a><b><c/></b></a>").unwrap();
+    let b = doc.root().child("b").unwrap();
+    let (b, c) = (b.id(), b.child("c").unwrap().id());
+    doc.append_child(c, b);
+}
+
+#[test]
+fn remove() {
+    let mut doc = Document::parse("<!--c-->\n<a><b><c/></b>x</a>").unwrap();
+    let b = doc.root().child("b").unwrap().id();
+    doc.remove(b);
+    assert_eq!(doc.to_string(), "<!--c-->\n<a>x</a>");
+    assert!(doc.get(b).parent().is_none());
+    assert_eq!(doc.get(b).to_string(), "<b><c/></b>");
+    let comment = doc.children().next().unwrap().id();
+    doc.remove(comment);
+    assert_eq!(doc.to_string(), "\n<a>x</a>");
+    let a = doc.root().id();
+    doc.append_child(a, comment);
+    assert_eq!(doc.to_string(), "\n<a>x<!--c--></a>");
+}
+
+#[test]
+fn attrs() {
+    let mut doc = Document::parse("<a x='1' y=\"2\"/>").unwrap();
+    let a = doc.root().id();
+    doc.set_attr(a, "x", "\"3\"");
+    doc.set_attr(a, "z", "4");
+    assert_eq!(doc.to_string(), r#"<a x="&quot;3&quot;" y="2" z="4"/>"#);
+    doc.insert_attr(a, 0, "z", "5");
+    doc.insert_attr(a, 1, "w", "6");
+    assert_eq!(
+        doc.to_string(),
+        r#"<a z="5" w="6" x="&quot;3&quot;" y="2"/>"#
+    );
+    assert!(doc.remove_attr(a, "x"));
+    assert!(!doc.remove_attr(a, "x"));
+    assert_eq!(doc.to_string(), r#"<a z="5" w="6" y="2"/>"#);
+    assert_eq!(doc.root().attr("z").unwrap(), "5");
+}
+
+#[test]
+fn text() {
+    let mut doc = Document::parse("<a><b>x<c/>y</b><![CDATA[z]]></a>").unwrap();
+    let root = doc.root();
+    let b = root.child("b").unwrap().id();
+    let cdata = root.children().nth(1).unwrap().id();
+    doc.set_text(b, "&");
+    doc.set_text(cdata, "<");
+    assert_eq!(doc.to_string(), "<a><b>&amp;</b>&lt;</a>");
+    let text = doc.root().child("b").unwrap().children().next().unwrap();
+    assert_eq!(text.kind(), NodeKind::Text("&".into()));
+    let text = text.id();
+    doc.set_text(text, "1");
+    doc.set_name(b, "c");
+    assert_eq!(doc.to_string(), "<a><c>1</c>&lt;</a>");
+    assert_eq!(doc.root().text(), "1<");
+}
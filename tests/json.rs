@@ -0,0 +1,112 @@
+use txml::json::{from_xml, to_xml, Error, Value};
+use txml::writer::Writer;
+
+#[test]
+fn convert() {
+    let value = from_xml(r#"<a x="1"><b>text</b><b/><c/></a>"#).unwrap();
+    assert_eq!(
+        value.to_string(),
+        r#"{"a":{"@x":"1","b":[{"$":"text"},{}],"c":{}}}"#
+    );
+}
+
+#[test]
+fn mismatched_tags() {
+    assert_eq!(from_xml("<a><b></c></a>"), Err(Error::Malformed));
+    assert_eq!(from_xml("<a><b></a></b>"), Err(Error::Malformed));
+    assert_eq!(from_xml("<a></a></a>"), Err(Error::Malformed));
+}
+
+#[test]
+fn numbers() {
+    for (text, n) in [
+        ("0", 0.0),
+        ("-0", 0.0),
+        ("12", 12.0),
+        ("-1.5", -1.5),
+        ("1e3", 1000.0),
+        ("2E-2", 0.02),
+        ("0.5e+1", 5.0),
+    ] {
+        assert_eq!(Value::parse(text), Ok(Value::Number(n)), "{}", text);
+    }
+    for (text, offset) in [
+        ("1.", 2),
+        ("1.e5", 2),
+        (".5", 0),
+        ("-", 1),
+        ("+1", 0),
+        ("01", 1),
+        ("-01", 2),
+        ("1e", 2),
+        ("1e+", 3),
+        ("1.5.2", 3),
+        ("[1,]", 3),
+        ("0x10", 1),
+        ("Infinity", 0),
+    ] {
+        assert_eq!(Value::parse(text), Err(Error::Syntax(offset)), "{}", text);
+    }
+}
+
+#[test]
+fn depth() {
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(Value::parse(&nested(Value::MAX_DEPTH)).is_ok());
+    let err = Value::parse(&nested(Value::MAX_DEPTH + 1)).unwrap_err();
+    assert_eq!(err, Error::TooDeep(Value::MAX_DEPTH));
+    let deep = "{\"a\":".repeat(100_000);
+    assert_eq!(
+        Value::parse(&deep),
+        Err(Error::TooDeep(5 * Value::MAX_DEPTH))
+    );
+}
+
+#[test]
+fn names() {
+    let convert = |json: &str| {
+        let mut writer = Writer::new(String::new());
+        to_xml(&Value::parse(json).unwrap(), &mut writer).map(|()| writer.into_inner())
+    };
+    assert_eq!(
+        convert(r#"{"a-b.c":{"@x:y":1,"d_1":"t","é":null}}"#).unwrap(),
+        r#"<a-b.c x:y="1"><d_1>t</d_1><é></é></a-b.c>"#
+    );
+    for json in [
+        r#"{"":{}}"#,
+        r#"{"1a":{}}"#,
+        r#"{"a b":{}}"#,
+        r#"{"a><script":{}}"#,
+        r#"{"a":{"b/":[{}]}}"#,
+    ] {
+        let err = convert(json).unwrap_err();
+        assert_eq!(
+            err,
+            Error::Invalid("an element name isn't a valid XML name"),
+            "{}",
+            json
+        );
+    }
+    for json in [
+        r#"{"a":{"@":1}}"#,
+        r#"{"a":{"@x=\"1\" y":1}}"#,
+        r#"{"a":{"@-x":1}}"#,
+    ] {
+        let err = convert(json).unwrap_err();
+        assert_eq!(
+            err,
+            Error::Invalid("an attribute name isn't a valid XML name"),
+            "{}",
+            json
+        );
+    }
+    let err = convert(r#"{"a":{"@xmlns":{"p:q":"urn:x"}}}"#).unwrap_err();
+    assert_eq!(
+        err,
+        Error::Invalid("a namespace prefix isn't a valid XML name")
+    );
+    assert_eq!(
+        convert(r#"{"a":{"@xmlns":{"$":"urn:x","p":"urn:y"}}}"#).unwrap(),
+        r#"<a xmlns="urn:x" xmlns:p="urn:y"/>"#
+    );
+}
@@ -0,0 +1,55 @@
+use txml::{Event, OwnedEvent, OwnedText, Parser, Text};
+
+const DOC: &str =
+    r#"<!DOCTYPE a []><a x="1 &amp; 2" y='&lt;'><?pi data?><!--c-->t &amp; u<![CDATA[<v>]]></a>"#;
+
+#[test]
+fn round_trip() {
+    let events: Vec<Event> = Parser::new(DOC).collect();
+    let owned: Vec<OwnedEvent> = events.iter().map(Event::to_owned).collect();
+    let borrowed: Vec<Event> = owned.iter().map(OwnedEvent::as_borrowed).collect();
+    assert_eq!(borrowed, events);
+    assert_eq!(events.len(), 7);
+    assert_eq!(OwnedEvent::from(events[0].clone()), owned[0]);
+}
+
+#[test]
+fn attrs() {
+    let owned = match Parser::new(DOC).nth(1).unwrap().to_owned() {
+        OwnedEvent::Open(name, attrs) => {
+            assert_eq!(name, "a");
+            attrs
+        }
+        event => panic!("unexpected {:?}", event),
+    };
+    let attrs: Vec<(&str, String)> = owned
+        .as_borrowed()
+        .map(|(name, value)| (name, value.collect()))
+        .collect();
+    assert_eq!(attrs, [("x", "1 & 2".to_string()), ("y", "<".to_string())]);
+    assert_eq!(format!("{:?}", owned), r#"{"x": "1 & 2", "y": "<"}"#);
+}
+
+#[test]
+fn text() {
+    let events: Vec<OwnedEvent> = Parser::new(DOC).map(|e| e.to_owned()).collect();
+    let escaped = match events[4] {
+        OwnedEvent::Text(ref text) => text.clone(),
+        ref event => panic!("unexpected {:?}", event),
+    };
+    let verbatim = match events[5] {
+        OwnedEvent::Text(ref text) => text.clone(),
+        ref event => panic!("unexpected {:?}", event),
+    };
+    // Escaped text keeps its references, and verbatim text its markup.
+    assert_eq!(escaped, OwnedText::Escaped("t &amp; u".into()));
+    assert!(matches!(escaped.as_borrowed(), Text::Escaped("t &amp; u")));
+    assert_eq!(escaped.to_string(), "t & u");
+    assert_eq!(verbatim, OwnedText::Verbatim("<v>".into()));
+    assert!(matches!(verbatim.as_borrowed(), Text::Verbatim("<v>")));
+
+    // Texts are equal when they decode to the same characters.
+    assert_eq!(OwnedText::Escaped("&lt;v&gt;".into()), verbatim);
+    assert_ne!(OwnedText::Verbatim("<v".into()), verbatim);
+    assert_ne!(OwnedText::Verbatim("<v>>".into()), verbatim);
+}
@@ -0,0 +1,135 @@
+use txml::{Event, NestedElements, Parser, TextError};
+
+/// Opens the first element and returns the parser positioned after it.
+fn open(doc: &str) -> Parser<'_> {
+    let mut parser = Parser::new(doc);
+    assert!(matches!(parser.next(), Some(Event::Open("a", _))));
+    parser
+}
+
+fn names(events: impl Iterator<Item = Event<'static>>) -> Vec<String> {
+    events
+        .map(|event| match event {
+            Event::Open(name, _) => format!("<{}>", name),
+            Event::Close(name) => format!("</{}>", name),
+            Event::Text(text) => text.collect(),
+            event => format!("{:?}", event),
+        })
+        .collect()
+}
+
+#[test]
+fn skip_element() {
+    let mut parser = open("<a><a><a>x</a></a><b/></a><c/>");
+    assert_eq!(parser.skip_element(), Some(()));
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+
+    let mut parser = open("<a/><c/>");
+    assert_eq!(parser.skip_element(), Some(()));
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+
+    let mut parser = open("<a><b>");
+    assert_eq!(parser.skip_element(), None);
+}
+
+#[test]
+fn element_events() {
+    let mut parser = open("<a>x<a>y</a><b/></a><c/>");
+    let events = names(parser.element_events());
+    assert_eq!(events, ["x", "<a>", "y", "</a>", "<b>", "</b>"]);
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+
+    let mut parser = open("<a/><c/>");
+    assert_eq!(parser.element_events().count(), 0);
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+}
+
+#[test]
+fn element_events_drop() {
+    let mut parser = open("<a><a><a/></a>x</a><c/>");
+    {
+        let mut events = parser.element_events();
+        assert!(matches!(events.next(), Some(Event::Open("a", _))));
+        assert!(matches!(events.next(), Some(Event::Open("a", _))));
+    }
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+
+    let mut parser = open("<a><b/></a><c/>");
+    drop(parser.element_events());
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+}
+
+#[test]
+fn nested_element_events() {
+    let mut parser = open("<a><a><b/>x</a>y<a/>z</a><c/>");
+    let mut events = parser.element_events();
+    assert!(matches!(events.next(), Some(Event::Open("a", _))));
+    let inner = names(events.element_events());
+    assert_eq!(inner, ["<b>", "</b>", "x"]);
+    assert!(matches!(events.next(), Some(Event::Text(_))));
+    assert!(matches!(events.next(), Some(Event::Open("a", _))));
+    assert_eq!(events.skip_element(), Some(()));
+    assert_eq!(names(events.by_ref()), ["z"]);
+    assert_eq!(events.skip_element(), None);
+    drop(events);
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+}
+
+fn read_text(doc: &str, nested: NestedElements) -> (Result<String, TextError>, Parser<'_>) {
+    let mut parser = open(doc);
+    let mut text = String::new();
+    let result = parser.read_text_to(&mut text, nested).map(|()| text);
+    (result, parser)
+}
+
+#[test]
+fn read_text_decoding() {
+    let doc = "<a>1 &lt; 2 &amp;&#x20;<![CDATA[<&amp;>]]><!--c-->3<?pi?>4</a><c/>";
+    let (text, mut parser) = read_text(doc, NestedElements::Reject);
+    assert_eq!(text.unwrap(), "1 < 2 & <&amp;>34");
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+    let (text, _) = read_text("<a/>", NestedElements::Reject);
+    assert_eq!(text.unwrap(), "");
+}
+
+#[test]
+fn read_text_nested() {
+    let doc = "<a>1<b>2<a>3</a></b>4</a><c/>";
+    let (text, mut parser) = read_text(doc, NestedElements::Include);
+    assert_eq!(text.unwrap(), "1234");
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+
+    let (text, mut parser) = read_text(doc, NestedElements::Skip);
+    assert_eq!(text.unwrap(), "14");
+    assert!(matches!(parser.next(), Some(Event::Open("c", _))));
+
+    let (text, mut parser) = read_text(doc, NestedElements::Reject);
+    assert_eq!(text, Err(TextError::NestedElement));
+    assert!(matches!(parser.next(), Some(Event::Text(_))));
+    assert!(matches!(parser.next(), Some(Event::Open("a", _))));
+}
+
+#[test]
+fn read_text_errors() {
+    let (text, _) = read_text("<a>x", NestedElements::Include);
+    assert_eq!(text, Err(TextError::Malformed));
+
+    struct Full;
+    impl std::fmt::Write for Full {
+        fn write_str(&mut self, _: &str) -> std::fmt::Result {
+            Err(std::fmt::Error)
+        }
+    }
+    let mut parser = open("<a>x</a>");
+    let result = parser.read_text_to(&mut Full, NestedElements::Include);
+    assert_eq!(result, Err(TextError::Write));
+    assert_eq!(TextError::Write.to_string(), "failed to write output");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn read_text_string() {
+    let mut parser = open("<a>x &amp; <!-- y -->z</a>");
+    assert_eq!(parser.read_text(NestedElements::Skip).unwrap(), "x & z");
+    assert!(parser.next().is_none());
+}
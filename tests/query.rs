@@ -0,0 +1,147 @@
+use txml::dom::Document;
+use txml::query::{Query, Value};
+
+const DOC: &str = r#"<a id="1">
+  <b id="2" n="3"><c id="3">x</c><c id="4">y</c></b>
+  <b id="5" n="10"><c id="6">z</c></b>
+  <d id="7">5</d>
+</a>"#;
+
+fn texts(doc: &Document, query: &str) -> Vec<String> {
+    Query::parse(query)
+        .unwrap()
+        .select(doc)
+        .iter()
+        .map(|item| item.text())
+        .collect()
+}
+
+fn ids(doc: &Document, query: &str) -> Vec<String> {
+    texts(doc, &format!("({})/@id", query))
+}
+
+fn number(doc: &Document, query: &str) -> f64 {
+    match Query::parse(query).unwrap().evaluate(doc) {
+        Value::Number(n) => n,
+        value => panic!("{:?} is not a number", value),
+    }
+}
+
+fn boolean(doc: &Document, query: &str) -> bool {
+    match Query::parse(query).unwrap().evaluate(doc) {
+        Value::Boolean(b) => b,
+        value => panic!("{:?} is not a boolean", value),
+    }
+}
+
+#[test]
+fn axes() {
+    let doc = Document::parse(DOC).unwrap();
+    assert_eq!(ids(&doc, "/a/b"), ["2", "5"]);
+    assert_eq!(ids(&doc, "/child::a/child::*"), ["2", "5", "7"]);
+    assert_eq!(ids(&doc, "/a/descendant::c"), ["3", "4", "6"]);
+    assert_eq!(
+        ids(&doc, "/a/b/descendant-or-self::*"),
+        ["2", "3", "4", "5", "6"]
+    );
+    assert_eq!(ids(&doc, "//c"), ["3", "4", "6"]);
+    assert_eq!(ids(&doc, "//c/parent::*"), ["2", "5"]);
+    assert_eq!(ids(&doc, "//c/.."), ["2", "5"]);
+    assert_eq!(ids(&doc, "//c[@id='6']/ancestor::*"), ["1", "5"]);
+    assert_eq!(ids(&doc, "//b/self::*"), ["2", "5"]);
+    assert_eq!(ids(&doc, "//b/."), ["2", "5"]);
+    assert_eq!(texts(&doc, "//c/text()"), ["x", "y", "z"]);
+    assert_eq!(texts(&doc, "/a/b/attribute::n"), ["3", "10"]);
+    assert_eq!(number(&doc, "count(/a/node())"), 7.0);
+}
+
+#[test]
+fn positions() {
+    let doc = Document::parse(DOC).unwrap();
+    assert_eq!(ids(&doc, "//c[1]"), ["3", "6"]);
+    assert_eq!(ids(&doc, "//c[last()]"), ["4", "6"]);
+    assert_eq!(ids(&doc, "/a/*[position() > 1]"), ["5", "7"]);
+    assert_eq!(ids(&doc, "/a/b[2]/c"), ["6"]);
+    assert_eq!(ids(&doc, "/a/b/c[2]"), ["4"]);
+    assert_eq!(ids(&doc, "/a/b[c][1]"), ["2"]);
+    assert!(ids(&doc, "//c[4]").is_empty());
+}
+
+#[test]
+fn attributes() {
+    let doc = Document::parse(DOC).unwrap();
+    assert_eq!(texts(&doc, "/a/@id"), ["1"]);
+    assert_eq!(texts(&doc, "//@n"), ["3", "10"]);
+    assert_eq!(number(&doc, "count(//@*)"), 9.0);
+    assert_eq!(ids(&doc, "//*[@n]"), ["2", "5"]);
+    assert!(texts(&doc, "/a/@missing").is_empty());
+    match Query::parse("name(//@n)").unwrap().evaluate(&doc) {
+        Value::String(name) => assert_eq!(name, "n"),
+        value => panic!("{:?} is not a string", value),
+    }
+}
+
+#[test]
+fn comparisons() {
+    let doc = Document::parse(DOC).unwrap();
+    // A node set equals a string or number if any of its nodes does.
+    assert!(boolean(&doc, "//c = 'y'"));
+    assert!(!boolean(&doc, "//c = 'w'"));
+    assert!(boolean(&doc, "//c != 'x'"));
+    assert!(boolean(&doc, "//@n = 10"));
+    assert!(boolean(&doc, "//@n > 9"));
+    assert!(!boolean(&doc, "//@n > 10"));
+    assert!(boolean(&doc, "//@n <= 3"));
+    // Numeric comparisons convert strings to numbers, not lexical order.
+    assert_eq!(ids(&doc, "//b[@n < 5]"), ["2"]);
+    assert_eq!(ids(&doc, "//b[@n >= '10']"), ["5"]);
+    // Two node sets compare equal if any pair of their nodes does.
+    assert!(boolean(&doc, "/a/d = //b/@id"));
+    assert!(!boolean(&doc, "//b/@n = //c"));
+    assert!(!boolean(&doc, "/a/missing = /a/missing"));
+    assert_eq!(ids(&doc, "//c[. = 'x' or . = 'z']"), ["3", "6"]);
+    assert_eq!(
+        ids(&doc, "//c[contains(., 'y') and starts-with(@id, '4')]"),
+        ["4"]
+    );
+    assert_eq!(ids(&doc, "//c[not(. = 'x')]"), ["4", "6"]);
+    assert_eq!(ids(&doc, "//d | //b"), ["2", "5", "7"]);
+}
+
+#[test]
+fn wayland() {
+    let text = std::fs::read_to_string("fixtures/wayland/wayland.xml").unwrap();
+    let doc = Document::parse(&text).unwrap();
+    assert_eq!(number(&doc, "count(//interface)"), 23.0);
+    assert_eq!(texts(&doc, "/protocol/interface[1]/@name"), ["wl_display"]);
+    assert_eq!(
+        texts(&doc, "//interface[@name='wl_surface']/request[1]/@name"),
+        ["destroy"]
+    );
+    assert_eq!(
+        number(&doc, "count(//interface[@name='wl_compositor']/request)"),
+        3.0
+    );
+    assert!(boolean(&doc, "//interface/@name = 'wl_seat'"));
+}
+
+#[test]
+fn errors() {
+    for (query, offset) in [
+        ("", 0),
+        ("/a[", 3),
+        ("/a[1", 4),
+        ("/a]", 2),
+        ("//", 2),
+        ("a/", 2),
+        ("'a", 0),
+        ("a =", 3),
+        ("bogus::a", 0),
+        ("unknown()", 0),
+        ("count(", 6),
+        ("a #", 2),
+    ] {
+        let error = Query::parse(query).unwrap_err();
+        assert_eq!(error.offset(), offset, "{}", query);
+    }
+}
@@ -0,0 +1,259 @@
+//! Checks the code generated from the protocols in `fixtures/wayland`
+//! against the expected output in `fixtures/wayland/generated`, and compiles
+//! the generated Rust bindings. Run with `TXML_BLESS=1` to update the
+//! expected output.
+//!
+//! The first line of the C output names the generator and its version, so
+//! it's left out of the comparison: the expected files can be replaced with
+//! the output of `wayland-scanner` as is.
+//!
+//! The expected C files are txml's own output, checked by hand against the
+//! templates in `wayland-scanner`'s source rather than generated by it, so
+//! they only catch regressions. They should be replaced with the output of
+//! `wayland-scanner` 1.24 for the same protocols.
+
+use std::fmt::Write;
+use txml::wayland::{self, c, doc, rust};
+
+#[allow(dead_code)]
+mod wayland_protocol {
+    include!("../fixtures/wayland/generated/wayland.rs");
+}
+
+#[allow(dead_code)]
+mod xdg_shell_protocol {
+    include!("../fixtures/wayland/generated/xdg-shell.rs");
+}
+
+const WAYLAND: &str = include_str!("../fixtures/wayland/wayland.xml");
+const XDG_SHELL: &str = include_str!("../fixtures/wayland/xdg-shell.xml");
+
+fn check(name: &str, expected: &str, generate: impl FnOnce(&mut String) -> std::fmt::Result) {
+    let mut actual = String::new();
+    generate(&mut actual).unwrap();
+    let path = format!(
+        "{}/fixtures/wayland/generated/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    if std::env::var_os("TXML_BLESS").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+    } else if without_version(&actual) != without_version(expected) {
+        let line = actual
+            .lines()
+            .zip(expected.lines())
+            .position(|(a, b)| a != b && without_version(a) != without_version(b))
+            .map_or(actual.lines().count().min(expected.lines().count()), |i| i);
+        panic!(
+            "{} differs from the generated output at line {}; run with TXML_BLESS=1 to update it",
+            path,
+            line + 1
+        );
+    }
+}
+
+/// Strips the `Generated by` line from the start of C output.
+fn without_version(output: &str) -> &str {
+    match output.strip_prefix("/* Generated by ") {
+        Some(rest) => rest.find('\n').map_or("", |end| &rest[end + 1..]),
+        None => output,
+    }
+}
+
+#[test]
+fn rust_bindings() {
+    let core = &wayland::parse(WAYLAND).unwrap();
+    let xdg = &wayland::parse(XDG_SHELL).unwrap();
+    check(
+        "wayland.rs",
+        include_str!("../fixtures/wayland/generated/wayland.rs"),
+        |out| rust::generate(core, &rust::Options::default(), out),
+    );
+    let options = rust::Options {
+        support: Some("crate::wayland_protocol"),
+    };
+    check(
+        "xdg-shell.rs",
+        include_str!("../fixtures/wayland/generated/xdg-shell.rs"),
+        |out| rust::generate(xdg, &options, out),
+    );
+
+    use wayland_protocol::{wl_data_device_manager::DndAction, wl_display, wl_shm, Fixed, NewId};
+    use xdg_shell_protocol::xdg_toplevel;
+
+    assert_eq!(wl_display::NAME, "wl_display");
+    assert_eq!(wl_shm::VERSION, 3);
+    let surface = wayland_protocol::wl_surface::WlSurface(wayland_protocol::ObjectId(7));
+    assert_eq!(surface.0, wayland_protocol::ObjectId(7));
+    assert_eq!(wayland_protocol::wl_surface::WlSurface::NAME, "wl_surface");
+    assert_eq!(xdg_toplevel::XdgToplevel::VERSION, 7);
+    let sync = wl_display::Request::Sync { callback: NewId(3) };
+    assert_eq!(sync.opcode(), 0);
+    assert_eq!(wl_shm::Request::Release.since(), 2);
+    assert_eq!(wl_shm::REQUESTS[1].signature, "2");
+    assert_eq!(wayland_protocol::wl_registry::REQUESTS[0].signature, "usun");
+    assert_eq!(
+        wl_shm::Format::from_raw(0x20203843),
+        Some(wl_shm::Format::C8)
+    );
+    assert_eq!(wl_shm::Format::from_raw(7), None);
+
+    let actions = DndAction::COPY | DndAction::MOVE;
+    assert!(actions.contains(DndAction::MOVE));
+    assert!(!actions.contains(DndAction::ASK));
+    assert_eq!((actions & DndAction::ASK).bits(), 0);
+    assert_eq!(Fixed::from_f64(1.5).to_f64(), 1.5);
+
+    let resize = xdg_toplevel::Request::Resize {
+        seat: wayland_protocol::ObjectId(4),
+        serial: 5,
+        edges: xdg_toplevel::ResizeEdge::BottomRight,
+    };
+    assert_eq!(resize.opcode(), 6);
+
+    let mut summary = String::new();
+    for message in xdg_toplevel::EVENTS {
+        writeln!(
+            summary,
+            "{}@{}: {}",
+            message.name, message.since, message.signature
+        )
+        .unwrap();
+    }
+    assert_eq!(
+        summary,
+        "configure@1: iia\nclose@1: \nconfigure_bounds@4: 4ii\nwm_capabilities@5: 5a\n"
+    );
+    // Summaries can span lines, as in `xdg_toplevel.error`.
+    let protocol = wayland::parse(
+        r#"<protocol name="p"><interface name="i" version="1"><enum name="e">
+  <entry name="a" value="0" summary="first line
+    second line"/>
+</enum></interface></protocol>"#,
+    )
+    .unwrap();
+    let mut bindings = String::new();
+    rust::generate(&protocol, &rust::Options::default(), &mut bindings).unwrap();
+    assert!(bindings.contains("        /// first line second line\n        A = 0,\n"));
+
+    // `Self` can't be a variant, so a message named `self` gets a `_`.
+    let protocol = wayland::parse(
+        r#"<protocol name="p"><interface name="i" version="1">
+  <request name="self"><arg name="self" type="int"/></request>
+</interface></protocol>"#,
+    )
+    .unwrap();
+    let mut bindings = String::new();
+    rust::generate(&protocol, &rust::Options::default(), &mut bindings).unwrap();
+    assert!(bindings.contains("        Self_ {\n            self_: i32,\n        },\n"));
+    assert!(bindings.contains("                Request::Self_ { .. } => 0,\n"));
+}
+
+#[test]
+fn c_output() {
+    let core = &wayland::parse(WAYLAND).unwrap();
+    check(
+        "wayland-client-protocol.h",
+        include_str!("../fixtures/wayland/generated/wayland-client-protocol.h"),
+        |out| c::client_header(core, out),
+    );
+    check(
+        "wayland-server-protocol.h",
+        include_str!("../fixtures/wayland/generated/wayland-server-protocol.h"),
+        |out| c::server_header(core, out),
+    );
+    check(
+        "wayland-protocol.c",
+        include_str!("../fixtures/wayland/generated/wayland-protocol.c"),
+        |out| c::private_code(core, out),
+    );
+}
+
+#[test]
+fn documentation() {
+    let xdg = &wayland::parse(XDG_SHELL).unwrap();
+    let imports = [wayland::parse(WAYLAND).unwrap()];
+    let markdown = doc::Options {
+        format: doc::Format::Markdown,
+        imports: &imports,
+    };
+    let html = doc::Options {
+        format: doc::Format::Html,
+        ..markdown
+    };
+    check(
+        "xdg_shell.md",
+        include_str!("../fixtures/wayland/generated/xdg_shell.md"),
+        |out| doc::protocol(xdg, &markdown, out),
+    );
+    check(
+        "xdg_positioner.md",
+        include_str!("../fixtures/wayland/generated/xdg_positioner.md"),
+        |out| doc::interface(xdg, &xdg.interfaces[1], &markdown, out),
+    );
+    check(
+        "xdg_toplevel.html",
+        include_str!("../fixtures/wayland/generated/xdg_toplevel.html"),
+        |out| doc::interface(xdg, &xdg.interfaces[3], &html, out),
+    );
+
+    let protocol = wayland::parse(
+        r#"<protocol name="test">
+  <interface name="test_interface" version="3">
+    <description summary="an *interface*">
+      The first paragraph,
+      on two lines.
+
+      The second paragraph:
+      - an item
+        continued here
+      - a &lt;link&gt; to [x]
+    </description>
+    <event name="gone" since="2" deprecated-since="3"/>
+    <enum name="error">
+      <entry name="invalid" value="0" summary="a summary
+        on two lines"/>
+    </enum>
+  </interface>
+</protocol>"#,
+    )
+    .unwrap();
+    let mut out = String::new();
+    doc::interface(
+        &protocol,
+        &protocol.interfaces[0],
+        &doc::Options::default(),
+        &mut out,
+    )
+    .unwrap();
+    assert_eq!(
+        out,
+        "# `test_interface` `version 3`
+
+Part of the [`test`](test.md) protocol.
+
+*an \\*interface\\**
+
+The first paragraph, on two lines.
+
+The second paragraph:
+
+- an item continued here
+- a \\<link\\> to \\[x\\]
+
+## Events
+
+<a id=\"event-gone\"></a>
+### `gone` `since 2` `deprecated since 3`
+
+## Enums
+
+<a id=\"enum-error\"></a>
+### `error`
+
+| Entry | Value | Description |
+| --- | --- | --- |
+| `invalid` | `0` | a summary on two lines |
+"
+    );
+}
@@ -0,0 +1,300 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Interface {
+    #[serde(rename = "request", default)]
+    requests: Vec<Message>,
+    #[serde(rename = "event", default)]
+    events: Vec<Message>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Message {
+    #[serde(rename = "@name")]
+    name: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Messages {
+    #[serde(rename = "$value")]
+    messages: Vec<Item>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Item {
+    Request(Message),
+    Event(Message),
+}
+
+#[test]
+fn repeated_children() {
+    let interface: Interface =
+        txml::de::from_str(r#"<i><request name="a"/><request name="b"/><event name="c"/></i>"#)
+            .unwrap();
+    assert_eq!(interface.requests.len(), 2);
+    assert_eq!(interface.events.len(), 1);
+
+    // Repeats that aren't adjacent still make up one sequence.
+    const INTERLEAVED: &str =
+        r#"<i><request name="a"/><event name="c"/>x<request name="b"/><event name="d"/></i>"#;
+    let interface: Interface = txml::de::from_str(INTERLEAVED).unwrap();
+    let names = |messages: &[Message]| messages.iter().map(|m| m.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names(&interface.requests), ["a", "b"]);
+    assert_eq!(names(&interface.events), ["c", "d"]);
+
+    // A field that isn't a sequence can't be repeated.
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Single {
+        request: Message,
+        #[serde(rename = "$text")]
+        text: String,
+    }
+    let err = txml::de::from_str::<Single>(INTERLEAVED).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate field `request`");
+
+    let messages: Messages = txml::de::from_str(INTERLEAVED).unwrap();
+    let names: Vec<_> = messages
+        .messages
+        .iter()
+        .map(|item| match item {
+            Item::Request(m) => format!("request {}", m.name),
+            Item::Event(m) => format!("event {}", m.name),
+        })
+        .collect();
+    assert_eq!(names, ["request a", "event c", "request b", "event d"]);
+}
+
+mod wayland {
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub struct Protocol<'a> {
+        #[serde(rename = "@name")]
+        pub name: &'a str,
+        pub copyright: String,
+        pub interface: Vec<Interface>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Interface {
+        #[serde(rename = "@name")]
+        pub name: String,
+        #[serde(rename = "@version")]
+        pub version: u32,
+        pub description: Description,
+        #[serde(default)]
+        pub request: Vec<Msg>,
+        #[serde(default)]
+        pub event: Vec<Msg>,
+        #[serde(default, rename = "enum")]
+        pub enums: Vec<Enum>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Description {
+        #[serde(rename = "@summary")]
+        pub summary: String,
+        #[serde(rename = "$text", default)]
+        pub body: String,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Msg {
+        #[serde(rename = "@name")]
+        pub name: String,
+        #[serde(rename = "@type")]
+        pub kind: Option<String>,
+        #[serde(rename = "@since")]
+        pub since: Option<u32>,
+        #[serde(default)]
+        pub arg: Vec<Arg>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Arg {
+        #[serde(rename = "@name")]
+        pub name: String,
+        #[serde(rename = "@type")]
+        pub kind: String,
+        #[serde(rename = "@interface")]
+        pub interface: Option<String>,
+        #[serde(rename = "@allow-null", default)]
+        pub allow_null: bool,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Enum {
+        #[serde(rename = "@name")]
+        pub name: String,
+        #[serde(rename = "@bitfield", default)]
+        pub bitfield: bool,
+        pub entry: Vec<Entry>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Entry {
+        #[serde(rename = "@name")]
+        pub name: String,
+        #[serde(rename = "@value")]
+        pub value: String,
+    }
+}
+
+#[test]
+fn wayland_protocol() {
+    let xml = std::fs::read_to_string("fixtures/wayland/wayland.xml").unwrap();
+    let protocol: wayland::Protocol = txml::de::from_str(&xml).unwrap();
+    assert_eq!(protocol.name, "wayland");
+    assert!(protocol.copyright.contains("Copyright"));
+    let interfaces = &protocol.interface;
+    assert_eq!(interfaces.len(), 23);
+    let count = |f: fn(&wayland::Interface) -> usize| interfaces.iter().map(f).sum::<usize>();
+    assert_eq!(count(|i| i.request.len()), 72);
+    assert_eq!(count(|i| i.event.len()), 62);
+    assert_eq!(count(|i| i.enums.len()), 28);
+    assert_eq!(count(|i| i.enums.iter().map(|e| e.entry.len()).sum()), 230);
+    let args = |i: &wayland::Interface| {
+        let messages = i.request.iter().chain(&i.event);
+        messages.map(|m| m.arg.len()).sum()
+    };
+    assert_eq!(count(args), 217);
+
+    // wl_surface interleaves its requests and events.
+    let surface = interfaces.iter().find(|i| i.name == "wl_surface").unwrap();
+    assert_eq!(surface.version, 7);
+    assert_eq!(surface.description.summary, "an onscreen surface");
+    assert!(surface
+        .description
+        .body
+        .contains("A surface is a rectangular area"));
+    assert_eq!(surface.request.len(), 12);
+    assert_eq!(surface.event.len(), 4);
+    assert_eq!(surface.request[0].name, "destroy");
+    assert_eq!(surface.request[0].kind.as_deref(), Some("destructor"));
+    assert_eq!(surface.request[8].name, "set_buffer_scale");
+    assert_eq!(surface.request[8].since, Some(3));
+    assert_eq!(surface.request[11].name, "get_release");
+    assert_eq!(surface.event[2].name, "preferred_buffer_scale");
+    let attach = &surface.request[1].arg[0];
+    assert_eq!(
+        (attach.name.as_str(), attach.kind.as_str()),
+        ("buffer", "object")
+    );
+    assert_eq!(attach.interface.as_deref(), Some("wl_buffer"));
+    assert!(attach.allow_null);
+    let error = &surface.enums[0];
+    assert_eq!((error.name.as_str(), error.bitfield), ("error", false));
+    let entry = &error.entry[5];
+    assert_eq!(
+        (entry.name.as_str(), entry.value.as_str()),
+        ("no_buffer", "5")
+    );
+    let bitfields = interfaces
+        .iter()
+        .flat_map(|i| &i.enums)
+        .filter(|e| e.bitfield);
+    assert_eq!(bitfields.count(), 5);
+}
+
+mod round_trip {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    pub struct Outer {
+        #[serde(rename = "@id")]
+        pub id: u32,
+        #[serde(rename = "@label")]
+        pub label: Option<String>,
+        pub name: String,
+        pub inner: Inner,
+        pub maybe: Option<Inner>,
+        #[serde(rename = "item", default)]
+        pub items: Vec<Inner>,
+    }
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    pub struct Inner {
+        #[serde(rename = "@flag")]
+        pub flag: bool,
+        #[serde(rename = "$text", default)]
+        pub text: String,
+    }
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    pub struct Required {
+        pub item: Vec<u32>,
+    }
+
+    #[derive(Debug, Serialize)]
+    pub struct LateAttr {
+        pub name: String,
+        #[serde(rename = "@id")]
+        pub id: u32,
+    }
+}
+
+#[test]
+fn round_trip() {
+    use round_trip::{Inner, LateAttr, Outer, Required};
+
+    let inner = |flag, text: &str| Inner {
+        flag,
+        text: text.into(),
+    };
+    let value = Outer {
+        id: 1,
+        label: Some("a \"b\" & c".into()),
+        name: "<name>".into(),
+        inner: inner(true, "x"),
+        maybe: None,
+        items: vec![inner(false, ""), inner(true, "y")],
+    };
+    let xml = txml::ser::to_string(&value, "outer").unwrap();
+    assert_eq!(
+        xml,
+        concat!(
+            r#"<outer id="1" label="a &quot;b&quot; &amp; c"><name>&lt;name&gt;</name>"#,
+            r#"<inner flag="true">x</inner><item flag="false"/><item flag="true">y</item>"#,
+            "</outer>",
+        )
+    );
+    assert_eq!(txml::de::from_str::<Outer>(&xml).unwrap(), value);
+
+    let value = Outer {
+        label: None,
+        maybe: Some(inner(false, "z")),
+        items: Vec::new(),
+        ..value
+    };
+    let xml = txml::ser::to_string(&value, "outer").unwrap();
+    assert_eq!(
+        xml,
+        concat!(
+            r#"<outer id="1"><name>&lt;name&gt;</name><inner flag="true">x</inner>"#,
+            r#"<maybe flag="false">z</maybe></outer>"#,
+        )
+    );
+    assert_eq!(txml::de::from_str::<Outer>(&xml).unwrap(), value);
+
+    // An empty sequence isn't written, so it only reads back with a default.
+    let value = Required { item: vec![1, 2] };
+    let xml = txml::ser::to_string(&value, "r").unwrap();
+    assert_eq!(xml, "<r><item>1</item><item>2</item></r>");
+    assert_eq!(txml::de::from_str::<Required>(&xml).unwrap(), value);
+    let xml = txml::ser::to_string(&Required { item: Vec::new() }, "r").unwrap();
+    assert_eq!(xml, "<r/>");
+    let err = txml::de::from_str::<Required>(&xml).unwrap_err();
+    assert_eq!(err.to_string(), "missing field `item`");
+
+    let late = LateAttr {
+        name: "n".into(),
+        id: 2,
+    };
+    let err = txml::ser::to_string(&late, "l").unwrap_err();
+    assert_eq!(
+        err,
+        txml::ser::Error::Unsupported("an attribute after content")
+    );
+}
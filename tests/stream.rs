@@ -0,0 +1,123 @@
+use txml::stream::{Matcher, Pattern};
+use txml::{Event, Parser};
+
+const DOC: &str = r#"<protocol>
+<interface name="a"><request name="r1"><arg type="fd"/></request><event name="e1"/></interface>
+<interface name="b"><request name="r2"><arg type="int"/></request></interface>
+<x><interface name="c"><request name="r3"/></interface></x>
+</protocol>"#;
+
+fn names(pattern: &str) -> Vec<String> {
+    let mut stack = [0; 8];
+    let pattern = Pattern::new(pattern).unwrap();
+    Matcher::new(Parser::new(DOC), pattern, &mut stack)
+        .filter_map(|event| match event {
+            (0, Event::Open(_, attrs)) => attrs.get("name").map(Iterator::collect),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn steps() {
+    assert_eq!(names("/protocol/interface"), ["a", "b"]);
+    assert_eq!(names("//interface"), ["a", "b", "c"]);
+    assert_eq!(names("/protocol//request"), ["r1", "r2", "r3"]);
+    assert_eq!(names("/protocol/*/request"), ["r1", "r2"]);
+    assert_eq!(names("/protocol/x/interface/*"), ["r3"]);
+    assert!(names("/interface").is_empty());
+}
+
+#[test]
+fn predicates() {
+    assert_eq!(names("//interface[@name='b']/request"), ["r2"]);
+    assert_eq!(names("//request[@name]"), ["r1", "r2", "r3"]);
+    assert_eq!(names("//interface[@name = \"a\"]/*"), ["r1", "e1"]);
+    assert_eq!(
+        names("//request[@name='r1'][@missing]"),
+        Vec::<String>::new()
+    );
+    assert_eq!(names("//interface[@name ='c']//request"), ["r3"]);
+    assert_eq!(names("//*[@name= 'e1']"), ["e1"]);
+}
+
+#[test]
+fn depths() {
+    let mut stack = [0; 8];
+    let pattern = Pattern::new("//request[@name='r1']").unwrap();
+    let events: Vec<_> = Matcher::new(Parser::new(DOC), pattern, &mut stack)
+        .map(|(depth, event)| (depth, matches!(event, Event::Open(..))))
+        .collect();
+    assert_eq!(events, [(0, true), (1, true), (1, false), (0, false)]);
+}
+
+#[test]
+fn nested_matches() {
+    let doc = "<a><a><a/></a></a><a/>";
+    let mut stack = [0; 4];
+    let pattern = Pattern::new("//a").unwrap();
+    let depths: Vec<usize> = Matcher::new(Parser::new(doc), pattern, &mut stack)
+        .map(|(depth, _)| depth)
+        .collect();
+    assert_eq!(depths, [0, 1, 2, 2, 1, 0, 0, 0]);
+}
+
+#[test]
+fn stack_size() {
+    let doc = "<a><b><c><d/></c></b></a>";
+    let pattern = Pattern::new("//d").unwrap();
+    let mut stack = [0; 4];
+    assert_eq!(
+        Matcher::new(Parser::new(doc), pattern, &mut stack).count(),
+        2
+    );
+    // The stream ends where the nesting exceeds the stack.
+    let mut stack = [0; 3];
+    assert_eq!(
+        Matcher::new(Parser::new(doc), pattern, &mut stack).count(),
+        0
+    );
+    assert_eq!(Matcher::new(Parser::new(doc), pattern, &mut []).count(), 0);
+}
+
+#[test]
+fn errors() {
+    for (pattern, offset) in [
+        ("", 0),
+        ("a", 0),
+        ("/", 1),
+        ("/a/", 3),
+        ("/a b", 1),
+        ("/a[name]", 2),
+        ("/a[@]", 2),
+        ("/a[@name", 2),
+        ("/a[@name=x]", 2),
+        ("/a[@name='x\"]", 2),
+        ("/a[@ = 'x']", 2),
+        ("/a[@n m='x']", 2),
+        ("/a[.//b]", 2),
+    ] {
+        assert_eq!(
+            Pattern::new(pattern).unwrap_err().offset(),
+            offset,
+            "{}",
+            pattern
+        );
+    }
+}
+
+#[test]
+fn max_steps() {
+    let steps = "/a".repeat(Pattern::MAX_STEPS);
+    assert!(Pattern::new(&steps).is_ok());
+    let steps = "/a".repeat(Pattern::MAX_STEPS + 1);
+    let error = Pattern::new(&steps).unwrap_err();
+    assert_eq!(error.offset(), 2 * Pattern::MAX_STEPS);
+
+    let doc = format!("{}{}", "<a>".repeat(63), "</a>".repeat(63));
+    let pattern = Pattern::new(&steps[2..]).unwrap();
+    let mut stack = [0; 63];
+    let events: Vec<_> = Matcher::new(Parser::new(&doc), pattern, &mut stack).collect();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].0, 0);
+}
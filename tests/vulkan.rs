@@ -0,0 +1,379 @@
+use txml::vulkan::{self, Category, EnumsKind, ErrorKind, ExtensionKind, Member, Value};
+use txml::AttrError;
+
+const VK: &str = include_str!("../fixtures/vulkan/vk.xml");
+
+fn registry() -> vulkan::Registry {
+    vulkan::parse(VK).unwrap()
+}
+
+#[test]
+fn types() {
+    let registry = registry();
+    let ty = |name| registry.types.iter().find(|t| t.name == name).unwrap();
+    let member = |ty: &str, name| {
+        let members = &registry
+            .types
+            .iter()
+            .find(|t| t.name == ty)
+            .unwrap()
+            .members;
+        members.iter().find(|m| m.name == name).unwrap().clone()
+    };
+
+    assert_eq!(registry.types.len(), 40);
+    assert_eq!(ty("uint32_t").category, None);
+    assert_eq!(ty("uint32_t").requires.as_deref(), Some("vk_platform"));
+    assert_eq!(ty("vk_platform").code, "#include \"vk_platform.h\"");
+
+    let version = ty("VK_API_VERSION_1_0");
+    assert_eq!(version.category, Some(Category::Define));
+    assert_eq!(version.api, ["vulkan"]);
+    assert_eq!(version.base.as_deref(), Some("VK_MAKE_API_VERSION"));
+    assert_eq!(
+        version.code,
+        "// Vulkan 1.0 version number\n\
+         #define VK_API_VERSION_1_0 VK_MAKE_API_VERSION(0, 1, 0, 0)\
+         // Patch version should always be set to 0"
+    );
+    assert!(ty("VK_MAKE_VERSION").code.contains("<< 22U"));
+
+    let flags = ty("VkFlags");
+    assert_eq!(flags.category, Some(Category::BaseType));
+    assert_eq!(flags.base.as_deref(), Some("uint32_t"));
+    assert_eq!(flags.code, "typedef uint32_t VkFlags;");
+
+    let queue_flags = ty("VkQueueFlags");
+    assert_eq!(queue_flags.category, Some(Category::Bitmask));
+    assert_eq!(queue_flags.requires.as_deref(), Some("VkQueueFlagBits"));
+    let access_flags = ty("VkAccessFlags2");
+    assert_eq!(access_flags.base.as_deref(), Some("VkFlags64"));
+    assert_eq!(
+        access_flags.bit_values.as_deref(),
+        Some("VkAccessFlagBits2")
+    );
+    assert_eq!(
+        ty("VkAccessFlags2KHR").alias.as_deref(),
+        Some("VkAccessFlags2")
+    );
+
+    let device = ty("VkDevice");
+    assert_eq!(device.category, Some(Category::Handle));
+    assert_eq!(device.parent, ["VkPhysicalDevice"]);
+    assert_eq!(device.base.as_deref(), Some("VK_DEFINE_HANDLE"));
+    assert_eq!(device.code, "VK_DEFINE_HANDLE(VkDevice)");
+    assert_eq!(
+        ty("VkSurfaceKHR").base.as_deref(),
+        Some("VK_DEFINE_NON_DISPATCHABLE_HANDLE")
+    );
+    assert_eq!(ty("VkResult").category, Some(Category::Enum));
+
+    let callback = ty("PFN_vkDebugUtilsMessengerCallbackEXT");
+    assert_eq!(callback.category, Some(Category::FuncPointer));
+    assert_eq!(callback.base.as_deref(), Some("VkBool32"));
+    assert!(callback
+        .code
+        .starts_with("typedef VkBool32 (VKAPI_PTR *PFN_vkDebugUtilsMessengerCallbackEXT)("));
+    assert_eq!(ty("PFN_vkVoidFunction").base.as_deref(), Some("void"));
+
+    let create_info = ty("VkInstanceCreateInfo");
+    assert_eq!(create_info.category, Some(Category::Struct));
+    assert_eq!(create_info.members.len(), 8);
+    assert_eq!(create_info.code, "");
+    assert_eq!(
+        member("VkInstanceCreateInfo", "sType").values.as_deref(),
+        Some("VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO")
+    );
+    let layers = member("VkInstanceCreateInfo", "ppEnabledLayerNames");
+    assert_eq!(
+        layers,
+        Member {
+            span: layers.span,
+            name: "ppEnabledLayerNames".into(),
+            type_name: "char".into(),
+            code: "const char* const* ppEnabledLayerNames".into(),
+            is_const: true,
+            pointers: 2,
+            len: vec!["enabledLayerCount".into(), "null-terminated".into()],
+            comment: Some("Ordered list of layer names to be enabled".into()),
+            ..Default::default()
+        }
+    );
+    let info = member("VkInstanceCreateInfo", "pApplicationInfo");
+    assert!(info.is_const);
+    assert_eq!((info.pointers, &info.optional[..]), (1, &[true][..]));
+    let next = member("VkBaseOutStructure", "pNext");
+    assert_eq!(next.code, "struct VkBaseOutStructure* pNext");
+    assert_eq!((next.is_const, next.pointers), (false, 1));
+
+    let properties = ty("VkExtensionProperties");
+    assert!(properties.returned_only);
+    let name = member("VkExtensionProperties", "extensionName");
+    assert_eq!(name.array, ["VK_MAX_EXTENSION_NAME_SIZE"]);
+    assert_eq!(name.code, "char extensionName[VK_MAX_EXTENSION_NAME_SIZE]");
+    assert_eq!(name.comment.as_deref(), Some("extension name"));
+    assert_eq!(member("VkTransformMatrixKHR", "matrix").array, ["3", "4"]);
+    let mask = member("VkAccelerationStructureInstanceKHR", "mask");
+    assert_eq!((mask.bits, &mask.code[..]), (Some(8), "uint32_t mask:8"));
+    assert_eq!(
+        member("VkAccelerationStructureInstanceKHR", "instanceCustomIndex").bits,
+        Some(24)
+    );
+    assert_eq!(
+        ty("VkPhysicalDeviceVulkan11Features").struct_extends,
+        ["VkPhysicalDeviceFeatures2", "VkDeviceCreateInfo"]
+    );
+    let clear_color = ty("VkClearColorValue");
+    assert_eq!(clear_color.category, Some(Category::Union));
+    assert_eq!(clear_color.members[1].array, ["4"]);
+}
+
+#[test]
+fn enums() {
+    let registry = &registry();
+    let names: Vec<_> = registry.enums.iter().map(|e| &e.name[..]).collect();
+    assert_eq!(
+        names,
+        [
+            "API Constants",
+            "VkResult",
+            "VkStructureType",
+            "VkFormat",
+            "VkInstanceCreateFlagBits",
+            "VkQueueFlagBits",
+            "VkAccessFlagBits2",
+        ]
+    );
+
+    let constants = &registry.enums[0];
+    assert_eq!(constants.kind, EnumsKind::Constants);
+    let size = &constants.values[0];
+    assert_eq!(size.name, "VK_MAX_EXTENSION_NAME_SIZE");
+    assert_eq!(size.type_name.as_deref(), Some("uint32_t"));
+    assert_eq!(size.value.as_ref().unwrap().to_i64(None), Some(256));
+    let lod = constants.values[2].value.as_ref().unwrap();
+    assert_eq!(lod, &Value::Literal("1000.0F".into()));
+    assert_eq!(lod.to_i64(None), None);
+
+    let result = &registry.enums[1];
+    assert_eq!(result.kind, EnumsKind::Enum);
+    assert_eq!(result.values.len(), 6);
+    let memory = &result.values[2];
+    assert_eq!(memory.value.as_ref().unwrap().to_i64(None), Some(-1));
+    assert_eq!(
+        memory.comment.as_deref(),
+        Some("A host memory allocation has failed")
+    );
+
+    let queue = &registry.enums[5];
+    assert_eq!((queue.kind, queue.bit_width), (EnumsKind::Bitmask, 32));
+    assert_eq!(queue.values[2].value, Some(Value::BitPos(2)));
+    let access = &registry.enums[6];
+    assert_eq!(access.bit_width, 64);
+    let sampled = access.values[2].value.as_ref().unwrap();
+    assert_eq!(sampled.to_i64(None), Some(1 << 32));
+}
+
+#[test]
+fn commands() {
+    let registry = &registry();
+    assert_eq!(registry.commands.len(), 7);
+    let command = |name| registry.commands.iter().find(|c| c.name == name).unwrap();
+
+    let create = command("vkCreateInstance");
+    assert_eq!(create.return_type, "VkResult");
+    assert_eq!(create.success_codes, ["VK_SUCCESS"]);
+    assert_eq!(create.error_codes.len(), 6);
+    let params: Vec<_> = create.params.iter().map(|p| &p.code[..]).collect();
+    assert_eq!(
+        params,
+        [
+            "const VkInstanceCreateInfo* pCreateInfo",
+            "const VkAllocationCallbacks* pAllocator",
+            "VkInstance* pInstance",
+        ]
+    );
+
+    let destroy = command("vkDestroyInstance");
+    assert_eq!(destroy.return_type, "void");
+    assert_eq!(destroy.params.len(), 2);
+    assert_eq!(destroy.params[0].extern_sync.as_deref(), Some("true"));
+
+    let enumerate = command("vkEnumeratePhysicalDevices");
+    assert_eq!(enumerate.params[1].optional, [false, true]);
+    assert_eq!(enumerate.params[2].len, ["pPhysicalDeviceCount"]);
+
+    let proc_addr = command("vkGetInstanceProcAddr");
+    assert_eq!(proc_addr.return_type, "PFN_vkVoidFunction");
+    assert_eq!(command("vkCmdDispatch").queues, ["graphics", "compute"]);
+
+    let alias = command("vkGetPhysicalDeviceFeatures2KHR");
+    assert_eq!(alias.alias.as_deref(), Some("vkGetPhysicalDeviceFeatures2"));
+    assert!(alias.params.is_empty());
+}
+
+#[test]
+fn features() {
+    let registry = &registry();
+    let core = &registry.features[0];
+    assert_eq!(
+        (&core.name[..], &core.number[..]),
+        ("VK_VERSION_1_0", "1.0")
+    );
+    assert_eq!(core.api, ["vulkan", "vulkansc"]);
+    assert_eq!(core.require.len(), 3);
+    assert_eq!(core.require[0].types, ["vk_platform"]);
+    assert_eq!(core.require[1].enums[1].name, "VK_UUID_SIZE");
+    assert_eq!(core.require[1].enums[1].value, None);
+    assert_eq!(core.require[2].commands.len(), 4);
+
+    let features = &registry.features[1];
+    assert_eq!(features.depends.as_deref(), Some("VK_VERSION_1_1"));
+    let features_type = &features.require[0].enums[0];
+    assert_eq!(features_type.extends.as_deref(), Some("VkStructureType"));
+    let value = features_type.value.as_ref().unwrap();
+    assert_eq!(value.to_i64(None), Some(1000049049));
+
+    let names: Vec<_> = registry.extensions.iter().map(|e| &e.name[..]).collect();
+    assert_eq!(
+        names,
+        [
+            "VK_KHR_surface",
+            "VK_KHR_get_physical_device_properties2",
+            "VK_KHR_portability_enumeration",
+            "VK_KHR_portability_subset",
+        ]
+    );
+
+    let surface = &registry.extensions[0];
+    assert_eq!(surface.number, 1);
+    assert_eq!(surface.kind, Some(ExtensionKind::Instance));
+    assert_eq!(surface.author.as_deref(), Some("KHR"));
+    assert_eq!(surface.supported, ["vulkan", "vulkansc"]);
+    let require = &surface.require[0];
+    assert_eq!(
+        (&require.types[..], &require.commands[..]),
+        (
+            &["VkSurfaceKHR".to_string()][..],
+            &["vkDestroySurfaceKHR".to_string()][..],
+        )
+    );
+    assert_eq!(
+        require.enums[1].value,
+        Some(Value::Literal("\"VK_KHR_surface\"".into()))
+    );
+    let lost = &require.enums[2];
+    assert_eq!(lost.name, "VK_ERROR_SURFACE_LOST_KHR");
+    assert_eq!(
+        lost.value,
+        Some(Value::Offset {
+            extension: None,
+            offset: 0,
+            negative: true,
+        })
+    );
+    let lost = lost.value.as_ref().unwrap();
+    assert_eq!(lost.to_i64(Some(surface.number)), Some(-1000000000));
+    assert_eq!(lost.to_i64(None), None);
+
+    let properties = &registry.extensions[1];
+    assert_eq!(properties.promoted_to.as_deref(), Some("VK_VERSION_1_1"));
+    assert_eq!(
+        properties.require[0].enums[2].value,
+        Some(Value::Alias(
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2".into()
+        ))
+    );
+
+    let portability = &registry.extensions[2].require[0].enums[2];
+    assert_eq!(
+        portability.extends.as_deref(),
+        Some("VkInstanceCreateFlagBits")
+    );
+    assert_eq!(portability.value, Some(Value::BitPos(0)));
+
+    let subset = &registry.extensions[3];
+    assert_eq!(subset.kind, Some(ExtensionKind::Device));
+    assert!(subset.provisional);
+    assert_eq!(subset.platform.as_deref(), Some("provisional"));
+    assert_eq!(
+        subset.depends.as_deref(),
+        Some("VK_KHR_get_physical_device_properties2,VK_VERSION_1_1")
+    );
+}
+
+#[test]
+fn formats() {
+    let registry = &registry();
+    assert_eq!(registry.formats.len(), 4);
+
+    let packed = &registry.formats[0];
+    assert_eq!(packed.class, "8-bit");
+    assert_eq!((packed.block_size, packed.packed), (1, Some(8)));
+    assert_eq!(packed.components[1].bits, Some(4));
+
+    let rgba = &registry.formats[1];
+    assert_eq!(rgba.components.len(), 4);
+    assert_eq!(rgba.components[3].name, "A");
+    assert_eq!(rgba.spirv_image_format.as_deref(), Some("Rgba8"));
+
+    let bc1 = &registry.formats[2];
+    assert_eq!(bc1.block_extent, [4, 4, 1]);
+    assert_eq!(bc1.compressed.as_deref(), Some("BC"));
+    assert_eq!(bc1.components[0].bits, None);
+
+    let planar = &registry.formats[3];
+    assert_eq!(planar.chroma.as_deref(), Some("420"));
+    assert_eq!(planar.components[2].plane_index, Some(1));
+    assert_eq!(planar.planes.len(), 2);
+    assert_eq!(
+        (
+            planar.planes[1].width_divisor,
+            &planar.planes[1].compatible[..]
+        ),
+        (2, "VK_FORMAT_R8G8_UNORM")
+    );
+}
+
+#[test]
+fn errors() {
+    let error = |doc| {
+        let err = vulkan::parse(doc).unwrap_err();
+        (err.kind().clone(), err.line_column(doc))
+    };
+
+    assert_eq!(error("<types/>"), (ErrorKind::NotRegistry("types"), (1, 2)));
+    assert_eq!(
+        error("<registry>\n  <enums type=\"enum\"/>\n</registry>"),
+        (ErrorKind::Attr(AttrError::Missing("name")), (2, 4))
+    );
+    assert_eq!(
+        error("<registry><commands><command><proto><type>void</type></proto></command></commands></registry>"),
+        (ErrorKind::MissingName("proto"), (1, 31))
+    );
+    assert_eq!(
+        error(
+            "<registry><types><type category=\"basetype\">typedef int;</type></types></registry>"
+        ),
+        (ErrorKind::MissingName("type"), (1, 19))
+    );
+    let err = vulkan::parse("<registry>\n<types>\n</registry>").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "closing tag `registry` doesn't match open element `types` at offset 19"
+    );
+}
+
+#[test]
+fn unknown_category() {
+    let doc = "<registry><types>\
+               <type category=\"class\" name=\"T\">class <name>T</name>;</type>\
+               <type category=\"funcpointer\">typedef void* (VKAPI_PTR *<name>PFN_f</name>)(\
+               <type>uint32_t</type> n);</type>\
+               </types></registry>";
+    let registry = vulkan::parse(doc).unwrap();
+    let class = &registry.types[0];
+    assert_eq!(class.category, Some(Category::Other("class".into())));
+    assert_eq!(class.code, "class T;");
+    assert_eq!(registry.types[1].base.as_deref(), Some("void*"));
+}
@@ -0,0 +1,350 @@
+use txml::wayland::diff::{diff, ChangeKind};
+use txml::wayland::validate::{self, validate};
+use txml::wayland::{self, ArgKind, ErrorKind};
+use txml::AttrError;
+
+const WAYLAND: &str = include_str!("../fixtures/wayland/wayland.xml");
+const XDG_SHELL: &str = include_str!("../fixtures/wayland/xdg-shell.xml");
+
+#[test]
+fn core_protocol() {
+    let core = wayland::parse(WAYLAND).unwrap();
+    assert_eq!(core.name, "wayland");
+    assert!(core
+        .copyright
+        .as_deref()
+        .unwrap()
+        .trim_start()
+        .starts_with("Copyright © 2008-2011 Kristian Høgsberg"));
+    let names: Vec<_> = core.interfaces.iter().map(|i| &i.name[..]).collect();
+    assert_eq!(
+        names,
+        [
+            "wl_display",
+            "wl_registry",
+            "wl_callback",
+            "wl_compositor",
+            "wl_shm_pool",
+            "wl_shm",
+            "wl_buffer",
+            "wl_data_offer",
+            "wl_data_source",
+            "wl_data_device",
+            "wl_data_device_manager",
+            "wl_shell",
+            "wl_shell_surface",
+            "wl_surface",
+            "wl_seat",
+            "wl_pointer",
+            "wl_keyboard",
+            "wl_touch",
+            "wl_output",
+            "wl_region",
+            "wl_subcompositor",
+            "wl_subsurface",
+            "wl_fixes",
+        ]
+    );
+    let interface = |name| core.interfaces.iter().find(|i| i.name == name).unwrap();
+
+    let bind = &interface("wl_registry").requests[0];
+    assert_eq!(bind.args[1].kind, ArgKind::NewId);
+    assert_eq!(bind.args[1].interface, None);
+
+    let callback = interface("wl_callback");
+    assert!(callback.frozen);
+    assert!(callback.events[0].destructor);
+    assert!(!interface("wl_surface").frozen);
+
+    let format = &interface("wl_shm").enums[1];
+    assert_eq!(format.entries[2].name, "c8");
+    assert_eq!(format.entries[2].value, 0x20203843);
+
+    let set_actions = &interface("wl_data_offer").requests[4];
+    assert_eq!(set_actions.name, "set_actions");
+    assert_eq!(set_actions.since, 3);
+    let dnd_actions = set_actions.args[0].enumeration.as_ref().unwrap();
+    assert_eq!(dnd_actions.to_string(), "wl_data_device_manager.dnd_action");
+    assert!(interface("wl_data_device_manager").enums[0].bitfield);
+
+    let pointer = interface("wl_pointer");
+    assert!(pointer.requests[0].args[1].allow_null);
+    let axis_discrete = pointer
+        .events
+        .iter()
+        .find(|e| e.name == "axis_discrete")
+        .unwrap();
+    assert_eq!(
+        (axis_discrete.since, axis_discrete.deprecated_since),
+        (5, Some(8))
+    );
+
+    // `wl_shell_surface.resize` names combinations of edges.
+    let resize = &interface("wl_shell_surface").enums[0];
+    assert!(resize.bitfield);
+    assert_eq!(resize.entries[4].name, "top_left");
+    assert_eq!(resize.entries[4].value, 5);
+
+    assert_eq!(validate(&core, &[]), []);
+}
+
+#[test]
+fn xdg_shell() {
+    let core = wayland::parse(WAYLAND).unwrap();
+    let xdg = wayland::parse(XDG_SHELL).unwrap();
+    assert_eq!(xdg.name, "xdg_shell");
+    let names: Vec<_> = xdg.interfaces.iter().map(|i| &i.name[..]).collect();
+    assert_eq!(
+        names,
+        [
+            "xdg_wm_base",
+            "xdg_positioner",
+            "xdg_surface",
+            "xdg_toplevel",
+            "xdg_popup",
+        ]
+    );
+    let toplevel = &xdg.interfaces[3];
+    let state = toplevel.enums.iter().find(|e| e.name == "state").unwrap();
+    assert_eq!(state.entries.last().unwrap().name, "constrained_bottom");
+    assert_eq!(state.entries.last().unwrap().since, 7);
+    assert!(xdg.interfaces[1].enums[3].bitfield);
+
+    let unknown = validate(&xdg, &[]);
+    assert_eq!(
+        unknown[0].kind(),
+        &validate::ErrorKind::UnknownInterface("wl_surface".into())
+    );
+    assert_eq!(validate(&xdg, &[core]), []);
+}
+
+#[test]
+fn frozen() {
+    const XML: &str = r#"<protocol name="p">
+  <interface name="a" version="1" frozen="true"/>
+  <interface name="b" version="1" frozen="false"/>
+  <interface name="c" version="1"/>
+</protocol>"#;
+
+    let protocol = wayland::parse(XML).unwrap();
+    let frozen: Vec<_> = protocol.interfaces.iter().map(|i| i.frozen).collect();
+    assert_eq!(frozen, [true, false, false]);
+}
+
+#[test]
+fn errors() {
+    let error = |doc| {
+        let err = wayland::parse(doc).unwrap_err();
+        (err.kind().clone(), err.line_column(doc))
+    };
+
+    assert_eq!(
+        error("<protocol name=\"p\">\n  <interface name=\"i\"/>\n</protocol>"),
+        (ErrorKind::Attr(AttrError::Missing("version")), (2, 4))
+    );
+    let doc = "<protocol name=\"p\">\n  <interface name=\"i\" version=\"1\">\n    \
+               <request name=\"r\"><arg name=\"a\" type=\"integer\"/></request>\n  \
+               </interface>\n</protocol>";
+    let (kind, position) = error(doc);
+    assert!(matches!(
+        kind,
+        ErrorKind::Attr(AttrError::Invalid("type", _, _))
+    ));
+    assert_eq!(position, (3, 43));
+    assert_eq!(
+        error("<protocol name=\"p\"><copyright>a <b/></copyright></protocol>"),
+        (ErrorKind::UnexpectedElement("b"), (1, 34))
+    );
+    assert_eq!(
+        error("<interface name=\"i\" version=\"1\"/>"),
+        (ErrorKind::NotProtocol("interface"), (1, 2))
+    );
+    let err = wayland::parse("<protocol name=\"p\">\n<interface>\n</protocol>").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "closing tag `protocol` doesn't match open element `interface` at offset 32"
+    );
+}
+
+#[test]
+fn validation() {
+    const XML: &str = r#"<protocol name="invalid">
+  <interface name="a" version="2">
+    <request name="destroy" type="destructor">
+      <arg name="id" type="new_id" interface="a"/>
+    </request>
+    <request name="late" since="3"/>
+    <request name="deprecated" since="2" deprecated-since="2"/>
+    <event name="refs">
+      <arg name="obj" type="object" interface="b"/>
+      <arg name="num" type="int" interface="a"/>
+      <arg name="local" type="uint" enum="missing"/>
+      <arg name="remote" type="uint" enum="c.flags"/>
+      <arg name="text" type="string" enum="flags"/>
+    </event>
+    <enum name="flags" bitfield="true">
+      <entry name="none" value="0"/>
+      <entry name="one" value="1"/>
+      <entry name="three" value="3"/>
+      <entry name="first" value="0x1" deprecated-since="4"/>
+    </enum>
+  </interface>
+  <interface name="c" version="1">
+    <enum name="flags" bitfield="true">
+      <entry name="one" value="1"/>
+      <entry name="both" value="0x3"/>
+      <entry name="two" value="2"/>
+    </enum>
+  </interface>
+</protocol>"#;
+
+    use validate::ErrorKind::*;
+    let protocol = wayland::parse(XML).unwrap();
+    let errors: Vec<_> = validate(&protocol, &[])
+        .into_iter()
+        .map(|err| (err.span().line_column(XML).0, err.kind().clone()))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            (4, DestructorWithNewId),
+            (
+                6,
+                SinceAfterVersion {
+                    since: 3,
+                    version: 2
+                }
+            ),
+            (
+                7,
+                DeprecatedBeforeSince {
+                    since: 2,
+                    deprecated_since: 2,
+                }
+            ),
+            (9, UnknownInterface("b".into())),
+            (10, UnexpectedInterface(ArgKind::Int)),
+            (11, UnknownEnum("missing".parse().unwrap())),
+            (13, UnexpectedEnum(ArgKind::String)),
+            (18, NotPowerOfTwo(3)),
+            (
+                19,
+                DeprecatedAfterVersion {
+                    deprecated_since: 4,
+                    version: 2,
+                }
+            ),
+            (19, OverlappingEntry("one".into())),
+        ]
+    );
+    let err = &validate(&protocol, &[])[1];
+    assert_eq!(err.span().line_column(XML), (6, 5));
+    assert_eq!(
+        err.to_string(),
+        "since 3 is after the interface version 2 at offset 178"
+    );
+}
+
+#[test]
+fn compatibility() {
+    const OLD: &str = r#"<protocol name="test">
+  <interface name="a" version="1">
+    <request name="destroy" type="destructor"/>
+    <request name="set" since="1">
+      <arg name="value" type="int"/>
+      <arg name="target" type="object" interface="b"/>
+    </request>
+    <event name="done"/>
+    <enum name="mode">
+      <entry name="off" value="0"/>
+      <entry name="on" value="1"/>
+    </enum>
+  </interface>
+  <interface name="b" version="2">
+    <request name="first"/>
+    <request name="second"/>
+  </interface>
+  <interface name="c" version="1">
+    <request name="kept"/>
+  </interface>
+  <interface name="gone" version="1"/>
+</protocol>"#;
+    const NEW: &str = r#"<protocol name="test">
+  <interface name="a" version="2">
+    <description summary="descriptions are ignored"/>
+    <request name="destroy" type="destructor"/>
+    <request name="set" since="1" deprecated-since="2">
+      <arg name="amount" type="uint" enum="mode"/>
+      <arg name="target" type="object" interface="b" allow-null="true"/>
+      <arg name="extra" type="int"/>
+    </request>
+    <request name="reset" since="2"/>
+    <event name="done"/>
+    <event name="early"/>
+    <enum name="mode">
+      <entry name="off" value="0"/>
+      <entry name="on" value="2"/>
+      <entry name="auto" value="3" since="2"/>
+      <entry name="later" value="4" since="3"/>
+    </enum>
+    <enum name="flags" bitfield="true"/>
+  </interface>
+  <interface name="b" version="1">
+    <request name="second"/>
+    <request name="first"/>
+  </interface>
+  <interface name="c" version="1">
+    <request name="kept"/>
+    <request name="unversioned" since="2"/>
+  </interface>
+  <interface name="added" version="1"/>
+</protocol>"#;
+
+    let old = wayland::parse(OLD).unwrap();
+    let new = wayland::parse(NEW).unwrap();
+    let changes: Vec<_> = diff(&old, &new).iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        changes,
+        [
+            "compatible: a: version changed from 1 to 2",
+            "compatible: a.request.set: deprecated-since changed from none to 2",
+            "compatible: a.request.set.amount: renamed from `value`",
+            "breaking: a.request.set.amount: type changed from `int` to `uint`",
+            "compatible: a.request.set.amount: enum changed from `none` to `mode`",
+            "breaking: a.request.set.target: now allows null",
+            "breaking: a.request.set.extra: added",
+            "compatible: a.request.reset: added",
+            "breaking: a.event.early: added",
+            "breaking: a.enum.mode.on: value changed from 1 to 2",
+            "compatible: a.enum.mode.auto: added",
+            "breaking: a.enum.mode.later: added",
+            "compatible: a.enum.flags: added",
+            "breaking: b: version changed from 2 to 1",
+            "breaking: b.request.first: opcode changed from 0 to 1",
+            "breaking: b.request.second: opcode changed from 1 to 0",
+            "breaking: c.request.unversioned: added",
+            "breaking: gone: removed",
+            "compatible: added: added",
+        ]
+    );
+    assert!(diff(&old, &old).is_empty());
+
+    let core = wayland::parse(WAYLAND).unwrap();
+    let mut next = core.clone();
+    let surface = next
+        .interfaces
+        .iter_mut()
+        .find(|i| i.name == "wl_surface")
+        .unwrap();
+    surface.version += 1;
+    surface.requests[0].since = 2;
+    let changes = diff(&core, &next);
+    assert_eq!(changes.len(), 2);
+    assert!(!changes[0].is_breaking());
+    assert_eq!(changes[1].path(), "wl_surface.request.destroy");
+    assert_eq!(
+        changes[1].kind(),
+        &ChangeKind::SinceChanged { old: 1, new: 2 }
+    );
+    assert!(changes[1].is_breaking());
+}
@@ -0,0 +1,32 @@
+use txml::writer::Writer;
+use txml::{Event, Parser, Text};
+
+fn write(f: impl FnOnce(&mut Writer<String>) -> std::fmt::Result) -> String {
+    let mut writer = Writer::new(String::new());
+    f(&mut writer).unwrap();
+    writer.into_inner()
+}
+
+#[test]
+fn cdata() {
+    let out = write(|w| w.cdata("a]]>b]]>"));
+    assert_eq!(out, "<![CDATA[a]]]]><![CDATA[>b]]]]><![CDATA[>]]>");
+    let text: String = Parser::new(&out)
+        .map(|event| match event {
+            Event::Text(Text::Verbatim(text)) => text,
+            event => panic!("unexpected {:?}", event),
+        })
+        .collect();
+    assert_eq!(text, "a]]>b]]>");
+}
+
+#[test]
+fn comment() {
+    assert_eq!(write(|w| w.comment("a--b---")), "<!--a- -b- - - -->");
+    assert_eq!(write(|w| w.comment(" ok ")), "<!-- ok -->");
+}
+
+#[test]
+fn pi() {
+    assert_eq!(write(|w| w.pi("x a?>b??")), "<?x a? >b???>");
+}
@@ -0,0 +1,182 @@
+//! Parsing of `#[xml(...)]` attributes.
+
+use syn::{
+    Data, DeriveInput, ExprPath, Fields, GenericArgument, Ident, LitStr, PathArguments, Token, Type,
+};
+
+/// A struct with `#[xml(...)]` attributes.
+pub struct Container<'a> {
+    pub deny_unknown: bool,
+    pub fields: Vec<Field<'a>>,
+}
+
+/// A field of a [`Container`].
+pub struct Field<'a> {
+    pub ident: &'a Ident,
+    pub ty: &'a Type,
+    pub kind: Kind,
+}
+
+/// What a field is read from or written to.
+pub enum Kind {
+    Attr { name: LitStr, default: Default },
+    Child(LitStr),
+    Text,
+    Skip,
+}
+
+/// What to use for a missing attribute.
+pub enum Default {
+    None,
+    Trait,
+    Path(ExprPath),
+}
+
+impl<'a> Container<'a> {
+    pub fn from_input(input: &'a DeriveInput) -> syn::Result<Self> {
+        let mut deny_unknown = false;
+        for attr in &input.attrs {
+            if !attr.path().is_ident("xml") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("deny_unknown") {
+                    deny_unknown = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown xml attribute"))
+                }
+            })?;
+        }
+        let fields = match input.data {
+            Data::Struct(ref data) => match data.fields {
+                Fields::Named(ref fields) => &fields.named,
+                Fields::Unit => {
+                    return Ok(Container {
+                        deny_unknown,
+                        fields: Vec::new(),
+                    })
+                }
+                Fields::Unnamed(_) => {
+                    return Err(syn::Error::new_spanned(
+                        &input.ident,
+                        "tuple structs are not supported",
+                    ))
+                }
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "only structs are supported",
+                ))
+            }
+        };
+        let fields = fields
+            .iter()
+            .map(|field| {
+                let ident = field.ident.as_ref().unwrap();
+                let mut kind = None;
+                let mut default = Default::None;
+                for attr in &field.attrs {
+                    if !attr.path().is_ident("xml") {
+                        continue;
+                    }
+                    attr.parse_nested_meta(|meta| {
+                        let new_kind = if meta.path.is_ident("attr") {
+                            Kind::Attr {
+                                name: meta.value()?.parse()?,
+                                default: Default::None,
+                            }
+                        } else if meta.path.is_ident("child") {
+                            Kind::Child(meta.value()?.parse()?)
+                        } else if meta.path.is_ident("text") {
+                            Kind::Text
+                        } else if meta.path.is_ident("skip") {
+                            Kind::Skip
+                        } else if meta.path.is_ident("default") {
+                            default = if meta.input.peek(Token![=]) {
+                                let path: LitStr = meta.value()?.parse()?;
+                                Default::Path(path.parse()?)
+                            } else {
+                                Default::Trait
+                            };
+                            return Ok(());
+                        } else {
+                            return Err(meta.error("unknown xml attribute"));
+                        };
+                        if kind.is_some() {
+                            return Err(meta.error(
+                                "a field can only have one of `attr`, `child`, `text` and `skip`",
+                            ));
+                        }
+                        kind = Some(new_kind);
+                        Ok(())
+                    })?;
+                }
+                let kind =
+                    match (kind, default) {
+                        (Some(Kind::Attr { name, .. }), default) => Kind::Attr { name, default },
+                        (Some(kind), Default::None) => kind,
+                        (Some(_), _) => {
+                            return Err(syn::Error::new_spanned(
+                                ident,
+                                "`default` can only be used with `attr`",
+                            ))
+                        }
+                        (None, _) => return Err(syn::Error::new_spanned(
+                            ident,
+                            "expected one of `#[xml(attr = \"...\")]`, `#[xml(child = \"...\")]`, \
+                             `#[xml(text)]` and `#[xml(skip)]`",
+                        )),
+                    };
+                Ok(Field {
+                    ident,
+                    ty: &field.ty,
+                    kind,
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        if fields
+            .iter()
+            .filter(|f| matches!(f.kind, Kind::Text))
+            .count()
+            > 1
+        {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "only one field can be `#[xml(text)]`",
+            ));
+        }
+        Ok(Container {
+            deny_unknown,
+            fields,
+        })
+    }
+}
+
+/// Returns `T` if `ty` is `wrapper<T>`, like `Option<T>` or `Vec<T>`.
+pub fn inner_type<'t>(ty: &'t Type, wrapper: &str) -> Option<&'t Type> {
+    let path = match *ty {
+        Type::Path(ref ty) if ty.qself.is_none() => &ty.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => match args.args[0] {
+            GenericArgument::Type(ref ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns whether `ty` is `bool`.
+pub fn is_bool(ty: &Type) -> bool {
+    match *ty {
+        Type::Path(ref ty) => ty.qself.is_none() && ty.path.is_ident("bool"),
+        _ => false,
+    }
+}
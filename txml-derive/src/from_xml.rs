@@ -0,0 +1,188 @@
+//! `#[derive(FromXml)]`.
+
+use crate::attr::{inner_type, is_bool, Container, Default, Kind};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, DeriveInput, GenericParam, Lifetime, LifetimeParam};
+
+pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let container = Container::from_input(input)?;
+    let ident = &input.ident;
+    let mut generics = input.generics.clone();
+    generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeParam::new(Lifetime::new(
+            "'__xml",
+            proc_macro2::Span::call_site(),
+        ))),
+    );
+    let where_clause = generics.make_where_clause();
+    for param in input.generics.type_params() {
+        let param = &param.ident;
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: ::txml::convert::FromXml<'__xml>));
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut attr_names = Vec::new();
+    let mut locals = Vec::new();
+    let mut children = Vec::new();
+    let mut values = Vec::new();
+    let mut has_text = false;
+    for (i, field) in container.fields.iter().enumerate() {
+        let var = format_ident!("__field{}", i);
+        let ty = field.ty;
+        let value = match field.kind {
+            Kind::Attr {
+                ref name,
+                ref default,
+            } => {
+                attr_names.push(name);
+                let optional = inner_type(ty, "Option");
+                let value_ty = optional.unwrap_or(ty);
+                let parse = if is_bool(value_ty) {
+                    quote!(__attrs.parse_bool(#name)?)
+                } else {
+                    quote!(__attrs.parse::<#value_ty>(#name)?)
+                };
+                match (optional, default) {
+                    (Some(_), _) => parse,
+                    (None, Default::None) => {
+                        quote!(#parse.ok_or(::txml::AttrError::Missing(#name))?)
+                    }
+                    (None, Default::Trait) => {
+                        quote!(#parse.unwrap_or_else(::core::default::Default::default))
+                    }
+                    (None, Default::Path(ref path)) => quote!(#parse.unwrap_or_else(#path)),
+                }
+            }
+            Kind::Child(ref name) => {
+                let read = quote! {
+                    ::txml::convert::FromXml::from_xml(__child, __child_attrs, __parser)?
+                };
+                if inner_type(ty, "Vec").is_some() {
+                    locals.push(quote! {
+                        let mut #var: #ty = ::core::default::Default::default();
+                    });
+                    children.push(quote!(#name => #var.push(#read),));
+                    quote!(#var)
+                } else {
+                    let optional = inner_type(ty, "Option");
+                    let value_ty = optional.unwrap_or(ty);
+                    locals.push(quote! {
+                        let mut #var: ::core::option::Option<#value_ty> = ::core::option::Option::None;
+                    });
+                    children.push(quote! {
+                        #name => {
+                            if #var.is_some() {
+                                return ::core::result::Result::Err(
+                                    ::txml::convert::Error::DuplicateElement(__child),
+                                );
+                            }
+                            #var = ::core::option::Option::Some(#read);
+                        }
+                    });
+                    if optional.is_some() {
+                        quote!(#var)
+                    } else {
+                        quote!(#var.ok_or(::txml::convert::Error::MissingElement(#name))?)
+                    }
+                }
+            }
+            Kind::Text => {
+                has_text = true;
+                let optional = inner_type(ty, "Option");
+                let value_ty = optional.unwrap_or(ty);
+                let parse = quote! {
+                    __text
+                        .parse::<#value_ty>()
+                        .map_err(|_| ::txml::convert::Error::InvalidText(__name))?
+                };
+                if optional.is_some() {
+                    quote! {
+                        if __text.is_empty() {
+                            ::core::option::Option::None
+                        } else {
+                            ::core::option::Option::Some(#parse)
+                        }
+                    }
+                } else {
+                    parse
+                }
+            }
+            Kind::Skip => quote!(::core::default::Default::default()),
+        };
+        let field_ident = field.ident;
+        values.push(quote!(#field_ident: #value));
+    }
+
+    let name_param = if has_text { quote!(__name) } else { quote!(_) };
+    let attrs_param = if attr_names.is_empty() && !container.deny_unknown {
+        quote!(_)
+    } else {
+        quote!(__attrs)
+    };
+    let check_attrs = if container.deny_unknown {
+        quote!(__attrs.extract([#(#attr_names),*])?;)
+    } else {
+        quote!()
+    };
+    let text = if has_text {
+        quote!(let mut __text = ::txml::__private::String::new();)
+    } else {
+        quote!()
+    };
+    let on_text = if has_text {
+        quote!(__text.extend(__text_event))
+    } else {
+        quote!(let _ = __text_event;)
+    };
+    let unknown = if container.deny_unknown {
+        quote! {
+            return ::core::result::Result::Err(
+                ::txml::convert::Error::UnexpectedElement(__child),
+            )
+        }
+    } else {
+        quote! {
+            __parser
+                .skip_element()
+                .ok_or(::txml::convert::Error::Malformed)?
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::txml::convert::FromXml<'__xml> for #ident #ty_generics #where_clause {
+            fn from_xml(
+                #name_param: &'__xml str,
+                #attrs_param: ::txml::Attrs<'__xml>,
+                __parser: &mut ::txml::Parser<'__xml>,
+            ) -> ::core::result::Result<Self, ::txml::convert::Error<'__xml>> {
+                #check_attrs
+                #(#locals)*
+                #text
+                loop {
+                    match __parser.next().ok_or(::txml::convert::Error::Malformed)? {
+                        ::txml::Event::Open(__child, __child_attrs) => match __child {
+                            #(#children)*
+                            _ => {
+                                let _ = __child_attrs;
+                                #unknown;
+                            }
+                        },
+                        ::txml::Event::Close(..) => break,
+                        ::txml::Event::Text(__text_event) => {
+                            #on_text;
+                        }
+                        _ => {}
+                    }
+                }
+                ::core::result::Result::Ok(#ident {
+                    #(#values,)*
+                })
+            }
+        }
+    })
+}
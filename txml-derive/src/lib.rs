@@ -0,0 +1,31 @@
+//! Derive macros for [txml](https://docs.rs/txml).
+//!
+//! These are re-exported by txml with its `derive` feature, and are
+//! documented in its `convert` module.
+
+extern crate proc_macro;
+
+mod attr;
+mod from_xml;
+mod to_xml;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `txml::convert::FromXml` for a struct.
+#[proc_macro_derive(FromXml, attributes(xml))]
+pub fn derive_from_xml(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_xml::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `txml::convert::ToXml` for a struct.
+#[proc_macro_derive(ToXml, attributes(xml))]
+pub fn derive_to_xml(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_xml::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
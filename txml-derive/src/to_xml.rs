@@ -0,0 +1,78 @@
+//! `#[derive(ToXml)]`.
+
+use crate::attr::{inner_type, Container, Kind};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let container = Container::from_input(input)?;
+    let ident = &input.ident;
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for param in input.generics.type_params() {
+        let param = &param.ident;
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: ::txml::convert::ToXml));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut attrs = Vec::new();
+    let mut content = Vec::new();
+    for field in &container.fields {
+        let field_ident = field.ident;
+        let ty = field.ty;
+        match field.kind {
+            Kind::Attr { ref name, .. } => attrs.push(if inner_type(ty, "Option").is_some() {
+                quote! {
+                    if let ::core::option::Option::Some(ref __value) = self.#field_ident {
+                        __writer.attr_display(#name, __value)?;
+                    }
+                }
+            } else {
+                quote!(__writer.attr_display(#name, &self.#field_ident)?;)
+            }),
+            Kind::Child(ref name) => content.push(if inner_type(ty, "Vec").is_some() {
+                quote! {
+                    for __value in &self.#field_ident {
+                        ::txml::convert::ToXml::to_xml(__value, #name, __writer)?;
+                    }
+                }
+            } else if inner_type(ty, "Option").is_some() {
+                quote! {
+                    if let ::core::option::Option::Some(ref __value) = self.#field_ident {
+                        ::txml::convert::ToXml::to_xml(__value, #name, __writer)?;
+                    }
+                }
+            } else {
+                quote!(::txml::convert::ToXml::to_xml(&self.#field_ident, #name, __writer)?;)
+            }),
+            Kind::Text => content.push(if inner_type(ty, "Option").is_some() {
+                quote! {
+                    if let ::core::option::Option::Some(ref __value) = self.#field_ident {
+                        __writer.text_display(__value)?;
+                    }
+                }
+            } else {
+                quote!(__writer.text_display(&self.#field_ident)?;)
+            }),
+            Kind::Skip => {}
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::txml::convert::ToXml for #ident #ty_generics #where_clause {
+            fn to_xml<__W: ::core::fmt::Write>(
+                &self,
+                __name: &str,
+                __writer: &mut ::txml::writer::Writer<__W>,
+            ) -> ::core::fmt::Result {
+                __writer.open(__name)?;
+                #(#attrs)*
+                #(#content)*
+                __writer.close(__name)
+            }
+        }
+    })
+}